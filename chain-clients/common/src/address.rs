@@ -0,0 +1,100 @@
+//! 32-byte address encoding shared by the chain clients and services.
+//!
+//! GMP messages carry every address as 32 bytes regardless of the source chain:
+//! EVM addresses (20 bytes) and Move addresses rendered without leading zeros are
+//! left-padded with zeros, matching `bytes32(uint256(uint160(addr)))` on EVM and
+//! BCS-encoded `address` on Move.
+//!
+//! Move renders the same 32-byte address in several ways: the full 64-character
+//! hex form, with leading zeros stripped (event fields and type names such as
+//! `0xa4c::solver_registry::SolverRegistry`), with or without the `0x` prefix,
+//! and in any letter case. [`normalize_move_address`] produces the canonical
+//! string used for REST arguments and comparison; [`move_addresses_match`]
+//! compares two renderings; [`hex_to_bytes32`] yields the raw bytes.
+
+/// Parses a hex address into its left-padded 32-byte form.
+///
+/// Accepts an optional `0x` prefix, any letter case, and odd-length input.
+///
+/// # Arguments
+///
+/// * `value` - Hex address (e.g., a 20-byte EVM address or a Move address)
+///
+/// # Returns
+///
+/// `Some([u8; 32])` with the value right-aligned, or `None` if the input is empty,
+/// is not hex, or is longer than 32 bytes
+pub fn hex_to_bytes32(value: &str) -> Option<[u8; 32]> {
+    let hex = value.strip_prefix("0x").unwrap_or(value);
+    if hex.is_empty() || hex.len() > 64 || !hex.chars().all(|c| c.is_ascii_hexdigit()) {
+        return None;
+    }
+    let padded = format!("{:0>64}", hex);
+    let mut bytes = [0u8; 32];
+    for (i, byte) in bytes.iter_mut().enumerate() {
+        *byte = u8::from_str_radix(&padded[2 * i..2 * i + 2], 16).ok()?;
+    }
+    Some(bytes)
+}
+
+/// Parses a 32-byte field of an `EscrowCreated` event, naming the field on error.
+///
+/// Shared by the EVM and MVM clients when converting escrow events into
+/// `IntentRequirements` payloads.
+///
+/// # Arguments
+///
+/// * `value` - Hex value from the event
+/// * `field` - Event field name, used in the error message
+///
+/// # Returns
+///
+/// The left-padded 32-byte value, or an error naming the field and value
+pub fn event_bytes32(value: &str, field: &str) -> anyhow::Result<[u8; 32]> {
+    hex_to_bytes32(value).ok_or_else(|| {
+        anyhow::anyhow!(
+            "EscrowCreated {} is not a 32-byte hex value: {}",
+            field,
+            value
+        )
+    })
+}
+
+/// Normalizes a Move address to `0x` followed by 64 lowercase hex characters.
+///
+/// Leading zeros are restored, so `0x1`, `1` and the padded form all normalize to
+/// the same string. Input longer than 64 hex characters is not a Move address and
+/// is returned prefixed and lowercased but otherwise unchanged.
+///
+/// # Arguments
+///
+/// * `addr` - Move address (e.g., "0x1", "0xA4C...", or a 64-char unprefixed hex)
+///
+/// # Returns
+///
+/// The canonical `0x`-prefixed, zero-padded, lowercase address
+pub fn normalize_move_address(addr: &str) -> String {
+    let trimmed = addr.trim();
+    let hex = trimmed
+        .strip_prefix("0x")
+        .or_else(|| trimmed.strip_prefix("0X"))
+        .unwrap_or(trimmed);
+    format!("0x{:0>64}", hex.to_lowercase())
+}
+
+/// Compares two Move addresses in canonical form.
+///
+/// Prefixed, unprefixed, zero-stripped, padded, and mixed-case forms of the same
+/// address compare equal.
+///
+/// # Arguments
+///
+/// * `a` - First address
+/// * `b` - Second address
+///
+/// # Returns
+///
+/// `true` if both normalize to the same canonical form
+pub fn move_addresses_match(a: &str, b: &str) -> bool {
+    normalize_move_address(a) == normalize_move_address(b)
+}
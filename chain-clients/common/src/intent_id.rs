@@ -1,3 +1,12 @@
+//! Intent ID normalization shared by the solver, coordinator, and chain clients.
+//!
+//! Chains render the same 32-byte intent ID differently: Move VM may drop leading
+//! zeros, EVM pads to 32 bytes, and some sources omit the `0x` prefix. The canonical
+//! comparison form is produced by [`normalize_intent_id`]: `0x` prefix, lowercase,
+//! leading zeros stripped (the minimal representation, `0x0` for zero). Every
+//! intent ID comparison should go through [`intent_ids_equal`] rather than `==` on
+//! raw strings. [`normalize_intent_id_to_64_chars`] is for parsing/encoding only.
+
 /// Normalizes an intent ID by removing leading zeros after the 0x prefix and converting to lowercase.
 ///
 /// This ensures that intent IDs like "0x0911..." and "0x911..." are treated as the same value.
@@ -32,3 +41,20 @@ pub fn normalize_intent_id_to_64_chars(intent_id: &str) -> String {
     let stripped = intent_id.strip_prefix("0x").unwrap_or(intent_id);
     format!("0x{:0>64}", stripped.to_lowercase())
 }
+
+/// Compares two intent IDs in canonical form.
+///
+/// Padded, unpadded, `0x`-prefixed, unprefixed, and mixed-case forms of the same
+/// intent ID compare equal.
+///
+/// # Arguments
+///
+/// * `a` - First intent ID
+/// * `b` - Second intent ID
+///
+/// # Returns
+///
+/// `true` if both normalize to the same canonical form
+pub fn intent_ids_equal(a: &str, b: &str) -> bool {
+    normalize_intent_id(a) == normalize_intent_id(b)
+}
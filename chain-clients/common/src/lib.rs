@@ -1,3 +1,9 @@
+pub mod address;
 pub mod intent_id;
+pub mod reconnect;
+pub mod rpc_headers;
 
-pub use intent_id::{normalize_intent_id, normalize_intent_id_to_64_chars};
+pub use address::{event_bytes32, hex_to_bytes32, move_addresses_match, normalize_move_address};
+pub use intent_id::{intent_ids_equal, normalize_intent_id, normalize_intent_id_to_64_chars};
+pub use reconnect::{run_with_reconnect, ReconnectBackoff};
+pub use rpc_headers::build_header_map;
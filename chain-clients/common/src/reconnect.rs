@@ -0,0 +1,143 @@
+//! Reconnect loop with exponential backoff for long-lived subscriptions.
+//!
+//! Websocket subscriptions (EVM `eth_subscribe`, Solana `logsSubscribe`, relay
+//! polling streams) drop regularly. [`run_with_reconnect`] re-establishes the
+//! session with exponentially growing, jittered delays capped at a maximum, and
+//! resets the backoff once a session has stayed up for the configured stable
+//! period so a single blip after hours of uptime does not inherit old failures.
+
+use std::collections::hash_map::RandomState;
+use std::fmt::Display;
+use std::future::Future;
+use std::hash::{BuildHasher, Hasher};
+use std::time::{Duration, Instant};
+
+/// Default delay before the first reconnect attempt.
+pub const DEFAULT_INITIAL_BACKOFF: Duration = Duration::from_secs(1);
+
+/// Default upper bound for the reconnect delay.
+pub const DEFAULT_MAX_BACKOFF: Duration = Duration::from_secs(60);
+
+/// Default uptime after which a session counts as stable and the backoff resets.
+pub const DEFAULT_STABLE_AFTER: Duration = Duration::from_secs(30);
+
+/// Exponential backoff state for reconnecting a subscription.
+///
+/// The n-th consecutive failure waits a random delay in `[base / 2, base]`, where
+/// `base = min(initial * 2^n, max)`. Jitter keeps many clients from reconnecting
+/// in lockstep after a shared outage.
+#[derive(Debug, Clone)]
+pub struct ReconnectBackoff {
+    initial: Duration,
+    max: Duration,
+    stable_after: Duration,
+    attempt: u32,
+}
+
+impl ReconnectBackoff {
+    /// Creates a backoff with the given initial delay, maximum delay, and stable period.
+    pub fn new(initial: Duration, max: Duration, stable_after: Duration) -> Self {
+        Self {
+            initial,
+            max,
+            stable_after,
+            attempt: 0,
+        }
+    }
+
+    /// Number of consecutive reconnect attempts since the last reset.
+    pub fn attempt(&self) -> u32 {
+        self.attempt
+    }
+
+    /// Returns the delay before the next reconnect attempt and advances the attempt counter.
+    pub fn next_delay(&mut self) -> Duration {
+        let base = self.base_delay();
+        self.attempt = self.attempt.saturating_add(1);
+
+        let half = base / 2;
+        let jitter_range = (base - half).as_millis() as u64;
+        let jitter = if jitter_range == 0 {
+            0
+        } else {
+            random_u64() % (jitter_range + 1)
+        };
+        half + Duration::from_millis(jitter)
+    }
+
+    /// Records how long the last session stayed connected, resetting the
+    /// backoff if it reached the stable period.
+    pub fn record_session(&mut self, connected_for: Duration) {
+        if connected_for >= self.stable_after {
+            self.reset();
+        }
+    }
+
+    /// Resets the backoff to the initial delay.
+    pub fn reset(&mut self) {
+        self.attempt = 0;
+    }
+
+    fn base_delay(&self) -> Duration {
+        let factor = 2u32.checked_pow(self.attempt).unwrap_or(u32::MAX);
+        self.initial
+            .checked_mul(factor)
+            .map_or(self.max, |delay| delay.min(self.max))
+    }
+}
+
+impl Default for ReconnectBackoff {
+    fn default() -> Self {
+        Self::new(
+            DEFAULT_INITIAL_BACKOFF,
+            DEFAULT_MAX_BACKOFF,
+            DEFAULT_STABLE_AFTER,
+        )
+    }
+}
+
+/// Runs `session` forever, reconnecting with `backoff` whenever it ends.
+///
+/// `session` should connect, consume the subscription, and return when the
+/// connection closes (`Ok`) or fails (`Err`). Either way the loop waits the
+/// next backoff delay and starts a new session. The loop never returns; stop
+/// it by dropping or aborting the future.
+pub async fn run_with_reconnect<F, Fut, E>(
+    label: &str,
+    mut backoff: ReconnectBackoff,
+    mut session: F,
+) where
+    F: FnMut() -> Fut,
+    Fut: Future<Output = Result<(), E>>,
+    E: Display,
+{
+    loop {
+        let started = Instant::now();
+        let result = session().await;
+        backoff.record_session(started.elapsed());
+
+        let delay = backoff.next_delay();
+        match result {
+            Ok(()) => tracing::warn!(
+                "{} subscription closed; reconnecting in {:?} (attempt {})",
+                label,
+                delay,
+                backoff.attempt()
+            ),
+            Err(e) => tracing::warn!(
+                "{} subscription failed: {}; reconnecting in {:?} (attempt {})",
+                label,
+                e,
+                delay,
+                backoff.attempt()
+            ),
+        }
+
+        tokio::time::sleep(delay).await;
+    }
+}
+
+/// Per-call random value from the std hasher's random keys (avoids a `rand` dependency).
+fn random_u64() -> u64 {
+    RandomState::new().build_hasher().finish()
+}
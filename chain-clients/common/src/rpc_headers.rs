@@ -0,0 +1,21 @@
+//! Custom HTTP headers for RPC providers
+//!
+//! Shared by the EVM, MVM and SVM clients and the solver's hub client so every
+//! client applies provider headers (e.g. `x-api-key`) the same way.
+
+use anyhow::{Context, Result};
+use reqwest::header::{HeaderMap, HeaderName, HeaderValue};
+use std::collections::HashMap;
+
+/// Builds a default header map from name/value pairs (e.g. `x-api-key` for hosted RPC providers).
+pub fn build_header_map(headers: &HashMap<String, String>) -> Result<HeaderMap> {
+    let mut map = HeaderMap::new();
+    for (name, value) in headers {
+        let name = HeaderName::from_bytes(name.as_bytes())
+            .with_context(|| format!("Invalid RPC header name: {}", name))?;
+        let value = HeaderValue::from_str(value)
+            .with_context(|| format!("Invalid value for RPC header {}", name))?;
+        map.insert(name, value);
+    }
+    Ok(map)
+}
@@ -0,0 +1,145 @@
+use chain_clients_common::{
+    event_bytes32, hex_to_bytes32, move_addresses_match, normalize_move_address,
+};
+
+const FULL_ADDR: &str = "0x0a4c000000000000000000000000000000000000000000000000000000000001";
+
+// ============================================================================
+// HEX_TO_BYTES32 TESTS
+// ============================================================================
+
+// 1. Test: hex_to_bytes32 left-pads short addresses
+// Verifies that 20-byte EVM addresses and short Move addresses are right-aligned in 32 bytes.
+// Why: GMP payloads carry fixed-width addresses; padding on the wrong side changes the address.
+#[test]
+fn test_hex_to_bytes32_left_pads() {
+    let evm = hex_to_bytes32("0x00000000000000000000000000000000000000Ab").unwrap();
+    assert_eq!(evm[..31], [0u8; 31]);
+    assert_eq!(evm[31], 0xab);
+
+    let move_short = hex_to_bytes32("0x1").unwrap();
+    assert_eq!(move_short[..31], [0u8; 31]);
+    assert_eq!(move_short[31], 0x01);
+
+    let full = hex_to_bytes32(&"ff".repeat(32)).unwrap();
+    assert_eq!(full, [0xff; 32]);
+}
+
+// 2. Test: hex_to_bytes32 rejects invalid input
+// Verifies that empty, non-hex, and over-long values return None.
+// Why: Silently truncating or zero-filling would produce a valid-looking but wrong address.
+#[test]
+fn test_hex_to_bytes32_rejects_invalid() {
+    assert!(hex_to_bytes32("").is_none());
+    assert!(hex_to_bytes32("0x").is_none());
+    assert!(hex_to_bytes32("0xzz").is_none());
+    assert!(hex_to_bytes32(&format!("0x{}", "1".repeat(65))).is_none());
+}
+
+// ============================================================================
+// EVENT_BYTES32 TESTS
+// ============================================================================
+
+// 3. Test: event_bytes32 parses a field and names it on error
+// Verifies that a valid value is left-padded and an invalid value errors with the field name.
+// Why: The EVM and MVM clients rely on the error to identify which EscrowCreated field is bad.
+#[test]
+fn test_event_bytes32_names_field_on_error() {
+    assert_eq!(event_bytes32("0x1", "intent_id").unwrap()[31], 0x01);
+
+    let err = event_bytes32("0xzz", "token_addr").unwrap_err().to_string();
+    assert!(err.contains("token_addr"), "unexpected error: {}", err);
+    assert!(err.contains("0xzz"), "unexpected error: {}", err);
+}
+
+// ============================================================================
+// NORMALIZE_MOVE_ADDRESS TESTS
+// ============================================================================
+
+// 4. Test: normalize_move_address restores leading zeros
+// Verifies that short and zero-stripped addresses are left-padded to 64 hex characters.
+// Why: Move strips leading zeros in events and type names; the REST API and
+// comparisons need the full 32-byte form.
+#[test]
+fn test_normalize_restores_leading_zeros() {
+    assert_eq!(
+        normalize_move_address("0x1"),
+        "0x0000000000000000000000000000000000000000000000000000000000000001"
+    );
+    assert_eq!(
+        normalize_move_address("0xa4c000000000000000000000000000000000000000000000000000000000001"),
+        FULL_ADDR
+    );
+    assert_eq!(normalize_move_address(FULL_ADDR), FULL_ADDR);
+}
+
+// 5. Test: normalize_move_address handles missing prefix and mixed case
+// Verifies that unprefixed, `0X`-prefixed, uppercase, and whitespace-padded input
+// produce the lowercase `0x` form.
+// Why: Addresses arrive from config files, CLI output, and JSON with inconsistent formatting.
+#[test]
+fn test_normalize_prefix_and_case() {
+    assert_eq!(normalize_move_address(&FULL_ADDR[2..]), FULL_ADDR);
+    assert_eq!(normalize_move_address(&FULL_ADDR.to_uppercase()), FULL_ADDR);
+    assert_eq!(
+        normalize_move_address("0XA4C000000000000000000000000000000000000000000000000000000000001"),
+        FULL_ADDR
+    );
+    assert_eq!(
+        normalize_move_address(
+            " 0x0A4C000000000000000000000000000000000000000000000000000000000001 "
+        ),
+        FULL_ADDR
+    );
+}
+
+// 6. Test: normalize_move_address of the zero address
+// Verifies that "0x0", "0x", and "0" all normalize to 64 zeros.
+// Why: The zero address must not normalize to different strings depending on its rendering.
+#[test]
+fn test_normalize_zero_address() {
+    let zero = format!("0x{}", "0".repeat(64));
+    assert_eq!(normalize_move_address("0x0"), zero);
+    assert_eq!(normalize_move_address("0x"), zero);
+    assert_eq!(normalize_move_address("0"), zero);
+}
+
+// ============================================================================
+// MOVE_ADDRESSES_MATCH TESTS
+// ============================================================================
+
+// 7. Test: move_addresses_match is prefix-, leading-zero-, and case-insensitive
+// Verifies that every rendering of the same address matches the canonical form, in both directions.
+// Why: Registry lookups compare addresses from type names, map keys, and user input.
+#[test]
+fn test_matches_equivalent_renderings() {
+    let renderings = [
+        FULL_ADDR.to_string(),
+        FULL_ADDR[2..].to_string(),
+        "0xa4c000000000000000000000000000000000000000000000000000000000001".to_string(),
+        "a4c000000000000000000000000000000000000000000000000000000000001".to_string(),
+        "0xA4C000000000000000000000000000000000000000000000000000000000001".to_string(),
+    ];
+    for a in &renderings {
+        for b in &renderings {
+            assert!(move_addresses_match(a, b), "{} should match {}", a, b);
+        }
+    }
+    assert!(move_addresses_match(
+        "0x1",
+        "0x0000000000000000000000000000000000000000000000000000000000000001"
+    ));
+}
+
+// 8. Test: move_addresses_match distinguishes different addresses
+// Verifies that addresses differing in a digit or in trailing zeros do not match.
+// Why: Only leading zeros are insignificant; stripping trailing zeros would alias distinct accounts.
+#[test]
+fn test_matches_rejects_different_addresses() {
+    assert!(!move_addresses_match("0x1", "0x2"));
+    assert!(!move_addresses_match("0x1", "0x10"));
+    assert!(!move_addresses_match(
+        FULL_ADDR,
+        "0x0a4c000000000000000000000000000000000000000000000000000000000002"
+    ));
+}
@@ -1,4 +1,4 @@
-use chain_clients_common::{normalize_intent_id, normalize_intent_id_to_64_chars};
+use chain_clients_common::{intent_ids_equal, normalize_intent_id, normalize_intent_id_to_64_chars};
 
 // ============================================================================
 // NORMALIZE_INTENT_ID TESTS
@@ -112,3 +112,38 @@ fn test_normalize_intent_id_to_64_chars_no_prefix() {
         "0x0000000000000000000000000000000000000000000000000000000000abcdef"
     );
 }
+
+// ============================================================================
+// INTENT_IDS_EQUAL TESTS
+// ============================================================================
+
+// 8. Test: intent_ids_equal treats padded, unpadded, and prefixed forms as equal
+// Verifies that the 64-char padded, minimal unpadded, unprefixed, and uppercase renderings of the same intent ID all compare equal and share one canonical form.
+// Why: Each chain renders intent IDs differently; comparisons must not depend on which chain produced the string.
+#[test]
+fn test_intent_ids_equal_across_forms() {
+    let padded = "0x00000000000000000000000000000000000000000000000000000000000abc12";
+    let unpadded = "0xabc12";
+    let unprefixed = "abc12";
+    let unprefixed_padded = "000abc12";
+    let uppercase = "0x000ABC12";
+
+    for form in [unpadded, unprefixed, unprefixed_padded, uppercase] {
+        assert!(intent_ids_equal(padded, form), "{} should equal {}", padded, form);
+        assert_eq!(normalize_intent_id(form), "0xabc12");
+    }
+    assert_eq!(
+        normalize_intent_id_to_64_chars(unpadded),
+        normalize_intent_id_to_64_chars(padded)
+    );
+}
+
+// 9. Test: intent_ids_equal distinguishes different intent IDs
+// Verifies that intent IDs differing in a significant digit or by a trailing zero do not compare equal.
+// Why: Normalization must only drop leading zeros, never merge distinct intents.
+#[test]
+fn test_intent_ids_equal_rejects_different_ids() {
+    assert!(!intent_ids_equal("0xabc12", "0xabc13"));
+    assert!(!intent_ids_equal("0xabc12", "0xabc120"));
+    assert!(!intent_ids_equal("0x0", "0x1"));
+}
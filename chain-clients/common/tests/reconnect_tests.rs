@@ -0,0 +1,134 @@
+use chain_clients_common::{run_with_reconnect, ReconnectBackoff};
+use futures_util::StreamExt;
+use std::sync::{Arc, Mutex};
+use std::time::{Duration, Instant};
+use tokio::net::TcpListener;
+
+// ============================================================================
+// RECONNECT BACKOFF TESTS
+// ============================================================================
+
+// 1. Test: Backoff delay is capped at the maximum
+// Verifies that repeated failures never produce a delay above the configured maximum.
+// Why: Without a cap a long outage would push reconnects out by hours after the provider recovers.
+#[test]
+fn test_backoff_capped_at_max() {
+    let max = Duration::from_millis(500);
+    let mut backoff =
+        ReconnectBackoff::new(Duration::from_millis(100), max, Duration::from_secs(30));
+
+    for _ in 0..20 {
+        let delay = backoff.next_delay();
+        assert!(delay <= max, "delay {:?} exceeds max {:?}", delay, max);
+    }
+    assert!(backoff.next_delay() >= max / 2);
+}
+
+// 2. Test: Backoff resets only after a stable session
+// Verifies that record_session resets the attempt counter for sessions at least as long as the stable period, and keeps it otherwise.
+// Why: A connection that drops immediately must keep backing off; one that stayed up must start over from the initial delay.
+#[test]
+fn test_backoff_resets_after_stable_session() {
+    let mut backoff = ReconnectBackoff::new(
+        Duration::from_millis(100),
+        Duration::from_secs(10),
+        Duration::from_secs(30),
+    );
+    backoff.next_delay();
+    backoff.next_delay();
+    assert_eq!(backoff.attempt(), 2);
+
+    backoff.record_session(Duration::from_secs(1));
+    assert_eq!(backoff.attempt(), 2, "short session must not reset backoff");
+
+    backoff.record_session(Duration::from_secs(30));
+    assert_eq!(backoff.attempt(), 0, "stable session must reset backoff");
+    assert!(backoff.next_delay() <= Duration::from_millis(100));
+}
+
+// 3. Test: run_with_reconnect backs off against a dropping ws server and resets after a stable connection
+// Verifies that reconnect delays grow while a mock websocket server drops connections, and fall back to the initial delay after a connection stays up past the stable period.
+// Why: Subscription paths must neither hot-loop on a dead endpoint nor stay slow after it recovers.
+#[tokio::test]
+async fn test_reconnect_backoff_with_ws_server() {
+    let listener = TcpListener::bind("127.0.0.1:0").await.unwrap();
+    let url = format!("ws://{}", listener.local_addr().unwrap());
+
+    // Server script: drop the first 4 connections, hold the 5th open past the
+    // stable period, then drop everything after.
+    tokio::spawn(async move {
+        let mut connection = 0;
+        loop {
+            let (stream, _) = listener.accept().await.unwrap();
+            connection += 1;
+            if connection == 5 {
+                let mut ws = tokio_tungstenite::accept_async(stream).await.unwrap();
+                tokio::time::sleep(Duration::from_millis(150)).await;
+                ws.close(None).await.ok();
+            } else {
+                drop(stream);
+            }
+        }
+    });
+
+    // (start, end) of every session
+    let sessions: Arc<Mutex<Vec<(Instant, Instant)>>> = Arc::new(Mutex::new(Vec::new()));
+    let backoff = ReconnectBackoff::new(
+        Duration::from_millis(20),
+        Duration::from_secs(1),
+        Duration::from_millis(100),
+    );
+
+    let recorded = sessions.clone();
+    let task = tokio::spawn(async move {
+        run_with_reconnect("test-ws", backoff, || {
+            let url = url.clone();
+            let recorded = recorded.clone();
+            async move {
+                let start = Instant::now();
+                let result = match tokio_tungstenite::connect_async(&url).await {
+                    Ok((mut ws, _)) => {
+                        while let Some(Ok(_)) = ws.next().await {}
+                        Ok(())
+                    }
+                    Err(e) => Err(e),
+                };
+                recorded.lock().unwrap().push((start, Instant::now()));
+                result
+            }
+        })
+        .await
+    });
+
+    while sessions.lock().unwrap().len() < 7 {
+        tokio::time::sleep(Duration::from_millis(10)).await;
+    }
+    task.abort();
+
+    let sessions = sessions.lock().unwrap().clone();
+    let delays: Vec<Duration> = sessions
+        .windows(2)
+        .map(|w| w[1].0.duration_since(w[0].1))
+        .collect();
+
+    // Delays after the 4 dropped connections: [10,20], [20,40], [40,80], [80,160] ms
+    let min_delays = [10, 20, 40, 80];
+    for (i, min_ms) in min_delays.iter().enumerate() {
+        assert!(
+            delays[i] >= Duration::from_millis(*min_ms),
+            "delay {} was {:?}, expected at least {}ms",
+            i,
+            delays[i],
+            min_ms
+        );
+    }
+    assert!(delays[3] > delays[0], "backoff must grow across failures");
+
+    // Session 5 stayed up past the stable period, so the next delay restarts at [10,20] ms
+    assert!(sessions[4].1.duration_since(sessions[4].0) >= Duration::from_millis(100));
+    assert!(
+        delays[4] < Duration::from_millis(80),
+        "backoff must reset after a stable session, got {:?}",
+        delays[4]
+    );
+}
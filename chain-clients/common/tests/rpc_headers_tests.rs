@@ -0,0 +1,37 @@
+use chain_clients_common::build_header_map;
+use std::collections::HashMap;
+
+// ============================================================================
+// BUILD_HEADER_MAP TESTS
+// ============================================================================
+
+// 1. Test: build_header_map keeps every configured header
+// Verifies that each name/value pair ends up in the header map, and that no headers gives an empty map.
+// Why: Hosted RPC providers authenticate via headers; a dropped header would fail every call.
+#[test]
+fn test_build_header_map_keeps_headers() {
+    let headers = HashMap::from([
+        ("x-api-key".to_string(), "test-key".to_string()),
+        ("X-Client".to_string(), "solver".to_string()),
+    ]);
+    let map = build_header_map(&headers).unwrap();
+    assert_eq!(map.len(), 2);
+    assert_eq!(map["x-api-key"], "test-key");
+    assert_eq!(map["x-client"], "solver");
+
+    assert!(build_header_map(&HashMap::new()).unwrap().is_empty());
+}
+
+// 2. Test: build_header_map rejects invalid names and values
+// Verifies that a header name with a space and a value with a newline are errors naming the header.
+// Why: A malformed header from config should fail at client construction, not on the first request.
+#[test]
+fn test_build_header_map_rejects_invalid() {
+    let bad_name = HashMap::from([("x api key".to_string(), "v".to_string())]);
+    let err = build_header_map(&bad_name).unwrap_err();
+    assert!(err.to_string().contains("Invalid RPC header name"));
+
+    let bad_value = HashMap::from([("x-api-key".to_string(), "a\nb".to_string())]);
+    let err = build_header_map(&bad_value).unwrap_err();
+    assert!(err.to_string().contains("x-api-key"));
+}
@@ -4,13 +4,39 @@
 //! via their JSON-RPC API. Used by coordinator, integrated-gmp, and solver.
 
 use anyhow::{Context, Result};
+use chain_clients_common::build_header_map;
 use reqwest::Client;
 use sha3::{Digest, Keccak256};
+use std::collections::HashMap;
 use std::time::Duration;
 
-use crate::types::{EscrowCreatedEvent, EvmLog};
+use crate::error::EvmRpcError;
+use crate::types::{
+    EscrowCancelledEvent, EscrowClaimedEvent, EscrowCreatedEvent, EvmLog, EvmTransaction,
+};
+
+/// Default widest block range requested in one eth_getLogs call.
+///
+/// Most hosted RPC providers reject wider ranges.
+pub const DEFAULT_MAX_BLOCK_SPAN: u64 = 2000;
+
+/// What a chunked eth_getLogs query does when one block-range chunk fails
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum ChunkFailureStrategy {
+    /// Return the chunk's error and discard the events already collected
+    Bail,
+    /// Stop at the failed chunk and return the events from the chunks before it.
+    /// The results stay contiguous from `from_block`, so callers can resume after
+    /// the last block they saw.
+    ReturnPartial,
+}
+
+/// Solidity signature of the escrow contract's EscrowCreated event
+pub(crate) const ESCROW_CREATED_SIGNATURE: &str =
+    "EscrowCreated(bytes32,bytes32,address,uint64,address,bytes32,uint64)";
 
 /// Client for communicating with EVM-compatible blockchain nodes via JSON-RPC
+#[derive(Clone)]
 pub struct EvmClient {
     /// HTTP client for making requests
     client: Client,
@@ -18,14 +44,30 @@ pub struct EvmClient {
     base_url: String,
     /// Escrow contract address
     escrow_contract_addr: String,
+    /// Widest block range requested in one eth_getLogs call
+    max_block_span: u64,
+    /// WebSocket endpoint for subscriptions (None = derived from `base_url`)
+    ws_url: Option<String>,
 }
 
 impl EvmClient {
     /// Creates a new EVM client for the given node URL and escrow contract address.
     pub fn new(node_url: &str, escrow_contract_addr: &str) -> Result<Self> {
+        Self::new_with_headers(node_url, escrow_contract_addr, &HashMap::new())
+    }
+
+    /// Creates a new EVM client that sends `headers` with every RPC request.
+    ///
+    /// Used for RPC providers that authenticate via a header (e.g. `Authorization`, `x-api-key`).
+    pub fn new_with_headers(
+        node_url: &str,
+        escrow_contract_addr: &str,
+        headers: &HashMap<String, String>,
+    ) -> Result<Self> {
         let client = Client::builder()
             .timeout(Duration::from_secs(30))
             .no_proxy() // Avoid macOS system-configuration issues in tests
+            .default_headers(build_header_map(headers)?)
             .build()
             .context("Failed to create HTTP client")?;
 
@@ -33,9 +75,27 @@ impl EvmClient {
             client,
             base_url: node_url.to_string(),
             escrow_contract_addr: escrow_contract_addr.to_string(),
+            max_block_span: DEFAULT_MAX_BLOCK_SPAN,
+            ws_url: None,
         })
     }
 
+    /// Sets the widest block range requested in one eth_getLogs call (minimum 1).
+    ///
+    /// Escrow event queries split wider `[from_block, to_block]` ranges into chunks.
+    pub fn with_max_block_span(mut self, max_block_span: u64) -> Self {
+        self.max_block_span = max_block_span.max(1);
+        self
+    }
+
+    /// Sets the WebSocket endpoint used for `eth_subscribe` subscriptions.
+    ///
+    /// Needed when the node serves WebSocket on a different host or port than HTTP.
+    pub fn with_ws_url(mut self, ws_url: &str) -> Self {
+        self.ws_url = Some(ws_url.to_string());
+        self
+    }
+
     /// Creates a new EVM client without an escrow contract address.
     /// Used by consumers that only need generic RPC access (e.g., GmpEvmClient).
     pub fn new_rpc_only(node_url: &str) -> Result<Self> {
@@ -52,11 +112,35 @@ impl EvmClient {
         &self.escrow_contract_addr
     }
 
+    /// Returns the widest block range requested in one eth_getLogs call
+    pub fn max_block_span(&self) -> u64 {
+        self.max_block_span
+    }
+
+    /// Returns the WebSocket endpoint for subscriptions.
+    ///
+    /// Defaults to the HTTP URL with its scheme switched to `ws://` / `wss://`.
+    pub fn ws_url(&self) -> String {
+        if let Some(ws_url) = &self.ws_url {
+            return ws_url.clone();
+        }
+        if let Some(rest) = self.base_url.strip_prefix("https://") {
+            format!("wss://{}", rest)
+        } else if let Some(rest) = self.base_url.strip_prefix("http://") {
+            format!("ws://{}", rest)
+        } else {
+            self.base_url.clone()
+        }
+    }
+
     // ========================================================================
     // Generic JSON-RPC
     // ========================================================================
 
     /// Generic JSON-RPC call with 15-second timeout.
+    ///
+    /// JSON-RPC error responses carry an [`EvmRpcError`], reachable via
+    /// `err.downcast_ref::<EvmRpcError>()`.
     pub async fn json_rpc<T: serde::de::DeserializeOwned>(
         &self,
         method: &str,
@@ -103,13 +187,12 @@ impl EvmClient {
                 .get("message")
                 .and_then(|m| m.as_str())
                 .unwrap_or("unknown error");
-            anyhow::bail!(
-                "JSON-RPC error from {} ({}): {} (code: {})",
-                self.base_url,
-                method,
-                message,
-                code
-            );
+            return Err(anyhow::Error::new(EvmRpcError::from_code(code, message)).context(
+                format!(
+                    "JSON-RPC error from {} ({}): {} (code: {})",
+                    self.base_url, method, message, code
+                ),
+            ));
         }
 
         let result = response
@@ -133,6 +216,22 @@ impl EvmClient {
         u64::from_str_radix(clean, 16).context("Failed to parse block number")
     }
 
+    /// Gets the timestamp (seconds) of the latest block via eth_getBlockByNumber
+    pub async fn get_latest_block_timestamp(&self) -> Result<u64> {
+        let block: serde_json::Value = self
+            .json_rpc(
+                "eth_getBlockByNumber",
+                vec![serde_json::json!("latest"), serde_json::json!(false)],
+            )
+            .await?;
+        let hex = block
+            .get("timestamp")
+            .and_then(|v| v.as_str())
+            .context("Latest block missing timestamp")?;
+        let clean = hex.strip_prefix("0x").unwrap_or(hex);
+        u64::from_str_radix(clean, 16).context("Failed to parse block timestamp")
+    }
+
     /// Generic eth_call to a contract.
     pub async fn eth_call(&self, to: &str, data: &str) -> Result<String> {
         self.json_rpc(
@@ -190,69 +289,137 @@ impl EvmClient {
         .await
     }
 
+    /// Get the transaction at `index` within block `block_number`, returns None
+    /// if the block or index does not exist.
+    ///
+    /// Used to resolve calldata when only a log's block/index position is known.
+    pub async fn get_transaction_by_block_number_and_index(
+        &self,
+        block_number: u64,
+        index: u64,
+    ) -> Result<Option<EvmTransaction>> {
+        self.json_rpc(
+            "eth_getTransactionByBlockNumberAndIndex",
+            vec![
+                serde_json::json!(format!("0x{:x}", block_number)),
+                serde_json::json!(format!("0x{:x}", index)),
+            ],
+        )
+        .await
+    }
+
     // ========================================================================
     // Escrow-specific methods (require escrow_contract_addr)
     // ========================================================================
 
     /// Queries EVM chain for EscrowCreated events via eth_getLogs
+    ///
+    /// A numeric range wider than `max_block_span` is queried in sequential chunks and
+    /// the results are concatenated in block order; `on_chunk_error` decides what a
+    /// failed chunk does.
     pub async fn get_escrow_created_events(
         &self,
         from_block: Option<u64>,
         to_block: Option<u64>,
+        on_chunk_error: ChunkFailureStrategy,
     ) -> Result<Vec<EscrowCreatedEvent>> {
-        let signature_string =
-            "EscrowCreated(bytes32,bytes32,address,uint64,address,bytes32,uint64)";
-        let mut hasher = Keccak256::new();
-        hasher.update(signature_string.as_bytes());
-        let hash = hasher.finalize();
-        let event_signature = format!("0x{}", hex::encode(hash));
+        let logs = self
+            .get_escrow_logs(
+                ESCROW_CREATED_SIGNATURE,
+                from_block,
+                to_block,
+                on_chunk_error,
+            )
+            .await?;
+        let mut events = Vec::new();
 
-        let from_block_str = from_block
-            .map(|n| format!("0x{:x}", n))
-            .unwrap_or_else(|| "latest".to_string());
-        let to_block_str = to_block
-            .map(|n| format!("0x{:x}", n))
-            .unwrap_or_else(|| "latest".to_string());
+        for log in logs {
+            if let Some(event) = parse_escrow_created_log(log)? {
+                events.push(event);
+            }
+        }
 
-        let filter = serde_json::json!({
-            "address": self.escrow_contract_addr,
-            "topics": [event_signature],
-            "fromBlock": from_block_str,
-            "toBlock": to_block_str,
-        });
+        Ok(events)
+    }
+
+    /// Queries EVM chain for EscrowReleased events (escrow claimed by the solver) via eth_getLogs
+    pub async fn get_escrow_claimed_events(
+        &self,
+        from_block: Option<u64>,
+        to_block: Option<u64>,
+    ) -> Result<Vec<EscrowClaimedEvent>> {
+        let logs = self
+            .get_escrow_logs(
+                "EscrowReleased(bytes32,address,uint64)",
+                from_block,
+                to_block,
+                ChunkFailureStrategy::Bail,
+            )
+            .await?;
+        let mut events = Vec::new();
+
+        for log in logs {
+            if log.topics.len() < 3 {
+                continue;
+            }
+
+            let intent_id = log.topics[1].clone();
+            let solver_addr = format!("0x{}", &log.topics[2][26..]);
+
+            let data = log.data.strip_prefix("0x").unwrap_or(&log.data);
+            if data.len() < 64 {
+                continue;
+            }
+
+            let amount = decode_u64_word(&data[0..64], "claimed amount")?;
+
+            events.push(EscrowClaimedEvent {
+                intent_id,
+                solver_addr,
+                amount,
+                block_number: log.block_number,
+                transaction_hash: log.transaction_hash,
+            });
+        }
+
+        Ok(events)
+    }
 
-        let logs: Vec<EvmLog> = self.get_logs(filter).await?;
+    /// Queries EVM chain for EscrowCancelled events via eth_getLogs
+    pub async fn get_escrow_cancelled_events(
+        &self,
+        from_block: Option<u64>,
+        to_block: Option<u64>,
+    ) -> Result<Vec<EscrowCancelledEvent>> {
+        let logs = self
+            .get_escrow_logs(
+                "EscrowCancelled(bytes32,address,uint64)",
+                from_block,
+                to_block,
+                ChunkFailureStrategy::Bail,
+            )
+            .await?;
         let mut events = Vec::new();
 
         for log in logs {
-            if log.topics.len() < 4 {
+            if log.topics.len() < 3 {
                 continue;
             }
 
             let intent_id = log.topics[1].clone();
             let requester_addr = format!("0x{}", &log.topics[2][26..]);
-            let token_addr = format!("0x{}", &log.topics[3][26..]);
 
             let data = log.data.strip_prefix("0x").unwrap_or(&log.data);
-            if data.len() < 256 {
+            if data.len() < 64 {
                 continue;
             }
 
-            let escrow_id = format!("0x{}", &data[0..64]);
-            let amount = u64::from_str_radix(&data[112..128], 16)
-                .context("Failed to parse escrow amount from EVM log data")?;
-            let reserved_solver = format!("0x{}", &data[128..192]);
-            let expiry = u64::from_str_radix(&data[240..256], 16)
-                .context("Failed to parse escrow expiry from EVM log data")?;
+            let amount = decode_u64_word(&data[0..64], "cancelled amount")?;
 
-            events.push(EscrowCreatedEvent {
+            events.push(EscrowCancelledEvent {
                 intent_id,
-                escrow_id,
                 requester_addr,
                 amount,
-                token_addr,
-                reserved_solver,
-                expiry,
                 block_number: log.block_number,
                 transaction_hash: log.transaction_hash,
             });
@@ -261,6 +428,88 @@ impl EvmClient {
         Ok(events)
     }
 
+    /// Fetches escrow contract logs for `signature`, splitting a numeric block range
+    /// wider than `max_block_span` into sequential eth_getLogs calls.
+    ///
+    /// Open-ended ranges ("latest" on either side) are sent as a single call.
+    async fn get_escrow_logs(
+        &self,
+        signature: &str,
+        from_block: Option<u64>,
+        to_block: Option<u64>,
+        on_chunk_error: ChunkFailureStrategy,
+    ) -> Result<Vec<EvmLog>> {
+        let (from, to) = match (from_block, to_block) {
+            (Some(from), Some(to)) if to >= from => (from, to),
+            _ => {
+                let filter = self.escrow_event_filter(signature, from_block, to_block);
+                return self.get_logs(filter).await;
+            }
+        };
+
+        let mut logs = Vec::new();
+        let mut chunk_start = from;
+        loop {
+            let chunk_end = chunk_start
+                .saturating_add(self.max_block_span - 1)
+                .min(to);
+            let filter =
+                self.escrow_event_filter(signature, Some(chunk_start), Some(chunk_end));
+            match self.get_logs(filter).await {
+                Ok(chunk_logs) => logs.extend(chunk_logs),
+                Err(e) => match on_chunk_error {
+                    ChunkFailureStrategy::Bail => {
+                        return Err(e.context(format!(
+                            "eth_getLogs failed for blocks {}..={}",
+                            chunk_start, chunk_end
+                        )));
+                    }
+                    ChunkFailureStrategy::ReturnPartial => {
+                        tracing::warn!(
+                            "eth_getLogs failed for blocks {}..={}, returning logs before block {}: {:#}",
+                            chunk_start,
+                            chunk_end,
+                            chunk_start,
+                            e
+                        );
+                        break;
+                    }
+                },
+            }
+            if chunk_end == to {
+                break;
+            }
+            chunk_start = chunk_end + 1;
+        }
+        Ok(logs)
+    }
+
+    /// Builds an eth_getLogs filter for an escrow contract event.
+    ///
+    /// Block bounds default to "latest" when not provided.
+    fn escrow_event_filter(
+        &self,
+        signature: &str,
+        from_block: Option<u64>,
+        to_block: Option<u64>,
+    ) -> serde_json::Value {
+        let event_signature = event_signature_hash(signature);
+
+        let from_block_str = from_block
+            .map(|n| format!("0x{:x}", n))
+            .unwrap_or_else(|| "latest".to_string());
+        let to_block_str = to_block
+            .map(|n| format!("0x{:x}", n))
+            .unwrap_or_else(|| "latest".to_string());
+
+        serde_json::json!({
+            "address": self.escrow_contract_addr,
+            "topics": [event_signature],
+            "fromBlock": from_block_str,
+            "toBlock": to_block_str,
+        })
+    }
+
     /// Queries the ERC20 balance of an account via eth_call balanceOf(address)
     pub async fn get_token_balance(
         &self,
@@ -326,6 +575,67 @@ impl EvmClient {
         Ok(balance)
     }
 
+    /// Checks whether an address is an ERC20 token via eth_call decimals()
+    ///
+    /// Returns `Ok(false)` when the call returns no data (no contract at the address)
+    /// or fails with a non-transient JSON-RPC error such as a revert. Transport and
+    /// rate-limit errors are returned as errors so callers can retry.
+    pub async fn is_erc20_token(&self, token_addr: &str) -> Result<bool> {
+        let token_normalized = normalize_evm_address(token_addr)?;
+
+        // decimals() selector: 0x313ce567
+        let result = match self.eth_call(&token_normalized, "0x313ce567").await {
+            Ok(result) => result,
+            Err(e) => match e.downcast_ref::<EvmRpcError>() {
+                Some(rpc_err) if !rpc_err.is_transient() => return Ok(false),
+                _ => return Err(e.context("Failed eth_call for decimals")),
+            },
+        };
+
+        // ABI uint8: one 32-byte word
+        let clean = result.strip_prefix("0x").unwrap_or(&result);
+        Ok(clean.len() >= 64)
+    }
+
+    /// Queries the decimals of an ERC20 token via eth_call decimals()
+    ///
+    /// Fails with a "not an ERC20 token" error when the call returns no data (no
+    /// contract at the address) or reverts. Transport and rate-limit errors are
+    /// returned unchanged so callers can retry.
+    pub async fn get_token_decimals(&self, token_addr: &str) -> Result<u8> {
+        let token_normalized = normalize_evm_address(token_addr)?;
+
+        // decimals() selector: 0x313ce567
+        let result = match self.eth_call(&token_normalized, "0x313ce567").await {
+            Ok(result) => result,
+            Err(e) => match e.downcast_ref::<EvmRpcError>() {
+                Some(rpc_err) if !rpc_err.is_transient() => anyhow::bail!(
+                    "{} is not an ERC20 token: decimals() failed: {}",
+                    token_normalized,
+                    rpc_err
+                ),
+                _ => return Err(e.context("Failed eth_call for decimals")),
+            },
+        };
+
+        // ABI uint8: one 32-byte word
+        let clean = result.strip_prefix("0x").unwrap_or(&result);
+        if clean.len() < 64 {
+            anyhow::bail!(
+                "{} is not an ERC20 token: decimals() returned no data",
+                token_normalized
+            );
+        }
+        if clean[..62].chars().any(|c| c != '0') {
+            anyhow::bail!(
+                "decimals() of {} does not fit in a uint8: 0x{}",
+                token_normalized,
+                &clean[..64]
+            );
+        }
+        u8::from_str_radix(&clean[62..64], 16).context("Failed to parse decimals from hex")
+    }
+
     /// Checks if an inflow escrow has been released via eth_call isReleased(bytes32)
     pub async fn is_escrow_released(&self, intent_id: &str) -> Result<bool> {
         // Function selector: keccak256("isReleased(bytes32)")[0:4]
@@ -347,6 +657,64 @@ impl EvmClient {
     }
 }
 
+/// Keccak-256 topic hash of a Solidity event signature, 0x-prefixed.
+pub(crate) fn event_signature_hash(signature: &str) -> String {
+    let mut hasher = Keccak256::new();
+    hasher.update(signature.as_bytes());
+    format!("0x{}", hex::encode(hasher.finalize()))
+}
+
+/// Parse an EscrowCreated log into an [`EscrowCreatedEvent`].
+///
+/// Returns `Ok(None)` for logs with too few topics or too little data to be an
+/// EscrowCreated event, and an error for `uint64` fields that overflow.
+pub(crate) fn parse_escrow_created_log(log: EvmLog) -> Result<Option<EscrowCreatedEvent>> {
+    if log.topics.len() < 4 {
+        return Ok(None);
+    }
+
+    let intent_id = log.topics[1].clone();
+    let requester_addr = format!("0x{}", &log.topics[2][26..]);
+    let token_addr = format!("0x{}", &log.topics[3][26..]);
+
+    let data = log.data.strip_prefix("0x").unwrap_or(&log.data);
+    if data.len() < 256 {
+        return Ok(None);
+    }
+
+    let escrow_id = format!("0x{}", &data[0..64]);
+    let amount = decode_u64_word(&data[64..128], "escrow amount")?;
+    let reserved_solver = format!("0x{}", &data[128..192]);
+    let expiry = decode_u64_word(&data[192..256], "escrow expiry")?;
+
+    Ok(Some(EscrowCreatedEvent {
+        intent_id,
+        escrow_id,
+        requester_addr,
+        amount,
+        token_addr,
+        reserved_solver,
+        expiry,
+        block_number: log.block_number,
+        transaction_hash: log.transaction_hash,
+    }))
+}
+
+/// Decode a 32-byte ABI word (64 hex chars) holding a `uint64` log field.
+///
+/// The value sits in the low 8 bytes; the upper 24 bytes must be zero. A word
+/// with non-zero upper bytes is rejected instead of silently truncated.
+fn decode_u64_word(word: &str, field: &str) -> Result<u64> {
+    if word.len() != 64 {
+        anyhow::bail!("EVM log {} is not a 32-byte word: 0x{}", field, word);
+    }
+    if !word[..48].chars().all(|c| c == '0') {
+        anyhow::bail!("EVM log {} exceeds u64: 0x{}", field, word);
+    }
+    u64::from_str_radix(&word[48..], 16)
+        .with_context(|| format!("Failed to parse {} from EVM log data", field))
+}
+
 /// Normalize an EVM address that may be 32-byte padded (for Move compatibility) to 20 bytes.
 ///
 /// Addresses in solver configs may be stored as 32-byte hex (64 chars) for cross-chain
@@ -0,0 +1,94 @@
+//! Typed JSON-RPC errors
+//!
+//! `EvmClient::json_rpc` attaches an [`EvmRpcError`] to every JSON-RPC error
+//! response. Callers recover it with `err.downcast_ref::<EvmRpcError>()` and
+//! check [`EvmRpcError::is_transient`] to decide whether to retry, without
+//! matching on provider-specific message strings.
+
+/// JSON-RPC error code: the method does not exist or is not available.
+pub const METHOD_NOT_FOUND: i64 = -32601;
+
+/// JSON-RPC error code: invalid method parameter(s).
+pub const INVALID_PARAMS: i64 = -32602;
+
+/// JSON-RPC error code (EIP-1474): request exceeds a provider limit.
+pub const LIMIT_EXCEEDED: i64 = -32005;
+
+/// JSON-RPC error code: generic server error. Providers also use it for
+/// rate limits, which are told apart by the message.
+pub const SERVER_ERROR: i64 = -32000;
+
+/// Classified JSON-RPC error returned by an EVM node.
+///
+/// Every variant preserves the raw `code` and `message` from the response.
+#[derive(Debug, Clone, PartialEq, Eq, thiserror::Error)]
+pub enum EvmRpcError {
+    /// The node does not support the method (-32601)
+    #[error("method not found: {message} (code: {code})")]
+    MethodNotFound { code: i64, message: String },
+    /// The request parameters were rejected, e.g. an invalid log filter (-32602)
+    #[error("invalid params: {message} (code: {code})")]
+    InvalidParams { code: i64, message: String },
+    /// Rate limit or request limit exceeded (-32005, or -32000 with a limit message)
+    #[error("limit exceeded: {message} (code: {code})")]
+    LimitExceeded { code: i64, message: String },
+    /// Any other server error (-32000 without a limit message, e.g. execution reverted)
+    #[error("server error: {message} (code: {code})")]
+    Server { code: i64, message: String },
+    /// Any other error code
+    #[error("{message} (code: {code})")]
+    Other { code: i64, message: String },
+}
+
+impl EvmRpcError {
+    /// Classifies a JSON-RPC error object by its code (and message, for -32000).
+    pub fn from_code(code: i64, message: &str) -> Self {
+        let message = message.to_string();
+        match code {
+            METHOD_NOT_FOUND => Self::MethodNotFound { code, message },
+            INVALID_PARAMS => Self::InvalidParams { code, message },
+            LIMIT_EXCEEDED => Self::LimitExceeded { code, message },
+            SERVER_ERROR if is_limit_message(&message) => Self::LimitExceeded { code, message },
+            SERVER_ERROR => Self::Server { code, message },
+            _ => Self::Other { code, message },
+        }
+    }
+
+    /// Returns whether retrying the same request later may succeed.
+    ///
+    /// Only limit errors are transient; method-not-found, invalid params, and
+    /// server errors such as reverts fail the same way on every retry.
+    pub fn is_transient(&self) -> bool {
+        matches!(self, Self::LimitExceeded { .. })
+    }
+
+    /// Raw JSON-RPC error code.
+    pub fn code(&self) -> i64 {
+        match self {
+            Self::MethodNotFound { code, .. }
+            | Self::InvalidParams { code, .. }
+            | Self::LimitExceeded { code, .. }
+            | Self::Server { code, .. }
+            | Self::Other { code, .. } => *code,
+        }
+    }
+
+    /// Raw JSON-RPC error message.
+    pub fn message(&self) -> &str {
+        match self {
+            Self::MethodNotFound { message, .. }
+            | Self::InvalidParams { message, .. }
+            | Self::LimitExceeded { message, .. }
+            | Self::Server { message, .. }
+            | Self::Other { message, .. } => message,
+        }
+    }
+}
+
+/// Whether a -32000 message reports a rate or request limit.
+fn is_limit_message(message: &str) -> bool {
+    let message = message.to_lowercase();
+    ["rate limit", "limit exceeded", "too many requests", "capacity exceeded"]
+        .iter()
+        .any(|pattern| message.contains(pattern))
+}
@@ -3,9 +3,18 @@
 //! Shared EVM JSON-RPC client used by coordinator, integrated-gmp, and solver.
 
 pub mod client;
+pub mod error;
+#[cfg(feature = "ws")]
+pub mod subscription;
 pub mod types;
 
-pub use client::{normalize_evm_address, EvmClient};
+pub use client::{
+    normalize_evm_address, ChunkFailureStrategy, EvmClient, DEFAULT_MAX_BLOCK_SPAN,
+};
+pub use error::EvmRpcError;
+#[cfg(feature = "ws")]
+pub use subscription::ESCROW_SUBSCRIPTION_BUFFER;
 pub use types::{
-    EscrowCreatedEvent, EvmLog, EvmTransaction, JsonRpcError, JsonRpcRequest, JsonRpcResponse,
+    EscrowCancelledEvent, EscrowClaimedEvent, EscrowCreatedEvent, EvmLog, EvmTransaction,
+    JsonRpcError, JsonRpcRequest, JsonRpcResponse,
 };
@@ -0,0 +1,193 @@
+//! WebSocket escrow event subscriptions (`ws` feature)
+//!
+//! Streams EscrowCreated events pushed by the node through `eth_subscribe("logs")`
+//! instead of polling eth_getLogs. Dropped connections are re-established by
+//! [`run_with_reconnect`], and every (re)connect backfills the blocks missed
+//! while disconnected with eth_getLogs.
+
+use anyhow::{Context, Result};
+use chain_clients_common::{run_with_reconnect, ReconnectBackoff};
+use futures_util::{SinkExt, StreamExt};
+use std::sync::{Arc, Mutex};
+use tokio::net::TcpStream;
+use tokio::sync::mpsc;
+use tokio_tungstenite::tungstenite::Message;
+use tokio_tungstenite::{MaybeTlsStream, WebSocketStream};
+
+use crate::client::{
+    event_signature_hash, parse_escrow_created_log, ChunkFailureStrategy, EvmClient,
+    ESCROW_CREATED_SIGNATURE,
+};
+use crate::error::EvmRpcError;
+use crate::types::{EscrowCreatedEvent, EvmLog};
+
+/// Events buffered on a subscription channel before the stream waits for the receiver.
+pub const ESCROW_SUBSCRIPTION_BUFFER: usize = 256;
+
+type WsStream = WebSocketStream<MaybeTlsStream<TcpStream>>;
+
+impl EvmClient {
+    /// Subscribes to EscrowCreated events over the node's WebSocket endpoint.
+    ///
+    /// Spawns a background task (requires a Tokio runtime) that connects to
+    /// [`EvmClient::ws_url`], sends `eth_subscribe("logs", filter)` for the escrow
+    /// contract, and forwards each decoded event on the returned channel. The task
+    /// reconnects with [`ReconnectBackoff::default`] whenever the connection drops
+    /// and stops once the receiver is dropped.
+    ///
+    /// After every connect the blocks since the last delivered event are backfilled
+    /// with eth_getLogs, starting at `from_block` on the first connect (`None` =
+    /// only events from the current head on). Delivery is at-least-once: events in
+    /// the block being streamed when a connection drops are delivered again. Logs
+    /// the node marks `removed` (reorged out) are skipped.
+    pub fn subscribe_escrow_events(
+        &self,
+        from_block: Option<u64>,
+    ) -> mpsc::Receiver<EscrowCreatedEvent> {
+        let (tx, rx) = mpsc::channel(ESCROW_SUBSCRIPTION_BUFFER);
+        let client = Arc::new(self.clone());
+        let next_block = Arc::new(Mutex::new(from_block));
+
+        tokio::spawn(async move {
+            let reconnect =
+                run_with_reconnect("EVM escrow events", ReconnectBackoff::default(), || {
+                    let client = client.clone();
+                    let tx = tx.clone();
+                    let next_block = next_block.clone();
+                    async move { client.escrow_subscription_session(&tx, &next_block).await }
+                });
+            tokio::select! {
+                _ = reconnect => {}
+                _ = tx.closed() => {}
+            }
+        });
+
+        rx
+    }
+
+    /// Runs one WebSocket session: subscribe, backfill, then forward pushed logs
+    /// until the connection closes or the receiver is dropped.
+    async fn escrow_subscription_session(
+        &self,
+        tx: &mpsc::Sender<EscrowCreatedEvent>,
+        next_block: &Mutex<Option<u64>>,
+    ) -> Result<()> {
+        let ws_url = self.ws_url();
+        let (mut ws, _) = tokio_tungstenite::connect_async(ws_url.as_str())
+            .await
+            .with_context(|| format!("Failed to connect to {}", ws_url))?;
+
+        let request = serde_json::json!({
+            "jsonrpc": "2.0",
+            "id": 1,
+            "method": "eth_subscribe",
+            "params": ["logs", {
+                "address": self.escrow_contract_addr(),
+                "topics": [event_signature_hash(ESCROW_CREATED_SIGNATURE)],
+            }],
+        });
+        ws.send(Message::Text(request.to_string()))
+            .await
+            .with_context(|| format!("Failed to send eth_subscribe to {}", ws_url))?;
+
+        let subscription_id = loop {
+            let Some(message) = next_json(&mut ws).await? else {
+                return Ok(());
+            };
+            if message.get("id").and_then(|id| id.as_u64()) != Some(1) {
+                continue;
+            }
+            if let Some(error) = message.get("error") {
+                let code = error.get("code").and_then(|c| c.as_i64()).unwrap_or(0);
+                let message = error
+                    .get("message")
+                    .and_then(|m| m.as_str())
+                    .unwrap_or("unknown error");
+                return Err(anyhow::Error::new(EvmRpcError::from_code(code, message))
+                    .context(format!("eth_subscribe rejected by {}", ws_url)));
+            }
+            break message
+                .get("result")
+                .and_then(|r| r.as_str())
+                .map(str::to_string)
+                .ok_or_else(|| anyhow::anyhow!("No subscription id from {}", ws_url))?;
+        };
+
+        // Logs at or below the backfilled head were already delivered by eth_getLogs.
+        let head = self.get_block_number().await?;
+        let resume_from = *next_block.lock().unwrap();
+        if let Some(from) = resume_from {
+            if from <= head {
+                let events = self
+                    .get_escrow_created_events(Some(from), Some(head), ChunkFailureStrategy::Bail)
+                    .await?;
+                for event in events {
+                    if tx.send(event).await.is_err() {
+                        return Ok(());
+                    }
+                }
+            }
+        }
+        *next_block.lock().unwrap() = Some(head + 1);
+
+        while let Some(message) = next_json(&mut ws).await? {
+            let params = match message.get("params") {
+                Some(params)
+                    if params.get("subscription").and_then(|s| s.as_str())
+                        == Some(subscription_id.as_str()) =>
+                {
+                    params
+                }
+                _ => continue,
+            };
+            let Some(result) = params.get("result") else {
+                continue;
+            };
+            if result.get("removed").and_then(|r| r.as_bool()) == Some(true) {
+                tracing::warn!("Skipping reorged EscrowCreated log: {}", result);
+                continue;
+            }
+
+            let log: EvmLog = serde_json::from_value(result.clone())
+                .context("Failed to deserialize pushed EscrowCreated log")?;
+            let block = parse_block_number(&log.block_number)?;
+            if block <= head {
+                continue;
+            }
+            let Some(event) = parse_escrow_created_log(log)? else {
+                continue;
+            };
+            if tx.send(event).await.is_err() {
+                return Ok(());
+            }
+            *next_block.lock().unwrap() = Some(block);
+        }
+
+        Ok(())
+    }
+}
+
+/// Reads the next text frame as JSON, skipping control frames.
+///
+/// Returns `Ok(None)` once the server closes the connection.
+async fn next_json(ws: &mut WsStream) -> Result<Option<serde_json::Value>> {
+    while let Some(message) = ws.next().await {
+        match message.context("WebSocket receive failed")? {
+            Message::Text(text) => {
+                let value = serde_json::from_str(&text)
+                    .with_context(|| format!("Invalid JSON on subscription: {}", text))?;
+                return Ok(Some(value));
+            }
+            Message::Close(_) => return Ok(None),
+            _ => continue,
+        }
+    }
+    Ok(None)
+}
+
+/// Parses a hex block number ("0x1a") from a log.
+fn parse_block_number(block_hex: &str) -> Result<u64> {
+    let clean = block_hex.strip_prefix("0x").unwrap_or(block_hex);
+    u64::from_str_radix(clean, 16)
+        .with_context(|| format!("Invalid block number in pushed log: {}", block_hex))
+}
@@ -3,6 +3,8 @@
 //! These types are used by the coordinator, integrated-gmp, and solver
 //! for communicating with EVM-compatible blockchain nodes.
 
+use chain_clients_common::event_bytes32;
+use gmp_common::IntentRequirements;
 use serde::{Deserialize, Serialize};
 
 /// EVM JSON-RPC request wrapper
@@ -82,6 +84,75 @@ pub struct EscrowCreatedEvent {
     pub transaction_hash: String,
 }
 
+impl EscrowCreatedEvent {
+    /// Converts the escrow event into the GMP IntentRequirements the hub expects.
+    ///
+    /// All ids and addresses are left-padded to 32 bytes, so the 20-byte requester
+    /// and token addresses map to the same bytes the escrow contract would emit.
+    ///
+    /// # Returns
+    ///
+    /// * `Ok(IntentRequirements)` - Requirements with the escrowed amount and expiry
+    /// * `Err(anyhow::Error)` - If any id or address is not valid hex of at most 32 bytes
+    pub fn to_intent_requirements(&self) -> anyhow::Result<IntentRequirements> {
+        Ok(IntentRequirements {
+            intent_id: event_bytes32(&self.intent_id, "intent_id")?,
+            requester_addr: event_bytes32(&self.requester_addr, "requester_addr")?,
+            amount_required: self.amount,
+            token_addr: event_bytes32(&self.token_addr, "token_addr")?,
+            solver_addr: event_bytes32(&self.reserved_solver, "reserved_solver")?,
+            expiry: self.expiry,
+        })
+    }
+
+    /// Encodes the escrow event as an IntentRequirements GMP payload.
+    pub fn encode_intent_requirements(&self) -> anyhow::Result<Vec<u8>> {
+        Ok(self.to_intent_requirements()?.encode().to_vec())
+    }
+}
+
+/// EscrowReleased event data parsed from EVM logs (escrow claimed by the solver)
+///
+/// Event signature: EscrowReleased(bytes32 indexed intentId, address indexed solver, uint64 amount)
+/// topics[0] = event signature hash
+/// topics[1] = intentId (bytes32)
+/// topics[2] = solver (address, padded to 32 bytes)
+/// data = abi.encode(amount) = 64 hex chars
+#[derive(Debug, Clone, Serialize, Deserialize)]
+pub struct EscrowClaimedEvent {
+    /// Intent ID (indexed topic[1], bytes32)
+    pub intent_id: String,
+    /// Solver address that received the funds (indexed topic[2], address)
+    pub solver_addr: String,
+    /// Amount released (from data, uint64)
+    pub amount: u64,
+    /// Block number
+    pub block_number: String,
+    /// Transaction hash
+    pub transaction_hash: String,
+}
+
+/// EscrowCancelled event data parsed from EVM logs
+///
+/// Event signature: EscrowCancelled(bytes32 indexed intentId, address indexed requester, uint64 amount)
+/// topics[0] = event signature hash
+/// topics[1] = intentId (bytes32)
+/// topics[2] = requester (address, padded to 32 bytes)
+/// data = abi.encode(amount) = 64 hex chars
+#[derive(Debug, Clone, Serialize, Deserialize)]
+pub struct EscrowCancelledEvent {
+    /// Intent ID (indexed topic[1], bytes32)
+    pub intent_id: String,
+    /// Requester address that was refunded (indexed topic[2], address)
+    pub requester_addr: String,
+    /// Amount refunded (from data, uint64)
+    pub amount: u64,
+    /// Block number
+    pub block_number: String,
+    /// Transaction hash
+    pub transaction_hash: String,
+}
+
 /// EVM transaction details from JSON-RPC
 #[derive(Debug, Clone, Deserialize, Serialize)]
 pub struct EvmTransaction {
@@ -3,10 +3,14 @@
 //! Test ordering matches chain-clients/extension-checklist.md for cross-VM synchronization.
 //! Tests marked N/A in the checklist are skipped in this file.
 
-use chain_clients_evm::{normalize_evm_address, EvmClient};
+use chain_clients_evm::{
+    normalize_evm_address, ChunkFailureStrategy, EscrowCreatedEvent, EvmClient, EvmRpcError,
+};
+use gmp_common::IntentRequirements;
 use serde_json::json;
 use sha3::{Digest, Keccak256};
-use wiremock::matchers::method;
+use std::collections::HashMap;
+use wiremock::matchers::{body_partial_json, header, method};
 use wiremock::{Mock, MockServer, ResponseTemplate};
 
 // ============================================================================
@@ -364,7 +368,7 @@ async fn test_get_escrow_events_success() {
     let client =
         EvmClient::new(&mock_server.uri(), DUMMY_ESCROW_CONTRACT_ADDR).unwrap();
     let events = client
-        .get_escrow_created_events(None, None)
+        .get_escrow_created_events(None, None, ChunkFailureStrategy::Bail)
         .await
         .unwrap();
 
@@ -397,7 +401,7 @@ async fn test_get_escrow_events_empty() {
     let client =
         EvmClient::new(&mock_server.uri(), DUMMY_ESCROW_CONTRACT_ADDR).unwrap();
     let events = client
-        .get_escrow_created_events(None, None)
+        .get_escrow_created_events(None, None, ChunkFailureStrategy::Bail)
         .await
         .unwrap();
     assert_eq!(events.len(), 0);
@@ -421,7 +425,9 @@ async fn test_get_escrow_events_error() {
 
     let client =
         EvmClient::new(&mock_server.uri(), DUMMY_ESCROW_CONTRACT_ADDR).unwrap();
-    let result = client.get_escrow_created_events(None, None).await;
+    let result = client
+        .get_escrow_created_events(None, None, ChunkFailureStrategy::Bail)
+        .await;
     assert!(result.is_err());
     assert!(result.unwrap_err().to_string().contains("JSON-RPC error"));
 }
@@ -474,3 +480,820 @@ fn test_normalize_evm_address_rejects_non_zero_high_bytes() {
 
 // #25-26: pubkey_from_hex — N/A for EVM (SVM-specific)
 // #27-28: escrow_account_borsh — N/A for EVM (SVM-specific)
+
+// ============================================================================
+// #29: custom RPC headers
+// ============================================================================
+
+// 29. Test: client sends configured headers on every request
+// Verifies that a client built with new_with_headers() sends the configured header, and that a client without it is rejected by a provider that requires it.
+// Why: Hosted RPC providers authenticate via headers (e.g. x-api-key); a dropped header would fail every call in production.
+#[tokio::test]
+async fn test_client_sends_custom_headers() {
+    let mock_server = MockServer::start().await;
+
+    Mock::given(method("POST"))
+        .and(header("x-api-key", "test-key"))
+        .respond_with(ResponseTemplate::new(200).set_body_json(json!({
+            "jsonrpc": "2.0",
+            "result": format!("0x{:064x}", 1u64),
+            "id": 1
+        })))
+        .mount(&mock_server)
+        .await;
+
+    let headers = HashMap::from([("x-api-key".to_string(), "test-key".to_string())]);
+    let client =
+        EvmClient::new_with_headers(&mock_server.uri(), DUMMY_ESCROW_CONTRACT_ADDR, &headers)
+            .unwrap();
+    let released = client.is_escrow_released(DUMMY_INTENT_ID).await.unwrap();
+    assert!(released);
+
+    let client_without_headers =
+        EvmClient::new(&mock_server.uri(), DUMMY_ESCROW_CONTRACT_ADDR).unwrap();
+    let result = client_without_headers.is_escrow_released(DUMMY_INTENT_ID).await;
+    assert!(result.is_err());
+}
+
+// ============================================================================
+// #30-31: escrow settlement event parsing
+// ============================================================================
+
+// 30. Test: get_escrow_claimed_events parses EscrowReleased events correctly
+// Verifies that get_escrow_claimed_events decodes the intent ID, solver address, and amount from an EscrowReleased log.
+// Why: Claim events are how the EVM side's settlement is reconciled against the hub; a misparse would leave intents unreconciled.
+#[tokio::test]
+async fn test_get_escrow_claimed_events_success() {
+    let mock_server = MockServer::start().await;
+
+    let mut hasher = Keccak256::new();
+    hasher.update(b"EscrowReleased(bytes32,address,uint64)");
+    let event_topic = format!("0x{}", hex::encode(hasher.finalize()));
+
+    let solver_addr = "0x0000000000000000000000000000000000000009";
+    let amount_hex = "00000000000000000000000000000000000000000000000000000000000f4240"; // 1000000
+
+    Mock::given(method("POST"))
+        .respond_with(ResponseTemplate::new(200).set_body_json(json!({
+            "jsonrpc": "2.0",
+            "result": [
+                {
+                    "address": DUMMY_ESCROW_CONTRACT_ADDR,
+                    "topics": [
+                        event_topic,
+                        DUMMY_INTENT_ID,
+                        format!("0x000000000000000000000000{}", solver_addr.strip_prefix("0x").unwrap())
+                    ],
+                    "data": format!("0x{}", amount_hex),
+                    "blockNumber": "0x1001",
+                    "transactionHash": DUMMY_TX_HASH,
+                    "logIndex": "0x1"
+                }
+            ],
+            "id": 1
+        })))
+        .mount(&mock_server)
+        .await;
+
+    let client =
+        EvmClient::new(&mock_server.uri(), DUMMY_ESCROW_CONTRACT_ADDR).unwrap();
+    let events = client
+        .get_escrow_claimed_events(Some(0x1000), Some(0x1001))
+        .await
+        .unwrap();
+
+    assert_eq!(events.len(), 1);
+    assert_eq!(events[0].intent_id, DUMMY_INTENT_ID);
+    assert_eq!(events[0].solver_addr, solver_addr);
+    assert_eq!(events[0].amount, 1000000);
+    assert_eq!(events[0].block_number, "0x1001");
+    assert_eq!(events[0].transaction_hash, DUMMY_TX_HASH);
+}
+
+// 31. Test: get_escrow_cancelled_events parses EscrowCancelled events correctly
+// Verifies that get_escrow_cancelled_events decodes the intent ID, requester address, and amount from an EscrowCancelled log.
+// Why: Cancelled escrows must be reconciled as refunded; a misparse would report funds as still locked.
+#[tokio::test]
+async fn test_get_escrow_cancelled_events_success() {
+    let mock_server = MockServer::start().await;
+
+    let mut hasher = Keccak256::new();
+    hasher.update(b"EscrowCancelled(bytes32,address,uint64)");
+    let event_topic = format!("0x{}", hex::encode(hasher.finalize()));
+
+    let amount_hex = "00000000000000000000000000000000000000000000000000000000000003e8"; // 1000
+
+    Mock::given(method("POST"))
+        .respond_with(ResponseTemplate::new(200).set_body_json(json!({
+            "jsonrpc": "2.0",
+            "result": [
+                {
+                    "address": DUMMY_ESCROW_CONTRACT_ADDR,
+                    "topics": [
+                        event_topic,
+                        DUMMY_INTENT_ID,
+                        format!("0x000000000000000000000000{}", DUMMY_REQUESTER_ADDR.strip_prefix("0x").unwrap())
+                    ],
+                    "data": format!("0x{}", amount_hex),
+                    "blockNumber": "0x2000",
+                    "transactionHash": DUMMY_TX_HASH,
+                    "logIndex": "0x0"
+                }
+            ],
+            "id": 1
+        })))
+        .mount(&mock_server)
+        .await;
+
+    let client =
+        EvmClient::new(&mock_server.uri(), DUMMY_ESCROW_CONTRACT_ADDR).unwrap();
+    let events = client
+        .get_escrow_cancelled_events(None, None)
+        .await
+        .unwrap();
+
+    assert_eq!(events.len(), 1);
+    assert_eq!(events[0].intent_id, DUMMY_INTENT_ID);
+    assert_eq!(events[0].requester_addr, DUMMY_REQUESTER_ADDR);
+    assert_eq!(events[0].amount, 1000);
+    assert_eq!(events[0].block_number, "0x2000");
+}
+
+// ============================================================================
+// #32-33: get_transaction_by_block_number_and_index
+// ============================================================================
+
+// 32. Test: get_transaction_by_block_number_and_index returns the transaction
+// Verifies that get_transaction_by_block_number_and_index sends hex-encoded block/index params and parses the returned transaction.
+// Why: Subscriptions can yield only a log's block/index position; the validator needs the full transaction to read calldata.
+#[tokio::test]
+async fn test_get_transaction_by_block_number_and_index_success() {
+    let mock_server = MockServer::start().await;
+
+    Mock::given(method("POST"))
+        .and(body_partial_json(json!({
+            "method": "eth_getTransactionByBlockNumberAndIndex",
+            "params": ["0x1001", "0x2"]
+        })))
+        .respond_with(ResponseTemplate::new(200).set_body_json(json!({
+            "jsonrpc": "2.0",
+            "result": {
+                "hash": DUMMY_TX_HASH,
+                "blockNumber": "0x1001",
+                "transactionIndex": "0x2",
+                "from": DUMMY_REQUESTER_ADDR,
+                "to": DUMMY_ESCROW_CONTRACT_ADDR,
+                "input": "0xa9059cbb",
+                "value": "0x0",
+                "gas": "0x5208",
+                "gasPrice": "0x3b9aca00"
+            },
+            "id": 1
+        })))
+        .mount(&mock_server)
+        .await;
+
+    let client =
+        EvmClient::new(&mock_server.uri(), DUMMY_ESCROW_CONTRACT_ADDR).unwrap();
+    let tx = client
+        .get_transaction_by_block_number_and_index(0x1001, 2)
+        .await
+        .unwrap()
+        .expect("transaction should be found");
+
+    assert_eq!(tx.hash, DUMMY_TX_HASH);
+    assert_eq!(tx.block_number.as_deref(), Some("0x1001"));
+    assert_eq!(tx.transaction_index.as_deref(), Some("0x2"));
+    assert_eq!(tx.from, DUMMY_REQUESTER_ADDR);
+    assert_eq!(tx.input, "0xa9059cbb");
+}
+
+// 33. Test: get_transaction_by_block_number_and_index returns None for an out-of-range index
+// Verifies that a null result from eth_getTransactionByBlockNumberAndIndex is returned as None rather than an error.
+// Why: An index past the end of the block is a normal "not found", not an RPC failure; callers must be able to tell the two apart.
+#[tokio::test]
+async fn test_get_transaction_by_block_number_and_index_not_found() {
+    let mock_server = MockServer::start().await;
+
+    Mock::given(method("POST"))
+        .respond_with(ResponseTemplate::new(200).set_body_json(json!({
+            "jsonrpc": "2.0",
+            "result": null,
+            "id": 1
+        })))
+        .mount(&mock_server)
+        .await;
+
+    let client =
+        EvmClient::new(&mock_server.uri(), DUMMY_ESCROW_CONTRACT_ADDR).unwrap();
+    let tx = client
+        .get_transaction_by_block_number_and_index(0x1001, 999)
+        .await
+        .unwrap();
+
+    assert!(tx.is_none());
+}
+
+// ============================================================================
+// #34-35: JSON-RPC error classification
+// ============================================================================
+
+// 34. Test: EvmRpcError::from_code maps well-known codes to typed variants
+// Verifies that -32601, -32602, -32005, and -32000 map to MethodNotFound, InvalidParams, LimitExceeded, and LimitExceeded/Server (by message), preserving the raw code and message, and that only limit errors are transient.
+// Why: Callers decide retry vs fail from the error class; retrying a method-not-found or a revert would never succeed.
+#[test]
+fn test_evm_rpc_error_classification() {
+    let err = EvmRpcError::from_code(-32601, "the method eth_foo does not exist");
+    assert!(matches!(err, EvmRpcError::MethodNotFound { .. }));
+    assert_eq!(err.code(), -32601);
+    assert_eq!(err.message(), "the method eth_foo does not exist");
+    assert!(!err.is_transient());
+
+    let err = EvmRpcError::from_code(-32602, "invalid argument 0: hex string without 0x prefix");
+    assert!(matches!(err, EvmRpcError::InvalidParams { .. }));
+    assert!(!err.is_transient());
+
+    let err = EvmRpcError::from_code(-32005, "request rate exceeded");
+    assert!(matches!(err, EvmRpcError::LimitExceeded { .. }));
+    assert!(err.is_transient());
+
+    let err = EvmRpcError::from_code(-32000, "Rate limit exceeded, retry later");
+    assert!(matches!(err, EvmRpcError::LimitExceeded { .. }));
+    assert!(err.is_transient());
+
+    let err = EvmRpcError::from_code(-32000, "execution reverted");
+    assert!(matches!(err, EvmRpcError::Server { .. }));
+    assert!(!err.is_transient());
+
+    let err = EvmRpcError::from_code(3, "execution reverted: escrow not found");
+    assert!(matches!(err, EvmRpcError::Other { .. }));
+    assert_eq!(err.code(), 3);
+}
+
+// 35. Test: json_rpc errors carry a downcastable EvmRpcError
+// Verifies that a JSON-RPC error response surfaces as an anyhow error that downcasts to the classified EvmRpcError, while the error string still contains the method, message, and code.
+// Why: Callers need the typed class for retry decisions without losing the human-readable message in logs.
+#[tokio::test]
+async fn test_json_rpc_error_is_typed() {
+    let mock_server = MockServer::start().await;
+
+    Mock::given(method("POST"))
+        .respond_with(ResponseTemplate::new(200).set_body_json(json!({
+            "jsonrpc": "2.0",
+            "error": { "code": -32601, "message": "the method eth_foo does not exist" },
+            "id": 1
+        })))
+        .mount(&mock_server)
+        .await;
+
+    let client = EvmClient::new_rpc_only(&mock_server.uri()).unwrap();
+    let err = client
+        .json_rpc::<serde_json::Value>("eth_foo", vec![])
+        .await
+        .unwrap_err();
+
+    let rpc_err = err
+        .downcast_ref::<EvmRpcError>()
+        .expect("JSON-RPC error should carry EvmRpcError");
+    assert!(matches!(rpc_err, EvmRpcError::MethodNotFound { .. }));
+    assert!(!rpc_err.is_transient());
+
+    let msg = err.to_string();
+    assert!(msg.contains("JSON-RPC error"));
+    assert!(msg.contains("eth_foo"));
+    assert!(msg.contains("-32601"));
+}
+
+// #36: test_escrow_pda_helpers_match_program_seeds — N/A for EVM (Solana program-derived addresses)
+
+// ============================================================================
+// #37: gas price estimate
+// ============================================================================
+
+// 37. Test: gas price estimate is read from the node
+// Verifies that gas_price() decodes the hex result of eth_gasPrice.
+// Why: Solvers multiply this price by expected gas units to enforce a per-fulfillment fee budget; a wrong price breaks the cap.
+#[tokio::test]
+async fn test_gas_price_estimate() {
+    let mock_server = MockServer::start().await;
+
+    Mock::given(method("POST"))
+        .and(body_partial_json(json!({"method": "eth_gasPrice"})))
+        .respond_with(ResponseTemplate::new(200).set_body_json(json!({
+            "jsonrpc": "2.0",
+            "result": "0x3b9aca00",
+            "id": 1
+        })))
+        .mount(&mock_server)
+        .await;
+
+    let client = EvmClient::new_rpc_only(&mock_server.uri()).unwrap();
+    assert_eq!(client.gas_price().await.unwrap(), 1_000_000_000);
+}
+
+// ============================================================================
+// #38-39: token metadata resolution
+// ============================================================================
+
+// 38. Test: ERC20 token resolves
+// Verifies that is_erc20_token() returns true when decimals() returns a 32-byte word.
+// Why: Solvers only accept intents whose tokens exist on the chain they claim to be on.
+#[tokio::test]
+async fn test_token_metadata_resolves() {
+    let mock_server = MockServer::start().await;
+
+    Mock::given(method("POST"))
+        .and(body_partial_json(json!({"method": "eth_call"})))
+        .respond_with(ResponseTemplate::new(200).set_body_json(json!({
+            "jsonrpc": "2.0",
+            "result": format!("0x{:064x}", 6),
+            "id": 1
+        })))
+        .mount(&mock_server)
+        .await;
+
+    let client = EvmClient::new_rpc_only(&mock_server.uri()).unwrap();
+    assert!(client.is_erc20_token(DUMMY_TOKEN_ADDR).await.unwrap());
+}
+
+// 39. Test: non-token address does not resolve
+// Verifies that is_erc20_token() returns false for an empty eth_call result (no contract) and for a reverted call.
+// Why: An address without ERC20 code is not a token here; it must reject the token, not fail as an RPC error.
+#[tokio::test]
+async fn test_token_metadata_unresolvable() {
+    let empty_server = MockServer::start().await;
+    Mock::given(method("POST"))
+        .respond_with(ResponseTemplate::new(200).set_body_json(json!({
+            "jsonrpc": "2.0",
+            "result": "0x",
+            "id": 1
+        })))
+        .mount(&empty_server)
+        .await;
+
+    let client = EvmClient::new_rpc_only(&empty_server.uri()).unwrap();
+    assert!(!client.is_erc20_token(DUMMY_TOKEN_ADDR).await.unwrap());
+
+    let revert_server = MockServer::start().await;
+    Mock::given(method("POST"))
+        .respond_with(ResponseTemplate::new(200).set_body_json(json!({
+            "jsonrpc": "2.0",
+            "error": { "code": -32000, "message": "execution reverted" },
+            "id": 1
+        })))
+        .mount(&revert_server)
+        .await;
+
+    let client = EvmClient::new_rpc_only(&revert_server.uri()).unwrap();
+    assert!(!client.is_erc20_token(DUMMY_TOKEN_ADDR).await.unwrap());
+}
+
+// ============================================================================
+// ESCROW EVENT TO INTENT REQUIREMENTS
+// ============================================================================
+
+// 40. Test: escrow event converts to IntentRequirements
+// Verifies that an EscrowCreated event encodes to an IntentRequirements payload that decodes back with 32-byte left-padded addresses.
+// Why: The hub decodes these bytes; a 20-byte address placed at the wrong offset would point the requirements at a different account.
+#[test]
+fn test_escrow_event_to_intent_requirements() {
+    let event = EscrowCreatedEvent {
+        intent_id: DUMMY_INTENT_ID.to_string(),
+        escrow_id: DUMMY_INTENT_ID.to_string(),
+        requester_addr: "0x00000000000000000000000000000000000000bb".to_string(),
+        amount: 1_000_000,
+        token_addr: DUMMY_TOKEN_ADDR.to_string(),
+        reserved_solver: "0x00000000000000000000000000000000000000000000000000000000000000cc"
+            .to_string(),
+        expiry: 1_700_000_000,
+        block_number: "0x1".to_string(),
+        transaction_hash: "0x2".to_string(),
+    };
+
+    let payload = event.encode_intent_requirements().unwrap();
+    let decoded = IntentRequirements::decode(&payload).unwrap();
+
+    assert_eq!(decoded, event.to_intent_requirements().unwrap());
+    assert_eq!(decoded.intent_id[31], 0x01);
+    assert_eq!(decoded.requester_addr[..31], [0u8; 31]);
+    assert_eq!(decoded.requester_addr[31], 0xbb);
+    assert_eq!(decoded.token_addr[31], 0x0a);
+    assert_eq!(decoded.solver_addr[31], 0xcc);
+    assert_eq!(decoded.amount_required, 1_000_000);
+    assert_eq!(decoded.expiry, 1_700_000_000);
+
+    let invalid = EscrowCreatedEvent {
+        requester_addr: "0xnot-hex".to_string(),
+        ..event
+    };
+    assert!(invalid.to_intent_requirements().is_err());
+}
+
+// ============================================================================
+// #41: chain timestamp
+// ============================================================================
+
+// 41. Test: latest chain timestamp is read from the node
+// Verifies that get_latest_block_timestamp() decodes the hex timestamp of the latest block from eth_getBlockByNumber.
+// Why: Services compare the chain clock against the host clock; a wrong decode would report a huge drift.
+#[tokio::test]
+async fn test_get_chain_timestamp() {
+    let mock_server = MockServer::start().await;
+
+    Mock::given(method("POST"))
+        .and(body_partial_json(json!({"method": "eth_getBlockByNumber"})))
+        .respond_with(ResponseTemplate::new(200).set_body_json(json!({
+            "jsonrpc": "2.0",
+            "result": { "number": "0x10", "timestamp": "0x6553f100" },
+            "id": 1
+        })))
+        .mount(&mock_server)
+        .await;
+
+    let client = EvmClient::new_rpc_only(&mock_server.uri()).unwrap();
+    assert_eq!(
+        client.get_latest_block_timestamp().await.unwrap(),
+        1_700_000_000
+    );
+}
+
+// ============================================================================
+// #42: token decimals
+// ============================================================================
+
+// 42. Test: token decimals are read from the chain
+// Verifies that get_token_decimals() decodes the uint8 returned by decimals() and fails with a "not an ERC20 token" error for an empty eth_call result.
+// Why: Amounts are converted with the token's decimals; a non-token address must be rejected, not treated as 0 decimals.
+#[tokio::test]
+async fn test_get_token_decimals() {
+    let mock_server = MockServer::start().await;
+    Mock::given(method("POST"))
+        .and(body_partial_json(json!({"method": "eth_call"})))
+        .respond_with(ResponseTemplate::new(200).set_body_json(json!({
+            "jsonrpc": "2.0",
+            "result": format!("0x{:064x}", 6),
+            "id": 1
+        })))
+        .mount(&mock_server)
+        .await;
+
+    let client = EvmClient::new_rpc_only(&mock_server.uri()).unwrap();
+    assert_eq!(
+        client.get_token_decimals(DUMMY_TOKEN_ADDR).await.unwrap(),
+        6
+    );
+
+    let empty_server = MockServer::start().await;
+    Mock::given(method("POST"))
+        .respond_with(ResponseTemplate::new(200).set_body_json(json!({
+            "jsonrpc": "2.0",
+            "result": "0x",
+            "id": 1
+        })))
+        .mount(&empty_server)
+        .await;
+
+    let client = EvmClient::new_rpc_only(&empty_server.uri()).unwrap();
+    let err = client
+        .get_token_decimals(DUMMY_TOKEN_ADDR)
+        .await
+        .unwrap_err();
+    assert!(err.to_string().contains("is not an ERC20 token"));
+}
+
+// ============================================================================
+// #43-#44: uint64 log word decoding
+// ============================================================================
+
+/// Mounts a single EscrowCreated log with the given amount and expiry words.
+async fn mount_escrow_created_log(amount_hex: &str, expiry_hex: &str) -> MockServer {
+    let mock_server = MockServer::start().await;
+
+    let mut hasher = Keccak256::new();
+    hasher.update(b"EscrowCreated(bytes32,bytes32,address,uint64,address,bytes32,uint64)");
+    let event_topic = format!("0x{}", hex::encode(hasher.finalize()));
+
+    let escrow_id_hex = "0000000000000000000000000000000000000000000000000000000000000002";
+    let solver_hex = "0000000000000000000000000000000000000000000000000000000000000009";
+
+    Mock::given(method("POST"))
+        .respond_with(ResponseTemplate::new(200).set_body_json(json!({
+            "jsonrpc": "2.0",
+            "result": [
+                {
+                    "address": DUMMY_ESCROW_CONTRACT_ADDR,
+                    "topics": [
+                        event_topic,
+                        DUMMY_INTENT_ID,
+                        format!("0x000000000000000000000000{}", DUMMY_REQUESTER_ADDR.strip_prefix("0x").unwrap()),
+                        format!("0x000000000000000000000000{}", DUMMY_TOKEN_ADDR.strip_prefix("0x").unwrap())
+                    ],
+                    "data": format!("0x{}{}{}{}", escrow_id_hex, amount_hex, solver_hex, expiry_hex),
+                    "blockNumber": "0x1000",
+                    "transactionHash": DUMMY_TX_HASH,
+                    "logIndex": "0x0"
+                }
+            ],
+            "id": 1
+        })))
+        .mount(&mock_server)
+        .await;
+
+    mock_server
+}
+
+// 43. Test: uint64 log word with non-zero upper bytes is rejected
+// Verifies that get_escrow_created_events fails with an "exceeds u64" error when the amount word has a non-zero byte above the low 8 bytes.
+// Why: Parsing only the low 8 bytes would silently truncate the amount and let the solver act on a value the contract never escrowed.
+#[tokio::test]
+async fn test_escrow_log_u64_word_overflow_rejected() {
+    // 2^64: lowest value that no longer fits in a u64
+    let amount_hex = "0000000000000000000000000000000000000000000000010000000000000000";
+    let expiry_hex = "0000000000000000000000000000000000000000000000000000000065f00000";
+    let mock_server = mount_escrow_created_log(amount_hex, expiry_hex).await;
+
+    let client =
+        EvmClient::new(&mock_server.uri(), DUMMY_ESCROW_CONTRACT_ADDR).unwrap();
+    let err = client
+        .get_escrow_created_events(None, None, ChunkFailureStrategy::Bail)
+        .await
+        .unwrap_err();
+    assert!(err.to_string().contains("escrow amount exceeds u64"));
+}
+
+// 44. Test: uint64 log words within range are decoded
+// Verifies that get_escrow_created_events decodes a small amount and an amount of exactly u64::MAX with a zeroed upper word.
+// Why: The overflow guard must only reject values that do not fit; the full u64 range stays valid.
+#[tokio::test]
+async fn test_escrow_log_u64_word_decoded() {
+    let expiry_hex = "0000000000000000000000000000000000000000000000000000000065f00000";
+
+    let small_hex = "00000000000000000000000000000000000000000000000000000000000003e8"; // 1000
+    let mock_server = mount_escrow_created_log(small_hex, expiry_hex).await;
+    let client =
+        EvmClient::new(&mock_server.uri(), DUMMY_ESCROW_CONTRACT_ADDR).unwrap();
+    let events = client
+        .get_escrow_created_events(None, None, ChunkFailureStrategy::Bail)
+        .await
+        .unwrap();
+    assert_eq!(events[0].amount, 1000);
+    assert_eq!(events[0].expiry, 0x65f00000);
+
+    let max_hex = "000000000000000000000000000000000000000000000000ffffffffffffffff";
+    let mock_server = mount_escrow_created_log(max_hex, expiry_hex).await;
+    let client =
+        EvmClient::new(&mock_server.uri(), DUMMY_ESCROW_CONTRACT_ADDR).unwrap();
+    let events = client
+        .get_escrow_created_events(None, None, ChunkFailureStrategy::Bail)
+        .await
+        .unwrap();
+    assert_eq!(events[0].amount, u64::MAX);
+}
+
+// ============================================================================
+// #45: eth_getLogs block range chunking
+// ============================================================================
+
+/// Builds an EscrowCreated log for `intent_id` emitted in block `block_hex`.
+fn escrow_created_log(intent_id: &str, block_hex: &str) -> serde_json::Value {
+    let mut hasher = Keccak256::new();
+    hasher.update(b"EscrowCreated(bytes32,bytes32,address,uint64,address,bytes32,uint64)");
+    let event_topic = format!("0x{}", hex::encode(hasher.finalize()));
+    let word = |n: u64| format!("{:064x}", n);
+
+    json!({
+        "address": DUMMY_ESCROW_CONTRACT_ADDR,
+        "topics": [
+            event_topic,
+            intent_id,
+            format!("0x000000000000000000000000{}", DUMMY_REQUESTER_ADDR.strip_prefix("0x").unwrap()),
+            format!("0x000000000000000000000000{}", DUMMY_TOKEN_ADDR.strip_prefix("0x").unwrap())
+        ],
+        "data": format!("0x{}{}{}{}", word(2), word(1000), word(9), word(0)),
+        "blockNumber": block_hex,
+        "transactionHash": DUMMY_TX_HASH,
+        "logIndex": "0x0"
+    })
+}
+
+/// Mounts an eth_getLogs response for exactly the block range `[from_hex, to_hex]`.
+async fn mount_logs_for_range(
+    mock_server: &MockServer,
+    from_hex: &str,
+    to_hex: &str,
+    response: serde_json::Value,
+) {
+    Mock::given(method("POST"))
+        .and(body_partial_json(json!({
+            "method": "eth_getLogs",
+            "params": [{ "fromBlock": from_hex, "toBlock": to_hex }]
+        })))
+        .respond_with(ResponseTemplate::new(200).set_body_json(response))
+        .expect(1)
+        .mount(mock_server)
+        .await;
+}
+
+// 45. Test: wide eth_getLogs ranges are split into chunks
+// Verifies that get_escrow_created_events splits a range wider than max_block_span into sequential eth_getLogs calls and merges their events in block order, and that a failed chunk either fails the query (Bail) or returns the events before it (ReturnPartial).
+// Why: Most RPC providers reject eth_getLogs ranges wider than ~2000 blocks; without chunking a catch-up scan would fail outright.
+#[tokio::test]
+async fn test_get_escrow_events_chunks_block_range() {
+    let intent_id_a = "0x00000000000000000000000000000000000000000000000000000000000000aa";
+    let intent_id_b = "0x00000000000000000000000000000000000000000000000000000000000000bb";
+
+    let mock_server = MockServer::start().await;
+    mount_logs_for_range(
+        &mock_server,
+        "0x0",
+        "0x9",
+        json!({ "jsonrpc": "2.0", "result": [escrow_created_log(intent_id_a, "0x5")], "id": 1 }),
+    )
+    .await;
+    mount_logs_for_range(
+        &mock_server,
+        "0xa",
+        "0xf",
+        json!({ "jsonrpc": "2.0", "result": [escrow_created_log(intent_id_b, "0xc")], "id": 1 }),
+    )
+    .await;
+
+    let client = EvmClient::new(&mock_server.uri(), DUMMY_ESCROW_CONTRACT_ADDR)
+        .unwrap()
+        .with_max_block_span(10);
+    let events = client
+        .get_escrow_created_events(Some(0), Some(15), ChunkFailureStrategy::Bail)
+        .await
+        .unwrap();
+    let intent_ids: Vec<&str> = events.iter().map(|e| e.intent_id.as_str()).collect();
+    assert_eq!(intent_ids, vec![intent_id_a, intent_id_b]);
+    assert_eq!(events[0].block_number, "0x5");
+    assert_eq!(events[1].block_number, "0xc");
+
+    // Second chunk fails: Bail surfaces the error, ReturnPartial keeps the first chunk
+    let failing_server = MockServer::start().await;
+    Mock::given(method("POST"))
+        .and(body_partial_json(json!({ "params": [{ "fromBlock": "0x0" }] })))
+        .respond_with(ResponseTemplate::new(200).set_body_json(json!({
+            "jsonrpc": "2.0",
+            "result": [escrow_created_log(intent_id_a, "0x5")],
+            "id": 1
+        })))
+        .mount(&failing_server)
+        .await;
+    Mock::given(method("POST"))
+        .and(body_partial_json(json!({ "params": [{ "fromBlock": "0xa" }] })))
+        .respond_with(ResponseTemplate::new(200).set_body_json(json!({
+            "jsonrpc": "2.0",
+            "error": { "code": -32005, "message": "query returned more than 10000 results" },
+            "id": 1
+        })))
+        .mount(&failing_server)
+        .await;
+
+    let client = EvmClient::new(&failing_server.uri(), DUMMY_ESCROW_CONTRACT_ADDR)
+        .unwrap()
+        .with_max_block_span(10);
+    let err = client
+        .get_escrow_created_events(Some(0), Some(15), ChunkFailureStrategy::Bail)
+        .await
+        .unwrap_err();
+    assert!(err.to_string().contains("blocks 10..=15"));
+    let events = client
+        .get_escrow_created_events(Some(0), Some(15), ChunkFailureStrategy::ReturnPartial)
+        .await
+        .unwrap();
+    assert_eq!(events.len(), 1);
+    assert_eq!(events[0].intent_id, intent_id_a);
+}
+
+// #46: test_get_account_module_events_resumes_from_cursor — N/A for EVM (Aptos account transaction cursor)
+
+// ============================================================================
+// #47: typed JSON-RPC errors from client methods
+// ============================================================================
+
+// 47. Test: client methods surface JSON-RPC errors as typed EvmRpcError
+// Verifies that a -32005 response to get_block_number downcasts to EvmRpcError::LimitExceeded (transient), and that an unrecognized code returned to get_logs downcasts to EvmRpcError::Other with the raw code and message preserved.
+// Why: Pollers call the public methods, not json_rpc directly; they must be able to tell a rate limit (retry) from an invalid request (fail) without parsing strings.
+#[tokio::test]
+async fn test_client_methods_surface_typed_rpc_errors() {
+    let mock_server = MockServer::start().await;
+
+    Mock::given(method("POST"))
+        .and(body_partial_json(json!({ "method": "eth_blockNumber" })))
+        .respond_with(ResponseTemplate::new(200).set_body_json(json!({
+            "jsonrpc": "2.0",
+            "error": { "code": -32005, "message": "daily request count exceeded, request rate limited" },
+            "id": 1
+        })))
+        .mount(&mock_server)
+        .await;
+    Mock::given(method("POST"))
+        .and(body_partial_json(json!({ "method": "eth_getLogs" })))
+        .respond_with(ResponseTemplate::new(200).set_body_json(json!({
+            "jsonrpc": "2.0",
+            "error": { "code": -32099, "message": "backend unavailable" },
+            "id": 1
+        })))
+        .mount(&mock_server)
+        .await;
+
+    let client = EvmClient::new_rpc_only(&mock_server.uri()).unwrap();
+
+    let err = client.get_block_number().await.unwrap_err();
+    let rpc_err = err
+        .downcast_ref::<EvmRpcError>()
+        .expect("get_block_number error should carry EvmRpcError");
+    assert!(matches!(rpc_err, EvmRpcError::LimitExceeded { .. }));
+    assert!(rpc_err.is_transient());
+    assert!(err.to_string().contains("daily request count exceeded"));
+
+    let err = client.get_logs(json!({})).await.unwrap_err();
+    let rpc_err = err
+        .downcast_ref::<EvmRpcError>()
+        .expect("get_logs error should carry EvmRpcError");
+    assert_eq!(
+        rpc_err,
+        &EvmRpcError::Other {
+            code: -32099,
+            message: "backend unavailable".to_string()
+        }
+    );
+    assert!(!rpc_err.is_transient());
+    assert!(err.to_string().contains("backend unavailable"));
+}
+
+// ============================================================================
+// #48: WebSocket escrow event subscription
+// ============================================================================
+
+// 48. Test: eth_subscribe pushes decoded EscrowCreated events onto the channel
+// Verifies that subscribe_escrow_events sends eth_subscribe("logs") for the escrow contract to a mock WebSocket server, and that a log pushed after the subscription ack arrives on the channel decoded as an EscrowCreatedEvent.
+// Why: Push-based delivery replaces interval polling; a pushed log must decode exactly like one returned by eth_getLogs.
+#[cfg(feature = "ws")]
+#[tokio::test]
+async fn test_subscribe_escrow_events_receives_pushed_log() {
+    use futures_util::{SinkExt, StreamExt};
+    use tokio_tungstenite::tungstenite::Message;
+
+    let intent_id = "0x00000000000000000000000000000000000000000000000000000000000000cc";
+
+    let http_server = MockServer::start().await;
+    Mock::given(method("POST"))
+        .and(body_partial_json(json!({ "method": "eth_blockNumber" })))
+        .respond_with(ResponseTemplate::new(200).set_body_json(json!({
+            "jsonrpc": "2.0",
+            "result": "0x10",
+            "id": 1
+        })))
+        .mount(&http_server)
+        .await;
+
+    let listener = tokio::net::TcpListener::bind("127.0.0.1:0").await.unwrap();
+    let ws_url = format!("ws://{}", listener.local_addr().unwrap());
+    let pushed_log = escrow_created_log(intent_id, "0x11");
+    tokio::spawn(async move {
+        let (stream, _) = listener.accept().await.unwrap();
+        let mut ws = tokio_tungstenite::accept_async(stream).await.unwrap();
+
+        let request = match ws.next().await.unwrap().unwrap() {
+            Message::Text(text) => serde_json::from_str::<serde_json::Value>(&text).unwrap(),
+            other => panic!("expected eth_subscribe request, got {:?}", other),
+        };
+        assert_eq!(request["method"], "eth_subscribe");
+        assert_eq!(request["params"][0], "logs");
+        assert_eq!(request["params"][1]["address"], DUMMY_ESCROW_CONTRACT_ADDR);
+
+        let ack = json!({ "jsonrpc": "2.0", "id": request["id"], "result": "0xsub1" });
+        ws.send(Message::Text(ack.to_string())).await.unwrap();
+        let notification = json!({
+            "jsonrpc": "2.0",
+            "method": "eth_subscription",
+            "params": { "subscription": "0xsub1", "result": pushed_log }
+        });
+        ws.send(Message::Text(notification.to_string()))
+            .await
+            .unwrap();
+
+        // Hold the connection open until the client goes away
+        while let Some(Ok(_)) = ws.next().await {}
+    });
+
+    let client = EvmClient::new(&http_server.uri(), DUMMY_ESCROW_CONTRACT_ADDR)
+        .unwrap()
+        .with_ws_url(&ws_url);
+    let mut events = client.subscribe_escrow_events(None);
+
+    let event = tokio::time::timeout(std::time::Duration::from_secs(5), events.recv())
+        .await
+        .expect("pushed event should arrive")
+        .expect("subscription channel should stay open");
+    assert_eq!(event.intent_id, intent_id);
+    assert_eq!(event.amount, 1000);
+    assert_eq!(event.expiry, 0);
+    assert_eq!(event.block_number, "0x11");
+    assert_eq!(event.transaction_hash, DUMMY_TX_HASH);
+}
+
+// #49: test_parse_legacy_escrow_without_refund_recipient — N/A for EVM (SVM escrow account layout)
@@ -5,8 +5,11 @@
 //! solver registry lookups.
 
 use anyhow::{Context, Result};
+use chain_clients_common::{build_header_map, move_addresses_match, normalize_move_address};
 use reqwest::Client;
-use std::time::Duration;
+use std::collections::HashMap;
+use std::sync::Mutex;
+use std::time::{Duration, Instant};
 
 use crate::types::*;
 
@@ -19,8 +22,89 @@ use crate::types::*;
 /// Move addresses are 32 bytes but leading zeros may be stripped in event data,
 /// producing odd-length hex strings that the Aptos REST API rejects.
 pub fn normalize_hex_to_address(hex: &str) -> String {
-    let without_prefix = hex.strip_prefix("0x").unwrap_or(hex);
-    format!("0x{:0>64}", without_prefix)
+    normalize_move_address(hex)
+}
+
+/// Decodes an unprefixed hex string into bytes (no hex crate dependency).
+fn decode_hex(hex_str: &str) -> Result<Vec<u8>> {
+    let digit = |b: u8| (b as char).to_digit(16);
+    hex_str
+        .as_bytes()
+        .chunks(2)
+        .map(|pair| match *pair {
+            [hi, lo] => match (digit(hi), digit(lo)) {
+                (Some(hi), Some(lo)) => Ok((hi * 16 + lo) as u8),
+                _ => Err(anyhow::anyhow!(
+                    "invalid hex digit in '{}'",
+                    String::from_utf8_lossy(pair)
+                )),
+            },
+            _ => Err(anyhow::anyhow!(
+                "odd number of hex digits ({})",
+                hex_str.len()
+            )),
+        })
+        .collect()
+}
+
+// ============================================================================
+// SOLVER REGISTRY CACHE
+// ============================================================================
+
+/// Default expiry for cached solver registry lookups.
+pub const DEFAULT_SOLVER_REGISTRY_CACHE_TTL: Duration = Duration::from_secs(30);
+
+/// Solver registry field held by a cache entry.
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash)]
+enum SolverLookup {
+    PublicKey,
+    EvmAddress,
+    SvmAddress,
+    MvmAddress,
+}
+
+#[derive(Debug, Clone)]
+enum SolverLookupValue {
+    PublicKey(Option<Vec<u8>>),
+    Address(Option<String>),
+}
+
+/// Key: (field, solver_addr, solver_registry_addr)
+type SolverCacheKey = (SolverLookup, String, String);
+
+/// In-memory TTL cache for successful solver registry lookups.
+///
+/// Only `Ok` results are stored; a failed lookup is retried on the next call.
+#[derive(Debug)]
+struct SolverRegistryCache {
+    ttl: Duration,
+    entries: Mutex<HashMap<SolverCacheKey, (Instant, SolverLookupValue)>>,
+}
+
+impl SolverRegistryCache {
+    fn new(ttl: Duration) -> Self {
+        Self {
+            ttl,
+            entries: Mutex::new(HashMap::new()),
+        }
+    }
+
+    fn get(&self, key: &SolverCacheKey) -> Option<SolverLookupValue> {
+        let mut entries = self.entries.lock().unwrap_or_else(|e| e.into_inner());
+        match entries.get(key) {
+            Some((stored_at, value)) if stored_at.elapsed() < self.ttl => Some(value.clone()),
+            Some(_) => {
+                entries.remove(key);
+                None
+            }
+            None => None,
+        }
+    }
+
+    fn insert(&self, key: SolverCacheKey, value: SolverLookupValue) {
+        let mut entries = self.entries.lock().unwrap_or_else(|e| e.into_inner());
+        entries.insert(key, (Instant::now(), value));
+    }
 }
 
 // ============================================================================
@@ -33,6 +117,8 @@ pub struct MvmClient {
     client: Client,
     /// Base URL of the Move VM node (e.g., "http://127.0.0.1:8080")
     base_url: String,
+    /// Cache for solver registry lookups; `None` queries the node every time
+    solver_cache: Option<SolverRegistryCache>,
 }
 
 impl MvmClient {
@@ -42,9 +128,20 @@ impl MvmClient {
     ///
     /// * `node_url` - Base URL of the Move VM node (e.g., "http://127.0.0.1:8080")
     pub fn new(node_url: &str) -> Result<Self> {
+        Self::new_with_headers(node_url, &HashMap::new())
+    }
+
+    /// Creates a new Move VM client that sends `headers` with every request
+    ///
+    /// # Arguments
+    ///
+    /// * `node_url` - Base URL of the Move VM node (e.g., "http://127.0.0.1:8080")
+    /// * `headers` - Default headers for API-key RPC providers (e.g. `Authorization`, `x-api-key`)
+    pub fn new_with_headers(node_url: &str, headers: &HashMap<String, String>) -> Result<Self> {
         let client = Client::builder()
             .timeout(Duration::from_secs(30))
             .no_proxy() // Avoid macOS system-configuration issues in tests
+            .default_headers(build_header_map(headers)?)
             .build()
             .context("Failed to create HTTP client")?;
 
@@ -54,7 +151,103 @@ impl MvmClient {
             .trim_end_matches("/v1")
             .to_string();
 
-        Ok(Self { client, base_url })
+        Ok(Self {
+            client,
+            base_url,
+            solver_cache: None,
+        })
+    }
+
+    /// Creates a new Move VM client, optionally caching solver registry lookups
+    ///
+    /// With `cached` set, `get_solver_public_key` and the `get_solver_*_address`
+    /// lookups are served from memory for [`DEFAULT_SOLVER_REGISTRY_CACHE_TTL`]
+    /// per `(solver_addr, solver_registry_addr)`; use
+    /// [`MvmClient::with_solver_registry_cache_ttl`] to change the expiry.
+    ///
+    /// # Arguments
+    ///
+    /// * `node_url` - Base URL of the Move VM node (e.g., "http://127.0.0.1:8080")
+    /// * `headers` - Default headers for API-key RPC providers (e.g. `Authorization`, `x-api-key`)
+    /// * `cached` - Whether to cache solver registry lookups
+    pub fn new_with_cache(
+        node_url: &str,
+        headers: &HashMap<String, String>,
+        cached: bool,
+    ) -> Result<Self> {
+        let client = Self::new_with_headers(node_url, headers)?;
+        Ok(if cached {
+            client.with_solver_registry_cache_ttl(DEFAULT_SOLVER_REGISTRY_CACHE_TTL)
+        } else {
+            client
+        })
+    }
+
+    /// Caches solver registry lookups for `ttl`, replacing any existing cache.
+    pub fn with_solver_registry_cache_ttl(mut self, ttl: Duration) -> Self {
+        self.solver_cache = Some(SolverRegistryCache::new(ttl));
+        self
+    }
+
+    /// Returns a cached solver registry lookup that has not expired.
+    fn cached_solver_lookup(
+        &self,
+        lookup: SolverLookup,
+        solver_addr: &str,
+        solver_registry_addr: &str,
+    ) -> Option<SolverLookupValue> {
+        let cache = self.solver_cache.as_ref()?;
+        cache.get(&(
+            lookup,
+            solver_addr.to_string(),
+            solver_registry_addr.to_string(),
+        ))
+    }
+
+    /// Stores a successful solver registry lookup when caching is enabled.
+    fn cache_solver_lookup(
+        &self,
+        lookup: SolverLookup,
+        solver_addr: &str,
+        solver_registry_addr: &str,
+        value: SolverLookupValue,
+    ) {
+        if let Some(cache) = &self.solver_cache {
+            cache.insert(
+                (
+                    lookup,
+                    solver_addr.to_string(),
+                    solver_registry_addr.to_string(),
+                ),
+                value,
+            );
+        }
+    }
+
+    /// Serves a solver address lookup from the cache, or runs `fetch` and caches its `Ok` result.
+    async fn cached_solver_address<F>(
+        &self,
+        lookup: SolverLookup,
+        solver_addr: &str,
+        solver_registry_addr: &str,
+        fetch: F,
+    ) -> Result<Option<String>>
+    where
+        F: std::future::Future<Output = Result<Option<String>>>,
+    {
+        if let Some(SolverLookupValue::Address(address)) =
+            self.cached_solver_lookup(lookup, solver_addr, solver_registry_addr)
+        {
+            return Ok(address);
+        }
+        let address = fetch.await?;
+        self.cache_solver_lookup(
+            lookup,
+            solver_addr,
+            solver_registry_addr,
+            SolverLookupValue::Address(address.clone()),
+        );
+        Ok(address)
     }
 
     /// Queries account information from the Move VM blockchain
@@ -101,19 +294,42 @@ impl MvmClient {
         }
 
         // For modern module events, query the account's transactions to find events
+        let (events, _next_start) = self
+            .get_account_module_events(address, start, limit)
+            .await?;
+        Ok(events)
+    }
+
+    /// Queries module events from an account's transactions, resuming at `start`.
+    ///
+    /// `start` is passed through as the `start` query param of
+    /// `/v1/accounts/{address}/transactions`, which Aptos interprets as the
+    /// account's transaction sequence number. Returns the events together with
+    /// the cursor for the next call: one past the highest sequence number seen,
+    /// or `start` unchanged when no transactions were returned.
+    pub async fn get_account_module_events(
+        &self,
+        address: &str,
+        start: Option<u64>,
+        limit: Option<u64>,
+    ) -> Result<(Vec<MvmEvent>, Option<u64>)> {
         let limit = limit.unwrap_or(100);
         let url = format!("{}/v1/accounts/{}/transactions", self.base_url, address);
 
+        let mut query = vec![("limit", limit.to_string())];
+        if let Some(start) = start {
+            query.push(("start", start.to_string()));
+        }
         let response = self
             .client
             .get(&url)
-            .query(&[("limit", limit.to_string())])
+            .query(&query)
             .send()
             .await
             .context("Failed to query account transactions")?;
 
         if !response.status().is_success() {
-            return Ok(vec![]); // Account might not exist or have no transactions
+            return Ok((vec![], start)); // Account might not exist or have no transactions
         }
 
         let transactions: Vec<serde_json::Value> = response
@@ -123,7 +339,15 @@ impl MvmClient {
 
         // Extract events from transactions
         let mut events = Vec::new();
+        let mut next_start = start;
         for tx in transactions {
+            if let Some(sequence_number) = tx
+                .get("sequence_number")
+                .and_then(|s| s.as_str())
+                .and_then(|s| s.parse::<u64>().ok())
+            {
+                next_start = next_start.max(Some(sequence_number + 1));
+            }
             if let Some(tx_events) = tx.get("events").and_then(|e| e.as_array()) {
                 for event_json in tx_events {
                     let event_type = event_json
@@ -162,7 +386,7 @@ impl MvmClient {
             }
         }
 
-        Ok(events)
+        Ok((events, next_start))
     }
 
     /// Queries events for a specific creation number (legacy EventHandle events)
@@ -314,6 +538,128 @@ impl MvmClient {
         Ok(())
     }
 
+    /// Queries the node's latest ledger timestamp in seconds.
+    ///
+    /// Reads `ledger_timestamp` (microseconds) from the `/v1` ledger info.
+    pub async fn get_ledger_timestamp(&self) -> Result<u64> {
+        let url = format!("{}/v1", self.base_url);
+
+        let response = self
+            .client
+            .get(&url)
+            .send()
+            .await
+            .context("Failed to send ledger info request")?
+            .error_for_status()
+            .context("Ledger info request failed")?;
+
+        let body: serde_json::Value = response
+            .json()
+            .await
+            .context("Failed to parse ledger info response")?;
+
+        let micros = body
+            .get("ledger_timestamp")
+            .and_then(|v| v.as_str())
+            .context("Ledger info response missing ledger_timestamp")?
+            .parse::<u64>()
+            .context("Failed to parse ledger_timestamp")?;
+
+        Ok(micros / 1_000_000)
+    }
+
+    /// Queries the node's current gas unit price estimate (octas per gas unit).
+    ///
+    /// Returns the `gas_estimate` field of `/v1/estimate_gas_price`.
+    pub async fn estimate_gas_price(&self) -> Result<u64> {
+        let url = format!("{}/v1/estimate_gas_price", self.base_url);
+
+        let response = self
+            .client
+            .get(&url)
+            .send()
+            .await
+            .context("Failed to send gas price estimate request")?
+            .error_for_status()
+            .context("Gas price estimate request failed")?;
+
+        let body: serde_json::Value = response
+            .json()
+            .await
+            .context("Failed to parse gas price estimate response")?;
+
+        body.get("gas_estimate")
+            .and_then(|v| v.as_u64())
+            .context("Gas price estimate response missing gas_estimate")
+    }
+
+    /// Checks whether an address holds fungible asset metadata (`0x1::fungible_asset::Metadata`).
+    ///
+    /// Returns `Ok(false)` when the node reports the resource as missing (404),
+    /// so callers can reject unknown tokens without treating it as an RPC failure.
+    pub async fn is_fungible_asset_metadata(&self, address: &str) -> Result<bool> {
+        let url = format!(
+            "{}/v1/accounts/{}/resource/0x1::fungible_asset::Metadata",
+            self.base_url,
+            normalize_hex_to_address(address)
+        );
+
+        let response = self
+            .client
+            .get(&url)
+            .send()
+            .await
+            .context("Failed to send fungible asset metadata request")?;
+
+        if response.status() == reqwest::StatusCode::NOT_FOUND {
+            return Ok(false);
+        }
+
+        response
+            .error_for_status()
+            .context("Fungible asset metadata request failed")?;
+        Ok(true)
+    }
+
+    /// Queries the decimals of a fungible asset from its `0x1::fungible_asset::Metadata` resource.
+    ///
+    /// Fails with a "not a fungible asset" error when the node reports the resource
+    /// as missing (404); other HTTP failures are returned as request errors.
+    pub async fn get_token_decimals(&self, metadata_addr: &str) -> Result<u8> {
+        let address = normalize_hex_to_address(metadata_addr);
+        let url = format!(
+            "{}/v1/accounts/{}/resource/0x1::fungible_asset::Metadata",
+            self.base_url, address
+        );
+
+        let response = self
+            .client
+            .get(&url)
+            .send()
+            .await
+            .context("Failed to send fungible asset metadata request")?;
+
+        if response.status() == reqwest::StatusCode::NOT_FOUND {
+            anyhow::bail!(
+                "{} is not a fungible asset: no 0x1::fungible_asset::Metadata resource",
+                address
+            );
+        }
+
+        let resource: serde_json::Value = response
+            .error_for_status()
+            .context("Fungible asset metadata request failed")?
+            .json()
+            .await
+            .context("Failed to parse fungible asset metadata response")?;
+
+        let decimals = resource["data"]["decimals"]
+            .as_u64()
+            .with_context(|| format!("Metadata resource of {} has no decimals field", address))?;
+        u8::try_from(decimals)
+            .with_context(|| format!("decimals of {} does not fit in a u8: {}", address, decimals))
+    }
+
     /// Returns the base URL of this client
     #[allow(dead_code)]
     pub fn base_url(&self) -> &str {
@@ -360,6 +706,28 @@ impl MvmClient {
         &self,
         solver_addr: &str,
         solver_registry_addr: &str,
+    ) -> Result<Option<Vec<u8>>> {
+        if let Some(SolverLookupValue::PublicKey(public_key)) =
+            self.cached_solver_lookup(SolverLookup::PublicKey, solver_addr, solver_registry_addr)
+        {
+            return Ok(public_key);
+        }
+        let public_key = self
+            .fetch_solver_public_key(solver_addr, solver_registry_addr)
+            .await?;
+        self.cache_solver_lookup(
+            SolverLookup::PublicKey,
+            solver_addr,
+            solver_registry_addr,
+            SolverLookupValue::PublicKey(public_key.clone()),
+        );
+        Ok(public_key)
+    }
+
+    async fn fetch_solver_public_key(
+        &self,
+        solver_addr: &str,
+        solver_registry_addr: &str,
     ) -> Result<Option<Vec<u8>>> {
         if !solver_addr.starts_with("0x") {
             return Err(anyhow::anyhow!(
@@ -373,78 +741,32 @@ impl MvmClient {
             solver_addr
         );
 
-        let result = self
-            .call_view_function(
+        let public_key = self
+            .call_view_bytes(
                 solver_registry_addr,
                 "solver_registry",
                 "get_public_key",
                 vec![],
                 vec![serde_json::json!(solver_addr)],
             )
-            .await;
-
-        match result {
-            Ok(value) => {
-                tracing::debug!(
-                    "View function returned for solver '{}': {:?}",
+            .await
+            .map_err(|e| {
+                anyhow::anyhow!(
+                    "Failed to query solver public key for '{}': {:#}",
                     solver_addr,
-                    value
-                );
-                let outer_array = value.as_array().ok_or_else(|| {
-                    anyhow::anyhow!(
-                        "Unexpected response format for solver '{}': expected array, got {:?}",
-                        solver_addr,
-                        value
-                    )
-                })?;
+                    e
+                )
+            })?;
 
-                let first_result = outer_array.first().ok_or_else(|| {
-                    anyhow::anyhow!(
-                        "Empty response array for solver '{}': expected at least one element",
-                        solver_addr
-                    )
-                })?;
-
-                let hex_str = first_result.as_str().ok_or_else(|| {
-                    anyhow::anyhow!(
-                        "Unexpected response format for solver '{}': expected hex string, got {:?}",
-                        solver_addr,
-                        first_result
-                    )
-                })?;
-
-                let hex_str = hex_str.strip_prefix("0x").unwrap_or(hex_str);
-                if hex_str.is_empty() {
-                    tracing::debug!("Solver '{}' not registered (empty public key)", solver_addr);
-                    Ok(None)
-                } else {
-                    // Decode hex manually (no hex crate dependency)
-                    let bytes = (0..hex_str.len())
-                        .step_by(2)
-                        .map(|i| {
-                            u8::from_str_radix(&hex_str[i..i + 2], 16).map_err(|e| {
-                                anyhow::anyhow!(
-                                    "Failed to decode hex public key for solver '{}': {}",
-                                    solver_addr,
-                                    e
-                                )
-                            })
-                        })
-                        .collect::<Result<Vec<u8>>>()?;
-                    tracing::debug!(
-                        "Solver '{}' registered with public key ({} bytes)",
-                        solver_addr,
-                        bytes.len()
-                    );
-                    Ok(Some(bytes))
-                }
-            }
-            Err(e) => Err(anyhow::anyhow!(
-                "Failed to query solver public key for '{}': {}",
+        match &public_key {
+            Some(bytes) => tracing::debug!(
+                "Solver '{}' registered with public key ({} bytes)",
                 solver_addr,
-                e
-            )),
+                bytes.len()
+            ),
+            None => tracing::debug!("Solver '{}' not registered (empty public key)", solver_addr),
         }
+        Ok(public_key)
     }
 
     /// Queries the solver registry to get a solver's EVM address.
@@ -452,6 +774,20 @@ impl MvmClient {
         &self,
         solver_addr: &str,
         solver_registry_addr: &str,
+    ) -> Result<Option<String>> {
+        self.cached_solver_address(
+            SolverLookup::EvmAddress,
+            solver_addr,
+            solver_registry_addr,
+            self.fetch_solver_evm_address(solver_addr, solver_registry_addr),
+        )
+        .await
+    }
+
+    async fn fetch_solver_evm_address(
+        &self,
+        solver_addr: &str,
+        solver_registry_addr: &str,
     ) -> Result<Option<String>> {
         tracing::debug!(
             "get_solver_evm_address called with solver_addr='{}' (len: {}, type: str), solver_registry_addr='{}' (len: {}, type: str)",
@@ -461,11 +797,7 @@ impl MvmClient {
             solver_registry_addr.len()
         );
 
-        let solver_addr_stripped = solver_addr
-            .strip_prefix("0x")
-            .unwrap_or(solver_addr)
-            .to_lowercase();
-        let solver_addr_normalized = format!("{:0>64}", solver_addr_stripped);
+        let solver_addr_normalized = normalize_move_address(solver_addr);
 
         tracing::debug!(
             "Normalized solver_addr='{}' -> normalized='{}' (len: {})",
@@ -482,10 +814,7 @@ impl MvmClient {
                 None => return Ok(None),
             };
 
-        let data_array = match Self::extract_solvers_data_array(registry_resource) {
-            Some(array) => array,
-            None => return Ok(None),
-        };
+        let data_array = Self::extract_solvers_data_array(registry_resource)?;
 
         let entry_obj =
             match Self::find_solver_entry(data_array, solver_addr, &solver_addr_normalized) {
@@ -591,11 +920,21 @@ impl MvmClient {
         solver_addr: &str,
         solver_registry_addr: &str,
     ) -> Result<Option<String>> {
-        let solver_addr_stripped = solver_addr
-            .strip_prefix("0x")
-            .unwrap_or(solver_addr)
-            .to_lowercase();
-        let solver_addr_normalized = format!("{:0>64}", solver_addr_stripped);
+        self.cached_solver_address(
+            SolverLookup::SvmAddress,
+            solver_addr,
+            solver_registry_addr,
+            self.fetch_solver_svm_address(solver_addr, solver_registry_addr),
+        )
+        .await
+    }
+
+    async fn fetch_solver_svm_address(
+        &self,
+        solver_addr: &str,
+        solver_registry_addr: &str,
+    ) -> Result<Option<String>> {
+        let solver_addr_normalized = normalize_move_address(solver_addr);
 
         let resources = self.get_resources(solver_registry_addr).await?;
 
@@ -605,10 +944,7 @@ impl MvmClient {
                 None => return Ok(None),
             };
 
-        let data_array = match Self::extract_solvers_data_array(registry_resource) {
-            Some(array) => array,
-            None => return Ok(None),
-        };
+        let data_array = Self::extract_solvers_data_array(registry_resource)?;
 
         let entry_obj =
             match Self::find_solver_entry(data_array, solver_addr, &solver_addr_normalized) {
@@ -666,11 +1002,21 @@ impl MvmClient {
         solver_addr: &str,
         solver_registry_addr: &str,
     ) -> Result<Option<String>> {
-        let solver_addr_stripped = solver_addr
-            .strip_prefix("0x")
-            .unwrap_or(solver_addr)
-            .to_lowercase();
-        let solver_addr_normalized = format!("{:0>64}", solver_addr_stripped);
+        self.cached_solver_address(
+            SolverLookup::MvmAddress,
+            solver_addr,
+            solver_registry_addr,
+            self.fetch_solver_mvm_address(solver_addr, solver_registry_addr),
+        )
+        .await
+    }
+
+    async fn fetch_solver_mvm_address(
+        &self,
+        solver_addr: &str,
+        solver_registry_addr: &str,
+    ) -> Result<Option<String>> {
+        let solver_addr_normalized = normalize_move_address(solver_addr);
 
         let resources = self.get_resources(solver_registry_addr).await?;
 
@@ -680,10 +1026,7 @@ impl MvmClient {
                 None => return Ok(None),
             };
 
-        let data_array = match Self::extract_solvers_data_array(registry_resource) {
-            Some(array) => array,
-            None => return Ok(None),
-        };
+        let data_array = Self::extract_solvers_data_array(registry_resource)?;
 
         let entry_obj =
             match Self::find_solver_entry(data_array, solver_addr, &solver_addr_normalized) {
@@ -732,35 +1075,20 @@ impl MvmClient {
         resources: &'a [ResourceData],
         solver_registry_addr: &str,
     ) -> Option<&'a ResourceData> {
-        let registry_addr_normalized = solver_registry_addr
-            .strip_prefix("0x")
-            .unwrap_or(solver_registry_addr)
-            .trim_start_matches('0');
-
-        let registry_resource_type_with_prefix =
-            format!("0x{}::solver_registry::SolverRegistry", registry_addr_normalized);
-        let registry_resource_type_without_prefix =
-            format!("{}::solver_registry::SolverRegistry", registry_addr_normalized);
-
-        let registry_addr_with_zeros = solver_registry_addr
-            .strip_prefix("0x")
-            .unwrap_or(solver_registry_addr);
-        let registry_resource_type_with_zeros =
-            format!("0x{}::solver_registry::SolverRegistry", registry_addr_with_zeros);
-
         let resource = resources.iter().find(|r| {
-            r.resource_type == registry_resource_type_with_prefix
-                || r.resource_type == registry_resource_type_without_prefix
-                || r.resource_type == registry_resource_type_with_zeros
+            r.resource_type
+                .split_once("::")
+                .is_some_and(|(addr, rest)| {
+                    rest == "solver_registry::SolverRegistry"
+                        && move_addresses_match(addr, solver_registry_addr)
+                })
         });
 
         if resource.is_none() {
             tracing::warn!(
-                "SolverRegistry resource not found. Registry address: {}, Tried types: '{}', '{}', and '{}', Available resources: {:?}",
+                "SolverRegistry resource not found. Registry address: {} (normalized: {}), Available resources: {:?}",
                 solver_registry_addr,
-                registry_resource_type_with_prefix,
-                registry_resource_type_without_prefix,
-                registry_resource_type_with_zeros,
+                normalize_move_address(solver_registry_addr),
                 resources.iter().map(|r| &r.resource_type).collect::<Vec<_>>()
             );
         }
@@ -771,28 +1099,47 @@ impl MvmClient {
     /// Extract the solvers data array from the SolverRegistry resource.
     ///
     /// SimpleMap<address, SolverInfo> is serialized as {"data": [{"key": address, "value": SolverInfo}, ...]}
-    fn extract_solvers_data_array(registry_resource: &ResourceData) -> Option<&serde_json::Value> {
-        let data = registry_resource.data.as_object()?;
-        let solvers = data.get("solvers")?.as_object()?;
-        solvers.get("data")
+    ///
+    /// A missing, null, or non-array `data` is treated as an empty registry. A resource
+    /// without a `solvers` map does not have the SolverRegistry layout and is an error.
+    fn extract_solvers_data_array(
+        registry_resource: &ResourceData,
+    ) -> Result<&[serde_json::Value]> {
+        let solvers = registry_resource
+            .data
+            .get("solvers")
+            .and_then(|s| s.as_object())
+            .ok_or_else(|| {
+                anyhow::anyhow!(
+                    "Malformed SolverRegistry resource '{}': missing solvers map",
+                    registry_resource.resource_type
+                )
+            })?;
+
+        match solvers.get("data").and_then(|d| d.as_array()) {
+            Some(array) => Ok(array.as_slice()),
+            None => {
+                tracing::debug!(
+                    "SolverRegistry solvers.data is missing or not an array ({:?}); treating registry as empty",
+                    solvers.get("data")
+                );
+                Ok(&[])
+            }
+        }
     }
 
     /// Find the solver entry in the data array by matching normalized addresses.
     fn find_solver_entry<'a>(
-        data_array: &'a serde_json::Value,
+        data_array: &'a [serde_json::Value],
         solver_addr: &str,
         solver_addr_normalized: &str,
     ) -> Option<&'a serde_json::Map<String, serde_json::Value>> {
-        let data_array = data_array.as_array()?;
-
         let available_solvers_debug: Vec<(String, String)> = data_array
             .iter()
             .filter_map(|entry| {
                 let entry_obj = entry.as_object()?;
                 let key = entry_obj.get("key")?.as_str()?;
-                let key_without_prefix = key.strip_prefix("0x").unwrap_or(key).to_lowercase();
-                let key_normalized = format!("{:0>64}", key_without_prefix);
-                Some((key.to_string(), key_normalized))
+                Some((key.to_string(), normalize_move_address(key)))
             })
             .collect();
 
@@ -807,8 +1154,7 @@ impl MvmClient {
         let solver_entry = data_array.iter().find_map(|entry| {
             let entry_obj = entry.as_object()?;
             let key = entry_obj.get("key")?.as_str()?;
-            let key_without_prefix = key.strip_prefix("0x").unwrap_or(key).to_lowercase();
-            let key_normalized = format!("{:0>64}", key_without_prefix);
+            let key_normalized = normalize_move_address(key);
 
             tracing::debug!(
                 "Comparing - Looking for: '{}' (normalized: '{}', len: {}) vs Registry key: '{}' (normalized: '{}', len: {}) -> Match: {}",
@@ -912,17 +1258,12 @@ impl MvmClient {
                     );
                     return Ok(None);
                 }
-                (0..hex_clean.len())
-                    .step_by(2)
-                    .map(|i| {
-                        u8::from_str_radix(&hex_clean[i..i + 2], 16).map_err(|e| {
-                            anyhow::anyhow!(
-                                "Invalid hex byte '{}' at offset {} in {} address for solver '{}': {}",
-                                &hex_clean[i..i + 2], i, chain_label, solver_addr, e
-                            )
-                        })
-                    })
-                    .collect::<Result<Vec<u8>>>()?
+                decode_hex(hex_clean).with_context(|| {
+                    format!(
+                        "Invalid hex in {} address for solver '{}'",
+                        chain_label, solver_addr
+                    )
+                })?
             } else {
                 let vec0_json = serde_json::to_string(bytes_val)
                     .unwrap_or_else(|_| "failed to serialize".to_string());
@@ -1021,6 +1362,80 @@ impl MvmClient {
         Ok(result)
     }
 
+    /// Calls a view function and deserializes the first element of its result.
+    ///
+    /// Aptos returns view results as a JSON array with one entry per return
+    /// value; a non-array or empty response is an error.
+    pub async fn call_view<T: serde::de::DeserializeOwned>(
+        &self,
+        module_addr: &str,
+        module_name: &str,
+        function_name: &str,
+        type_args: Vec<String>,
+        args: Vec<serde_json::Value>,
+    ) -> Result<T> {
+        let function = format!("{}::{}::{}", module_addr, module_name, function_name);
+        let result = self
+            .call_view_function(module_addr, module_name, function_name, type_args, args)
+            .await?;
+
+        let first = match result {
+            serde_json::Value::Array(values) => values.into_iter().next().ok_or_else(|| {
+                anyhow::anyhow!(
+                    "Empty response array from view function {}: expected at least one element",
+                    function
+                )
+            })?,
+            other => {
+                return Err(anyhow::anyhow!(
+                    "Unexpected response format from view function {}: expected array, got {}",
+                    function,
+                    other
+                ))
+            }
+        };
+
+        serde_json::from_value(first)
+            .with_context(|| format!("Failed to deserialize result of view function {}", function))
+    }
+
+    /// Calls a view function returning `vector<u8>` and decodes its hex string.
+    ///
+    /// Aptos serializes an empty vector as `"0x"`, which is returned as `None`.
+    pub async fn call_view_bytes(
+        &self,
+        module_addr: &str,
+        module_name: &str,
+        function_name: &str,
+        type_args: Vec<String>,
+        args: Vec<serde_json::Value>,
+    ) -> Result<Option<Vec<u8>>> {
+        let function = format!("{}::{}::{}", module_addr, module_name, function_name);
+        let value: serde_json::Value = self
+            .call_view(module_addr, module_name, function_name, type_args, args)
+            .await?;
+
+        let hex_str = value.as_str().ok_or_else(|| {
+            anyhow::anyhow!(
+                "Unexpected response format from view function {}: expected hex string, got {}",
+                function,
+                value
+            )
+        })?;
+
+        let hex_str = hex_str.strip_prefix("0x").unwrap_or(hex_str);
+        if hex_str.is_empty() {
+            return Ok(None);
+        }
+        decode_hex(hex_str).map(Some).map_err(|e| {
+            anyhow::anyhow!(
+                "Failed to decode hex result of view function {}: {}",
+                function,
+                e
+            )
+        })
+    }
+
     /// Queries the intent registry for active requester addresses.
     pub async fn get_active_requesters(
         &self,
@@ -1157,12 +1572,7 @@ impl MvmClient {
         solver_addr: &str,
         solver_registry_addr: &str,
     ) -> Result<bool> {
-        // Normalize address (ensure 0x prefix)
-        let solver_addr_normalized = if solver_addr.starts_with("0x") {
-            solver_addr.to_string()
-        } else {
-            format!("0x{}", solver_addr)
-        };
+        let solver_addr_normalized = normalize_move_address(solver_addr);
 
         let result = self
             .call_view_function(
@@ -1,9 +1,10 @@
 pub mod client;
 pub mod types;
 
-pub use client::{normalize_hex_to_address, MvmClient};
+pub use client::{normalize_hex_to_address, MvmClient, DEFAULT_SOLVER_REGISTRY_CACHE_TTL};
 pub use types::{
-    deserialize_move_option_string, deserialize_u64_string, AccountInfo, EventGuid, EventHandle,
-    EventHandleGuid, EventHandleGuidId, LimitOrderEvent, LimitOrderFulfillmentEvent, ModuleInfo,
-    MvmEvent, MvmResponse, MvmTransaction, OracleLimitOrderEvent, ResourceData, Resources,
+    deserialize_move_option_string, deserialize_u64_string, AccountInfo, EscrowCreatedEvent,
+    EventGuid, EventHandle, EventHandleGuid, EventHandleGuidId, LimitOrderEvent,
+    LimitOrderFulfillmentEvent, ModuleInfo, MvmEvent, MvmResponse, MvmTransaction,
+    OracleLimitOrderEvent, ResourceData, Resources,
 };
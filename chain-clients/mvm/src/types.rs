@@ -1,12 +1,19 @@
 //! Type definitions for Move VM REST API responses and Move event structures.
 
+use anyhow::Context;
+use chain_clients_common::event_bytes32;
+use gmp_common::IntentRequirements;
 use serde::{Deserialize, Deserializer, Serialize};
 
 // ============================================================================
 // DESERIALIZATION HELPERS
 // ============================================================================
 
-/// Deserialize u64 from either string or number (Aptos returns chain_id as either).
+/// Deserialize u64 from either string or number (Aptos returns chain_id and amounts as either).
+///
+/// JSON numbers are read through the integer path (`as_u64`), so amounts above 2^53
+/// keep full precision. Fractional, negative, or out-of-range numbers are rejected
+/// instead of being passed on in float form.
 pub fn deserialize_u64_string<'de, D>(deserializer: D) -> Result<String, D::Error>
 where
     D: Deserializer<'de>,
@@ -15,33 +22,47 @@ where
     let value: serde_json::Value = Deserialize::deserialize(deserializer)?;
     match value {
         serde_json::Value::String(s) => Ok(s),
-        serde_json::Value::Number(n) => Ok(n.to_string()),
+        serde_json::Value::Number(n) => n.as_u64().map(|v| v.to_string()).ok_or_else(|| {
+            D::Error::custom(format!(
+                "expected an integer number within u64 range, got: {}",
+                n
+            ))
+        }),
         _ => Err(D::Error::custom(format!(
-            "expected string or number for chain_id, got: {:?}",
+            "expected string or number for u64 field, got: {:?}",
             value
         ))),
     }
 }
 
 /// Deserialize Move's Option<T> format: {"vec": [value]} for Some, {"vec": []} for None.
+///
+/// Also accepts `null` (None) and a bare string (Some) so events that were already
+/// decoded and re-serialized, or fields from older event versions, still parse.
+/// Use together with `#[serde(default)]` so a missing field yields None.
 pub fn deserialize_move_option_string<'de, D>(deserializer: D) -> Result<Option<String>, D::Error>
 where
     D: Deserializer<'de>,
 {
     use serde::de::Error;
     #[derive(Deserialize)]
-    struct MoveOption {
-        vec: Vec<String>,
+    #[serde(untagged)]
+    enum MoveOptionRepr {
+        Wrapped { vec: Vec<String> },
+        Plain(String),
     }
 
-    let opt: MoveOption = Deserialize::deserialize(deserializer)?;
-    match opt.vec.as_slice() {
-        [value] => Ok(Some(value.clone())),
-        [] => Ok(None),
-        _ => Err(D::Error::custom(format!(
-            "expected Move Option format with 0 or 1 element in vec, got {} elements",
-            opt.vec.len()
-        ))),
+    match Option::<MoveOptionRepr>::deserialize(deserializer)? {
+        None => Ok(None),
+        Some(MoveOptionRepr::Plain(value)) => Ok(Some(value)),
+        Some(MoveOptionRepr::Wrapped { vec }) => match vec.as_slice() {
+            [value] => Ok(Some(value.clone())),
+            [] => Ok(None),
+            _ => Err(D::Error::custom(format!(
+                "expected Move Option format with 0 or 1 element in vec, got {} elements",
+                vec.len()
+            ))),
+        },
     }
 }
 
@@ -163,24 +184,29 @@ pub struct LimitOrderEvent {
     pub offered_metadata: serde_json::Value,
     #[serde(
         rename = "offered_metadata_addr",
+        default,
         deserialize_with = "deserialize_move_option_string",
         skip_serializing_if = "Option::is_none"
     )]
     pub offered_metadata_address: Option<String>,
+    #[serde(deserialize_with = "deserialize_u64_string")]
     pub offered_amount: String,
     pub offered_chain_id: String,
     pub desired_metadata: serde_json::Value,
+    #[serde(deserialize_with = "deserialize_u64_string")]
     pub desired_amount: String,
     pub desired_chain_id: String,
     pub requester_addr: String,
     pub expiry_time: String,
     pub revocable: bool,
     #[serde(
+        default,
         deserialize_with = "deserialize_move_option_string",
         skip_serializing_if = "Option::is_none"
     )]
     pub reserved_solver: Option<String>,
     #[serde(
+        default,
         deserialize_with = "deserialize_move_option_string",
         skip_serializing_if = "Option::is_none"
     )]
@@ -193,16 +219,19 @@ pub struct OracleLimitOrderEvent {
     pub intent_addr: String,
     pub intent_id: String,
     pub offered_metadata: serde_json::Value,
+    #[serde(deserialize_with = "deserialize_u64_string")]
     pub offered_amount: String,
     #[serde(deserialize_with = "deserialize_u64_string")]
     pub offered_chain_id: String,
     pub desired_metadata: serde_json::Value,
     #[serde(
         rename = "desired_metadata_addr",
+        default,
         deserialize_with = "deserialize_move_option_string",
         skip_serializing_if = "Option::is_none"
     )]
     pub desired_metadata_address: Option<String>,
+    #[serde(deserialize_with = "deserialize_u64_string")]
     pub desired_amount: String,
     #[serde(deserialize_with = "deserialize_u64_string")]
     pub desired_chain_id: String,
@@ -211,11 +240,13 @@ pub struct OracleLimitOrderEvent {
     pub min_reported_value: String,
     pub revocable: bool,
     #[serde(
+        default,
         deserialize_with = "deserialize_move_option_string",
         skip_serializing_if = "Option::is_none"
     )]
     pub reserved_solver: Option<String>,
     #[serde(
+        default,
         deserialize_with = "deserialize_move_option_string",
         skip_serializing_if = "Option::is_none"
     )]
@@ -230,6 +261,57 @@ pub struct LimitOrderFulfillmentEvent {
     #[serde(rename = "solver")]
     pub solver_addr: String,
     pub provided_metadata: serde_json::Value,
+    #[serde(deserialize_with = "deserialize_u64_string")]
     pub provided_amount: String,
     pub timestamp: String,
 }
+
+/// Represents an EscrowCreated event emitted by the connected-chain inflow escrow
+///
+/// Move renders `vector<u8>` fields as `0x`-prefixed hex and `u64` fields as strings.
+#[derive(Debug, Clone, Serialize, Deserialize)]
+pub struct EscrowCreatedEvent {
+    pub intent_id: String,
+    pub escrow_id: String,
+    #[serde(rename = "requester")]
+    pub requester_addr: String,
+    #[serde(deserialize_with = "deserialize_u64_string")]
+    pub amount: String,
+    pub token_addr: String,
+    pub reserved_solver: String,
+    #[serde(deserialize_with = "deserialize_u64_string")]
+    pub expiry: String,
+}
+
+impl EscrowCreatedEvent {
+    /// Converts the escrow event into the GMP IntentRequirements the hub expects.
+    ///
+    /// Move addresses may be rendered without leading zeros; they are left-padded
+    /// to 32 bytes, matching their BCS encoding.
+    ///
+    /// # Returns
+    ///
+    /// * `Ok(IntentRequirements)` - Requirements with the escrowed amount and expiry
+    /// * `Err(anyhow::Error)` - If an id or address is not 32-byte hex, or a u64 field does not parse
+    pub fn to_intent_requirements(&self) -> anyhow::Result<IntentRequirements> {
+        Ok(IntentRequirements {
+            intent_id: event_bytes32(&self.intent_id, "intent_id")?,
+            requester_addr: event_bytes32(&self.requester_addr, "requester")?,
+            amount_required: self
+                .amount
+                .parse()
+                .with_context(|| format!("EscrowCreated amount is not a u64: {}", self.amount))?,
+            token_addr: event_bytes32(&self.token_addr, "token_addr")?,
+            solver_addr: event_bytes32(&self.reserved_solver, "reserved_solver")?,
+            expiry: self
+                .expiry
+                .parse()
+                .with_context(|| format!("EscrowCreated expiry is not a u64: {}", self.expiry))?,
+        })
+    }
+
+    /// Encodes the escrow event as an IntentRequirements GMP payload.
+    pub fn encode_intent_requirements(&self) -> anyhow::Result<Vec<u8>> {
+        Ok(self.to_intent_requirements()?.encode().to_vec())
+    }
+}
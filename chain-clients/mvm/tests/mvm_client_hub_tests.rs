@@ -7,7 +7,9 @@
 //! Consolidated from coordinator/tests/mvm_client_tests.rs and
 //! integrated-gmp/tests/mvm_client_tests.rs to eliminate duplicate tests.
 
-use chain_clients_mvm::MvmClient;
+use chain_clients_mvm::{
+    LimitOrderEvent, LimitOrderFulfillmentEvent, MvmClient, OracleLimitOrderEvent,
+};
 use serde_json::json;
 use wiremock::matchers::{method, path};
 use wiremock::{Mock, MockServer, ResponseTemplate};
@@ -516,6 +518,23 @@ async fn test_get_solver_svm_address_leading_zero_mismatch() {
     assert_eq!(result, Some(DUMMY_SOLVER_ADDR_SVM.to_string()));
 }
 
+// 41. Test: get_solver_svm_address returns None when address not set
+// Verifies that a registered solver with an empty connected_chain_svm_addr Option yields None.
+// Why: Mirrors the MVM/EVM absent cases so an unset SVM address is not mistaken for a lookup error.
+#[tokio::test]
+async fn test_get_solver_svm_address_not_set() {
+    let resources = create_solver_registry_resource_with_svm_address_array(
+        DUMMY_SOLVER_REGISTRY_ADDR, DUMMY_SOLVER_ADDR_HUB, None,
+    );
+    let (_s, client) = setup_mock_server_with_resources(DUMMY_SOLVER_REGISTRY_ADDR, resources).await;
+
+    let result = client
+        .get_solver_svm_address(DUMMY_SOLVER_ADDR_HUB, DUMMY_SOLVER_REGISTRY_ADDR)
+        .await
+        .unwrap();
+    assert_eq!(result, None);
+}
+
 // ============================================================================
 // SOLVER PUBLIC KEY
 // ============================================================================
@@ -904,3 +923,411 @@ async fn test_has_outflow_requirements_error() {
         .await;
     assert!(result.is_err());
 }
+
+// ============================================================================
+// MOVE OPTION EVENT FIELDS
+// ============================================================================
+
+/// Builds an OracleLimitOrderEvent JSON body with the given Move Option fields.
+fn oracle_limit_order_event_json(
+    reserved_solver: serde_json::Value,
+    requester_addr_connected_chain: serde_json::Value,
+) -> serde_json::Value {
+    json!({
+        "intent_addr": DUMMY_MODULE_ADDR,
+        "intent_id": DUMMY_INTENT_ID,
+        "offered_metadata": {"inner": DUMMY_MODULE_ADDR},
+        "offered_amount": "1000",
+        "offered_chain_id": "1",
+        "desired_metadata": {"inner": DUMMY_MODULE_ADDR},
+        "desired_metadata_addr": {"vec": []},
+        "desired_amount": "1000",
+        "desired_chain_id": "2",
+        "requester_addr": DUMMY_MODULE_ADDR,
+        "expiry_time": "1000000",
+        "min_reported_value": "0",
+        "revocable": false,
+        "reserved_solver": reserved_solver,
+        "requester_addr_connected_chain": requester_addr_connected_chain
+    })
+}
+
+// 33. Test: OracleLimitOrderEvent with a reserved solver
+// Verifies that {"vec": [addr]} Move Option fields decode to Some(addr).
+// Why: Outflow intents always carry a reserved solver; losing it would reject every outflow.
+#[test]
+fn test_oracle_limit_order_event_with_reserved_solver() {
+    let json = oracle_limit_order_event_json(
+        json!({"vec": [DUMMY_SOLVER_ADDR_HUB]}),
+        json!({"vec": [DUMMY_SOLVER_ADDR_MVMCON]}),
+    );
+
+    let event: OracleLimitOrderEvent = serde_json::from_value(json).unwrap();
+    assert_eq!(event.reserved_solver.as_deref(), Some(DUMMY_SOLVER_ADDR_HUB));
+    assert_eq!(
+        event.requester_addr_connected_chain.as_deref(),
+        Some(DUMMY_SOLVER_ADDR_MVMCON)
+    );
+    assert_eq!(event.desired_metadata_address, None);
+}
+
+// 34. Test: OracleLimitOrderEvent without a reserved solver
+// Verifies that an empty {"vec": []}, null, or missing Move Option field decodes to None.
+// Why: An absent reserved solver is a valid event; it must surface as None, not a parse error that drops the event.
+#[test]
+fn test_oracle_limit_order_event_without_reserved_solver() {
+    let json = oracle_limit_order_event_json(json!({"vec": []}), serde_json::Value::Null);
+    let event: OracleLimitOrderEvent = serde_json::from_value(json).unwrap();
+    assert_eq!(event.reserved_solver, None);
+    assert_eq!(event.requester_addr_connected_chain, None);
+
+    let mut json = oracle_limit_order_event_json(json!({"vec": []}), json!({"vec": []}));
+    let fields = json.as_object_mut().unwrap();
+    fields.remove("reserved_solver");
+    fields.remove("requester_addr_connected_chain");
+    fields.remove("desired_metadata_addr");
+    let event: OracleLimitOrderEvent = serde_json::from_value(json).unwrap();
+    assert_eq!(event.reserved_solver, None);
+    assert_eq!(event.requester_addr_connected_chain, None);
+    assert_eq!(event.desired_metadata_address, None);
+}
+
+// 35. Test: LimitOrderEvent Move Option fields round-trip through serde
+// Verifies that a decoded event re-serialized as plain strings decodes again, and that a
+// multi-element vec is rejected.
+// Why: Decoded events are forwarded as JSON; re-parsing them must not fail on the unwrapped form.
+#[test]
+fn test_limit_order_event_move_option_roundtrip() {
+    let json = json!({
+        "intent_addr": DUMMY_MODULE_ADDR,
+        "intent_id": DUMMY_INTENT_ID,
+        "offered_metadata": {"inner": DUMMY_MODULE_ADDR},
+        "offered_metadata_addr": {"vec": []},
+        "offered_amount": "1000",
+        "offered_chain_id": "2",
+        "desired_metadata": {"inner": DUMMY_MODULE_ADDR},
+        "desired_amount": "1000",
+        "desired_chain_id": "1",
+        "requester_addr": DUMMY_MODULE_ADDR,
+        "expiry_time": "1000000",
+        "revocable": true,
+        "reserved_solver": {"vec": [DUMMY_SOLVER_ADDR_HUB]},
+        "requester_addr_connected_chain": {"vec": []}
+    });
+
+    let event: LimitOrderEvent = serde_json::from_value(json.clone()).unwrap();
+    assert_eq!(event.reserved_solver.as_deref(), Some(DUMMY_SOLVER_ADDR_HUB));
+    assert_eq!(event.offered_metadata_address, None);
+
+    let reparsed: LimitOrderEvent =
+        serde_json::from_value(serde_json::to_value(&event).unwrap()).unwrap();
+    assert_eq!(reparsed.reserved_solver, event.reserved_solver);
+    assert_eq!(reparsed.requester_addr_connected_chain, None);
+
+    let mut invalid = json;
+    invalid["reserved_solver"] = json!({"vec": [DUMMY_SOLVER_ADDR_HUB, DUMMY_SOLVER_ADDR_MVMCON]});
+    assert!(serde_json::from_value::<LimitOrderEvent>(invalid).is_err());
+}
+
+// ============================================================================
+// NUMERIC EVENT FIELDS
+// ============================================================================
+
+// 36. Test: Fulfillment amount above 2^53 delivered as a JSON number
+// Verifies that a numeric provided_amount of 9_007_199_254_740_993 parses exactly, and that
+// fractional or out-of-range numbers are rejected.
+// Why: An f64 path would round it to 9_007_199_254_740_992 and silently corrupt the amount.
+#[test]
+fn test_fulfillment_event_numeric_amount_above_f64_precision() {
+    let fulfillment_json = |provided_amount: serde_json::Value| {
+        json!({
+            "intent_addr": DUMMY_MODULE_ADDR,
+            "intent_id": DUMMY_INTENT_ID,
+            "solver": DUMMY_SOLVER_ADDR_HUB,
+            "provided_metadata": {"inner": DUMMY_MODULE_ADDR},
+            "provided_amount": provided_amount,
+            "timestamp": "1000000"
+        })
+    };
+
+    let event: LimitOrderFulfillmentEvent =
+        serde_json::from_value(fulfillment_json(json!(9_007_199_254_740_993u64))).unwrap();
+    assert_eq!(event.provided_amount, "9007199254740993");
+
+    let event: LimitOrderFulfillmentEvent =
+        serde_json::from_value(fulfillment_json(json!(u64::MAX))).unwrap();
+    assert_eq!(event.provided_amount, u64::MAX.to_string());
+
+    for invalid in [json!(1.5), json!(-1), json!(18_446_744_073_709_551_616f64)] {
+        assert!(
+            serde_json::from_value::<LimitOrderFulfillmentEvent>(fulfillment_json(invalid))
+                .is_err()
+        );
+    }
+}
+
+// ============================================================================
+// SOLVER REGISTRY SHAPE EDGE CASES
+// ============================================================================
+
+/// Create a SolverRegistry resource whose `solvers` map is the given JSON value
+fn create_registry_with_solvers(solvers: serde_json::Value) -> serde_json::Value {
+    json!([{
+        "type": format!("{}::solver_registry::SolverRegistry", DUMMY_SOLVER_REGISTRY_ADDR),
+        "data": {"solvers": solvers}
+    }])
+}
+
+// 37. Test: Empty or non-array solvers.data yields None lookups
+// Verifies that a registry with `solvers: {}`, `solvers: {"data": null}`, or
+// `solvers: {"data": {}}` returns None from the MVM, EVM, and SVM address lookups.
+// Why: A freshly initialized or oddly serialized registry has no solvers; lookups must
+// report "not registered" instead of failing opaquely.
+#[tokio::test]
+async fn test_solver_lookup_empty_or_non_array_solvers_data() {
+    for solvers in [json!({}), json!({"data": null}), json!({"data": {}})] {
+        let (_s, client) = setup_mock_server_with_resources(
+            DUMMY_SOLVER_REGISTRY_ADDR,
+            create_registry_with_solvers(solvers.clone()),
+        )
+        .await;
+
+        let mvm = client
+            .get_solver_mvm_address(DUMMY_SOLVER_ADDR_HUB, DUMMY_SOLVER_REGISTRY_ADDR)
+            .await
+            .unwrap();
+        let evm = client
+            .get_solver_evm_address(DUMMY_SOLVER_ADDR_HUB, DUMMY_SOLVER_REGISTRY_ADDR)
+            .await
+            .unwrap();
+        let svm = client
+            .get_solver_svm_address(DUMMY_SOLVER_ADDR_HUB, DUMMY_SOLVER_REGISTRY_ADDR)
+            .await
+            .unwrap();
+        assert_eq!(mvm, None, "solvers = {}", solvers);
+        assert_eq!(evm, None, "solvers = {}", solvers);
+        assert_eq!(svm, None, "solvers = {}", solvers);
+    }
+}
+
+// 38. Test: SolverRegistry resource without a solvers map is an error
+// Verifies that a resource typed as SolverRegistry but lacking the `solvers` map makes
+// the lookup return an error naming the malformed resource.
+// Why: A resource that does not have the registry layout signals a wrong address or
+// module upgrade, which must not be mistaken for an empty registry.
+#[tokio::test]
+async fn test_solver_lookup_errors_on_malformed_registry_resource() {
+    let resources = json!([{
+        "type": format!("{}::solver_registry::SolverRegistry", DUMMY_SOLVER_REGISTRY_ADDR),
+        "data": {"unexpected": []}
+    }]);
+    let (_s, client) =
+        setup_mock_server_with_resources(DUMMY_SOLVER_REGISTRY_ADDR, resources).await;
+
+    let result = client
+        .get_solver_mvm_address(DUMMY_SOLVER_ADDR_HUB, DUMMY_SOLVER_REGISTRY_ADDR)
+        .await;
+    assert!(result
+        .unwrap_err()
+        .to_string()
+        .contains("Malformed SolverRegistry"));
+}
+
+// ============================================================================
+// SOLVER REGISTRY CACHE
+// ============================================================================
+
+// 39. Test: Cached solver lookups within the TTL hit the node once
+// Verifies that with caching enabled, two get_solver_evm_address calls and two
+// get_solver_public_key calls for the same (solver, registry) each send exactly
+// one request to the node.
+// Why: Validating a batch of fulfillments for one solver must not repeat the same
+// registry query per fulfillment.
+#[tokio::test]
+async fn test_solver_registry_cache_serves_repeat_lookups() {
+    let mock_server = MockServer::start().await;
+    Mock::given(method("GET"))
+        .and(path(format!(
+            "/v1/accounts/{}/resources",
+            DUMMY_SOLVER_REGISTRY_ADDR
+        )))
+        .respond_with(ResponseTemplate::new(200).set_body_json(
+            create_solver_registry_resource_with_evm_address_array(
+                DUMMY_SOLVER_REGISTRY_ADDR,
+                DUMMY_SOLVER_ADDR_HUB,
+                Some(DUMMY_SOLVER_ADDR_EVM),
+            ),
+        ))
+        .expect(1)
+        .mount(&mock_server)
+        .await;
+    Mock::given(method("POST"))
+        .and(path("/v1/view"))
+        .respond_with(ResponseTemplate::new(200).set_body_json(json!(["0x01020304"])))
+        .expect(1)
+        .mount(&mock_server)
+        .await;
+
+    let client = MvmClient::new_with_cache(&mock_server.uri(), &Default::default(), true).unwrap();
+    for _ in 0..2 {
+        let evm = client
+            .get_solver_evm_address(DUMMY_SOLVER_ADDR_HUB, DUMMY_SOLVER_REGISTRY_ADDR)
+            .await
+            .unwrap();
+        assert_eq!(evm.as_deref(), Some(DUMMY_SOLVER_ADDR_EVM));
+        let public_key = client
+            .get_solver_public_key(DUMMY_SOLVER_ADDR_HUB, DUMMY_SOLVER_REGISTRY_ADDR)
+            .await
+            .unwrap();
+        assert_eq!(public_key, Some(DUMMY_PUBLIC_KEY.to_vec()));
+    }
+}
+
+// 40. Test: Solver registry cache expires and never stores errors
+// Verifies that a failed lookup is retried on the next call instead of being cached,
+// and that a cached value is refetched once the TTL has passed.
+// Why: A transient node error must not stick, and registry updates must become
+// visible after the TTL.
+#[tokio::test]
+async fn test_solver_registry_cache_expires_and_skips_errors() {
+    let mock_server = MockServer::start().await;
+    Mock::given(method("POST"))
+        .and(path("/v1/view"))
+        .respond_with(ResponseTemplate::new(500))
+        .up_to_n_times(1)
+        .expect(1)
+        .mount(&mock_server)
+        .await;
+    Mock::given(method("POST"))
+        .and(path("/v1/view"))
+        .respond_with(ResponseTemplate::new(200).set_body_json(json!(["0x01020304"])))
+        .expect(2)
+        .mount(&mock_server)
+        .await;
+
+    let client = MvmClient::new(&mock_server.uri())
+        .unwrap()
+        .with_solver_registry_cache_ttl(std::time::Duration::from_millis(50));
+
+    assert!(client
+        .get_solver_public_key(DUMMY_SOLVER_ADDR_HUB, DUMMY_SOLVER_REGISTRY_ADDR)
+        .await
+        .is_err());
+    for _ in 0..2 {
+        let public_key = client
+            .get_solver_public_key(DUMMY_SOLVER_ADDR_HUB, DUMMY_SOLVER_REGISTRY_ADDR)
+            .await
+            .unwrap();
+        assert_eq!(public_key, Some(DUMMY_PUBLIC_KEY.to_vec()));
+    }
+
+    tokio::time::sleep(std::time::Duration::from_millis(60)).await;
+    client
+        .get_solver_public_key(DUMMY_SOLVER_ADDR_HUB, DUMMY_SOLVER_REGISTRY_ADDR)
+        .await
+        .unwrap();
+}
+
+// ============================================================================
+// VIEW FUNCTION HELPERS
+// ============================================================================
+
+/// Setup a mock server whose /v1/view endpoint returns the given JSON body
+async fn setup_mock_server_with_view_response(
+    view_response: serde_json::Value,
+) -> (MockServer, MvmClient) {
+    let mock_server = MockServer::start().await;
+    Mock::given(method("POST"))
+        .and(path("/v1/view"))
+        .respond_with(ResponseTemplate::new(200).set_body_json(view_response))
+        .mount(&mock_server)
+        .await;
+
+    let client = MvmClient::new(&mock_server.uri()).unwrap();
+    (mock_server, client)
+}
+
+// 42. Test: call_view deserializes the first element of the result array
+// Verifies that call_view returns the first array element typed as T, and rejects a
+// non-array body, an empty array, and an element of the wrong type.
+// Why: Every typed view query relies on this shape check; a mismatched response must
+// fail with a clear error instead of a default value.
+#[tokio::test]
+async fn test_call_view_typed_result_and_errors() {
+    let (_s, client) = setup_mock_server_with_view_response(json!(["42", "ignored"])).await;
+    let value: String = client
+        .call_view(DUMMY_MODULE_ADDR, "m", "f", vec![], vec![])
+        .await
+        .unwrap();
+    assert_eq!(value, "42");
+
+    let (_s, client) = setup_mock_server_with_view_response(json!({"value": true})).await;
+    let err = client
+        .call_view::<bool>(DUMMY_MODULE_ADDR, "m", "f", vec![], vec![])
+        .await
+        .unwrap_err();
+    assert!(err.to_string().contains("expected array"));
+
+    let (_s, client) = setup_mock_server_with_view_response(json!([])).await;
+    let err = client
+        .call_view::<bool>(DUMMY_MODULE_ADDR, "m", "f", vec![], vec![])
+        .await
+        .unwrap_err();
+    assert!(err.to_string().contains("Empty response array"));
+
+    let (_s, client) = setup_mock_server_with_view_response(json!(["not a bool"])).await;
+    let err = client
+        .call_view::<bool>(DUMMY_MODULE_ADDR, "m", "f", vec![], vec![])
+        .await
+        .unwrap_err();
+    assert!(err.to_string().contains("Failed to deserialize result"));
+}
+
+// 43. Test: call_view_bytes decodes hex and maps empty "0x" to None
+// Verifies that a "0x"-prefixed hex string decodes to bytes and that "0x" yields None.
+// Why: Aptos serializes vector<u8> as a hex string and an empty vector as "0x".
+#[tokio::test]
+async fn test_call_view_bytes_decodes_hex() {
+    let (_s, client) = setup_mock_server_with_view_response(json!(["0x0a0Bff"])).await;
+    let bytes = client
+        .call_view_bytes(DUMMY_MODULE_ADDR, "m", "f", vec![], vec![])
+        .await
+        .unwrap();
+    assert_eq!(bytes, Some(vec![0x0a, 0x0b, 0xff]));
+
+    let (_s, client) = setup_mock_server_with_view_response(json!(["0x"])).await;
+    let bytes = client
+        .call_view_bytes(DUMMY_MODULE_ADDR, "m", "f", vec![], vec![])
+        .await
+        .unwrap();
+    assert_eq!(bytes, None);
+}
+
+// 44. Test: call_view_bytes rejects malformed hex results
+// Verifies that an odd-length hex string, a non-string element, and non-ASCII content
+// return errors.
+// Why: An odd-length value used to be sliced past its end; malformed input must fail
+// without panicking.
+#[tokio::test]
+async fn test_call_view_bytes_rejects_malformed_hex() {
+    let (_s, client) = setup_mock_server_with_view_response(json!(["0x123"])).await;
+    let err = client
+        .call_view_bytes(DUMMY_MODULE_ADDR, "m", "f", vec![], vec![])
+        .await
+        .unwrap_err();
+    assert!(err.to_string().contains("odd number of hex digits"));
+
+    let (_s, client) = setup_mock_server_with_view_response(json!([[1, 2, 3]])).await;
+    let err = client
+        .call_view_bytes(DUMMY_MODULE_ADDR, "m", "f", vec![], vec![])
+        .await
+        .unwrap_err();
+    assert!(err.to_string().contains("expected hex string"));
+
+    let (_s, client) = setup_mock_server_with_view_response(json!(["0xé1"])).await;
+    let err = client
+        .call_view_bytes(DUMMY_MODULE_ADDR, "m", "f", vec![], vec![])
+        .await
+        .unwrap_err();
+    assert!(err.to_string().contains("Failed to decode hex"));
+}
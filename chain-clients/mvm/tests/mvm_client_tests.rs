@@ -3,9 +3,11 @@
 //! Test ordering matches chain-clients/extension-checklist.md for cross-VM synchronization.
 //! Tests marked N/A in the checklist are skipped with comments.
 
-use chain_clients_mvm::{normalize_hex_to_address, MvmClient};
+use chain_clients_mvm::{normalize_hex_to_address, EscrowCreatedEvent, MvmClient};
+use gmp_common::IntentRequirements;
 use serde_json::json;
-use wiremock::matchers::{method, path};
+use std::collections::HashMap;
+use wiremock::matchers::{header, method, path, query_param};
 use wiremock::{Mock, MockServer, ResponseTemplate};
 
 // ============================================================================
@@ -233,3 +235,299 @@ fn test_normalize_hex_to_address_no_prefix() {
 // #22-#24: EVM address normalization - N/A for MVM
 // #25-#26: SVM pubkey from hex - N/A for MVM
 // #27-#28: SVM escrow parsing - N/A for MVM
+
+// ============================================================================
+// #29: custom RPC headers
+// ============================================================================
+
+// 29. Test: client sends configured headers on every request
+// Verifies that a client built with new_with_headers() sends the configured header, and that a client without it is rejected by a provider that requires it.
+// Why: Hosted RPC providers authenticate via headers (e.g. x-api-key); a dropped header would fail every call in production.
+#[tokio::test]
+async fn test_client_sends_custom_headers() {
+    let mock_server = MockServer::start().await;
+
+    Mock::given(method("POST"))
+        .and(path("/v1/view"))
+        .and(header("x-api-key", "test-key"))
+        .respond_with(ResponseTemplate::new(200).set_body_json(json!(["1000000"])))
+        .mount(&mock_server)
+        .await;
+
+    let headers = HashMap::from([("x-api-key".to_string(), "test-key".to_string())]);
+    let client = MvmClient::new_with_headers(&mock_server.uri(), &headers).unwrap();
+    let balance = client
+        .get_token_balance(DUMMY_TOKEN_ADDR, DUMMY_TOKEN_ADDR)
+        .await
+        .unwrap();
+    assert_eq!(balance, 1_000_000);
+
+    let client_without_headers = MvmClient::new(&mock_server.uri()).unwrap();
+    let result = client_without_headers
+        .get_token_balance(DUMMY_TOKEN_ADDR, DUMMY_TOKEN_ADDR)
+        .await;
+    assert!(result.is_err());
+}
+
+// #30: get_escrow_claimed_events_success - N/A for MVM (EVM log-based settlement monitor)
+// #31: get_escrow_cancelled_events_success - N/A for MVM
+// #32: get_transaction_by_block_number_and_index_success - N/A for MVM (EVM block/index transaction lookup)
+// #33: get_transaction_by_block_number_and_index_not_found - N/A for MVM
+// #34: test_evm_rpc_error_classification - N/A for MVM (EVM JSON-RPC error codes)
+// #35: test_json_rpc_error_is_typed - N/A for MVM
+
+// #36: test_escrow_pda_helpers_match_program_seeds - N/A for MVM (Solana program-derived addresses)
+
+// ============================================================================
+// #37: gas price estimate
+// ============================================================================
+
+// 37. Test: gas price estimate is read from the node
+// Verifies that estimate_gas_price() returns gas_estimate from /v1/estimate_gas_price and fails when the field is missing.
+// Why: Solvers multiply this price by expected gas units to enforce a per-fulfillment fee budget; a wrong price breaks the cap.
+#[tokio::test]
+async fn test_gas_price_estimate() {
+    let mock_server = MockServer::start().await;
+
+    Mock::given(method("GET"))
+        .and(path("/v1/estimate_gas_price"))
+        .respond_with(ResponseTemplate::new(200).set_body_json(json!({
+            "deprioritized_gas_estimate": 100,
+            "gas_estimate": 150,
+            "prioritized_gas_estimate": 200
+        })))
+        .mount(&mock_server)
+        .await;
+
+    let client = MvmClient::new(&mock_server.uri()).unwrap();
+    assert_eq!(client.estimate_gas_price().await.unwrap(), 150);
+
+    let empty_server = MockServer::start().await;
+    Mock::given(method("GET"))
+        .and(path("/v1/estimate_gas_price"))
+        .respond_with(ResponseTemplate::new(200).set_body_json(json!({})))
+        .mount(&empty_server)
+        .await;
+
+    let client = MvmClient::new(&empty_server.uri()).unwrap();
+    assert!(client.estimate_gas_price().await.is_err());
+}
+
+// ============================================================================
+// #38-39: token metadata resolution
+// ============================================================================
+
+// 38. Test: fungible asset metadata resolves
+// Verifies that is_fungible_asset_metadata() returns true when the 0x1::fungible_asset::Metadata resource exists.
+// Why: Solvers only accept intents whose tokens exist on the chain they claim to be on.
+#[tokio::test]
+async fn test_token_metadata_resolves() {
+    let mock_server = MockServer::start().await;
+
+    Mock::given(method("GET"))
+        .and(path(format!(
+            "/v1/accounts/{}/resource/0x1::fungible_asset::Metadata",
+            DUMMY_TOKEN_ADDR
+        )))
+        .respond_with(ResponseTemplate::new(200).set_body_json(json!({
+            "type": "0x1::fungible_asset::Metadata",
+            "data": {"decimals": 8, "name": "Test", "symbol": "TST"}
+        })))
+        .mount(&mock_server)
+        .await;
+
+    let client = MvmClient::new(&mock_server.uri()).unwrap();
+    assert!(client
+        .is_fungible_asset_metadata(DUMMY_TOKEN_ADDR)
+        .await
+        .unwrap());
+}
+
+// 39. Test: unknown token metadata does not resolve
+// Verifies that is_fungible_asset_metadata() returns false when the node answers 404 for the resource.
+// Why: A missing resource means the address is not a token here; it must reject the token, not fail as an RPC error.
+#[tokio::test]
+async fn test_token_metadata_unresolvable() {
+    let mock_server = MockServer::start().await;
+
+    Mock::given(method("GET"))
+        .respond_with(ResponseTemplate::new(404).set_body_json(json!({
+            "error_code": "resource_not_found"
+        })))
+        .mount(&mock_server)
+        .await;
+
+    let client = MvmClient::new(&mock_server.uri()).unwrap();
+    assert!(!client
+        .is_fungible_asset_metadata(DUMMY_TOKEN_ADDR)
+        .await
+        .unwrap());
+}
+
+// ============================================================================
+// ESCROW EVENT TO INTENT REQUIREMENTS
+// ============================================================================
+
+// 40. Test: escrow event converts to IntentRequirements
+// Verifies that an EscrowCreated event as returned by the REST API encodes to an IntentRequirements payload that decodes back with 32-byte left-padded addresses.
+// Why: The node renders addresses without leading zeros and u64 fields as strings; both must map to the fixed-width GMP layout.
+#[test]
+fn test_escrow_event_to_intent_requirements() {
+    let event: EscrowCreatedEvent = serde_json::from_value(json!({
+        "intent_id": DUMMY_INTENT_ID,
+        "escrow_id": DUMMY_INTENT_ID,
+        "requester": "0xbb",
+        "amount": "1000000",
+        "token_addr": DUMMY_TOKEN_ADDR,
+        "reserved_solver": "0xcc",
+        "expiry": "1700000000"
+    }))
+    .unwrap();
+
+    let payload = event.encode_intent_requirements().unwrap();
+    let decoded = IntentRequirements::decode(&payload).unwrap();
+
+    assert_eq!(decoded, event.to_intent_requirements().unwrap());
+    assert_eq!(decoded.requester_addr[..31], [0u8; 31]);
+    assert_eq!(decoded.requester_addr[31], 0xbb);
+    assert_eq!(decoded.solver_addr[31], 0xcc);
+    assert_eq!(decoded.amount_required, 1_000_000);
+    assert_eq!(decoded.expiry, 1_700_000_000);
+
+    let invalid = EscrowCreatedEvent {
+        expiry: "soon".to_string(),
+        ..event
+    };
+    assert!(invalid.to_intent_requirements().is_err());
+}
+
+// ============================================================================
+// #41: chain timestamp
+// ============================================================================
+
+// 41. Test: latest chain timestamp is read from the node
+// Verifies that get_ledger_timestamp() converts ledger_timestamp from /v1 (microseconds) to seconds and fails when the field is missing.
+// Why: Services compare the chain clock against the host clock; a unit mix-up would report a huge drift.
+#[tokio::test]
+async fn test_get_chain_timestamp() {
+    let mock_server = MockServer::start().await;
+
+    Mock::given(method("GET"))
+        .and(path("/v1"))
+        .respond_with(ResponseTemplate::new(200).set_body_json(json!({
+            "chain_id": 4,
+            "ledger_version": "100",
+            "ledger_timestamp": "1700000000123456"
+        })))
+        .mount(&mock_server)
+        .await;
+
+    let client = MvmClient::new(&mock_server.uri()).unwrap();
+    assert_eq!(client.get_ledger_timestamp().await.unwrap(), 1_700_000_000);
+
+    let empty_server = MockServer::start().await;
+    Mock::given(method("GET"))
+        .and(path("/v1"))
+        .respond_with(ResponseTemplate::new(200).set_body_json(json!({})))
+        .mount(&empty_server)
+        .await;
+
+    let client = MvmClient::new(&empty_server.uri()).unwrap();
+    assert!(client.get_ledger_timestamp().await.is_err());
+}
+
+// ============================================================================
+// #42: token decimals
+// ============================================================================
+
+// 42. Test: token decimals are read from the chain
+// Verifies that get_token_decimals() reads decimals from the 0x1::fungible_asset::Metadata resource and fails with a "not a fungible asset" error on 404.
+// Why: Amounts are converted with the token's decimals; a non-token address must be rejected, not treated as 0 decimals.
+#[tokio::test]
+async fn test_get_token_decimals() {
+    let mock_server = MockServer::start().await;
+    Mock::given(method("GET"))
+        .and(path(format!(
+            "/v1/accounts/{}/resource/0x1::fungible_asset::Metadata",
+            DUMMY_TOKEN_ADDR
+        )))
+        .respond_with(ResponseTemplate::new(200).set_body_json(json!({
+            "type": "0x1::fungible_asset::Metadata",
+            "data": {"decimals": 8, "name": "Test", "symbol": "TST"}
+        })))
+        .mount(&mock_server)
+        .await;
+
+    let client = MvmClient::new(&mock_server.uri()).unwrap();
+    assert_eq!(
+        client.get_token_decimals(DUMMY_TOKEN_ADDR).await.unwrap(),
+        8
+    );
+
+    let missing_server = MockServer::start().await;
+    Mock::given(method("GET"))
+        .respond_with(ResponseTemplate::new(404).set_body_json(json!({
+            "error_code": "resource_not_found"
+        })))
+        .mount(&missing_server)
+        .await;
+
+    let client = MvmClient::new(&missing_server.uri()).unwrap();
+    let err = client
+        .get_token_decimals(DUMMY_TOKEN_ADDR)
+        .await
+        .unwrap_err();
+    assert!(err.to_string().contains("is not a fungible asset"));
+}
+
+// #43: test_escrow_log_u64_word_overflow_rejected - N/A for MVM (EVM ABI log words)
+// #44: test_escrow_log_u64_word_decoded - N/A for MVM
+// #45: test_get_escrow_events_chunks_block_range - N/A for MVM (EVM eth_getLogs block ranges)
+
+// ============================================================================
+// ACCOUNT TRANSACTION CURSOR
+// ============================================================================
+
+// 46. Test: get_account_module_events forwards start and returns the next cursor
+// Verifies that the start cursor is sent as the `start` query param and that the returned cursor is one past the highest transaction sequence number in the response.
+// Why: A long-running poller must resume where it stopped instead of re-scanning the account from its first transaction.
+#[tokio::test]
+async fn test_get_account_module_events_resumes_from_cursor() {
+    let mock_server = MockServer::start().await;
+    Mock::given(method("GET"))
+        .and(path(format!(
+            "/v1/accounts/{}/transactions",
+            DUMMY_MODULE_ADDR
+        )))
+        .and(query_param("start", "7"))
+        .and(query_param("limit", "2"))
+        .respond_with(ResponseTemplate::new(200).set_body_json(json!([
+            {
+                "version": "1200",
+                "sequence_number": "7",
+                "events": [{
+                    "type": format!("{}::intent_inflow_escrow::EscrowCreated", DUMMY_MODULE_ADDR),
+                    "sequence_number": "0",
+                    "data": {"intent_id": DUMMY_INTENT_ID}
+                }]
+            },
+            {"version": "1250", "sequence_number": "8", "events": []}
+        ])))
+        .expect(1)
+        .mount(&mock_server)
+        .await;
+
+    let client = MvmClient::new(&mock_server.uri()).unwrap();
+    let (events, next_start) = client
+        .get_account_module_events(DUMMY_MODULE_ADDR, Some(7), Some(2))
+        .await
+        .unwrap();
+
+    assert_eq!(events.len(), 1);
+    assert_eq!(events[0].data["intent_id"], DUMMY_INTENT_ID);
+    assert_eq!(next_start, Some(9));
+}
+
+// #47: test_client_methods_surface_typed_rpc_errors - N/A for MVM (EVM JSON-RPC error codes)
+// #48: test_subscribe_escrow_events_receives_pushed_log - N/A for MVM (EVM eth_subscribe)
+// #49: test_parse_legacy_escrow_without_refund_recipient - N/A for MVM (SVM escrow account layout)
@@ -5,10 +5,11 @@
 
 use anyhow::{Context, Result};
 use base64::{engine::general_purpose::STANDARD, Engine as _};
-use borsh::BorshDeserialize;
+use chain_clients_common::build_header_map;
 use reqwest::Client;
 use serde::Serialize;
 use solana_program::pubkey::Pubkey;
+use std::collections::HashMap;
 use std::str::FromStr;
 use std::time::Duration;
 
@@ -45,9 +46,21 @@ pub struct SvmClient {
 impl SvmClient {
     /// Creates a new SVM client for the given RPC URL and escrow program ID
     pub fn new(rpc_url: &str, program_id: &str) -> Result<Self> {
+        Self::new_with_headers(rpc_url, program_id, &HashMap::new())
+    }
+
+    /// Creates a new SVM client that sends `headers` with every RPC request.
+    ///
+    /// Used for RPC providers that authenticate via a header (e.g. `Authorization`, `x-api-key`).
+    pub fn new_with_headers(
+        rpc_url: &str,
+        program_id: &str,
+        headers: &HashMap<String, String>,
+    ) -> Result<Self> {
         let client = Client::builder()
             .timeout(Duration::from_secs(30))
             .no_proxy()
+            .default_headers(build_header_map(headers)?)
             .build()
             .context("Failed to create HTTP client")?;
 
@@ -73,7 +86,7 @@ impl SvmClient {
 
     /// Derives the escrow PDA for a given intent ID
     pub fn escrow_pda(&self, intent_id: &[u8; 32]) -> Pubkey {
-        Pubkey::find_program_address(&[b"escrow", intent_id], &self.program_id).0
+        crate::pda::escrow_pda(&self.program_id, intent_id)
     }
 
     /// Reads raw account data (base64-decoded) for any Solana account.
@@ -119,9 +132,11 @@ impl SvmClient {
         Ok(Some(data))
     }
 
-    /// Checks if an inflow escrow has been released (is_claimed == true).
+    /// Checks if an inflow escrow has been released to the solver (status == Claimed).
     ///
     /// Reads the escrow PDA account via getAccountInfo and parses the Borsh data.
+    /// A legacy escrow cancelled before the status byte existed also reads as
+    /// `Claimed` (see [`EscrowStatus`]), so this returns true for it as well.
     pub async fn is_escrow_released(&self, intent_id: &str) -> Result<bool> {
         let intent_bytes = parse_intent_id(intent_id)?;
         let escrow_pda = self.escrow_pda(&intent_bytes);
@@ -131,10 +146,10 @@ impl SvmClient {
             .await?
             .context("Escrow account not found")?;
 
-        let escrow = EscrowAccount::try_from_slice(&data)
+        let escrow = EscrowAccount::unpack(&data)
             .context("Failed to parse escrow account data")?;
 
-        Ok(escrow.is_claimed)
+        Ok(escrow.status == EscrowStatus::Claimed)
     }
 
     /// Queries the SPL token balance for an owner's associated token account.
@@ -216,6 +231,61 @@ impl SvmClient {
         Ok(balance.value as u128)
     }
 
+    /// Queries the estimated production time (Unix seconds) of the latest slot.
+    ///
+    /// Calls getSlot, then getBlockTime for that slot.
+    pub async fn get_latest_block_time(&self) -> Result<u64> {
+        let slot_request = JsonRpcRequest {
+            jsonrpc: "2.0".to_string(),
+            method: "getSlot".to_string(),
+            params: serde_json::json!([]),
+            id: 1,
+        };
+
+        let slot_response: JsonRpcResponse<u64> = self
+            .client
+            .post(&self.rpc_url)
+            .json(&slot_request)
+            .send()
+            .await
+            .context("Failed to call getSlot")?
+            .json()
+            .await
+            .context("Failed to parse getSlot response")?;
+
+        if let Some(error) = slot_response.error {
+            return Err(anyhow::anyhow!("SVM RPC error: {}", error.message));
+        }
+        let slot = slot_response
+            .result
+            .context("No result in getSlot response")?;
+
+        let time_request = JsonRpcRequest {
+            jsonrpc: "2.0".to_string(),
+            method: "getBlockTime".to_string(),
+            params: serde_json::json!([slot]),
+            id: 1,
+        };
+
+        let time_response: JsonRpcResponse<u64> = self
+            .client
+            .post(&self.rpc_url)
+            .json(&time_request)
+            .send()
+            .await
+            .context("Failed to call getBlockTime")?
+            .json()
+            .await
+            .context("Failed to parse getBlockTime response")?;
+
+        if let Some(error) = time_response.error {
+            return Err(anyhow::anyhow!("SVM RPC error: {}", error.message));
+        }
+        time_response
+            .result
+            .context("No block time available for the latest slot")
+    }
+
     /// Queries all escrow accounts owned by the program via getProgramAccounts.
     ///
     /// Returns parsed escrow accounts with their on-chain addresses.
@@ -277,7 +347,7 @@ impl SvmClient {
             return Ok(None);
         };
 
-        let escrow = EscrowAccount::try_from_slice(&data)
+        let escrow = EscrowAccount::unpack(&data)
             .context("Failed to parse escrow account data")?;
 
         Ok(Some(escrow))
@@ -406,11 +476,11 @@ pub fn pubkey_from_hex(value: &str) -> Result<Pubkey> {
     Ok(Pubkey::new_from_array(array))
 }
 
-/// Parses escrow account data from base64-encoded Borsh bytes.
+/// Parses escrow account data from base64-encoded Borsh bytes (current or legacy layout).
 pub fn parse_escrow_data(data_base64: &str) -> Result<EscrowAccount> {
     let data = STANDARD.decode(data_base64)
         .context("Failed to decode base64 escrow account data")?;
-    EscrowAccount::try_from_slice(&data)
+    EscrowAccount::unpack(&data)
         .context("Failed to deserialize escrow account from Borsh bytes")
 }
 
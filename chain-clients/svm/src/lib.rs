@@ -3,10 +3,11 @@
 //! Shared SVM JSON-RPC client used by coordinator, integrated-gmp, and solver.
 
 pub mod client;
+pub mod pda;
 pub mod types;
 
 pub use client::{parse_escrow_data, parse_intent_id, pubkey_from_hex, pubkey_to_hex, SvmClient};
-pub use types::{EscrowAccount, EscrowEvent, EscrowWithPubkey};
+pub use types::{EscrowAccount, EscrowEvent, EscrowStatus, EscrowWithPubkey};
 
 // Re-export solana_program for consumers that need Pubkey
 pub use solana_program;
@@ -0,0 +1,43 @@
+//! PDA derivation for the `intent_inflow_escrow` program
+//!
+//! Off-chain services cannot depend on the on-chain program crate, so the seeds
+//! are mirrored here. They must match `intent_inflow_escrow::state::seeds` and
+//! the program's `pda` helpers.
+
+use solana_program::pubkey::Pubkey;
+
+/// Seed for the program's global state account
+pub const STATE_SEED: &[u8] = b"state";
+/// Seed for the per-intent escrow account
+pub const ESCROW_SEED: &[u8] = b"escrow";
+/// Seed for the per-intent escrow vault token account
+pub const VAULT_SEED: &[u8] = b"vault";
+/// Seed for the per-intent stored requirements account
+pub const REQUIREMENTS_SEED: &[u8] = b"requirements";
+/// Seed for the program's GMP config account
+pub const GMP_CONFIG_SEED: &[u8] = b"gmp_config";
+
+/// Derives the program's global state PDA: `["state"]`
+pub fn state_pda(program_id: &Pubkey) -> Pubkey {
+    Pubkey::find_program_address(&[STATE_SEED], program_id).0
+}
+
+/// Derives the escrow PDA for an intent: `["escrow", intent_id]`
+pub fn escrow_pda(program_id: &Pubkey, intent_id: &[u8; 32]) -> Pubkey {
+    Pubkey::find_program_address(&[ESCROW_SEED, intent_id], program_id).0
+}
+
+/// Derives the escrow vault PDA for an intent: `["vault", intent_id]`
+pub fn vault_pda(program_id: &Pubkey, intent_id: &[u8; 32]) -> Pubkey {
+    Pubkey::find_program_address(&[VAULT_SEED, intent_id], program_id).0
+}
+
+/// Derives the stored requirements PDA for an intent: `["requirements", intent_id]`
+pub fn requirements_pda(program_id: &Pubkey, intent_id: &[u8; 32]) -> Pubkey {
+    Pubkey::find_program_address(&[REQUIREMENTS_SEED, intent_id], program_id).0
+}
+
+/// Derives the program's GMP config PDA: `["gmp_config"]`
+pub fn gmp_config_pda(program_id: &Pubkey) -> Pubkey {
+    Pubkey::find_program_address(&[GMP_CONFIG_SEED], program_id).0
+}
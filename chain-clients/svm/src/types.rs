@@ -11,6 +11,19 @@ use solana_program::pubkey::Pubkey;
 // ESCROW ACCOUNT STRUCTURES
 // ============================================================================
 
+/// Escrow lifecycle status (mirrors `EscrowStatus` in the intent_inflow_escrow program)
+///
+/// Escrows written before the status byte replaced `is_claimed` store 1 for both
+/// released and cancelled escrows, so a legacy cancelled escrow reads as `Claimed`.
+#[derive(BorshDeserialize, BorshSerialize, Debug, Clone, Copy, PartialEq, Eq)]
+pub enum EscrowStatus {
+    Active,
+    Claimed,
+    Cancelled,
+    Expired,
+    Recovered,
+}
+
 /// On-chain escrow account data (Borsh-serialized by the intent_inflow_escrow program)
 #[derive(BorshDeserialize, BorshSerialize, Debug, Clone)]
 pub struct EscrowAccount {
@@ -18,11 +31,32 @@ pub struct EscrowAccount {
     pub requester: Pubkey,
     pub token_mint: Pubkey,
     pub amount: u64,
-    pub is_claimed: bool,
+    pub status: EscrowStatus,
     pub expiry: i64,
     pub reserved_solver: Pubkey,
     pub intent_id: [u8; 32],
     pub bump: u8,
+    /// Account refunded on cancel; `Pubkey::default()` when refunds go to the requester
+    pub refund_recipient: Pubkey,
+}
+
+impl EscrowAccount {
+    /// Current account size
+    pub const LEN: usize = 8 + 32 + 32 + 8 + 1 + 8 + 32 + 32 + 1 + 32; // 186 bytes
+    /// Size of escrows created before `refund_recipient` was added
+    pub const LEGACY_LEN: usize = Self::LEN - 32; // 154 bytes
+
+    /// Deserialize escrow account data in either the current or the legacy layout
+    ///
+    /// A legacy escrow has no refund recipient, so it reads as `Pubkey::default()`.
+    pub fn unpack(data: &[u8]) -> std::io::Result<Self> {
+        if data.len() == Self::LEGACY_LEN {
+            let mut padded = data.to_vec();
+            padded.extend_from_slice(Pubkey::default().as_ref());
+            return Self::try_from_slice(&padded);
+        }
+        Self::try_from_slice(data)
+    }
 }
 
 /// Escrow account paired with its on-chain address
@@ -5,9 +5,14 @@
 
 use base64::Engine;
 use borsh::BorshSerialize;
-use chain_clients_svm::{parse_escrow_data, pubkey_from_hex, pubkey_to_hex, EscrowAccount, SvmClient};
+use chain_clients_svm::{
+    parse_escrow_data, parse_intent_id, pda, pubkey_from_hex, pubkey_to_hex, EscrowAccount,
+    EscrowStatus, SvmClient,
+};
 use solana_program::pubkey::Pubkey;
-use wiremock::matchers::method;
+use std::collections::HashMap;
+use std::str::FromStr;
+use wiremock::matchers::{body_partial_json, header, method};
 use wiremock::{Mock, MockServer, ResponseTemplate};
 
 // ============================================================================
@@ -23,18 +28,19 @@ const DUMMY_INTENT_ID: &str =
 // HELPERS
 // ============================================================================
 
-/// Creates a test EscrowAccount with the given is_claimed state.
-fn make_escrow(is_claimed: bool) -> EscrowAccount {
+/// Creates a test EscrowAccount with the given status.
+fn make_escrow(status: EscrowStatus) -> EscrowAccount {
     EscrowAccount {
         discriminator: [0u8; 8],
         requester: Pubkey::default(),
         token_mint: Pubkey::default(),
         amount: 1_000_000,
-        is_claimed,
+        status,
         expiry: 9999999999,
         reserved_solver: Pubkey::default(),
         intent_id: [0u8; 32],
         bump: 255,
+        refund_recipient: Pubkey::default(),
     }
 }
 
@@ -128,13 +134,13 @@ fn test_client_new_rejects_invalid() {
 // ============================================================================
 
 // 3. Test: is_escrow_released returns true when escrow has been released
-// Verifies that is_escrow_released fetches the escrow PDA via getAccountInfo and reads the Borsh-decoded `is_claimed` field when the escrow is released.
+// Verifies that is_escrow_released fetches the escrow PDA via getAccountInfo and reads the Borsh-decoded `status` field when the escrow is released.
 // Why: Release state drives the solver's claim-detection logic; a false negative would block fulfillment.
 #[tokio::test]
 async fn test_is_escrow_released_success() {
     let mock_server = MockServer::start().await;
 
-    let escrow = make_escrow(true);
+    let escrow = make_escrow(EscrowStatus::Claimed);
     Mock::given(method("POST"))
         .respond_with(
             ResponseTemplate::new(200).set_body_json(mock_account_info_response(&escrow)),
@@ -154,7 +160,7 @@ async fn test_is_escrow_released_success() {
 async fn test_is_escrow_released_false() {
     let mock_server = MockServer::start().await;
 
-    let escrow = make_escrow(false);
+    let escrow = make_escrow(EscrowStatus::Active);
     Mock::given(method("POST"))
         .respond_with(
             ResponseTemplate::new(200).set_body_json(mock_account_info_response(&escrow)),
@@ -312,7 +318,7 @@ async fn test_get_native_balance_error() {
 async fn test_get_escrow_events_success() {
     let mock_server = MockServer::start().await;
 
-    let mut escrow = make_escrow(false);
+    let mut escrow = make_escrow(EscrowStatus::Active);
     escrow.intent_id = [0u8; 32];
     escrow.intent_id[31] = 1; // intent_id = 0x...0001
     let escrow_pubkey = Pubkey::new_from_array([42u8; 32]);
@@ -382,12 +388,12 @@ async fn test_get_escrow_events_error() {
 async fn test_get_all_escrows_parses_program_accounts() {
     let mock_server = MockServer::start().await;
 
-    let mut escrow1 = make_escrow(false);
+    let mut escrow1 = make_escrow(EscrowStatus::Active);
     escrow1.intent_id[31] = 1;
     escrow1.amount = 500_000;
     let pk1 = Pubkey::new_from_array([10u8; 32]);
 
-    let mut escrow2 = make_escrow(true);
+    let mut escrow2 = make_escrow(EscrowStatus::Claimed);
     escrow2.intent_id[31] = 2;
     escrow2.amount = 750_000;
     let pk2 = Pubkey::new_from_array([20u8; 32]);
@@ -405,10 +411,10 @@ async fn test_get_all_escrows_parses_program_accounts() {
     assert_eq!(escrows.len(), 2);
     assert_eq!(escrows[0].pubkey, pk1);
     assert_eq!(escrows[0].escrow.amount, 500_000);
-    assert!(!escrows[0].escrow.is_claimed);
+    assert_eq!(escrows[0].escrow.status, EscrowStatus::Active);
     assert_eq!(escrows[1].pubkey, pk2);
     assert_eq!(escrows[1].escrow.amount, 750_000);
-    assert!(escrows[1].escrow.is_claimed);
+    assert_eq!(escrows[1].escrow.status, EscrowStatus::Claimed);
 }
 
 // ============================================================================
@@ -463,11 +469,12 @@ fn test_escrow_account_borsh_roundtrip() {
         requester: Pubkey::new_from_array([1u8; 32]),
         token_mint: Pubkey::new_from_array([2u8; 32]),
         amount: 42,
-        is_claimed: false,
+        status: EscrowStatus::Active,
         expiry: 123456,
         reserved_solver: Pubkey::new_from_array([3u8; 32]),
         intent_id: [4u8; 32],
         bump: 1,
+        refund_recipient: Pubkey::new_from_array([5u8; 32]),
     };
 
     let serialized = escrow.try_to_vec().expect("serialize escrow");
@@ -478,11 +485,12 @@ fn test_escrow_account_borsh_roundtrip() {
     assert_eq!(parsed.requester, escrow.requester);
     assert_eq!(parsed.token_mint, escrow.token_mint);
     assert_eq!(parsed.amount, escrow.amount);
-    assert_eq!(parsed.is_claimed, escrow.is_claimed);
+    assert_eq!(parsed.status, escrow.status);
     assert_eq!(parsed.expiry, escrow.expiry);
     assert_eq!(parsed.reserved_solver, escrow.reserved_solver);
     assert_eq!(parsed.intent_id, escrow.intent_id);
     assert_eq!(parsed.bump, escrow.bump);
+    assert_eq!(parsed.refund_recipient, escrow.refund_recipient);
 }
 
 // 28. Test: parse_escrow_data returns Err for invalid base64
@@ -498,3 +506,149 @@ fn test_escrow_account_invalid_base64() {
     let result = parse_escrow_data(&too_short);
     assert!(result.is_err());
 }
+
+// ============================================================================
+// #29: custom RPC headers
+// ============================================================================
+
+// 29. Test: client sends configured headers on every request
+// Verifies that a client built with new_with_headers() sends the configured header, and that a client without it is rejected by a provider that requires it.
+// Why: Hosted RPC providers authenticate via headers (e.g. x-api-key); a dropped header would fail every call in production.
+#[tokio::test]
+async fn test_client_sends_custom_headers() {
+    let mock_server = MockServer::start().await;
+
+    let escrow = make_escrow(EscrowStatus::Claimed);
+    Mock::given(method("POST"))
+        .and(header("x-api-key", "test-key"))
+        .respond_with(
+            ResponseTemplate::new(200).set_body_json(mock_account_info_response(&escrow)),
+        )
+        .mount(&mock_server)
+        .await;
+
+    let headers = HashMap::from([("x-api-key".to_string(), "test-key".to_string())]);
+    let client =
+        SvmClient::new_with_headers(&mock_server.uri(), DUMMY_PROGRAM_ID, &headers).unwrap();
+    let released = client.is_escrow_released(DUMMY_INTENT_ID).await.unwrap();
+    assert!(released);
+
+    let client_without_headers = SvmClient::new(&mock_server.uri(), DUMMY_PROGRAM_ID).unwrap();
+    let result = client_without_headers.is_escrow_released(DUMMY_INTENT_ID).await;
+    assert!(result.is_err());
+}
+
+// #30: test_get_escrow_claimed_events_success — N/A for SVM (EVM log-based settlement monitor; SVM reads escrow status from account state)
+// #31: test_get_escrow_cancelled_events_success — N/A for SVM
+// #32: test_get_transaction_by_block_number_and_index_success — N/A for SVM (EVM block/index transaction lookup)
+// #33: test_get_transaction_by_block_number_and_index_not_found — N/A for SVM
+// #34: test_evm_rpc_error_classification — N/A for SVM (EVM JSON-RPC error codes)
+// #35: test_json_rpc_error_is_typed — N/A for SVM
+
+// ============================================================================
+// #36: escrow PDA derivation
+// ============================================================================
+
+// 36. Test: pda helpers match the escrow program seeds
+// Verifies that escrow/vault/requirements/gmp_config/state PDAs use the on-chain seeds and that SvmClient::escrow_pda agrees.
+// Why: The relay and services build instruction accounts from these helpers; a seed drift would point every GMP delivery at the wrong accounts.
+#[test]
+fn test_escrow_pda_helpers_match_program_seeds() {
+    let program_id = Pubkey::from_str(DUMMY_PROGRAM_ID).unwrap();
+    let intent_id = parse_intent_id(DUMMY_INTENT_ID).unwrap();
+
+    let expected = |seeds: &[&[u8]]| Pubkey::find_program_address(seeds, &program_id).0;
+    assert_eq!(
+        pda::escrow_pda(&program_id, &intent_id),
+        expected(&[b"escrow", &intent_id])
+    );
+    assert_eq!(
+        pda::vault_pda(&program_id, &intent_id),
+        expected(&[b"vault", &intent_id])
+    );
+    assert_eq!(
+        pda::requirements_pda(&program_id, &intent_id),
+        expected(&[b"requirements", &intent_id])
+    );
+    assert_eq!(pda::gmp_config_pda(&program_id), expected(&[b"gmp_config"]));
+    assert_eq!(pda::state_pda(&program_id), expected(&[b"state"]));
+
+    let client = SvmClient::new("http://127.0.0.1:1", DUMMY_PROGRAM_ID).unwrap();
+    assert_eq!(
+        client.escrow_pda(&intent_id),
+        pda::escrow_pda(&program_id, &intent_id)
+    );
+}
+
+// #37: test_gas_price_estimate — N/A for SVM (fixed per-signature fees, no gas price market)
+// #38: test_token_metadata_resolves — N/A for SVM (solver skips the token resolver for SVM mints)
+// #39: test_token_metadata_unresolvable — N/A for SVM
+// #40: test_escrow_event_to_intent_requirements — N/A for SVM (the SVM escrow program builds IntentRequirements on-chain)
+
+// ============================================================================
+// #41: chain timestamp
+// ============================================================================
+
+// 41. Test: latest chain timestamp is read from the node
+// Verifies that get_latest_block_time() reads the latest slot via getSlot and returns its getBlockTime result.
+// Why: Services compare the chain clock against the host clock; reading the wrong slot would report a stale time.
+#[tokio::test]
+async fn test_get_chain_timestamp() {
+    let mock_server = MockServer::start().await;
+
+    Mock::given(method("POST"))
+        .and(body_partial_json(serde_json::json!({"method": "getSlot"})))
+        .respond_with(ResponseTemplate::new(200).set_body_json(serde_json::json!({
+            "jsonrpc": "2.0",
+            "result": 4242,
+            "id": 1
+        })))
+        .mount(&mock_server)
+        .await;
+    Mock::given(method("POST"))
+        .and(body_partial_json(
+            serde_json::json!({"method": "getBlockTime", "params": [4242]}),
+        ))
+        .respond_with(ResponseTemplate::new(200).set_body_json(serde_json::json!({
+            "jsonrpc": "2.0",
+            "result": 1_700_000_000u64,
+            "id": 1
+        })))
+        .mount(&mock_server)
+        .await;
+
+    let client = SvmClient::new(&mock_server.uri(), DUMMY_PROGRAM_ID).unwrap();
+    assert_eq!(client.get_latest_block_time().await.unwrap(), 1_700_000_000);
+}
+
+// #42: test_get_token_decimals — N/A for SVM (solver clients read mint decimals only for MVM/EVM tokens)
+// #43: test_escrow_log_u64_word_overflow_rejected — N/A for SVM (EVM ABI log words)
+// #44: test_escrow_log_u64_word_decoded — N/A for SVM
+// #45: test_get_escrow_events_chunks_block_range — N/A for SVM (EVM eth_getLogs block ranges)
+// #46: test_get_account_module_events_resumes_from_cursor — N/A for SVM (Aptos account transaction cursor)
+// #47: test_client_methods_surface_typed_rpc_errors — N/A for SVM (EVM JSON-RPC error codes)
+// #48: test_subscribe_escrow_events_receives_pushed_log — N/A for SVM (EVM eth_subscribe)
+
+// ============================================================================
+// #49: Legacy escrow layout
+// ============================================================================
+
+// 49. Test: parse_escrow_data accepts the legacy 154-byte escrow layout
+// Verifies that an escrow written before refund_recipient existed parses with a default refund recipient.
+// Why: Escrows created by the earlier program version are still live on-chain and must stay readable.
+#[test]
+fn test_parse_legacy_escrow_without_refund_recipient() {
+    let mut escrow = make_escrow(EscrowStatus::Claimed);
+    escrow.refund_recipient = Pubkey::new_from_array([5u8; 32]);
+    let mut data = escrow.try_to_vec().expect("serialize escrow");
+    assert_eq!(data.len(), EscrowAccount::LEN);
+    data.truncate(EscrowAccount::LEGACY_LEN);
+    let encoded = base64::engine::general_purpose::STANDARD.encode(&data);
+
+    let parsed = parse_escrow_data(&encoded).expect("parse legacy escrow");
+
+    assert_eq!(parsed.status, EscrowStatus::Claimed);
+    assert_eq!(parsed.amount, escrow.amount);
+    assert_eq!(parsed.intent_id, escrow.intent_id);
+    assert_eq!(parsed.refund_recipient, Pubkey::default());
+}
@@ -121,6 +121,26 @@ pub struct CoordinatorConfig {
     pub polling_interval_ms: u64,
     /// Timeout for validation operations in milliseconds
     pub validation_timeout_ms: u64,
+    /// Interval for the settlement reconciliation sweep in milliseconds (disabled when unset)
+    #[serde(default)]
+    pub settlement_reconciliation_interval_ms: Option<u64>,
+    /// Seconds after a hub fulfillment before an unreleased source escrow is reported
+    #[serde(default = "default_settlement_grace_secs")]
+    pub settlement_grace_secs: u64,
+    /// Largest allowed difference between the host clock and any chain clock in seconds (disabled when unset)
+    #[serde(default)]
+    pub max_clock_drift_secs: Option<u64>,
+    /// Interval for re-checking the clock drift in milliseconds
+    #[serde(default = "default_clock_drift_check_interval_ms")]
+    pub clock_drift_check_interval_ms: u64,
+}
+
+fn default_settlement_grace_secs() -> u64 {
+    300
+}
+
+fn default_clock_drift_check_interval_ms() -> u64 {
+    300_000
 }
 
 /// API server configuration for external communication.
@@ -376,6 +396,10 @@ impl Config {
             coordinator: CoordinatorConfig {
                 polling_interval_ms: 2000,
                 validation_timeout_ms: 30000,
+                settlement_reconciliation_interval_ms: None,
+                settlement_grace_secs: 300,
+                max_clock_drift_secs: None,
+                clock_drift_check_interval_ms: 300_000,
             },
             api: ApiConfig {
                 host: "127.0.0.1".to_string(),
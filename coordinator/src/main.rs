@@ -36,9 +36,10 @@ use config::Config;
 /// This function:
 /// 1. Initializes logging and tracing
 /// 2. Loads configuration from TOML file
-/// 3. Initializes the event monitor
-/// 4. Starts the API server
-/// 5. Runs the service until shutdown
+/// 3. Checks host vs chain clock drift (when configured)
+/// 4. Initializes the event monitor
+/// 5. Starts the API server
+/// 6. Runs the service until shutdown
 #[tokio::main]
 async fn main() -> Result<()> {
     // Initialize structured JSON logging for production log aggregation
@@ -87,6 +88,9 @@ async fn main() -> Result<()> {
     let config = Config::load()?;
     info!("Configuration loaded successfully");
 
+    // Refuse to start if the host clock has drifted from the chains (when configured)
+    monitor::verify_clock_drift(&config).await?;
+
     // Initialize the event monitor
     let monitor = monitor::EventMonitor::new(&config).await?;
 
@@ -0,0 +1,167 @@
+//! Host vs chain clock drift check
+//!
+//! Expiry decisions (skipping expired intents, draft expiry) compare on-chain
+//! expiry timestamps against the host clock. If the host clock drifts away from
+//! the chains' clocks, those decisions go wrong in both directions.
+//!
+//! This module compares each configured chain's latest block/ledger timestamp
+//! with the host clock. At startup a drift beyond `max_clock_drift_secs` refuses
+//! to start the service; afterwards it is re-checked periodically and logged.
+
+use anyhow::Result;
+use chain_clients_evm::EvmClient;
+use chain_clients_mvm::MvmClient;
+use chain_clients_svm::SvmClient;
+use std::time::{SystemTime, UNIX_EPOCH};
+use tracing::{error, info, warn};
+
+use crate::config::Config;
+
+/// A chain whose clock differs from the host clock by more than the allowed drift.
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct ClockDrift {
+    pub chain_name: String,
+    pub chain_id: u64,
+    /// Latest block/ledger timestamp reported by the chain (Unix seconds)
+    pub chain_time: u64,
+    /// Host clock at the time of the check (Unix seconds)
+    pub host_time: u64,
+}
+
+impl ClockDrift {
+    /// Absolute difference between the host and chain clocks in seconds.
+    pub fn drift_secs(&self) -> u64 {
+        self.host_time.abs_diff(self.chain_time)
+    }
+}
+
+impl std::fmt::Display for ClockDrift {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        write!(
+            f,
+            "host clock drifts {}s from {} (chain_id={}, chain_time={}, host_time={})",
+            self.drift_secs(),
+            self.chain_name,
+            self.chain_id,
+            self.chain_time,
+            self.host_time
+        )
+    }
+}
+
+/// Queries the latest timestamp of every configured chain.
+///
+/// Chains whose query fails are skipped with a warning — an unreachable chain
+/// is reported by the other health checks, not as clock drift.
+async fn fetch_chain_times(config: &Config) -> Vec<(String, u64, u64)> {
+    let mut times = Vec::new();
+
+    for chain in std::iter::once(&config.hub_chain).chain(config.connected_chain_mvm.iter()) {
+        let result = match MvmClient::new(&chain.rpc_url) {
+            Ok(client) => client.get_ledger_timestamp().await,
+            Err(e) => Err(e),
+        };
+        match result {
+            Ok(t) => times.push((chain.name.clone(), chain.chain_id, t)),
+            Err(e) => warn!("Clock drift check: failed to read time from {}: {:#}", chain.name, e),
+        }
+    }
+    for chain in &config.connected_chain_evm {
+        let result = match EvmClient::new_rpc_only(&chain.rpc_url) {
+            Ok(client) => client.get_latest_block_timestamp().await,
+            Err(e) => Err(e),
+        };
+        match result {
+            Ok(t) => times.push((chain.name.clone(), chain.chain_id, t)),
+            Err(e) => warn!("Clock drift check: failed to read time from {}: {:#}", chain.name, e),
+        }
+    }
+    for chain in &config.connected_chain_svm {
+        let result = match SvmClient::new(&chain.rpc_url, &chain.escrow_program_id) {
+            Ok(client) => client.get_latest_block_time().await,
+            Err(e) => Err(e),
+        };
+        match result {
+            Ok(t) => times.push((chain.name.clone(), chain.chain_id, t)),
+            Err(e) => warn!("Clock drift check: failed to read time from {}: {:#}", chain.name, e),
+        }
+    }
+
+    times
+}
+
+/// Compares every configured chain's clock with the host clock.
+///
+/// # Arguments
+///
+/// * `config` - Service configuration (chain endpoints)
+/// * `max_drift_secs` - Largest allowed difference in seconds
+///
+/// # Returns
+///
+/// The chains whose clock differs from the host by more than `max_drift_secs`.
+/// Each one is logged as an error.
+pub async fn check_clock_drift(config: &Config, max_drift_secs: u64) -> Result<Vec<ClockDrift>> {
+    let mut drifts = Vec::new();
+
+    for (chain_name, chain_id, chain_time) in fetch_chain_times(config).await {
+        let host_time = SystemTime::now().duration_since(UNIX_EPOCH)?.as_secs();
+        let drift = ClockDrift {
+            chain_name,
+            chain_id,
+            chain_time,
+            host_time,
+        };
+        if drift.drift_secs() > max_drift_secs {
+            error!(
+                "CLOCK DRIFT: {} exceeds max_clock_drift_secs={}; expiry decisions are unreliable",
+                drift, max_drift_secs
+            );
+            drifts.push(drift);
+        }
+    }
+
+    Ok(drifts)
+}
+
+/// Startup check: refuses to operate when any chain exceeds the configured drift.
+///
+/// Does nothing when `max_clock_drift_secs` is unset.
+///
+/// # Returns
+///
+/// * `Ok(())` - Drift check disabled, or every reachable chain is within the threshold
+/// * `Err(anyhow::Error)` - At least one chain's clock drifts beyond the threshold
+pub async fn verify_clock_drift(config: &Config) -> Result<()> {
+    let Some(max_drift_secs) = config.coordinator.max_clock_drift_secs else {
+        return Ok(());
+    };
+
+    let drifts = check_clock_drift(config, max_drift_secs).await?;
+    if let Some(worst) = drifts.iter().max_by_key(|d| d.drift_secs()) {
+        anyhow::bail!(
+            "Refusing to start: {} (max_clock_drift_secs={}). Fix the host clock (NTP) before restarting.",
+            worst,
+            max_drift_secs
+        );
+    }
+
+    info!(
+        "Clock drift check passed (max_clock_drift_secs={})",
+        max_drift_secs
+    );
+    Ok(())
+}
+
+/// Re-checks the clock drift every `interval_ms`, logging chains beyond the threshold.
+///
+/// Runs indefinitely; spawned by [`crate::monitor::EventMonitor::start_monitoring`]
+/// when `max_clock_drift_secs` is configured.
+pub async fn monitor_clock_drift(config: &Config, max_drift_secs: u64, interval_ms: u64) {
+    loop {
+        tokio::time::sleep(std::time::Duration::from_millis(interval_ms)).await;
+        if let Err(e) = check_clock_drift(config, max_drift_secs).await {
+            warn!("Clock drift check failed: {:#}", e);
+        }
+    }
+}
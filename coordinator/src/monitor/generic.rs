@@ -10,7 +10,7 @@ use tokio::sync::RwLock;
 use crate::config::Config;
 
 // Re-export normalize functions from chain-clients-common
-pub use chain_clients_common::{normalize_intent_id, normalize_intent_id_to_64_chars};
+pub use chain_clients_common::{intent_ids_equal, normalize_intent_id, normalize_intent_id_to_64_chars};
 
 // ============================================================================
 // EVENT DATA STRUCTURES
@@ -44,6 +44,12 @@ pub struct IntentEvent {
     pub reserved_solver_addr: Option<String>,
     /// Connected chain ID where escrow will be created (None for regular intents)
     pub connected_chain_id: Option<u64>,
+    /// Whether this is a cross-chain inflow intent (escrow on the connected chain, fulfilled on the hub)
+    #[serde(default)]
+    pub is_inflow: bool,
+    /// Chain ID of the chain whose client produced this event (the hub for request-intent events)
+    #[serde(default)]
+    pub source_chain_id: u64,
     /// Unix timestamp when the intent expires
     pub expiry_time: u64,
     /// Timestamp when the event was received
@@ -139,7 +145,10 @@ impl EventMonitor {
     ///
     /// This function runs the hub chain monitoring loop for intent and
     /// fulfillment events. Connected chain escrow monitoring is handled
-    /// independently by the integrated-gmp and solver services.
+    /// independently by the integrated-gmp and solver services; when
+    /// `settlement_reconciliation_interval_ms` is set, a read-only settlement
+    /// reconciliation sweep is also spawned, and when `max_clock_drift_secs` is
+    /// set, the host vs chain clock drift is re-checked periodically.
     ///
     /// The function blocks until the monitor completes (which should be never
     /// in normal operation, as it runs an infinite loop).
@@ -154,6 +163,21 @@ impl EventMonitor {
 
         info!("Starting hub chain event monitoring");
 
+        if let Some(interval_ms) = self.config.coordinator.settlement_reconciliation_interval_ms {
+            let monitor = self.clone();
+            tokio::spawn(async move {
+                super::settlement::monitor_settlements(&monitor, interval_ms).await;
+            });
+        }
+
+        if let Some(max_drift_secs) = self.config.coordinator.max_clock_drift_secs {
+            let config = self.config.clone();
+            let interval_ms = config.coordinator.clock_drift_check_interval_ms;
+            tokio::spawn(async move {
+                super::clock_drift::monitor_clock_drift(&config, max_drift_secs, interval_ms).await;
+            });
+        }
+
         outflow_generic::monitor_hub_chain(self).await?;
 
         Ok(())
@@ -200,4 +224,23 @@ impl EventMonitor {
         outflow_generic::get_cached_fulfillment_events(self).await
     }
 
+    /// Compares the hub fulfillment with the source-chain escrow for an inflow intent.
+    ///
+    /// # Arguments
+    ///
+    /// * `intent_id` - Intent ID of a cached intent
+    ///
+    /// # Returns
+    ///
+    /// * `Ok(Some(SettlementMismatch))` - The two chains disagree
+    /// * `Ok(None)` - Consistent, or not an inflow intent
+    /// * `Err(anyhow::Error)` - Intent not cached or the source-chain query failed
+    #[allow(dead_code)]
+    pub async fn reconcile_settlement(
+        &self,
+        intent_id: &str,
+    ) -> anyhow::Result<Option<super::settlement::SettlementMismatch>> {
+        super::settlement::reconcile_settlement(self, intent_id).await
+    }
+
 }
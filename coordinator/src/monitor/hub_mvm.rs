@@ -114,6 +114,8 @@ pub async fn poll_hub_events(monitor: &EventMonitor) -> Result<Vec<IntentEvent>>
     let addresses_to_poll: Vec<String> = addresses_set.into_iter().collect();
 
     let mut intent_events = Vec::new();
+    // Request-intent events come from the hub; tag them so consumers need not infer the origin
+    let source_chain_id = monitor.config.hub_chain.chain_id;
     let timestamp = std::time::SystemTime::now()
         .duration_since(std::time::UNIX_EPOCH)?
         .as_secs();
@@ -176,11 +178,8 @@ pub async fn poll_hub_events(monitor: &EventMonitor) -> Result<Vec<IntentEvent>>
                     {
                         let intent_id = fulfillment_event.intent_id.clone();
                         let mut fulfillment_cache = monitor.fulfillment_cache.write().await;
-                        let normalized_intent_id =
-                            crate::monitor::generic::normalize_intent_id(&intent_id);
                         if !fulfillment_cache.iter().any(|cached| {
-                            crate::monitor::generic::normalize_intent_id(&cached.intent_id)
-                                == normalized_intent_id
+                            crate::monitor::generic::intent_ids_equal(&cached.intent_id, &intent_id)
                         }) {
                             fulfillment_cache.push(fulfillment_event.clone());
                             info!(
@@ -265,6 +264,8 @@ pub async fn poll_hub_events(monitor: &EventMonitor) -> Result<Vec<IntentEvent>>
                         .clone(),
                     reserved_solver_addr: Some(reserved_solver),
                     connected_chain_id,
+                    is_inflow: false,
+                    source_chain_id,
                     expiry_time: data
                         .expiry_time
                         .parse::<u64>()
@@ -320,6 +321,8 @@ pub async fn poll_hub_events(monitor: &EventMonitor) -> Result<Vec<IntentEvent>>
                     requester_addr_connected_chain: data.requester_addr_connected_chain.clone(),
                     reserved_solver_addr: reserved_solver,
                     connected_chain_id,
+                    is_inflow: connected_chain_id.is_some(),
+                    source_chain_id,
                     expiry_time: data
                         .expiry_time
                         .parse::<u64>()
@@ -401,6 +404,8 @@ pub async fn poll_hub_events(monitor: &EventMonitor) -> Result<Vec<IntentEvent>>
                         .clone(),
                     reserved_solver_addr: Some(reserved_solver),
                     connected_chain_id,
+                    is_inflow: false,
+                    source_chain_id,
                     expiry_time: data
                         .expiry_time
                         .parse::<u64>()
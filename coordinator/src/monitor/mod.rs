@@ -10,10 +10,12 @@
 //! (`revocable = false`) before allowing any cross-chain actions to proceed.
 
 // Generic shared code
+mod clock_drift;
 mod generic;
 
 // Flow-specific modules (chain-agnostic)
 mod outflow_generic;
+mod settlement;
 
 // Flow + chain specific modules
 mod hub_mvm;
@@ -23,6 +25,16 @@ pub use generic::{
     EventMonitor, FulfillmentEvent, IntentEvent,
 };
 
+// Re-export the host vs chain clock drift check
+#[allow(unused_imports)] // check_clock_drift and ClockDrift are used by integration tests (monitor_tests.rs)
+pub use clock_drift::{check_clock_drift, verify_clock_drift, ClockDrift};
+
+// Re-export settlement reconciliation
+#[allow(unused_imports)] // Used by integration tests (monitor_tests.rs)
+pub use settlement::{
+    classify_settlement, run_settlement_reconciliation, SettlementMismatch, SettlementSnapshot,
+};
+
 // Re-export utility functions (used in tests and API handlers)
 #[allow(unused_imports)] // Used by integration tests (monitor_tests.rs)
 pub use generic::normalize_intent_id;
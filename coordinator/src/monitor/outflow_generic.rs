@@ -56,11 +56,8 @@ pub async fn monitor_hub_chain(monitor: &EventMonitor) -> Result<()> {
                     {
                         let intent_id = event.intent_id.clone();
                         let mut cache = monitor.event_cache.write().await;
-                        let normalized_intent_id =
-                            crate::monitor::generic::normalize_intent_id(&intent_id);
                         if !cache.iter().any(|cached| {
-                            crate::monitor::generic::normalize_intent_id(&cached.intent_id)
-                                == normalized_intent_id
+                            crate::monitor::generic::intent_ids_equal(&cached.intent_id, &intent_id)
                         }) {
                             info!("New intent event: {} from {}", event.intent_id, event.requester_addr);
                             info!(
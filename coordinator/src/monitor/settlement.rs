@@ -0,0 +1,316 @@
+//! Settlement reconciliation (inflow)
+//!
+//! After an inflow intent settles, two things must hold together: the hub
+//! emitted a fulfillment event, and the escrow on the connected (source) chain
+//! was released to the solver by the `FulfillmentProof` GMP message. Nothing
+//! else checks that both sides agree, so a divergence (e.g. fulfilled on the
+//! hub but the escrow stuck on the source chain) would go unnoticed.
+//!
+//! This module reads both sides and reports mismatches. It is read-only: it
+//! logs divergences for operators and never tries to repair them. Each
+//! mismatch is logged once, when first seen, rather than on every sweep.
+//!
+//! Outflow intents are out of scope — they have no connected-chain escrow, so
+//! there is no source-chain release to compare against.
+
+use anyhow::{Context, Result};
+use chain_clients_evm::EvmClient;
+use chain_clients_mvm::MvmClient;
+use chain_clients_svm::SvmClient;
+use std::collections::{HashMap, HashSet};
+use std::time::{SystemTime, UNIX_EPOCH};
+use tracing::{info, warn};
+
+use crate::config::Config;
+use crate::monitor::generic::{intent_ids_equal, EventMonitor};
+
+/// Divergence between the hub fulfillment and the source-chain escrow.
+#[derive(Debug, Clone, PartialEq, Eq, Hash)]
+pub enum SettlementMismatch {
+    /// The hub recorded a fulfillment, but the source escrow is still locked
+    /// after the grace period.
+    FulfilledButEscrowNotReleased { intent_id: String, source_chain_id: u64 },
+    /// The source escrow was released, but the hub has no fulfillment for it.
+    EscrowReleasedWithoutFulfillment { intent_id: String, source_chain_id: u64 },
+}
+
+impl SettlementMismatch {
+    /// Intent ID the mismatch was found for.
+    pub fn intent_id(&self) -> &str {
+        match self {
+            SettlementMismatch::FulfilledButEscrowNotReleased { intent_id, .. }
+            | SettlementMismatch::EscrowReleasedWithoutFulfillment { intent_id, .. } => intent_id,
+        }
+    }
+}
+
+impl std::fmt::Display for SettlementMismatch {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        match self {
+            SettlementMismatch::FulfilledButEscrowNotReleased {
+                intent_id,
+                source_chain_id,
+            } => write!(
+                f,
+                "FulfilledButEscrowNotReleased: hub fulfilled but escrow on chain {} is not released (intent_id={})",
+                source_chain_id, intent_id
+            ),
+            SettlementMismatch::EscrowReleasedWithoutFulfillment {
+                intent_id,
+                source_chain_id,
+            } => write!(
+                f,
+                "EscrowReleasedWithoutFulfillment: escrow on chain {} released but hub has no fulfillment (intent_id={})",
+                source_chain_id, intent_id
+            ),
+        }
+    }
+}
+
+/// Both sides of a single inflow intent's settlement. Input to [`classify_settlement`].
+#[derive(Debug, Clone)]
+pub struct SettlementSnapshot {
+    pub intent_id: String,
+    pub source_chain_id: u64,
+    /// Unix timestamp of the hub fulfillment, if one was observed
+    pub hub_fulfilled_at: Option<u64>,
+    pub source_escrow_released: bool,
+}
+
+/// Classifies a snapshot into at most one mismatch.
+///
+/// A fulfilled intent whose escrow is still locked is only reported once
+/// `grace_secs` have passed since the fulfillment, since the release waits on
+/// GMP delivery to the source chain.
+pub fn classify_settlement(
+    snap: &SettlementSnapshot,
+    now: u64,
+    grace_secs: u64,
+) -> Option<SettlementMismatch> {
+    match (snap.hub_fulfilled_at, snap.source_escrow_released) {
+        (Some(fulfilled_at), false) if now.saturating_sub(fulfilled_at) >= grace_secs => {
+            Some(SettlementMismatch::FulfilledButEscrowNotReleased {
+                intent_id: snap.intent_id.clone(),
+                source_chain_id: snap.source_chain_id,
+            })
+        }
+        (None, true) => Some(SettlementMismatch::EscrowReleasedWithoutFulfillment {
+            intent_id: snap.intent_id.clone(),
+            source_chain_id: snap.source_chain_id,
+        }),
+        _ => None,
+    }
+}
+
+/// Escrow client for one connected chain.
+enum EscrowReader {
+    Mvm {
+        client: MvmClient,
+        module_addr: String,
+    },
+    Evm(EvmClient),
+    Svm(SvmClient),
+}
+
+/// Escrow clients for every configured connected chain, built once per sweep.
+///
+/// A chain whose client cannot be built keeps the error, so every intent on
+/// that chain fails with it instead of the whole sweep aborting.
+struct EscrowReaders {
+    readers: HashMap<u64, Result<EscrowReader, String>>,
+}
+
+impl EscrowReaders {
+    /// Builds a client for each connected chain in `config`.
+    fn from_config(config: &Config) -> Self {
+        let mut readers = HashMap::new();
+        for mvm in &config.connected_chain_mvm {
+            let reader = match &mvm.escrow_module_addr {
+                Some(module_addr) => MvmClient::new(&mvm.rpc_url).map(|client| EscrowReader::Mvm {
+                    client,
+                    module_addr: module_addr.clone(),
+                }),
+                None => Err(anyhow::anyhow!(
+                    "No escrow_module_addr configured for chain {}",
+                    mvm.chain_id
+                )),
+            };
+            readers.insert(mvm.chain_id, reader.map_err(|e| format!("{:#}", e)));
+        }
+        for evm in &config.connected_chain_evm {
+            let reader = EvmClient::new(&evm.rpc_url, &evm.escrow_contract_addr)
+                .map(EscrowReader::Evm)
+                .map_err(|e| format!("{:#}", e));
+            readers.insert(evm.chain_id, reader);
+        }
+        for svm in &config.connected_chain_svm {
+            let reader = SvmClient::new(&svm.rpc_url, &svm.escrow_program_id)
+                .map(EscrowReader::Svm)
+                .map_err(|e| format!("{:#}", e));
+            readers.insert(svm.chain_id, reader);
+        }
+        Self { readers }
+    }
+
+    /// Queries whether the escrow for `intent_id` on `chain_id` has been released.
+    ///
+    /// # Arguments
+    ///
+    /// * `chain_id` - Connected chain holding the escrow
+    /// * `intent_id` - Intent ID (hex)
+    ///
+    /// # Returns
+    ///
+    /// * `Ok(bool)` - Whether the escrow has been released to the solver
+    /// * `Err(anyhow::Error)` - Chain not configured, its client failed to build, or the query failed
+    async fn is_escrow_released(&self, chain_id: u64, intent_id: &str) -> Result<bool> {
+        let reader = self
+            .readers
+            .get(&chain_id)
+            .with_context(|| format!("Chain {} is not a configured connected chain", chain_id))?
+            .as_ref()
+            .map_err(|e| anyhow::anyhow!("Chain {} client unavailable: {}", chain_id, e))?;
+        match reader {
+            EscrowReader::Mvm {
+                client,
+                module_addr,
+            } => client.is_escrow_released(intent_id, module_addr).await,
+            EscrowReader::Evm(client) => client.is_escrow_released(intent_id).await,
+            EscrowReader::Svm(client) => client.is_escrow_released(intent_id).await,
+        }
+    }
+}
+
+/// Reconciles a single cached inflow intent.
+///
+/// # Arguments
+///
+/// * `monitor` - The event monitor instance (intent and fulfillment caches)
+/// * `intent_id` - Intent ID to reconcile
+///
+/// # Returns
+///
+/// * `Ok(Some(SettlementMismatch))` - The two chains disagree
+/// * `Ok(None)` - Consistent, or not an inflow intent
+/// * `Err(anyhow::Error)` - Intent not cached or the source-chain query failed
+pub async fn reconcile_settlement(
+    monitor: &EventMonitor,
+    intent_id: &str,
+) -> Result<Option<SettlementMismatch>> {
+    let readers = EscrowReaders::from_config(&monitor.config);
+    reconcile_settlement_with(monitor, &readers, intent_id).await
+}
+
+/// Reconciles a single cached inflow intent using already-built escrow clients.
+async fn reconcile_settlement_with(
+    monitor: &EventMonitor,
+    readers: &EscrowReaders,
+    intent_id: &str,
+) -> Result<Option<SettlementMismatch>> {
+    let intent = monitor
+        .event_cache
+        .read()
+        .await
+        .iter()
+        .find(|e| intent_ids_equal(&e.intent_id, intent_id))
+        .cloned()
+        .with_context(|| format!("Intent {} is not cached", intent_id))?;
+
+    let source_chain_id = match intent.connected_chain_id {
+        Some(chain_id) if intent.is_inflow => chain_id,
+        _ => return Ok(None),
+    };
+
+    let hub_fulfilled_at = monitor
+        .fulfillment_cache
+        .read()
+        .await
+        .iter()
+        .find(|f| intent_ids_equal(&f.intent_id, intent_id))
+        .map(|f| f.timestamp);
+
+    let source_escrow_released = readers
+        .is_escrow_released(source_chain_id, &intent.intent_id)
+        .await?;
+
+    let now = SystemTime::now().duration_since(UNIX_EPOCH)?.as_secs();
+    let snap = SettlementSnapshot {
+        intent_id: intent.intent_id,
+        source_chain_id,
+        hub_fulfilled_at,
+        source_escrow_released,
+    };
+    Ok(classify_settlement(
+        &snap,
+        now,
+        monitor.config.coordinator.settlement_grace_secs,
+    ))
+}
+
+/// Runs one sweep over all cached inflow intents and logs new mismatches.
+///
+/// Chain clients are built once for the sweep. A mismatch already in
+/// `reported` is not logged again; one that no longer occurs is dropped from
+/// `reported`, so a later recurrence is logged afresh. Intents whose
+/// source-chain query fails are skipped with a warning and retried on the
+/// next sweep; their reported mismatches are kept meanwhile.
+///
+/// # Arguments
+///
+/// * `monitor` - The event monitor instance (intent and fulfillment caches)
+/// * `reported` - Mismatches logged by earlier sweeps; updated in place
+///
+/// # Returns
+///
+/// The mismatches first reported in this sweep
+pub async fn run_settlement_reconciliation(
+    monitor: &EventMonitor,
+    reported: &mut HashSet<SettlementMismatch>,
+) -> Vec<SettlementMismatch> {
+    let intents = monitor.event_cache.read().await.clone();
+    let readers = EscrowReaders::from_config(&monitor.config);
+    let mut current = HashSet::new();
+    let mut failed = HashSet::new();
+    let mut new_mismatches = Vec::new();
+
+    for intent in intents.iter().filter(|e| e.is_inflow) {
+        match reconcile_settlement_with(monitor, &readers, &intent.intent_id).await {
+            Ok(Some(mismatch)) => {
+                if !reported.contains(&mismatch) {
+                    warn!("Settlement mismatch: {}", mismatch);
+                    new_mismatches.push(mismatch.clone());
+                }
+                current.insert(mismatch);
+            }
+            Ok(None) => {}
+            Err(e) => {
+                warn!(
+                    "Settlement reconciliation failed for intent {}: {:#}",
+                    intent.intent_id, e
+                );
+                failed.insert(intent.intent_id.as_str());
+            }
+        }
+    }
+
+    reported.retain(|m| failed.contains(m.intent_id()));
+    reported.extend(current);
+    new_mismatches
+}
+
+/// Runs the settlement reconciliation sweep every `interval_ms`.
+///
+/// Runs indefinitely; spawned by [`EventMonitor::start_monitoring`] when
+/// `settlement_reconciliation_interval_ms` is configured.
+pub async fn monitor_settlements(monitor: &EventMonitor, interval_ms: u64) {
+    info!(
+        "Starting settlement reconciliation (interval {} ms)",
+        interval_ms
+    );
+
+    let mut reported = HashSet::new();
+    loop {
+        tokio::time::sleep(std::time::Duration::from_millis(interval_ms)).await;
+        run_settlement_reconciliation(monitor, &mut reported).await;
+    }
+}
@@ -147,6 +147,10 @@ pub fn build_test_config_with_mvm() -> Config {
         coordinator: CoordinatorConfig {
             polling_interval_ms: 1000,
             validation_timeout_ms: 1000,
+            settlement_reconciliation_interval_ms: None,
+            settlement_grace_secs: 300,
+            max_clock_drift_secs: None,
+            clock_drift_check_interval_ms: 300_000,
         },
         api: ApiConfig {
             host: "127.0.0.1".to_string(),
@@ -226,6 +230,8 @@ pub fn create_default_intent_mvm() -> IntentEvent {
         requester_addr_connected_chain: Some(DUMMY_REQUESTER_ADDR_MVMCON.to_string()), // Required for outflow intents (connected_chain_id is Some). Move VM address format (32 bytes)
         reserved_solver_addr: Some(DUMMY_SOLVER_ADDR_HUB.to_string()), // Move VM address format (32 bytes)
         connected_chain_id: Some(2),
+        is_inflow: false,
+        source_chain_id: 1, // Hub chain ID (matches build_test_config_with_mvm)
         expiry_time: 0, // Should be set explicitly in tests
         timestamp: 0,
     }
@@ -6,13 +6,20 @@
 //! Note: Validation-related tests are in integrated-gmp since the coordinator
 //! is read-only and doesn't perform validation or signing.
 
-use coordinator::monitor::{EventMonitor, IntentEvent};
+use coordinator::monitor::{
+    check_clock_drift, run_settlement_reconciliation, verify_clock_drift, EventMonitor,
+    FulfillmentEvent, IntentEvent, SettlementMismatch,
+};
 #[path = "mod.rs"]
 mod test_helpers;
+use serde_json::json;
+use std::collections::HashSet;
 use test_helpers::{
     build_test_config_with_mvm,
-    create_default_intent_mvm,
+    create_default_fulfillment, create_default_intent_mvm,
 };
+use wiremock::matchers::{method, path};
+use wiremock::{Mock, MockServer, ResponseTemplate};
 
 // ============================================================================
 // HELPER FUNCTIONS
@@ -23,6 +30,43 @@ fn is_safe_for_escrow(event: &IntentEvent) -> bool {
     !event.revocable
 }
 
+/// Builds a monitor whose connected MVM chain answers `is_released` with `released`,
+/// with one cached inflow intent and, if `fulfilled_at` is set, its hub fulfillment.
+async fn setup_settlement_monitor(
+    released: bool,
+    fulfilled_at: Option<u64>,
+) -> (MockServer, EventMonitor) {
+    let mock_server = MockServer::start().await;
+    Mock::given(method("POST"))
+        .and(path("/v1/view"))
+        .respond_with(ResponseTemplate::new(200).set_body_json(json!([released])))
+        .mount(&mock_server)
+        .await;
+
+    let mut config = build_test_config_with_mvm();
+    config.connected_chain_mvm[0].rpc_url = mock_server.uri();
+    let monitor = EventMonitor::new(&config)
+        .await
+        .expect("Failed to create monitor");
+
+    monitor.event_cache.write().await.push(IntentEvent {
+        is_inflow: true,
+        ..create_default_intent_mvm()
+    });
+    if let Some(timestamp) = fulfilled_at {
+        monitor
+            .fulfillment_cache
+            .write()
+            .await
+            .push(FulfillmentEvent {
+                timestamp,
+                ..create_default_fulfillment()
+            });
+    }
+
+    (mock_server, monitor)
+}
+
 // ============================================================================
 // REVOCABILITY TESTS
 // ============================================================================
@@ -136,3 +180,187 @@ async fn test_monitor_initialization_and_basic_ops() {
     assert_eq!(cache.len(), 1);
     assert_eq!(cache[0].intent_id, intent.intent_id);
 }
+
+// ============================================================================
+// SETTLEMENT RECONCILIATION TESTS
+// ============================================================================
+
+// 4. Test: Consistent settlement reports no mismatch
+// Verifies that an inflow intent fulfilled on the hub with a released source escrow, one with neither, and one fulfilled within the grace period are not reported.
+// Why: The sweep runs continuously; false positives on healthy intents would bury real divergences.
+#[tokio::test]
+async fn test_settlement_reconciliation_consistent() {
+    let (_server, monitor) = setup_settlement_monitor(true, Some(1)).await;
+    let intent_id = create_default_intent_mvm().intent_id;
+    assert_eq!(monitor.reconcile_settlement(&intent_id).await.unwrap(), None);
+    assert!(run_settlement_reconciliation(&monitor, &mut HashSet::new())
+        .await
+        .is_empty());
+
+    let (_server, monitor) = setup_settlement_monitor(false, None).await;
+    assert_eq!(monitor.reconcile_settlement(&intent_id).await.unwrap(), None);
+
+    // Fulfilled just now: the escrow release is still in flight, within the grace period
+    let now = std::time::SystemTime::now()
+        .duration_since(std::time::UNIX_EPOCH)
+        .unwrap()
+        .as_secs();
+    let (_server, monitor) = setup_settlement_monitor(false, Some(now)).await;
+    assert_eq!(monitor.reconcile_settlement(&intent_id).await.unwrap(), None);
+}
+
+// 5. Test: Divergent settlement is reported once
+// Verifies that a hub fulfillment with a still-locked source escrow, and a released escrow without a hub fulfillment, are reported as mismatches, and that a later sweep does not report the same mismatch again.
+// Why: These are the silent divergences the check exists for: the solver is unpaid, or funds left the escrow without a matching fulfillment. Re-logging them every sweep would bury new ones.
+#[tokio::test]
+async fn test_settlement_reconciliation_divergent() {
+    let intent_id = create_default_intent_mvm().intent_id;
+
+    let (_server, monitor) = setup_settlement_monitor(false, Some(1)).await;
+    let mut reported = HashSet::new();
+    assert_eq!(
+        run_settlement_reconciliation(&monitor, &mut reported).await,
+        vec![SettlementMismatch::FulfilledButEscrowNotReleased {
+            intent_id: intent_id.clone(),
+            source_chain_id: 2,
+        }]
+    );
+    assert!(run_settlement_reconciliation(&monitor, &mut reported)
+        .await
+        .is_empty());
+    assert_eq!(reported.len(), 1);
+
+    let (_server, monitor) = setup_settlement_monitor(true, None).await;
+    assert_eq!(
+        monitor.reconcile_settlement(&intent_id).await.unwrap(),
+        Some(SettlementMismatch::EscrowReleasedWithoutFulfillment {
+            intent_id,
+            source_chain_id: 2,
+        })
+    );
+}
+
+// ============================================================================
+// CLOCK DRIFT TESTS
+// ============================================================================
+
+// 6. Test: Host vs chain clock drift beyond the threshold refuses startup
+// Verifies that a hub ledger timestamp one hour behind the host is reported by check_clock_drift and makes verify_clock_drift fail, while a 60s threshold passes for a ledger in sync.
+// Why: Expiry decisions compare on-chain expiry against the host clock; a drifted host must not operate silently.
+#[tokio::test]
+async fn test_clock_drift_exceeds_threshold() {
+    let now = std::time::SystemTime::now()
+        .duration_since(std::time::UNIX_EPOCH)
+        .unwrap()
+        .as_secs();
+
+    let drifted_server = MockServer::start().await;
+    Mock::given(method("GET"))
+        .and(path("/v1"))
+        .respond_with(ResponseTemplate::new(200).set_body_json(json!({
+            "ledger_timestamp": ((now - 3600) * 1_000_000).to_string()
+        })))
+        .mount(&drifted_server)
+        .await;
+
+    let mut config = build_test_config_with_mvm();
+    config.hub_chain.rpc_url = drifted_server.uri();
+    config.connected_chain_mvm.clear();
+    config.coordinator.max_clock_drift_secs = Some(60);
+
+    let drifts = check_clock_drift(&config, 60).await.unwrap();
+    assert_eq!(drifts.len(), 1);
+    assert_eq!(drifts[0].chain_id, config.hub_chain.chain_id);
+    assert!(drifts[0].drift_secs() >= 3600);
+    assert!(verify_clock_drift(&config).await.is_err());
+
+    let synced_server = MockServer::start().await;
+    Mock::given(method("GET"))
+        .and(path("/v1"))
+        .respond_with(ResponseTemplate::new(200).set_body_json(json!({
+            "ledger_timestamp": (now * 1_000_000).to_string()
+        })))
+        .mount(&synced_server)
+        .await;
+
+    config.hub_chain.rpc_url = synced_server.uri();
+    assert!(verify_clock_drift(&config).await.is_ok());
+}
+
+// ============================================================================
+// EVENT SOURCE TESTS
+// ============================================================================
+
+// 7. Test: Hub-polled intent events are tagged with the hub chain ID
+// Verifies that poll_hub_events sets source_chain_id to the hub chain ID on both the inflow (LimitOrderEvent) and outflow (OracleLimitOrderEvent) intent events it builds.
+// Why: Consumers branch on the producing chain from this tag instead of inferring it from address or metadata formats.
+#[tokio::test]
+async fn test_hub_intent_events_tagged_with_hub_chain_id() {
+    let mock_server = MockServer::start().await;
+    // Intent and solver registries both return the single requester account
+    Mock::given(method("POST"))
+        .and(path("/v1/view"))
+        .respond_with(ResponseTemplate::new(200).set_body_json(json!([["0xabc"]])))
+        .mount(&mock_server)
+        .await;
+    let inflow_event = json!({
+        "type": "0x1::fa_intent::LimitOrderEvent",
+        "sequence_number": "0",
+        "data": {
+            "intent_addr": "0x11",
+            "intent_id": "0x01",
+            "offered_metadata": { "inner": "0x21" },
+            "offered_amount": "100",
+            "offered_chain_id": "2",
+            "desired_metadata": { "inner": "0x22" },
+            "desired_amount": "100",
+            "desired_chain_id": "1",
+            "requester_addr": "0xabc",
+            "expiry_time": "9999999999",
+            "revocable": false,
+            "reserved_solver": { "vec": ["0xdef"] }
+        }
+    });
+    let outflow_event = json!({
+        "type": "0x1::fa_intent_with_oracle::OracleLimitOrderEvent",
+        "sequence_number": "1",
+        "data": {
+            "intent_addr": "0x12",
+            "intent_id": "0x02",
+            "offered_metadata": { "inner": "0x22" },
+            "offered_amount": "100",
+            "offered_chain_id": "1",
+            "desired_metadata": { "inner": "0x21" },
+            "desired_amount": "100",
+            "desired_chain_id": "2",
+            "requester_addr": "0xabc",
+            "expiry_time": "9999999999",
+            "min_reported_value": "0",
+            "revocable": false,
+            "reserved_solver": { "vec": ["0xdef"] },
+            "requester_addr_connected_chain": { "vec": ["0xabc"] }
+        }
+    });
+    Mock::given(method("GET"))
+        .and(path("/v1/accounts/abc/transactions"))
+        .respond_with(
+            ResponseTemplate::new(200)
+                .set_body_json(json!([{ "events": [inflow_event, outflow_event] }])),
+        )
+        .mount(&mock_server)
+        .await;
+
+    let mut config = build_test_config_with_mvm();
+    config.hub_chain.rpc_url = mock_server.uri();
+    let monitor = EventMonitor::new(&config)
+        .await
+        .expect("Failed to create monitor");
+
+    let events = monitor.poll_hub_events().await.unwrap();
+    assert_eq!(events.len(), 2);
+    assert!(events.iter().any(|e| e.is_inflow));
+    assert!(events.iter().any(|e| !e.is_inflow));
+    for event in &events {
+        assert_eq!(event.source_chain_id, config.hub_chain.chain_id);
+    }
+}
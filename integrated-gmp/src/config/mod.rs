@@ -77,6 +77,25 @@ pub struct EvmChainConfig {
     /// Address of the IntentOutflowValidator contract
     #[serde(default)]
     pub outflow_validator_addr: Option<String>,
+    /// How to treat a transaction receipt without a `status` field
+    /// (pre-Byzantium chains and some L2s omit it). Default: `failure`.
+    #[serde(default)]
+    pub unknown_status_is: UnknownStatusPolicy,
+    /// Blocks behind head to start polling from when the relay has no cursor yet
+    /// (cold start). Capped at `MAX_STARTUP_LOOKBACK_BLOCKS`. Default: one polling window.
+    #[serde(default)]
+    pub startup_lookback_blocks: Option<u64>,
+}
+
+/// Policy for an EVM transaction receipt that carries no `status` field.
+#[derive(Debug, Clone, Copy, Default, PartialEq, Eq, Serialize, Deserialize)]
+#[serde(rename_all = "lowercase")]
+pub enum UnknownStatusPolicy {
+    /// Fail the delivery immediately
+    #[default]
+    Failure,
+    /// Keep waiting for a receipt with a status; the delivery is retried if none arrives
+    Pending,
 }
 
 /// Configuration for a Solana chain (SVM).
@@ -118,6 +137,16 @@ pub struct IntegratedGmpConfig {
     pub polling_interval_ms: u64,
     /// Timeout for validation operations in milliseconds
     pub validation_timeout_ms: u64,
+    /// Minimum relay balance on MVM destinations, in octas of APT. Deliveries are
+    /// held back while the relay account is below it. Default: no check.
+    #[serde(default)]
+    pub min_relay_balance_mvm: Option<u64>,
+    /// Minimum relay balance on EVM destinations, in wei. Default: no check.
+    #[serde(default)]
+    pub min_relay_balance_evm: Option<u64>,
+    /// Minimum relay balance on SVM destinations, in lamports. Default: no check.
+    #[serde(default)]
+    pub min_relay_balance_svm: Option<u64>,
 }
 
 fn default_private_key_env() -> String {
@@ -331,6 +360,9 @@ impl Config {
                 public_key_env: "INTEGRATED_GMP_PUBLIC_KEY".to_string(),
                 polling_interval_ms: 2000,
                 validation_timeout_ms: 30000,
+                min_relay_balance_mvm: None,
+                min_relay_balance_evm: None,
+                min_relay_balance_svm: None,
             },
             api: ApiConfig {
                 host: "127.0.0.1".to_string(),
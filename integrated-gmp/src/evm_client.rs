@@ -9,8 +9,9 @@ use sha3::{Digest, Keccak256};
 use std::time::Duration;
 use tracing::warn;
 
+use crate::config::UnknownStatusPolicy;
 use crate::crypto::CryptoService;
-use crate::integrated_gmp_relay::GmpMessage;
+use crate::integrated_gmp_relay::{parse_32_byte_address, GmpMessage};
 
 // ============================================================================
 // CLIENT
@@ -21,6 +22,7 @@ pub struct GmpEvmClient {
     gmp_endpoint_addr: String,
     chain_id: u32,
     relay_address: String,
+    unknown_status_is: UnknownStatusPolicy,
 }
 
 impl GmpEvmClient {
@@ -29,6 +31,7 @@ impl GmpEvmClient {
         gmp_endpoint_addr: &str,
         chain_id: u32,
         relay_address: &str,
+        unknown_status_is: UnknownStatusPolicy,
     ) -> Result<Self> {
         let evm_client =
             EvmClient::new_rpc_only(rpc_url).context("Failed to create EVM RPC client")?;
@@ -37,6 +40,7 @@ impl GmpEvmClient {
             gmp_endpoint_addr: gmp_endpoint_addr.to_string(),
             chain_id,
             relay_address: relay_address.to_string(),
+            unknown_status_is,
         })
     }
 
@@ -60,6 +64,18 @@ impl GmpEvmClient {
         self.evm_client.get_block_number().await
     }
 
+    // ========================================================================
+    // Relay balance
+    // ========================================================================
+
+    /// Read the relay account's native balance (wei).
+    pub async fn get_relay_balance(&self, relay_addr: &str) -> Result<u128> {
+        self.evm_client
+            .get_native_balance(relay_addr)
+            .await
+            .context("Failed to read relay native balance")
+    }
+
     // ========================================================================
     // Authorization check
     // ========================================================================
@@ -147,28 +163,22 @@ impl GmpEvmClient {
     }
 
     /// Wait for an EVM transaction receipt and verify success.
+    ///
+    /// A receipt without a `status` field is handled per the chain's
+    /// `unknown_status_is` policy (see [`receipt_succeeded`]).
     pub async fn wait_for_receipt(&self, tx_hash: &str) -> Result<()> {
         for _ in 0..30 {
             let receipt: Option<serde_json::Value> =
                 self.evm_client.get_transaction_receipt(tx_hash).await?;
 
             if let Some(receipt) = receipt {
-                let status = receipt
-                    .get("status")
-                    .and_then(|s| s.as_str())
-                    .ok_or_else(|| anyhow::anyhow!(
-                        "EVM receipt for {} missing status field: {receipt}",
-                        tx_hash
-                    ))?;
-                if status == "0x1" {
+                if receipt_succeeded(tx_hash, &receipt, self.unknown_status_is)? {
                     return Ok(());
-                } else {
-                    anyhow::bail!(
-                        "EVM transaction {} failed with status: {}",
-                        tx_hash,
-                        status
-                    );
                 }
+                warn!(
+                    "EVM receipt for {} has no status field, waiting (unknown_status_is = pending)",
+                    tx_hash
+                );
             }
 
             tokio::time::sleep(Duration::from_millis(500)).await;
@@ -423,6 +433,34 @@ impl GmpEvmClient {
 // FREE FUNCTIONS (moved from integrated_gmp_relay.rs)
 // ============================================================================
 
+/// Classify a mined EVM transaction receipt.
+///
+/// Returns `Ok(true)` for status `0x1` and an error for any other status.
+/// A receipt without a `status` field is an error under
+/// [`UnknownStatusPolicy::Failure`] and `Ok(false)` (not final yet, keep
+/// waiting) under [`UnknownStatusPolicy::Pending`].
+pub fn receipt_succeeded(
+    tx_hash: &str,
+    receipt: &serde_json::Value,
+    unknown_status_is: UnknownStatusPolicy,
+) -> Result<bool> {
+    match receipt.get("status").and_then(|s| s.as_str()) {
+        Some("0x1") => Ok(true),
+        Some(status) => anyhow::bail!(
+            "EVM transaction {} failed with status: {}",
+            tx_hash,
+            status
+        ),
+        None => match unknown_status_is {
+            UnknownStatusPolicy::Failure => anyhow::bail!(
+                "EVM receipt for {} missing status field: {receipt}",
+                tx_hash
+            ),
+            UnknownStatusPolicy::Pending => Ok(false),
+        },
+    }
+}
+
 /// Compute the Keccak256 topic hash for an EVM event signature.
 fn evm_event_topic(signature: &str) -> String {
     let mut hasher = Keccak256::new();
@@ -478,15 +516,6 @@ fn evm_encode_deliver_message(
     Ok(format!("0x{}", hex::encode(data)))
 }
 
-fn parse_32_byte_address(addr: &str) -> Result<[u8; 32]> {
-    let hex_clean = addr.strip_prefix("0x").unwrap_or(addr);
-    let padded = format!("{:0>64}", hex_clean);
-    let bytes = hex::decode(&padded).context("Invalid hex address")?;
-    let mut array = [0u8; 32];
-    array.copy_from_slice(&bytes);
-    Ok(array)
-}
-
 fn hex_to_bytes(hex_str: &str) -> Result<Vec<u8>> {
     let hex_clean = hex_str.strip_prefix("0x").unwrap_or(hex_str);
     hex::decode(hex_clean).context("Invalid hex string")
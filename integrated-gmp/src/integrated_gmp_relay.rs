@@ -20,6 +20,7 @@
 use anyhow::{Context, Result};
 use base64::{engine::general_purpose::STANDARD, Engine as _};
 use borsh::BorshSerialize;
+use chain_clients_common::hex_to_bytes32;
 use ed25519_dalek::SigningKey;
 use serde::{Deserialize, Serialize};
 use solana_client::rpc_client::RpcClient;
@@ -37,11 +38,11 @@ use std::time::Duration;
 use tokio::sync::RwLock;
 use tracing::{debug, error, info, warn};
 
-use crate::config::Config;
+use crate::config::{Config, UnknownStatusPolicy};
 use crate::crypto::CryptoService;
 use crate::evm_client::GmpEvmClient;
 use crate::mvm_client::GmpMvmClient;
-use crate::svm_client::GmpSvmClient;
+use crate::svm_client::{EscrowIntentPdas, GmpSvmClient};
 
 // Well-known Solana program IDs.
 const SYSTEM_PROGRAM_ID: Pubkey = Pubkey::new_from_array([0; 32]);
@@ -72,6 +73,10 @@ pub struct EvmRelayChainConfig {
     pub chain_id: u32,
     /// EVM relay address (the `from` address for eth_sendRawTransaction, must be authorized relay in IntentGmp)
     pub relay_address: String,
+    /// How to treat a delivery receipt without a `status` field
+    pub unknown_status_is: UnknownStatusPolicy,
+    /// Blocks behind head to start polling from on a cold start (no cursor yet)
+    pub startup_lookback_blocks: Option<u64>,
 }
 
 /// Per-chain relay configuration for a connected SVM chain.
@@ -108,6 +113,12 @@ pub struct NativeGmpRelayConfig {
     pub polling_interval_ms: u64,
     /// Relay operator private key (base64 encoded Ed25519)
     pub operator_private_key: String,
+    /// Minimum relay balance on MVM destinations (octas), if checked
+    pub min_relay_balance_mvm: Option<u64>,
+    /// Minimum relay balance on EVM destinations (wei), if checked
+    pub min_relay_balance_evm: Option<u64>,
+    /// Minimum relay balance on SVM destinations (lamports), if checked
+    pub min_relay_balance_svm: Option<u64>,
 }
 
 impl NativeGmpRelayConfig {
@@ -133,6 +144,8 @@ impl NativeGmpRelayConfig {
                 gmp_endpoint_addr: evm.gmp_endpoint_addr.clone(),
                 chain_id: evm.chain_id as u32,
                 relay_address: evm.approver_evm_pubkey_hash.clone(),
+                unknown_status_is: evm.unknown_status_is,
+                startup_lookback_blocks: evm.startup_lookback_blocks,
             })
             .collect();
 
@@ -157,6 +170,9 @@ impl NativeGmpRelayConfig {
             svm_chains,
             polling_interval_ms: config.integrated_gmp.polling_interval_ms,
             operator_private_key,
+            min_relay_balance_mvm: config.integrated_gmp.min_relay_balance_mvm,
+            min_relay_balance_evm: config.integrated_gmp.min_relay_balance_evm,
+            min_relay_balance_svm: config.integrated_gmp.min_relay_balance_svm,
         })
     }
 
@@ -174,6 +190,28 @@ impl NativeGmpRelayConfig {
     pub fn find_svm_chain(&self, chain_id: u32) -> Option<&SvmRelayChainConfig> {
         self.svm_chains.iter().find(|c| c.chain_id == chain_id)
     }
+
+    /// Resolve the VM of a destination chain, in the same order `deliver_message` routes.
+    pub fn destination_vm(&self, chain_id: u32) -> Option<DestinationVm> {
+        if chain_id == self.mvm_chain_id || self.find_mvm_chain(chain_id).is_some() {
+            return Some(DestinationVm::Mvm);
+        }
+        if self.find_svm_chain(chain_id).is_some() {
+            return Some(DestinationVm::Svm);
+        }
+        if self.find_evm_chain(chain_id).is_some() {
+            return Some(DestinationVm::Evm);
+        }
+        None
+    }
+}
+
+/// VM of a destination chain. Selects the format `GmpMessage::dst_addr` is parsed in.
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum DestinationVm {
+    Mvm,
+    Evm,
+    Svm,
 }
 
 // ============================================================================
@@ -223,6 +261,53 @@ pub const MAX_DELIVERY_RETRIES: u32 = 3;
 /// Initial backoff duration in seconds after first delivery failure (doubles each retry)
 const INITIAL_DELIVERY_BACKOFF_SECS: u64 = 5;
 
+// ============================================================================
+// EVM POLLING CONFIGURATION
+// ============================================================================
+
+/// Maximum block range per `eth_getLogs` query (Alchemy free tier limit)
+pub const EVM_POLL_MAX_RANGE: u64 = 10;
+
+/// Upper bound on `startup_lookback_blocks` so a misconfigured value cannot
+/// trigger a replay from genesis on a long-lived chain
+pub const MAX_STARTUP_LOOKBACK_BLOCKS: u64 = 10_000;
+
+/// Compute the first block to poll on an EVM chain when the relay has no cursor yet.
+///
+/// Without a lookback the relay starts at the head window (`current_block - EVM_POLL_MAX_RANGE`).
+/// With a lookback it starts that many blocks behind head, capped at
+/// `MAX_STARTUP_LOOKBACK_BLOCKS`, and catches up one window per poll.
+pub fn evm_startup_from_block(current_block: u64, startup_lookback_blocks: Option<u64>) -> u64 {
+    match startup_lookback_blocks {
+        Some(lookback) => current_block.saturating_sub(lookback.min(MAX_STARTUP_LOOKBACK_BLOCKS)),
+        None => current_block.saturating_sub(EVM_POLL_MAX_RANGE),
+    }
+}
+
+// ============================================================================
+// RELAY BALANCE CHECK
+// ============================================================================
+
+/// Compare the relay signer's balance on a destination chain against its configured minimum.
+///
+/// Logs an error when the balance is short so operators are alerted before deliveries
+/// start failing for lack of fees. Returns `true` if delivery may proceed.
+pub fn is_relay_balance_sufficient(
+    dst_chain_id: u32,
+    relay_addr: &str,
+    balance: u128,
+    min_balance: u64,
+) -> bool {
+    if balance >= u128::from(min_balance) {
+        return true;
+    }
+    error!(
+        "RELAY BALANCE LOW on chain {}: relay {} holds {} (minimum {}). Holding back deliveries until it is funded.",
+        dst_chain_id, relay_addr, balance, min_balance
+    );
+    false
+}
+
 // ============================================================================
 // RELAY STATE
 // ============================================================================
@@ -268,24 +353,54 @@ impl DeliveryAttempt {
     }
 }
 
+/// Per-chain poll cursors: the last processed outbox nonce (MVM, SVM) or block (EVM).
+///
+/// Cursors only move forward. When several pollers finish out of order, a slower
+/// one cannot rewind a chain's cursor and cause its messages to be re-processed.
+#[derive(Debug, Default, Clone)]
+pub struct PollCursors {
+    /// chain_id -> last processed nonce or block number
+    cursors: HashMap<u32, u64>,
+}
+
+impl PollCursors {
+    /// Current cursor for a chain, or `None` if nothing has been processed yet.
+    pub fn get(&self, chain_id: u32) -> Option<u64> {
+        self.cursors.get(&chain_id).copied()
+    }
+
+    /// Move a chain's cursor to `value` if it is ahead of the current cursor.
+    ///
+    /// # Returns
+    ///
+    /// * `true` - The cursor now equals `value`
+    /// * `false` - `value` is at or behind the current cursor, which is left unchanged
+    pub fn advance_cursor(&mut self, chain_id: u32, value: u64) -> bool {
+        match self.cursors.get(&chain_id) {
+            Some(&current) if value <= current => false,
+            _ => {
+                self.cursors.insert(chain_id, value);
+                true
+            }
+        }
+    }
+}
+
 /// Internal state for tracking processed messages.
 #[derive(Debug, Default)]
 struct RelayState {
     /// Processed nonces per source chain (chain_id -> set of processed nonces)
     processed_nonces: HashMap<u32, HashSet<u64>>,
-    /// Last polled nonce for MVM hub outbox (view function based)
-    mvm_hub_last_nonce: u64,
-    /// Last polled nonce per connected MVM chain (chain_id -> last nonce)
-    mvm_connected_last_nonces: HashMap<u32, u64>,
-    /// Last polled nonce per connected SVM chain (chain_id -> last nonce)
-    svm_last_nonces: HashMap<u32, u64>,
-    /// Last polled EVM block number per chain (chain_id -> block number)
-    evm_last_blocks: HashMap<u32, u64>,
+    /// Poll cursors for every source chain (hub and connected; chain IDs are unique)
+    cursors: PollCursors,
     /// Per-message delivery attempt tracking: (src_chain_id, nonce) -> DeliveryAttempt
     delivery_attempts: HashMap<(u32, u64), DeliveryAttempt>,
     /// Per-chain poll failure tracking: chain_name -> DeliveryAttempt
     /// When a poll fails (RPC unreachable), the chain enters backoff before retrying.
     chain_poll_failures: HashMap<String, DeliveryAttempt>,
+    /// Destination chains where the relay signer was below its minimum balance
+    /// at the start of the current poll cycle
+    underfunded_chains: HashSet<u32>,
 }
 
 // ============================================================================
@@ -335,6 +450,7 @@ impl NativeGmpRelay {
                     gmp_endpoint,
                     evm_chain.chain_id,
                     &evm_chain.relay_address,
+                    evm_chain.unknown_status_is,
                 )
                 .with_context(|| format!("Failed to create EVM client for chain {}", evm_chain.chain_id))?;
                 evm_clients.insert(evm_chain.chain_id, client);
@@ -577,6 +693,9 @@ impl NativeGmpRelay {
         let interval = Duration::from_millis(self.config.polling_interval_ms);
 
         loop {
+            // Read the relay balance on each destination once per cycle
+            self.refresh_relay_funding().await;
+
             // Poll MVM hub for MessageSent events
             if self.should_poll_chain("mvm_hub").await {
                 match self.poll_mvm_events().await {
@@ -624,8 +743,9 @@ impl NativeGmpRelay {
 
     /// Poll MVM hub outbox for new messages via view functions.
     async fn poll_mvm_events(&self) -> Result<()> {
+        let hub_chain_id = self.config.mvm_chain_id;
         let last_nonce = {
-            self.state.read().await.mvm_hub_last_nonce
+            self.state.read().await.cursors.get(hub_chain_id).unwrap_or(0)
         };
 
         let new_last = self
@@ -633,7 +753,7 @@ impl NativeGmpRelay {
             .await?;
 
         if new_last > last_nonce {
-            self.state.write().await.mvm_hub_last_nonce = new_last;
+            self.state.write().await.cursors.advance_cursor(hub_chain_id, new_last);
         }
 
         Ok(())
@@ -645,7 +765,7 @@ impl NativeGmpRelay {
             .ok_or_else(|| anyhow::anyhow!("No MVM client for chain {}", mvm_chain.chain_id))?;
 
         let last_nonce = {
-            *self.state.read().await.mvm_connected_last_nonces.get(&mvm_chain.chain_id).unwrap_or(&0)
+            self.state.read().await.cursors.get(mvm_chain.chain_id).unwrap_or(0)
         };
 
         let chain_label = format!("connected({})", mvm_chain.chain_id);
@@ -654,7 +774,7 @@ impl NativeGmpRelay {
             .await?;
 
         if new_last > last_nonce {
-            self.state.write().await.mvm_connected_last_nonces.insert(mvm_chain.chain_id, new_last);
+            self.state.write().await.cursors.advance_cursor(mvm_chain.chain_id, new_last);
         }
 
         Ok(())
@@ -708,6 +828,12 @@ impl NativeGmpRelay {
                 chain_name, nonce, message.remote_gmp_endpoint_addr, message.dst_chain_id
             );
 
+            // Underfunded relay: stop here without advancing the cursor so the
+            // message is picked up again once the relay is funded
+            if self.relay_is_underfunded(message.dst_chain_id).await {
+                break;
+            }
+
             if !self.should_attempt_delivery(src_chain_id, nonce).await {
                 new_last = nonce;
                 continue;
@@ -766,7 +892,7 @@ impl NativeGmpRelay {
             .context("Failed to read SVM outbound nonce")?;
 
         let maybe_last = {
-            self.state.read().await.svm_last_nonces.get(&svm_chain_id).copied()
+            self.state.read().await.cursors.get(svm_chain_id)
         };
 
         let start = match maybe_last {
@@ -814,6 +940,11 @@ impl NativeGmpRelay {
                 nonce, message.remote_gmp_endpoint_addr, message.dst_chain_id
             );
 
+            // Underfunded relay: stop here without advancing the cursor
+            if self.relay_is_underfunded(message.dst_chain_id).await {
+                break;
+            }
+
             if !self.should_attempt_delivery(svm_chain_id, nonce).await {
                 new_last = Some(nonce);
                 continue;
@@ -847,17 +978,122 @@ impl NativeGmpRelay {
 
         if let Some(last) = new_last {
             if maybe_last != new_last {
-                self.state.write().await.svm_last_nonces.insert(svm_chain_id, last);
+                self.state.write().await.cursors.advance_cursor(svm_chain_id, last);
             }
         }
 
         Ok(())
     }
 
+    /// Read the relay signer's balance on a destination chain, with the configured minimum.
+    ///
+    /// Returns `None` when no minimum is configured for the destination's VM.
+    async fn relay_balance_on(&self, dst_chain_id: u32) -> Result<Option<(String, u128, u64)>> {
+        if dst_chain_id == self.config.mvm_chain_id
+            || self.config.find_mvm_chain(dst_chain_id).is_some()
+        {
+            let Some(min_balance) = self.config.min_relay_balance_mvm else {
+                return Ok(None);
+            };
+            let client = if dst_chain_id == self.config.mvm_chain_id {
+                &self.mvm_hub_client
+            } else {
+                self.mvm_connected_clients
+                    .get(&dst_chain_id)
+                    .ok_or_else(|| anyhow::anyhow!("No MVM client for chain {}", dst_chain_id))?
+            };
+            let relay_addr = self.crypto_service.get_move_address()?;
+            let balance = client.get_relay_balance(&relay_addr).await?;
+            return Ok(Some((relay_addr, balance, min_balance)));
+        }
+
+        if self.config.find_svm_chain(dst_chain_id).is_some() {
+            let Some(min_balance) = self.config.min_relay_balance_svm else {
+                return Ok(None);
+            };
+            let client = self
+                .svm_clients
+                .get(&dst_chain_id)
+                .ok_or_else(|| anyhow::anyhow!("No SVM client for chain {}", dst_chain_id))?;
+            let relay_addr = self.crypto_service.get_solana_address();
+            let balance = client.get_relay_balance(&relay_addr).await?;
+            return Ok(Some((relay_addr, balance, min_balance)));
+        }
+
+        if self.config.find_evm_chain(dst_chain_id).is_some() {
+            let Some(min_balance) = self.config.min_relay_balance_evm else {
+                return Ok(None);
+            };
+            let client = self
+                .evm_clients
+                .get(&dst_chain_id)
+                .ok_or_else(|| anyhow::anyhow!("No EVM client for chain {}", dst_chain_id))?;
+            let relay_addr = client.relay_address().to_string();
+            let balance = client.get_relay_balance(&relay_addr).await?;
+            return Ok(Some((relay_addr, balance, min_balance)));
+        }
+
+        Ok(None)
+    }
+
+    /// Check the relay signer is funded on the destination chain before delivering.
+    ///
+    /// A balance that cannot be read does not block delivery; the delivery itself
+    /// surfaces RPC failures through the retry path.
+    async fn relay_has_min_balance(&self, dst_chain_id: u32) -> bool {
+        match self.relay_balance_on(dst_chain_id).await {
+            Ok(Some((relay_addr, balance, min_balance))) => {
+                is_relay_balance_sufficient(dst_chain_id, &relay_addr, balance, min_balance)
+            }
+            Ok(None) => true,
+            Err(e) => {
+                warn!(
+                    "Could not read relay balance on chain {}: {:#}. Attempting delivery anyway.",
+                    dst_chain_id, e
+                );
+                true
+            }
+        }
+    }
+
+    /// Re-check the relay signer's balance on every destination chain.
+    ///
+    /// Runs once per poll cycle; deliveries consult the result through
+    /// `relay_is_underfunded` instead of reading the balance per message.
+    async fn refresh_relay_funding(&self) {
+        let destinations = std::iter::once(self.config.mvm_chain_id)
+            .chain(self.config.mvm_chains.iter().map(|c| c.chain_id))
+            .chain(self.config.svm_chains.iter().map(|c| c.chain_id))
+            .chain(self.config.evm_chains.iter().map(|c| c.chain_id));
+
+        let mut underfunded = HashSet::new();
+        for dst_chain_id in destinations {
+            if !self.relay_has_min_balance(dst_chain_id).await {
+                underfunded.insert(dst_chain_id);
+            }
+        }
+        self.state.write().await.underfunded_chains = underfunded;
+    }
+
+    /// Whether the last balance check found the relay underfunded on `dst_chain_id`.
+    async fn relay_is_underfunded(&self, dst_chain_id: u32) -> bool {
+        self.state
+            .read()
+            .await
+            .underfunded_chains
+            .contains(&dst_chain_id)
+    }
+
     /// Deliver a GMP message to the destination chain.
     async fn deliver_message(&self, message: &GmpMessage) -> Result<()> {
         let dst = message.dst_chain_id;
 
+        // Reject a destination address that is not in the destination VM's format
+        // instead of delivering to whatever it happens to parse as
+        if let Some(vm) = self.config.destination_vm(dst) {
+            parse_dst_addr(vm, &message.dst_addr)?;
+        }
+
         // Destination is MVM hub
         if dst == self.config.mvm_chain_id {
             return self.deliver_to_mvm_hub(message).await;
@@ -958,11 +1194,10 @@ impl NativeGmpRelay {
 
         let current_block = client.get_block_number().await?;
 
-        // Max 10 block range for Alchemy free tier
-        let max_range: u64 = 10;
-        let last_block = { *self.state.read().await.evm_last_blocks.get(&evm_chain_id).unwrap_or(&0) };
+        let max_range = EVM_POLL_MAX_RANGE;
+        let last_block = { self.state.read().await.cursors.get(evm_chain_id).unwrap_or(0) };
         let from_block = if last_block == 0 {
-            current_block.saturating_sub(max_range)
+            evm_startup_from_block(current_block, evm_chain.startup_lookback_blocks)
         } else {
             last_block + 1
         };
@@ -997,6 +1232,7 @@ impl NativeGmpRelay {
             );
         }
 
+        let mut underfunded = false;
         for message in &messages {
             info!(
                 "Found EVM MessageSent: dst_chain={}, nonce={}",
@@ -1017,6 +1253,13 @@ impl NativeGmpRelay {
                 }
             }
 
+            // Underfunded relay: keep the block cursor so this range is re-polled;
+            // already-delivered nonces are skipped via processed_nonces
+            if self.relay_is_underfunded(message.dst_chain_id).await {
+                underfunded = true;
+                break;
+            }
+
             if !self.should_attempt_delivery(evm_chain_id, message.nonce).await {
                 continue;
             }
@@ -1051,8 +1294,8 @@ impl NativeGmpRelay {
             }
         }
 
-        {
-            self.state.write().await.evm_last_blocks.insert(evm_chain_id, to_block);
+        if !underfunded {
+            self.state.write().await.cursors.advance_cursor(evm_chain_id, to_block);
         }
 
         Ok(())
@@ -1086,7 +1329,8 @@ impl NativeGmpRelay {
         let remote_gmp_endpoint_addr = parse_32_byte_address(&message.remote_gmp_endpoint_addr)?;
 
         // Parse destination address (the receiving program on SVM - e.g., outflow-validator)
-        let dst_program = parse_svm_pubkey(&message.dst_addr)?;
+        let dst_program =
+            Pubkey::new_from_array(parse_dst_addr(DestinationVm::Svm, &message.dst_addr)?);
 
         // Parse payload
         let payload = hex_to_bytes(&message.payload)?;
@@ -1188,14 +1432,9 @@ impl NativeGmpRelay {
                 );
 
                 // Derive intent_escrow PDAs (escrow_program is the intent_escrow)
-                let (escrow_requirements_pda, _) = Pubkey::find_program_address(
-                    &[b"requirements", &intent_id],
-                    &escrow_program,
-                );
-                let (escrow_gmp_config_pda, _) = Pubkey::find_program_address(
-                    &[b"gmp_config"],
-                    &escrow_program,
-                );
+                let escrow_pdas = EscrowIntentPdas::derive(&escrow_program, &intent_id);
+                let escrow_requirements_pda = escrow_pdas.requirements;
+                let escrow_gmp_config_pda = escrow_pdas.gmp_config;
 
                 debug!(
                     "Adding accounts for multi-destination GmpReceive CPI: outflow_req={}, outflow_cfg={}, escrow_req={}, escrow_cfg={}",
@@ -1229,22 +1468,12 @@ impl NativeGmpRelay {
                 solver_addr.copy_from_slice(&payload[33..65]);
 
                 // Derive intent_escrow PDAs
-                let (escrow_requirements_pda, _) = Pubkey::find_program_address(
-                    &[b"requirements", &intent_id],
-                    &escrow_program,
-                );
-                let (escrow_pda, _) = Pubkey::find_program_address(
-                    &[b"escrow", &intent_id],
-                    &escrow_program,
-                );
-                let (vault_pda, _) = Pubkey::find_program_address(
-                    &[b"vault", &intent_id],
-                    &escrow_program,
-                );
-                let (escrow_gmp_config_pda, _) = Pubkey::find_program_address(
-                    &[b"gmp_config"],
-                    &escrow_program,
-                );
+                let escrow_pdas = EscrowIntentPdas::derive(&escrow_program, &intent_id);
+                let escrow_requirements_pda = escrow_pdas.requirements;
+                let escrow_pda = escrow_pdas.escrow;
+                let vault_pda = escrow_pdas.vault;
+                let escrow_gmp_config_pda = escrow_pdas.gmp_config;
+                let escrow_state_pda = escrow_pdas.state;
 
                 // Read requirements account to get token_addr (mint)
                 let rpc_client_for_read = RpcClient::new_with_commitment(
@@ -1268,39 +1497,52 @@ impl NativeGmpRelay {
                 token_mint_bytes.copy_from_slice(&requirements_data[80..112]);
                 let token_mint = Pubkey::new_from_array(token_mint_bytes);
 
-                // Derive solver's ATA manually (PDA derivation)
-                // ATA = PDA([owner, TOKEN_PROGRAM_ID, mint], ASSOCIATED_TOKEN_PROGRAM_ID)
                 let solver_pubkey = Pubkey::new_from_array(solver_addr);
-                let token_program_id = Pubkey::from_str("TokenkegQfeZyiNwAJbNbGKPFXCWuBvf9Ss623VQ5DA")
-                    .expect("Invalid token program ID");
-                let associated_token_program_id = Pubkey::from_str("ATokenGPvbdGVxr1b2hvZbsiqW5xWH25efTNsLJA8knL")
-                    .expect("Invalid associated token program ID");
-                let (solver_ata, _) = Pubkey::find_program_address(
-                    &[
-                        solver_pubkey.as_ref(),
-                        token_program_id.as_ref(),
-                        token_mint.as_ref(),
-                    ],
-                    &associated_token_program_id,
-                );
+                let (token_program_id, solver_token) = if token_mint == Pubkey::default() {
+                    // Native SOL (zero-address token): the vault pays lamports straight
+                    // to the solver's wallet through the system program, no ATA involved
+                    (SYSTEM_PROGRAM_ID, solver_pubkey)
+                } else {
+                    // The mint's owner is its token program (SPL Token or Token-2022);
+                    // the vault, the solver's ATA and the release CPI all use it
+                    let token_program_id = rpc_client_for_read
+                        .get_account(&token_mint)
+                        .context("Failed to read token mint account for FulfillmentProof")?
+                        .owner;
+
+                    // Derive solver's ATA manually (PDA derivation)
+                    // ATA = PDA([owner, token_program, mint], ASSOCIATED_TOKEN_PROGRAM_ID)
+                    let associated_token_program_id = Pubkey::from_str("ATokenGPvbdGVxr1b2hvZbsiqW5xWH25efTNsLJA8knL")
+                        .expect("Invalid associated token program ID");
+                    let (solver_ata, _) = Pubkey::find_program_address(
+                        &[
+                            solver_pubkey.as_ref(),
+                            token_program_id.as_ref(),
+                            token_mint.as_ref(),
+                        ],
+                        &associated_token_program_id,
+                    );
+
+                    // Store ATA creation info for use when building transaction
+                    ata_create_info = Some((solver_ata, solver_pubkey, token_mint, token_program_id, associated_token_program_id));
+                    (token_program_id, solver_ata)
+                };
 
                 debug!(
-                    "FulfillmentProof accounts: requirements={}, escrow={}, vault={}, solver_ata={}, gmp_config={}, token_mint={}",
-                    escrow_requirements_pda, escrow_pda, vault_pda, solver_ata, escrow_gmp_config_pda, token_mint
+                    "FulfillmentProof accounts: requirements={}, escrow={}, vault={}, solver_token={}, gmp_config={}, token_mint={}",
+                    escrow_requirements_pda, escrow_pda, vault_pda, solver_token, escrow_gmp_config_pda, token_mint
                 );
 
-                // Store ATA creation info for use when building transaction
-                ata_create_info = Some((solver_ata, solver_pubkey, token_mint, token_program_id, associated_token_program_id));
-
                 // Accounts for intent_escrow's GmpReceiveFulfillmentProof
-                // Expected: requirements(w), escrow(w), vault(w), solver_token(w), gmp_config(r), gmp_caller(s), token_program
+                // Expected: requirements(w), escrow(w), vault(w), solver_token(w), gmp_config(r), gmp_caller(s), token_program, state(r)
                 accounts.push(AccountMeta::new(escrow_requirements_pda, false));     // 0: requirements (writable)
                 accounts.push(AccountMeta::new(escrow_pda, false));                  // 1: escrow (writable)
                 accounts.push(AccountMeta::new(vault_pda, false));                   // 2: vault (writable)
-                accounts.push(AccountMeta::new(solver_ata, false));                  // 3: solver_token (writable)
+                accounts.push(AccountMeta::new(solver_token, false));                // 3: solver_token (writable)
                 accounts.push(AccountMeta::new_readonly(escrow_gmp_config_pda, false)); // 4: gmp_config
                 accounts.push(AccountMeta::new_readonly(relay_pubkey, true));        // 5: gmp_caller (signer)
                 accounts.push(AccountMeta::new_readonly(token_program_id, false));   // 6: token_program
+                accounts.push(AccountMeta::new_readonly(escrow_state_pda, false));   // 7: state
             }
         }
 
@@ -1451,13 +1693,7 @@ pub fn hex_to_bytes(hex_str: &str) -> Result<Vec<u8>> {
 /// Handles Move VM addresses that may have leading zeros stripped.
 /// Left-pads short addresses to ensure exactly 32 bytes.
 pub fn parse_32_byte_address(addr: &str) -> Result<[u8; 32]> {
-    let hex_clean = addr.strip_prefix("0x").unwrap_or(addr);
-    // Left-pad to 64 hex chars (32 bytes) to handle addresses with stripped leading zeros
-    let padded = format!("{:0>64}", hex_clean);
-    let bytes = hex::decode(&padded).context("Invalid hex address")?;
-    let mut array = [0u8; 32];
-    array.copy_from_slice(&bytes);
-    Ok(array)
+    hex_to_bytes32(addr).ok_or_else(|| anyhow::anyhow!("Invalid 32-byte address: {}", addr))
 }
 
 /// Parse a Solana pubkey from hex (with 0x prefix) or base58.
@@ -1470,6 +1706,20 @@ pub fn parse_svm_pubkey(addr: &str) -> Result<Pubkey> {
     }
 }
 
+/// Parse a message's destination address in the format of the destination VM.
+///
+/// SVM destinations take a base58 pubkey or 0x-prefixed hex (as source events
+/// carry it); MVM and EVM destinations take a hex address. The format follows
+/// the resolved destination chain, so an address in another VM's format fails
+/// instead of being delivered to the wrong account.
+pub fn parse_dst_addr(vm: DestinationVm, addr: &str) -> Result<[u8; 32]> {
+    match vm {
+        DestinationVm::Svm => parse_svm_pubkey(addr).map(|pubkey| pubkey.to_bytes()),
+        DestinationVm::Mvm | DestinationVm::Evm => parse_32_byte_address(addr),
+    }
+    .with_context(|| format!("Invalid {:?} destination address: {}", vm, addr))
+}
+
 /// Convert a 32-byte Ed25519 seed to a 64-byte Solana keypair format.
 ///
 /// Solana keypairs are 64 bytes: 32-byte seed + 32-byte public key.
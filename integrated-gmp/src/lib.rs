@@ -10,6 +10,9 @@ pub mod svm_client;
 pub mod integrated_gmp_relay;
 
 // Re-export commonly used types
-pub use config::{ApiConfig, ChainConfig, Config, EvmChainConfig, SvmChainConfig, IntegratedGmpConfig};
+pub use config::{
+    ApiConfig, ChainConfig, Config, EvmChainConfig, IntegratedGmpConfig, SvmChainConfig,
+    UnknownStatusPolicy,
+};
 pub use crypto::CryptoService;
 pub use integrated_gmp_relay::{DeliveryAttempt, NativeGmpRelay, NativeGmpRelayConfig, MAX_DELIVERY_RETRIES};
@@ -5,12 +5,16 @@
 
 use anyhow::{Context, Result};
 use base64::{engine::general_purpose::STANDARD, Engine as _};
+use chain_clients_common::normalize_move_address;
 use chain_clients_mvm::MvmClient;
 use std::process::Command;
 use tracing::{debug, error, info, warn};
 
 use crate::integrated_gmp_relay::GmpMessage;
 
+/// Coin type of APT, the MVM gas token.
+const APT_COIN_TYPE: &str = "0x1::aptos_coin::AptosCoin";
+
 // ============================================================================
 // CLIENT
 // ============================================================================
@@ -44,6 +48,30 @@ impl GmpMvmClient {
         &self.mvm_client
     }
 
+    // ========================================================================
+    // Relay balance
+    // ========================================================================
+
+    /// Read the relay account's APT balance (octas) via `0x1::coin::balance<AptosCoin>`.
+    ///
+    /// `coin::balance` also counts APT already migrated to the primary fungible store.
+    pub async fn get_relay_balance(&self, relay_addr: &str) -> Result<u128> {
+        let balance: String = self
+            .mvm_client
+            .call_view(
+                "0x1",
+                "coin",
+                "balance",
+                vec![APT_COIN_TYPE.to_string()],
+                vec![serde_json::json!(relay_addr)],
+            )
+            .await
+            .context("Failed to read relay APT balance")?;
+        balance
+            .parse::<u128>()
+            .with_context(|| format!("Invalid relay APT balance: {}", balance))
+    }
+
     // ========================================================================
     // Authorization check
     // ========================================================================
@@ -256,12 +284,12 @@ impl GmpMvmClient {
 // HELPERS (moved from integrated_gmp_relay.rs)
 // ============================================================================
 
+/// Normalizes a Move address to `0x` + 64 lowercase hex characters.
+///
+/// The module address is forwarded as the 32-byte remote GMP endpoint, so a
+/// configured short form such as `0x1` must have its leading zeros restored.
 pub fn normalize_address(addr: &str) -> String {
-    if addr.starts_with("0x") {
-        addr.to_string()
-    } else {
-        format!("0x{}", addr)
-    }
+    normalize_move_address(addr)
 }
 
 pub fn parse_view_bytes(value: &serde_json::Value) -> Result<String> {
@@ -22,6 +22,14 @@ impl GmpSvmClient {
         Ok(Self { svm_client })
     }
 
+    /// Read the relay account's SOL balance (lamports).
+    pub async fn get_relay_balance(&self, relay_addr: &str) -> Result<u128> {
+        self.svm_client
+            .get_native_balance(relay_addr)
+            .await
+            .context("Failed to read relay SOL balance")
+    }
+
     /// Read the global outbound nonce from the GMP program.
     /// PDA seeds: ["nonce_out"]
     /// Returns the nonce value (next nonce to be assigned), or 0 if the account doesn't exist.
@@ -139,6 +147,32 @@ pub struct SvmOutboundMessage {
     pub payload: Vec<u8>,
 }
 
+/// Per-intent `intent_inflow_escrow` accounts, derived via `chain_clients_svm::pda`.
+#[derive(Debug, Clone, Copy)]
+pub struct EscrowIntentPdas {
+    pub requirements: Pubkey,
+    pub escrow: Pubkey,
+    pub vault: Pubkey,
+    pub gmp_config: Pubkey,
+    pub state: Pubkey,
+}
+
+impl EscrowIntentPdas {
+    /// Derive the escrow program PDAs for `intent_id` with the shared seed helpers.
+    pub fn derive(escrow_program: &Pubkey, intent_id: &[u8; 32]) -> Self {
+        use chain_clients_svm::pda;
+
+        let program = to_solana_program_pubkey(escrow_program);
+        Self {
+            requirements: from_solana_program_pubkey(&pda::requirements_pda(&program, intent_id)),
+            escrow: from_solana_program_pubkey(&pda::escrow_pda(&program, intent_id)),
+            vault: from_solana_program_pubkey(&pda::vault_pda(&program, intent_id)),
+            gmp_config: from_solana_program_pubkey(&pda::gmp_config_pda(&program)),
+            state: from_solana_program_pubkey(&pda::state_pda(&program)),
+        }
+    }
+}
+
 // ============================================================================
 // HELPERS
 // ============================================================================
@@ -149,3 +183,10 @@ fn to_solana_program_pubkey(
 ) -> chain_clients_svm::solana_program::pubkey::Pubkey {
     chain_clients_svm::solana_program::pubkey::Pubkey::new_from_array(pubkey.to_bytes())
 }
+
+/// Convert solana_program::Pubkey back to solana_sdk::Pubkey.
+fn from_solana_program_pubkey(
+    pubkey: &chain_clients_svm::solana_program::pubkey::Pubkey,
+) -> Pubkey {
+    Pubkey::new_from_array(pubkey.to_bytes())
+}
@@ -14,7 +14,7 @@ use test_helpers::{build_test_config_with_evm, DUMMY_ESCROW_CONTRACT_ADDR_EVM, D
 /// Why: Missing config fields would cause runtime failures when connecting to EVM chains.
 #[test]
 fn test_evm_chain_config_structure() {
-    use integrated_gmp::config::EvmChainConfig;
+    use integrated_gmp::config::{EvmChainConfig, UnknownStatusPolicy};
 
     let evm_config = EvmChainConfig {
         name: "Connected EVM Chain".to_string(),
@@ -24,6 +24,8 @@ fn test_evm_chain_config_structure() {
         approver_evm_pubkey_hash: DUMMY_APPROVER_EVM_PUBKEY_HASH.to_string(),
         gmp_endpoint_addr: None,
         outflow_validator_addr: None,
+        unknown_status_is: UnknownStatusPolicy::Failure,
+        startup_lookback_blocks: None,
     };
 
     assert_eq!(evm_config.name, "Connected EVM Chain");
@@ -44,7 +46,7 @@ fn test_evm_chain_config_structure() {
 /// Why: The EVM chain config must be settable for multi-chain deployments.
 #[test]
 fn test_connected_chain_evm_with_values() {
-    use integrated_gmp::config::EvmChainConfig;
+    use integrated_gmp::config::{EvmChainConfig, UnknownStatusPolicy};
     let mut config = Config::default();
 
     config.connected_chain_evm = vec![EvmChainConfig {
@@ -55,6 +57,8 @@ fn test_connected_chain_evm_with_values() {
         approver_evm_pubkey_hash: DUMMY_APPROVER_EVM_PUBKEY_HASH.to_string(),
         gmp_endpoint_addr: None,
         outflow_validator_addr: None,
+        unknown_status_is: UnknownStatusPolicy::Failure,
+        startup_lookback_blocks: None,
     }];
 
     assert!(!config.connected_chain_evm.is_empty());
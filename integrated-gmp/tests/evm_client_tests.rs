@@ -3,6 +3,28 @@
 //! All tests in this group are N/A for EVM — the functions under test
 //! (normalize_address, extract_transaction_hash, check_vm_status_success,
 //! parse_view_bytes) are MVM-specific helpers for parsing aptos CLI output.
+//! Receipt status tests are EVM-specific; relay balance tests cover all VMs.
+
+use integrated_gmp::config::UnknownStatusPolicy;
+use integrated_gmp::evm_client::{receipt_succeeded, GmpEvmClient};
+use integrated_gmp::integrated_gmp_relay::is_relay_balance_sufficient;
+use wiremock::matchers::{body_partial_json, method};
+use wiremock::{Mock, MockServer, ResponseTemplate};
+
+const TEST_EVM_CHAIN_ID: u32 = 31337;
+const DUMMY_RELAY_ADDR_EVM: &str = "0x000000000000000000000000000000000000000d";
+const DUMMY_GMP_ENDPOINT_ADDR_EVM: &str = "0x0000000000000000000000000000000000000011";
+
+fn test_client(rpc_url: &str) -> GmpEvmClient {
+    GmpEvmClient::new(
+        rpc_url,
+        DUMMY_GMP_ENDPOINT_ADDR_EVM,
+        TEST_EVM_CHAIN_ID,
+        DUMMY_RELAY_ADDR_EVM,
+        UnknownStatusPolicy::Failure,
+    )
+    .unwrap()
+}
 
 // ============================================================================
 // ADDRESS NORMALIZATION TESTS
@@ -52,3 +74,126 @@
 
 // 11. Test: Parse View Bytes Empty Array
 // NOTE: N/A for EVM - parse_view_bytes parses aptos view function responses
+
+// ============================================================================
+// RECEIPT STATUS TESTS
+// ============================================================================
+
+const DUMMY_EVM_TX_HASH: &str =
+    "0x0000000000000000000000000000000000000000000000000000000000000012";
+
+// 12. Test: Receipt Without Status Fails Under Failure Policy
+// Verifies that receipt_succeeded returns an error for a receipt with no status field when unknown_status_is = failure.
+// Why: Failure is the default and preserves the previous strict behavior for chains that always report status.
+#[test]
+fn test_receipt_without_status_fails_under_failure_policy() {
+    let receipt =
+        serde_json::json!({ "transactionHash": DUMMY_EVM_TX_HASH, "blockNumber": "0x10" });
+
+    let result = receipt_succeeded(DUMMY_EVM_TX_HASH, &receipt, UnknownStatusPolicy::Failure);
+
+    let err = result.expect_err("missing status must fail under Failure policy");
+    assert!(err.to_string().contains("missing status field"));
+
+    // Explicit statuses are unaffected by the policy
+    let failed = serde_json::json!({ "status": "0x0" });
+    assert!(receipt_succeeded(DUMMY_EVM_TX_HASH, &failed, UnknownStatusPolicy::Failure).is_err());
+    let succeeded = serde_json::json!({ "status": "0x1" });
+    assert!(
+        receipt_succeeded(DUMMY_EVM_TX_HASH, &succeeded, UnknownStatusPolicy::Failure).unwrap()
+    );
+}
+
+// 13. Test: Receipt Without Status Is Pending Under Pending Policy
+// Verifies that receipt_succeeded returns Ok(false) for a receipt with no status field when unknown_status_is = pending, while an explicit 0x0 status still fails.
+// Why: On chains that omit status, a hard failure would drop deliveries that simply haven't settled; they must be waited on and retried instead.
+#[test]
+fn test_receipt_without_status_is_pending_under_pending_policy() {
+    let receipt =
+        serde_json::json!({ "transactionHash": DUMMY_EVM_TX_HASH, "blockNumber": "0x10" });
+
+    let result = receipt_succeeded(DUMMY_EVM_TX_HASH, &receipt, UnknownStatusPolicy::Pending);
+
+    assert!(!result.expect("missing status must not fail under Pending policy"));
+
+    let failed = serde_json::json!({ "status": "0x0" });
+    assert!(receipt_succeeded(DUMMY_EVM_TX_HASH, &failed, UnknownStatusPolicy::Pending).is_err());
+}
+
+// ============================================================================
+// ESCROW PDA DERIVATION TESTS
+// ============================================================================
+
+// 14. Test: Escrow Intent PDAs Match Program Seeds
+// NOTE: N/A for EVM - EscrowIntentPdas derives Solana program-derived addresses
+
+// ============================================================================
+// RELAY BALANCE TESTS
+// ============================================================================
+
+// 15. Test: Relay Balance Reads Native Balance
+// Verifies that get_relay_balance reads the relay's native balance via eth_getBalance.
+// Why: The relay refuses to deliver when underfunded; a misread balance would either stall deliveries or let them fail on gas.
+#[tokio::test]
+async fn test_get_relay_balance_reads_native_balance() {
+    let mock_server = MockServer::start().await;
+    Mock::given(method("POST"))
+        .and(body_partial_json(
+            serde_json::json!({ "method": "eth_getBalance" }),
+        ))
+        .respond_with(ResponseTemplate::new(200).set_body_json(serde_json::json!({
+            "jsonrpc": "2.0", "id": 1, "result": "0xde0b6b3a7640000"
+        })))
+        .mount(&mock_server)
+        .await;
+    let client = test_client(&mock_server.uri());
+
+    let balance = client
+        .get_relay_balance(DUMMY_RELAY_ADDR_EVM)
+        .await
+        .unwrap();
+
+    assert_eq!(balance, 1_000_000_000_000_000_000);
+    assert!(is_relay_balance_sufficient(
+        TEST_EVM_CHAIN_ID,
+        DUMMY_RELAY_ADDR_EVM,
+        balance,
+        10_000_000_000_000_000
+    ));
+}
+
+// 16. Test: Relay Balance Below Minimum Blocks Delivery
+// Verifies that a relay native balance below the configured minimum is reported as insufficient, and that exactly the minimum is sufficient.
+// Why: Submitting deliveries from an underfunded relay burns retries on out-of-gas failures instead of alerting the operator.
+#[tokio::test]
+async fn test_relay_balance_below_minimum_blocks_delivery() {
+    let mock_server = MockServer::start().await;
+    Mock::given(method("POST"))
+        .and(body_partial_json(
+            serde_json::json!({ "method": "eth_getBalance" }),
+        ))
+        .respond_with(ResponseTemplate::new(200).set_body_json(serde_json::json!({
+            "jsonrpc": "2.0", "id": 1, "result": "0x3e8"
+        })))
+        .mount(&mock_server)
+        .await;
+    let client = test_client(&mock_server.uri());
+
+    let balance = client
+        .get_relay_balance(DUMMY_RELAY_ADDR_EVM)
+        .await
+        .unwrap();
+
+    assert!(!is_relay_balance_sufficient(
+        TEST_EVM_CHAIN_ID,
+        DUMMY_RELAY_ADDR_EVM,
+        balance,
+        1_001
+    ));
+    assert!(is_relay_balance_sufficient(
+        TEST_EVM_CHAIN_ID,
+        DUMMY_RELAY_ADDR_EVM,
+        balance,
+        1_000
+    ));
+}
@@ -10,10 +10,11 @@
 
 use base64::{engine::general_purpose, Engine as _};
 use ed25519_dalek::SigningKey;
-use rand::{Rng, RngCore};
 use integrated_gmp::config::{
-    ApiConfig, ChainConfig, Config, EvmChainConfig, SvmChainConfig, IntegratedGmpConfig,
+    ApiConfig, ChainConfig, Config, EvmChainConfig, IntegratedGmpConfig, SvmChainConfig,
+    UnknownStatusPolicy,
 };
+use rand::{Rng, RngCore};
 
 // ============================================================================
 // CONSTANTS
@@ -117,6 +118,9 @@ pub fn build_test_config_with_mvm() -> Config {
             public_key_env: public_key_env_name,
             polling_interval_ms: 1000,
             validation_timeout_ms: 1000,
+            min_relay_balance_mvm: None,
+            min_relay_balance_evm: None,
+            min_relay_balance_svm: None,
         },
         api: ApiConfig {
             host: "127.0.0.1".to_string(),
@@ -140,6 +144,8 @@ pub fn build_test_config_with_evm() -> Config {
         approver_evm_pubkey_hash: DUMMY_APPROVER_EVM_PUBKEY_HASH.to_string(),
         gmp_endpoint_addr: None,
         outflow_validator_addr: None,
+        unknown_status_is: UnknownStatusPolicy::Failure,
+        startup_lookback_blocks: None,
     }];
     config
 }
@@ -1,18 +1,23 @@
 //! Tests for integrated GMP relay generic helper functions.
 //!
 //! These tests cover the generic helper functions used by the relay:
-//! address parsing, Ed25519 keypair conversion, hex-to-bytes, and delivery retry tracking.
+//! address parsing, destination address routing, Ed25519 keypair conversion, hex-to-bytes,
+//! delivery retry tracking, poll cursors, and the EVM cold-start polling window.
 //!
 //! VM-specific tests are in relay_vm_tests.rs.
 
 mod helpers;
 
-use helpers::{DUMMY_INTENT_ID, DUMMY_SOLVER_ADDR_HUB};
+use helpers::{build_test_config_with_svm, DUMMY_INTENT_ID, DUMMY_SOLVER_ADDR_HUB};
+use solana_sdk::pubkey::Pubkey;
 use solana_sdk::signature::Keypair;
 use integrated_gmp::integrated_gmp_relay::{
-    ed25519_seed_to_keypair_bytes, hex_to_bytes,
-    parse_32_byte_address, DeliveryAttempt,
+    ed25519_seed_to_keypair_bytes, evm_startup_from_block, hex_to_bytes, parse_32_byte_address,
+    parse_dst_addr, DeliveryAttempt, DestinationVm, NativeGmpRelayConfig, PollCursors,
+    EVM_POLL_MAX_RANGE, MAX_STARTUP_LOOKBACK_BLOCKS,
 };
+use std::sync::Arc;
+use tokio::sync::RwLock;
 use integrated_gmp::MAX_DELIVERY_RETRIES;
 
 // ============================================================================
@@ -207,3 +212,130 @@ fn test_delivery_attempt_not_exhausted_under_limit() {
     let attempt = DeliveryAttempt { count: MAX_DELIVERY_RETRIES - 1, next_retry_after: 0 };
     assert!(!attempt.is_exhausted());
 }
+
+// ============================================================================
+// EVM STARTUP LOOKBACK TESTS
+// ============================================================================
+
+// 13. Test: EVM startup without lookback starts at the head window
+// Verifies that evm_startup_from_block returns `current_block - EVM_POLL_MAX_RANGE` when no lookback is configured, saturating at 0 near genesis.
+// Why: Existing deployments without the setting must keep polling from head on a cold start.
+#[test]
+fn test_evm_startup_without_lookback_starts_at_head() {
+    assert_eq!(evm_startup_from_block(5_000, None), 5_000 - EVM_POLL_MAX_RANGE);
+    assert_eq!(evm_startup_from_block(3, None), 0);
+}
+
+// 14. Test: EVM startup with lookback starts behind head and is capped
+// Verifies that evm_startup_from_block returns `current_block - lookback` for a configured lookback, and clamps the lookback to MAX_STARTUP_LOOKBACK_BLOCKS.
+// Why: A relay restarted after downtime must catch messages sent while it was down, without an unbounded replay from genesis.
+#[test]
+fn test_evm_startup_with_lookback_starts_behind_head() {
+    assert_eq!(evm_startup_from_block(5_000, Some(500)), 4_500);
+    assert_eq!(evm_startup_from_block(100, Some(500)), 0);
+
+    let current_block = MAX_STARTUP_LOOKBACK_BLOCKS * 3;
+    assert_eq!(
+        evm_startup_from_block(current_block, Some(u64::MAX)),
+        current_block - MAX_STARTUP_LOOKBACK_BLOCKS
+    );
+}
+
+// ============================================================================
+// DESTINATION ADDRESS FORMAT TESTS
+// ============================================================================
+
+// 15. Test: Same destination bytes parse per destination chain format
+// Verifies that destination_vm resolves the SVM and MVM chains, and that parse_dst_addr reads the same 32 bytes from base58 for the SVM destination and from hex for the MVM destination.
+// Why: The dst_addr parser must follow the destination chain, not a guess from the string's shape.
+#[test]
+fn test_parse_dst_addr_routes_by_destination_vm() {
+    let config = build_test_config_with_svm();
+    let relay_config = NativeGmpRelayConfig::from_config(&config).unwrap();
+    let svm_chain_id = config.connected_chain_svm[0].chain_id as u32;
+    let mvm_chain_id = config.connected_chain_mvm[0].chain_id as u32;
+
+    let svm_vm = relay_config.destination_vm(svm_chain_id).unwrap();
+    let mvm_vm = relay_config.destination_vm(mvm_chain_id).unwrap();
+    assert_eq!(svm_vm, DestinationVm::Svm);
+    assert_eq!(mvm_vm, DestinationVm::Mvm);
+    assert_eq!(relay_config.destination_vm(u32::MAX), None);
+
+    let bytes = [0xabu8; 32];
+    let base58_addr = Pubkey::new_from_array(bytes).to_string();
+    let hex_addr = format!("0x{}", hex::encode(bytes));
+
+    assert_eq!(parse_dst_addr(svm_vm, &base58_addr).unwrap(), bytes);
+    assert_eq!(parse_dst_addr(mvm_vm, &hex_addr).unwrap(), bytes);
+    // Source events carry 0x hex, which SVM destinations also accept
+    assert_eq!(parse_dst_addr(svm_vm, &hex_addr).unwrap(), bytes);
+}
+
+// 16. Test: Destination address in the wrong format is rejected
+// Verifies that parse_dst_addr errors on a base58 address for an MVM destination, and on hex longer than 32 bytes for any destination.
+// Why: A message addressed in another VM's format must fail instead of being delivered to an unrelated account.
+#[test]
+fn test_parse_dst_addr_rejects_wrong_format() {
+    let base58_addr = Pubkey::new_from_array([0xabu8; 32]).to_string();
+    let err = parse_dst_addr(DestinationVm::Mvm, &base58_addr).unwrap_err();
+    assert!(
+        format!("{:#}", err).contains("Invalid Mvm destination address"),
+        "Unexpected error: {:#}",
+        err
+    );
+
+    let oversized = format!("0x{}", "ab".repeat(33));
+    assert!(parse_dst_addr(DestinationVm::Mvm, &oversized).is_err());
+    assert!(parse_dst_addr(DestinationVm::Svm, &oversized).is_err());
+    assert!(parse_32_byte_address(&oversized).is_err());
+}
+
+// ============================================================================
+// POLL CURSOR TESTS
+// ============================================================================
+
+// 17. Test: Poll cursor ignores an out-of-order lower write
+// Verifies that advance_cursor accepts the first value (including 0) and higher values, rejects a lower or equal value, and keeps chains independent.
+// Why: A slower poller finishing after a faster one must not rewind the cursor and re-process messages.
+#[test]
+fn test_poll_cursor_rejects_lower_value() {
+    let mut cursors = PollCursors::default();
+    assert_eq!(cursors.get(1), None);
+
+    assert!(cursors.advance_cursor(1, 0));
+    assert!(cursors.advance_cursor(1, 10));
+    assert!(!cursors.advance_cursor(1, 5));
+    assert!(!cursors.advance_cursor(1, 10));
+    assert_eq!(cursors.get(1), Some(10));
+
+    assert!(cursors.advance_cursor(2, 3));
+    assert_eq!(cursors.get(2), Some(3));
+    assert_eq!(cursors.get(1), Some(10));
+}
+
+// 18. Test: Poll cursor keeps the highest value under concurrent writers
+// Verifies that concurrent advance_cursor calls through the relay's shared RwLock leave each chain at the highest value written, whatever order the writers run in.
+// Why: Parallel polling tasks update cursors concurrently; the final cursor must be the newest.
+#[tokio::test(flavor = "multi_thread", worker_threads = 4)]
+async fn test_poll_cursor_highest_value_wins_under_concurrent_writers() {
+    let cursors = Arc::new(RwLock::new(PollCursors::default()));
+
+    let mut handles = Vec::new();
+    for chain_id in [1u32, 2] {
+        // Write descending values so most writes arrive behind the current cursor
+        for value in (0..100u64).rev() {
+            let cursors = Arc::clone(&cursors);
+            handles.push(tokio::spawn(async move {
+                tokio::task::yield_now().await;
+                cursors.write().await.advance_cursor(chain_id, value);
+            }));
+        }
+    }
+    for handle in handles {
+        handle.await.unwrap();
+    }
+
+    let cursors = cursors.read().await;
+    assert_eq!(cursors.get(1), Some(99));
+    assert_eq!(cursors.get(2), Some(99));
+}
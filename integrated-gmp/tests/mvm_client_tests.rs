@@ -6,29 +6,36 @@
 
 mod helpers;
 
-use helpers::DUMMY_TX_HASH;
+use helpers::{DUMMY_SOLVER_ADDR_HUB, DUMMY_TX_HASH, TEST_MVM_CHAIN_ID};
+use integrated_gmp::integrated_gmp_relay::is_relay_balance_sufficient;
 use integrated_gmp::mvm_client::{
     check_vm_status_success, extract_transaction_hash, normalize_address, parse_view_bytes,
+    GmpMvmClient,
 };
+use wiremock::matchers::{body_partial_json, method, path};
+use wiremock::{Mock, MockServer, ResponseTemplate};
 
 // ============================================================================
 // ADDRESS NORMALIZATION TESTS
 // ============================================================================
 
 // 1. Test: Normalize Address Adds Prefix
-// Verifies that normalize_address adds a 0x prefix to addresses that are missing it.
+// Verifies that normalize_address adds a 0x prefix and restores leading zeros for addresses missing the prefix.
 // Why: Some Move VM addresses may be stored without prefix.
 #[test]
 fn test_normalize_address_adds_prefix() {
-    assert_eq!(normalize_address("abc123"), "0xabc123");
+    assert_eq!(normalize_address("abc123"), format!("0x{:0>64}", "abc123"));
 }
 
 // 2. Test: Normalize Address Preserves Existing Prefix
-// Verifies that normalize_address does not double-prefix addresses that already have 0x.
+// Verifies that normalize_address does not double-prefix addresses that already have 0x, and lowercases them.
 // Why: Should not double-prefix addresses.
 #[test]
 fn test_normalize_address_preserves_existing_prefix() {
-    assert_eq!(normalize_address("0xabc123"), "0xabc123");
+    assert_eq!(
+        normalize_address("0xABC123"),
+        format!("0x{:0>64}", "abc123")
+    );
 }
 
 // ============================================================================
@@ -136,3 +143,87 @@ fn test_parse_view_bytes_empty_array() {
     let result = parse_view_bytes(&value).unwrap();
     assert_eq!(result, "");
 }
+
+// ============================================================================
+// RECEIPT STATUS TESTS
+// ============================================================================
+
+// 12. Test: Receipt Without Status Fails Under Failure Policy
+// NOTE: N/A for MVM - receipt_succeeded classifies EVM transaction receipts
+
+// 13. Test: Receipt Without Status Is Pending Under Pending Policy
+// NOTE: N/A for MVM - receipt_succeeded classifies EVM transaction receipts
+
+// ============================================================================
+// ESCROW PDA DERIVATION TESTS
+// ============================================================================
+
+// 14. Test: Escrow Intent PDAs Match Program Seeds
+// NOTE: N/A for MVM - EscrowIntentPdas derives Solana program-derived addresses
+
+// ============================================================================
+// RELAY BALANCE TESTS
+// ============================================================================
+
+// 15. Test: Relay Balance Reads APT Coin Balance
+// Verifies that get_relay_balance reads the relay's APT balance via the 0x1::coin::balance<AptosCoin> view function.
+// Why: The relay refuses to deliver when underfunded; a misread balance would either stall deliveries or let them fail on gas.
+#[tokio::test]
+async fn test_get_relay_balance_reads_apt_balance() {
+    let mock_server = MockServer::start().await;
+    Mock::given(method("POST"))
+        .and(path("/v1/view"))
+        .and(body_partial_json(serde_json::json!({
+            "function": "0x1::coin::balance",
+            "type_arguments": ["0x1::aptos_coin::AptosCoin"],
+        })))
+        .respond_with(ResponseTemplate::new(200).set_body_json(serde_json::json!(["150000000"])))
+        .mount(&mock_server)
+        .await;
+    let client = GmpMvmClient::new(&mock_server.uri(), "0x1", TEST_MVM_CHAIN_ID).unwrap();
+
+    let balance = client
+        .get_relay_balance(DUMMY_SOLVER_ADDR_HUB)
+        .await
+        .unwrap();
+
+    assert_eq!(balance, 150_000_000);
+    assert!(is_relay_balance_sufficient(
+        TEST_MVM_CHAIN_ID,
+        DUMMY_SOLVER_ADDR_HUB,
+        balance,
+        100_000_000
+    ));
+}
+
+// 16. Test: Relay Balance Below Minimum Blocks Delivery
+// Verifies that a relay APT balance below the configured minimum is reported as insufficient, and that exactly the minimum is sufficient.
+// Why: Submitting deliveries from an underfunded relay burns retries on out-of-gas failures instead of alerting the operator.
+#[tokio::test]
+async fn test_relay_balance_below_minimum_blocks_delivery() {
+    let mock_server = MockServer::start().await;
+    Mock::given(method("POST"))
+        .and(path("/v1/view"))
+        .respond_with(ResponseTemplate::new(200).set_body_json(serde_json::json!(["99999999"])))
+        .mount(&mock_server)
+        .await;
+    let client = GmpMvmClient::new(&mock_server.uri(), "0x1", TEST_MVM_CHAIN_ID).unwrap();
+
+    let balance = client
+        .get_relay_balance(DUMMY_SOLVER_ADDR_HUB)
+        .await
+        .unwrap();
+
+    assert!(!is_relay_balance_sufficient(
+        TEST_MVM_CHAIN_ID,
+        DUMMY_SOLVER_ADDR_HUB,
+        balance,
+        100_000_000
+    ));
+    assert!(is_relay_balance_sufficient(
+        TEST_MVM_CHAIN_ID,
+        DUMMY_SOLVER_ADDR_HUB,
+        balance,
+        99_999_999
+    ));
+}
@@ -1,9 +1,18 @@
 //! SVM counterpart of *vm_client_tests.rs.
 //!
-//! All tests in this group are N/A for SVM — the functions under test
+//! Most tests in this group are N/A for SVM — the functions under test
 //! (normalize_address, extract_transaction_hash, check_vm_status_success,
 //! parse_view_bytes) are MVM-specific helpers for parsing aptos CLI output.
 
+mod helpers;
+
+use helpers::{DUMMY_SVM_ESCROW_PROGRAM_ID, TEST_SVM_CHAIN_ID};
+use integrated_gmp::integrated_gmp_relay::is_relay_balance_sufficient;
+use integrated_gmp::svm_client::{EscrowIntentPdas, GmpSvmClient};
+use solana_sdk::pubkey::Pubkey;
+use wiremock::matchers::{body_partial_json, method};
+use wiremock::{Mock, MockServer, ResponseTemplate};
+
 // ============================================================================
 // ADDRESS NORMALIZATION TESTS
 // ============================================================================
@@ -52,3 +61,100 @@
 
 // 11. Test: Parse View Bytes Empty Array
 // NOTE: N/A for SVM - parse_view_bytes parses aptos view function responses
+
+// ============================================================================
+// RECEIPT STATUS TESTS
+// ============================================================================
+
+// 12. Test: Receipt Without Status Fails Under Failure Policy
+// NOTE: N/A for SVM - receipt_succeeded classifies EVM transaction receipts
+
+// 13. Test: Receipt Without Status Is Pending Under Pending Policy
+// NOTE: N/A for SVM - receipt_succeeded classifies EVM transaction receipts
+
+// ============================================================================
+// ESCROW PDA DERIVATION TESTS
+// ============================================================================
+
+// 14. Test: Escrow Intent PDAs Match Program Seeds
+// Verifies that EscrowIntentPdas::derive uses the intent_inflow_escrow seeds for every account.
+// Why: The relay passes these accounts to GmpReceive; a seed mismatch makes every SVM delivery fail PDA validation on-chain.
+#[test]
+fn test_escrow_intent_pdas_match_program_seeds() {
+    let program = Pubkey::new_unique();
+    let intent_id = [0x42u8; 32];
+
+    let pdas = EscrowIntentPdas::derive(&program, &intent_id);
+
+    let expected = |seeds: &[&[u8]]| Pubkey::find_program_address(seeds, &program).0;
+    assert_eq!(pdas.requirements, expected(&[b"requirements", &intent_id]));
+    assert_eq!(pdas.escrow, expected(&[b"escrow", &intent_id]));
+    assert_eq!(pdas.vault, expected(&[b"vault", &intent_id]));
+    assert_eq!(pdas.gmp_config, expected(&[b"gmp_config"]));
+    assert_eq!(pdas.state, expected(&[b"state"]));
+}
+
+// ============================================================================
+// RELAY BALANCE TESTS
+// ============================================================================
+
+// 15. Test: Relay Balance Reads SOL Balance
+// Verifies that get_relay_balance reads the relay's lamport balance via getBalance.
+// Why: The relay refuses to deliver when underfunded; a misread balance would either stall deliveries or let them fail on fees.
+#[tokio::test]
+async fn test_get_relay_balance_reads_sol_balance() {
+    let mock_server = MockServer::start().await;
+    Mock::given(method("POST"))
+        .and(body_partial_json(serde_json::json!({ "method": "getBalance" })))
+        .respond_with(ResponseTemplate::new(200).set_body_json(serde_json::json!({
+            "jsonrpc": "2.0", "id": 1, "result": { "context": { "slot": 1 }, "value": 2_000_000_000u64 }
+        })))
+        .mount(&mock_server)
+        .await;
+    let client = GmpSvmClient::new(&mock_server.uri(), DUMMY_SVM_ESCROW_PROGRAM_ID).unwrap();
+    let relay_addr = Pubkey::new_unique().to_string();
+
+    let balance = client.get_relay_balance(&relay_addr).await.unwrap();
+
+    assert_eq!(balance, 2_000_000_000);
+    assert!(is_relay_balance_sufficient(
+        TEST_SVM_CHAIN_ID,
+        &relay_addr,
+        balance,
+        100_000_000
+    ));
+}
+
+// 16. Test: Relay Balance Below Minimum Blocks Delivery
+// Verifies that a relay lamport balance below the configured minimum is reported as insufficient, and that exactly the minimum is sufficient.
+// Why: Submitting deliveries from an underfunded relay burns retries on fee failures instead of alerting the operator.
+#[tokio::test]
+async fn test_relay_balance_below_minimum_blocks_delivery() {
+    let mock_server = MockServer::start().await;
+    Mock::given(method("POST"))
+        .and(body_partial_json(
+            serde_json::json!({ "method": "getBalance" }),
+        ))
+        .respond_with(ResponseTemplate::new(200).set_body_json(serde_json::json!({
+            "jsonrpc": "2.0", "id": 1, "result": { "context": { "slot": 1 }, "value": 5_000u64 }
+        })))
+        .mount(&mock_server)
+        .await;
+    let client = GmpSvmClient::new(&mock_server.uri(), DUMMY_SVM_ESCROW_PROGRAM_ID).unwrap();
+    let relay_addr = Pubkey::new_unique().to_string();
+
+    let balance = client.get_relay_balance(&relay_addr).await.unwrap();
+
+    assert!(!is_relay_balance_sufficient(
+        TEST_SVM_CHAIN_ID,
+        &relay_addr,
+        balance,
+        5_001
+    ));
+    assert!(is_relay_balance_sufficient(
+        TEST_SVM_CHAIN_ID,
+        &relay_addr,
+        balance,
+        5_000
+    ));
+}
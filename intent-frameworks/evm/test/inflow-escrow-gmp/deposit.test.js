@@ -186,4 +186,14 @@ describe("IntentInflowEscrow - Create Escrow (Deposit)", function () {
     const requirements = await escrow.getRequirements(intentId);
     expect(requirements.expiry).to.equal(expiry);
   });
+
+  // #5: test_create_escrow_with_sponsor_payer — N/A for EVM (gas sponsorship is handled by the transaction sender, not the contract)
+  // #6: test_create_escrow_sponsor_requires_requester_signature — N/A for EVM
+  // #7: test_mint_transfer_fee_parses_token_2022_extension — N/A for EVM (SVM Token-2022 mint extension parsing)
+  // #8: test_mint_transfer_fee_none_without_extension — N/A for EVM (SVM Token-2022 mint extension parsing)
+  // #9: test_unsupported_mint_extension_detected — N/A for EVM (SVM Token-2022 mint extension parsing)
+  // #10: test_create_and_release_escrow_with_token_2022_mint — N/A for EVM (SVM Token-2022 program)
+  // #11: test_create_native_sol_escrow — N/A for EVM (SVM native SOL vault)
+  // #12: test_release_native_sol_escrow_with_fulfillment_proof — N/A for EVM (SVM native SOL vault)
+  // #13: test_cancel_native_sol_escrow_after_expiry — N/A for EVM (SVM native SOL vault)
 });
@@ -276,4 +276,9 @@ describe("IntentInflowEscrow - Error Conditions", function () {
       escrow.connect(requester).createEscrowWithValidation(intentId, token.target, largeAmount)
     ).to.be.reverted;
   });
+
+  // #17: test_claim_rejects_swapped_vault_and_solver_token — N/A for EVM (no positional accounts; vault is contract storage)
+  // #18: test_claim_rejects_wrong_token_program — N/A for EVM (no positional accounts; token is called by address)
+  // #19: test_cancel_rejects_substituted_escrow_account — N/A for EVM (no positional accounts; escrow is contract storage)
+  // #20: test_fulfillment_proof_rejects_swapped_vault_and_solver_token — N/A for EVM (no positional accounts; vault is contract storage)
 });
@@ -631,6 +631,11 @@ describe("IntentInflowEscrow", function () {
   // #25: test_generic_gmp_receive_routes_requirements — N/A for EVM (SVM-specific pattern; EVM uses typed handler functions)
   // #26: test_generic_gmp_receive_routes_fulfillment_proof — N/A for EVM (SVM-specific pattern; EVM uses typed handler functions)
   // #27: test_generic_gmp_receive_rejects_unknown_message_type — N/A for EVM (SVM-specific pattern; EVM uses typed handler functions)
+  // #28: test_create_escrow_accepts_matching_solver — N/A for EVM (createEscrow takes the solver from stored requirements)
+  // #29: test_create_escrow_rejects_solver_mismatch — N/A for EVM (createEscrow takes the solver from stored requirements)
+  // #30: test_receive_requirements_batch_stores_all — N/A for EVM (SVM-specific batch instruction to save transactions)
+  // #31: test_receive_requirements_batch_skips_duplicate — N/A for EVM (SVM-specific batch instruction to save transactions)
+  // #32: test_create_escrow_accepts_overfunding — N/A for EVM (createEscrow always requires the exact amount)
 
   // ============================================================================
   // Helper Functions
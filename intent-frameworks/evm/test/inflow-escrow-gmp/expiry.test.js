@@ -157,4 +157,8 @@ describe("IntentInflowEscrow - Expiry Handling", function () {
     expect(await token.balanceOf(solver.address)).to.equal(DEFAULT_AMOUNT);
     expect(await escrow.isFulfilled(intentId)).to.equal(true);
   });
+
+  // #4-#7: clock skew tolerance tests — N/A for EVM (SVM-specific EscrowState.clock_skew_tolerance)
+  // #8: test_create_escrow_rejects_non_future_expiry — N/A for EVM (SVM-specific EscrowError::InvalidExpiry invariant)
+  // #9-#11: expiry extension tests — N/A for EVM (SVM-specific requester ExtendExpiry instruction)
 });
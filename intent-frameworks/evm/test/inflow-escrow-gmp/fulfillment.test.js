@@ -149,4 +149,22 @@ describe("IntentInflowEscrow - Fulfillment", function () {
       deliverFulfillmentProof(gmpEndpoint, nonExistentIntentId, solverAddr32, DEFAULT_AMOUNT, timestamp)
     ).to.be.revertedWithCustomError(escrow, "E_ESCROW_NOT_FOUND");
   });
+
+  // ============================================================================
+  // ESCROW STATUS TRANSITIONS
+  // ============================================================================
+
+  // #6: test_escrow_status_transitions — N/A for EVM (escrow state is tracked with flags, not a status enum)
+
+  // ============================================================================
+  // CLAIM-TIME TOKEN CROSS-CHECK
+  // ============================================================================
+
+  // #7: test_claim_rejects_token_mismatch_with_requirements — N/A for EVM (escrow token is copied from requirements at creation and released only via the fulfillment proof)
+
+  // ============================================================================
+  // CLAIM BEFORE FULFILLMENT
+  // ============================================================================
+
+  // #8: test_revert_claim_before_fulfillment_proof — N/A for EVM (no separate claim entry point; the fulfillment proof is the only release path)
 });
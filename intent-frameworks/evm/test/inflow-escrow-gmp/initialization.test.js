@@ -141,4 +141,6 @@ describe("IntentInflowEscrow - Initialization", function () {
       )
     ).to.be.revertedWithCustomError(escrow, "E_ZERO_AMOUNT");
   });
+
+  // #6: test_pda_helpers_match_on_chain_accounts — N/A for EVM (Solana program-derived addresses)
 });
@@ -535,4 +535,11 @@ describe("IntentGmp", function () {
       expect(await outflowHandler.requirementsReceived()).to.equal(true);
     });
   });
+
+  // #51: test_deliver_message_relay_quorum_met — N/A for EVM (SVM-specific relay co-signer quorum)
+  // #52: test_deliver_message_relay_quorum_not_met — N/A for EVM (SVM-specific relay co-signer quorum)
+  // #53: test_deliver_message_rejects_duplicate_relay_signer — N/A for EVM (SVM-specific relay co-signer quorum)
+  // #54: test_send_rejects_reinitialized_nonce_account — N/A for EVM (SVM-specific nonce_out PDA account)
+  // #55: test_send_nonce_increases_monotonically — N/A for EVM (SVM-specific nonce_out PDA account)
+  // #56: test_deliver_message_forwards_accounts_in_order — N/A for EVM (SVM-specific account list; destinations read their own state)
 });
@@ -492,4 +492,10 @@ module mvmt_intent::intent_gmp_tests {
     // #48: test_set_escrow_handler — N/A for MVM (EVM-specific handler configuration)
     // #49: test_set_outflow_handler — N/A for MVM (EVM-specific handler configuration)
     // #50: test_route_to_both_handlers — N/A for MVM (EVM-specific dual handler routing)
+    // #51: test_deliver_message_relay_quorum_met — N/A for MVM (SVM-specific relay co-signer quorum)
+    // #52: test_deliver_message_relay_quorum_not_met — N/A for MVM (SVM-specific relay co-signer quorum)
+    // #53: test_deliver_message_rejects_duplicate_relay_signer — N/A for MVM (SVM-specific relay co-signer quorum)
+    // #54: test_send_rejects_reinitialized_nonce_account — N/A for MVM (SVM-specific nonce_out PDA account)
+    // #55: test_send_nonce_increases_monotonically — N/A for MVM (SVM-specific nonce_out PDA account)
+    // #56: test_deliver_message_forwards_accounts_in_order — N/A for MVM (SVM-specific account list; destinations read their own state)
 }
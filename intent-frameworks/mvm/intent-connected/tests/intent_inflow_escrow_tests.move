@@ -1186,5 +1186,18 @@ module mvmt_intent::intent_inflow_escrow_tests {
     //     Why: Same as tests 25-26. MVM doesn't have a generic GmpReceive instruction
     //     that needs to reject unknown message types - each message type is handled
     //     by its own entry function in the destination module.
+    //
+    // #28: test_create_escrow_accepts_matching_solver — N/A for MVM
+    // #29: test_create_escrow_rejects_solver_mismatch — N/A for MVM
+    //     Why: MVM escrow creation takes the reserved solver from stored requirements;
+    //     there is no caller-supplied solver to validate.
+    //
+    // #30: test_receive_requirements_batch_stores_all — N/A for MVM
+    // #31: test_receive_requirements_batch_skips_duplicate — N/A for MVM
+    //     Why: Batched requirements delivery is an SVM-specific instruction that
+    //     creates several requirement PDAs in one transaction.
+    //
+    // #32: test_create_escrow_accepts_overfunding — N/A for MVM
+    //     Why: MVM escrow creation always requires amount == amount_required.
 
 }
@@ -62,6 +62,9 @@ impl std::error::Error for GmpError {}
 
 pub const INTENT_REQUIREMENTS_SIZE: usize = 145;
 
+/// IntentRequirements followed by an optional 32-byte payout address.
+pub const INTENT_REQUIREMENTS_WITH_PAYOUT_SIZE: usize = INTENT_REQUIREMENTS_SIZE + 32;
+
 /// Hub → Connected chain. Sent on intent creation to tell the connected chain
 /// what requirements must be met.
 #[derive(Debug, Clone, PartialEq, Eq)]
@@ -127,6 +130,37 @@ impl IntentRequirements {
             expiry,
         })
     }
+
+    /// Encodes the message followed by a payout address at bytes 145..177.
+    ///
+    /// The payout address is the account that must receive the outflow
+    /// fulfillment on the connected chain when it differs from the requester.
+    pub fn encode_with_payout(
+        &self,
+        payout_addr: &[u8; 32],
+    ) -> [u8; INTENT_REQUIREMENTS_WITH_PAYOUT_SIZE] {
+        let mut buf = [0u8; INTENT_REQUIREMENTS_WITH_PAYOUT_SIZE];
+        buf[..INTENT_REQUIREMENTS_SIZE].copy_from_slice(&self.encode());
+        buf[INTENT_REQUIREMENTS_SIZE..].copy_from_slice(payout_addr);
+        buf
+    }
+
+    /// Decodes a message with or without a trailing payout address.
+    ///
+    /// Returns `None` for the payout address when the payload is the plain
+    /// 145-byte message or the payout address is all zeros; the requester is
+    /// then the expected recipient.
+    pub fn decode_with_payout(data: &[u8]) -> Result<(Self, Option<[u8; 32]>), GmpError> {
+        if data.len() != INTENT_REQUIREMENTS_WITH_PAYOUT_SIZE {
+            return Ok((Self::decode(data)?, None));
+        }
+
+        let requirements = Self::decode(&data[..INTENT_REQUIREMENTS_SIZE])?;
+        let mut payout_addr = [0u8; 32];
+        payout_addr.copy_from_slice(&data[INTENT_REQUIREMENTS_SIZE..]);
+        let payout_addr = (payout_addr != [0u8; 32]).then_some(payout_addr);
+        Ok((requirements, payout_addr))
+    }
 }
 
 // ---------------------------------------------------------------------------
@@ -952,3 +952,63 @@ fn test_cross_chain_encoding_intent_requirements_max() {
     );
     println!("IntentRequirements max values encoding matches expected: {} bytes", encoded.len());
 }
+
+// #41: test_address_to_bytes32 — N/A for SVM (EVM address conversion helper)
+// #42: test_bytes32_to_address — N/A for SVM (EVM address conversion helper)
+// #43: test_address_conversion_roundtrip — N/A for SVM (EVM address conversion helper)
+// #44: test_message_type_constants — N/A for SVM (EVM library constants)
+// #45: test_message_size_constants — N/A for SVM (EVM library constants)
+
+// ============================================================================
+// PAYOUT ADDRESS EXTENSION TESTS
+// ============================================================================
+
+// 46. Test: IntentRequirements Payout Address Roundtrip
+// Verifies that encode_with_payout appends the payout address after the 145-byte message and decode_with_payout returns both.
+// Why: The outflow validator uses the payout address as the expected recipient; it must survive encoding byte-for-byte.
+#[test]
+fn test_intent_requirements_payout_roundtrip() {
+    let msg = IntentRequirements {
+        intent_id: test_intent_id(),
+        requester_addr: test_addr_1(),
+        amount_required: DUMMY_AMOUNT,
+        token_addr: test_addr_2(),
+        solver_addr: test_addr_3(),
+        expiry: DUMMY_EXPIRY,
+    };
+    let payout_addr = [0x55; 32];
+
+    let encoded = msg.encode_with_payout(&payout_addr);
+    assert_eq!(encoded.len(), INTENT_REQUIREMENTS_WITH_PAYOUT_SIZE);
+    assert_eq!(&encoded[..INTENT_REQUIREMENTS_SIZE], &msg.encode()[..]);
+
+    let (decoded, decoded_payout) = IntentRequirements::decode_with_payout(&encoded).unwrap();
+    assert_eq!(decoded, msg);
+    assert_eq!(decoded_payout, Some(payout_addr));
+}
+
+// 47. Test: IntentRequirements Payout Address Is Optional
+// Verifies that decode_with_payout returns no payout for the plain 145-byte message and for an all-zero payout, and still rejects other lengths.
+// Why: Hubs that do not send a payout address must keep working, with the requester as the recipient.
+#[test]
+fn test_intent_requirements_payout_optional() {
+    let msg = IntentRequirements {
+        intent_id: test_intent_id(),
+        requester_addr: test_addr_1(),
+        amount_required: DUMMY_AMOUNT,
+        token_addr: test_addr_2(),
+        solver_addr: test_addr_3(),
+        expiry: DUMMY_EXPIRY,
+    };
+
+    let (decoded, payout) = IntentRequirements::decode_with_payout(&msg.encode()).unwrap();
+    assert_eq!(decoded, msg);
+    assert_eq!(payout, None);
+
+    let (_, payout) =
+        IntentRequirements::decode_with_payout(&msg.encode_with_payout(&[0u8; 32])).unwrap();
+    assert_eq!(payout, None);
+
+    let too_long = [msg.encode_with_payout(&[0x55; 32]).to_vec(), vec![0]].concat();
+    assert!(IntentRequirements::decode_with_payout(&too_long).is_err());
+}
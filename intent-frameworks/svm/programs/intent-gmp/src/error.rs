@@ -49,6 +49,15 @@ pub enum GmpError {
 
     #[error("Invalid account count for operation")]
     InvalidAccountCount,
+
+    #[error("Invalid relay threshold: must be between 1 and the supported maximum")]
+    InvalidRelayThreshold,
+
+    #[error("Not enough relay signatures to meet the configured threshold")]
+    InsufficientRelaySignatures,
+
+    #[error("Duplicate relay signature")]
+    DuplicateRelaySigner,
 }
 
 impl From<GmpError> for ProgramError {
@@ -79,7 +79,9 @@ pub enum NativeGmpInstruction {
     ///
     /// Emits a `MessageSent` event that the GMP relay monitors.
     /// The relay picks up the event and calls `DeliverMessage` on the
-    /// destination chain.
+    /// destination chain. The relay reads the message from its `MessageAccount` PDA;
+    /// the event is also logged as a `MessageSentV1:` base64 Borsh `MessageSentEvent`
+    /// followed by a human-readable `MessageSent:` summary line.
     ///
     /// Accounts expected:
     /// 0. `[]` Config account (PDA: ["config"])
@@ -126,7 +128,20 @@ pub enum NativeGmpInstruction {
     /// 7. `[]` Routing config account (PDA: ["routing"]) - pass any account if routing not configured
     /// 8. `[]` Destination program 1 (outflow_validator for routing, or single destination)
     /// 9. `[]` Destination program 2 (intent_escrow for routing, or any account if not routing)
-    /// 10+. Additional accounts required by destination program(s)
+    /// 10..10+2*(M-1). Co-signing relays when the config's relay_threshold M > 1, as
+    ///    (`[]` Relay account PDA, `[signer]` Relay) pairs; each relay must be distinct
+    /// Then: additional accounts required by destination program(s)
+    ///
+    /// The additional accounts are forwarded to the destination `GmpReceive` CPI in the
+    /// order given, with their signer/writable flags. The relay derives the PDAs from the
+    /// payload's intent_id. Expected layout per message type:
+    /// - IntentRequirements (0x01), routed: outflow_validator's accounts, then intent_escrow's
+    ///   - 0-4: requirements(w), config, authority(s), payer(s, w), system program
+    ///   - 5-9: requirements(w), gmp_config, authority(s), payer(s, w), system program
+    /// - FulfillmentProof (0x03), routed to intent_escrow:
+    ///   - 0: requirements(w), 1: escrow(w), 2: vault(w), 3: solver token account(w),
+    ///     4: gmp_config, 5: gmp_caller(s), 6: token program, 7: escrow program state
+    /// - Unrouted: all additional accounts go to destination program 1 unchanged
     DeliverMessage {
         /// Source chain endpoint ID
         src_chain_id: u32,
@@ -135,4 +150,19 @@ pub enum NativeGmpInstruction {
         /// Message payload (encoded GMP message)
         payload: Vec<u8>,
     },
+
+    /// Set the number of distinct authorized relays required to deliver a message.
+    ///
+    /// Capped at `ConfigAccount::MAX_RELAY_THRESHOLD` while the integrated-gmp relay
+    /// cannot collect co-signatures. The admin is responsible for keeping the
+    /// threshold at or below the number of authorized relays; otherwise no message
+    /// can be delivered.
+    ///
+    /// Accounts expected:
+    /// 0. `[writable]` Config account (PDA: ["config"])
+    /// 1. `[signer]` Admin
+    SetRelayThreshold {
+        /// Required relay signatures per delivery (1..=`ConfigAccount::MAX_RELAY_THRESHOLD`)
+        threshold: u8,
+    },
 }
@@ -17,11 +17,13 @@
 //! - `SetRemoteGmpEndpointAddr`: Configure remote GMP endpoint addresses per chain
 //! - `Send`: Emit a MessageSent event for the relay to pick up
 //! - `DeliverMessage`: Called by relay to deliver messages to destination
+//! - `SetRelayThreshold`: Require M distinct relay signatures per delivery
 //!
 //! ## Security Model
 //!
 //! - Admin controls relay authorization and remote GMP endpoint configuration
-//! - Only authorized relays can deliver messages
+//! - Only authorized relays can deliver messages, co-signed by at least
+//!   `relay_threshold` distinct relays (default 1)
 //! - Messages from unknown remote GMP endpoints are rejected
 //! - (intent_id, msg_type) deduplication prevents replay attacks
 
@@ -39,6 +41,6 @@ pub use solana_program;
 pub use error::GmpError;
 pub use instruction::NativeGmpInstruction;
 pub use state::{
-    ConfigAccount, DeliveredMessage, MessageAccount, OutboundNonceAccount, RelayAccount,
-    RemoteGmpEndpoint,
+    ConfigAccount, DeliveredMessage, MessageAccount, MessageSentEvent, OutboundNonceAccount,
+    RelayAccount, RemoteGmpEndpoint,
 };
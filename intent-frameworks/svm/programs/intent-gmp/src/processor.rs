@@ -17,8 +17,8 @@ use solana_program::{
 use crate::error::GmpError;
 use crate::instruction::NativeGmpInstruction;
 use crate::state::{
-    seeds, ConfigAccount, DeliveredMessage, MessageAccount, OutboundNonceAccount, RelayAccount,
-    RoutingConfig, RemoteGmpEndpoint,
+    seeds, ConfigAccount, DeliveredMessage, MessageAccount, MessageSentEvent,
+    OutboundNonceAccount, RelayAccount, RoutingConfig, RemoteGmpEndpoint,
 };
 
 /// Message type constants (matches MVM's gmp_common)
@@ -79,6 +79,10 @@ pub fn process_instruction(
             msg!("Instruction: DeliverMessage");
             process_deliver_message(program_id, accounts, src_chain_id, remote_gmp_endpoint_addr, payload)
         }
+        NativeGmpInstruction::SetRelayThreshold { threshold } => {
+            msg!("Instruction: SetRelayThreshold");
+            process_set_relay_threshold(program_id, accounts, threshold)
+        }
     }
 }
 
@@ -156,8 +160,7 @@ fn process_add_relay(
     }
 
     // Load and verify config
-    let config = ConfigAccount::try_from_slice(&config_account.data.borrow())
-        .map_err(|_| GmpError::AccountNotInitialized)?;
+    let config = ConfigAccount::unpack(&config_account.data.borrow())?;
 
     if config.admin != *admin.key {
         return Err(GmpError::UnauthorizedAdmin.into());
@@ -220,8 +223,7 @@ fn process_remove_relay(
     }
 
     // Load and verify config
-    let config = ConfigAccount::try_from_slice(&config_account.data.borrow())
-        .map_err(|_| GmpError::AccountNotInitialized)?;
+    let config = ConfigAccount::unpack(&config_account.data.borrow())?;
 
     if config.admin != *admin.key {
         return Err(GmpError::UnauthorizedAdmin.into());
@@ -245,6 +247,45 @@ fn process_remove_relay(
     Ok(())
 }
 
+/// Set the number of distinct relay signatures required per delivery.
+fn process_set_relay_threshold(
+    program_id: &Pubkey,
+    accounts: &[AccountInfo],
+    threshold: u8,
+) -> ProgramResult {
+    let account_info_iter = &mut accounts.iter();
+    let config_account = next_account_info(account_info_iter)?;
+    let admin = next_account_info(account_info_iter)?;
+
+    // Verify admin is signer
+    if !admin.is_signer {
+        return Err(ProgramError::MissingRequiredSignature);
+    }
+
+    if threshold == 0 || threshold > ConfigAccount::MAX_RELAY_THRESHOLD {
+        return Err(GmpError::InvalidRelayThreshold.into());
+    }
+
+    // Verify config account
+    let (config_pda, _) = Pubkey::find_program_address(&[seeds::CONFIG_SEED], program_id);
+    if config_account.key != &config_pda {
+        return Err(GmpError::InvalidPda.into());
+    }
+
+    // Load and verify config
+    let mut config = ConfigAccount::unpack(&config_account.data.borrow())?;
+
+    if config.admin != *admin.key {
+        return Err(GmpError::UnauthorizedAdmin.into());
+    }
+
+    config.relay_threshold = threshold;
+    config.pack(&mut config_account.data.borrow_mut())?;
+
+    msg!("Relay threshold set: {}", threshold);
+    Ok(())
+}
+
 /// Set a remote GMP endpoint address for a source chain.
 fn process_set_remote_gmp_endpoint_addr(
     program_id: &Pubkey,
@@ -265,8 +306,7 @@ fn process_set_remote_gmp_endpoint_addr(
     }
 
     // Load and verify config
-    let config = ConfigAccount::try_from_slice(&config_account.data.borrow())
-        .map_err(|_| GmpError::AccountNotInitialized)?;
+    let config = ConfigAccount::unpack(&config_account.data.borrow())?;
 
     if config.admin != *admin.key {
         return Err(GmpError::UnauthorizedAdmin.into());
@@ -350,8 +390,7 @@ fn process_set_routing(
     }
 
     // Load and verify config
-    let config = ConfigAccount::try_from_slice(&config_account.data.borrow())
-        .map_err(|_| GmpError::AccountNotInitialized)?;
+    let config = ConfigAccount::unpack(&config_account.data.borrow())?;
 
     if config.admin != *admin.key {
         return Err(GmpError::UnauthorizedAdmin.into());
@@ -429,8 +468,7 @@ fn process_send(
     }
 
     // Load config to get this chain's ID
-    let config = ConfigAccount::try_from_slice(&config_account.data.borrow())
-        .map_err(|_| GmpError::AccountNotInitialized)?;
+    let config = ConfigAccount::unpack(&config_account.data.borrow())?;
 
     // Derive global nonce PDA (single sequence across all destinations)
     let (nonce_pda, nonce_bump) =
@@ -440,7 +478,10 @@ fn process_send(
         return Err(GmpError::InvalidPda.into());
     }
 
-    // Create nonce account if needed, then increment
+    // Create nonce account if needed, then increment. The counter is created
+    // exactly once: an existing account is never re-created or reset, since
+    // reusing a nonce would collide with an earlier MessageAccount and break
+    // relay dedup.
     let nonce = if nonce_account.data_is_empty() {
         let rent = Rent::get()?;
         let space = OutboundNonceAccount::SIZE;
@@ -463,8 +504,19 @@ fn process_send(
         nonce_data.serialize(&mut &mut nonce_account.data.borrow_mut()[..])?;
         nonce
     } else {
+        if nonce_account.owner != program_id {
+            return Err(GmpError::InvalidAccountOwner.into());
+        }
         let mut nonce_data = OutboundNonceAccount::try_from_slice(&nonce_account.data.borrow())
             .map_err(|_| GmpError::InvalidDiscriminator)?;
+        // A zeroed or foreign layout would restart the sequence at 0
+        if nonce_data.discriminator != OutboundNonceAccount::DISCRIMINATOR {
+            return Err(GmpError::InvalidDiscriminator.into());
+        }
+        // increment() saturates; at u64::MAX it would hand out the same nonce again
+        if nonce_data.nonce == u64::MAX {
+            return Err(GmpError::ArithmeticOverflow.into());
+        }
         let nonce = nonce_data.increment();
         nonce_data.serialize(&mut &mut nonce_account.data.borrow_mut()[..])?;
         nonce
@@ -518,17 +570,27 @@ fn process_send(
     );
     message_data.serialize(&mut &mut message_account.data.borrow_mut()[..])?;
 
-    // Also emit log for backward compatibility / debugging
+    // Structured event for log-based indexers (the relay reads the message PDA)
+    let event = MessageSentEvent {
+        src_chain_id: config.chain_id,
+        dst_chain_id,
+        nonce,
+        dst_addr,
+        remote_gmp_endpoint_addr,
+        payload: payload.clone(),
+    };
+    msg!("{}", event.to_log_line()?);
+
+    // Human-readable summary for debugging; the payload is only in the structured event
     let endpoint_addr_pubkey = Pubkey::new_from_array(remote_gmp_endpoint_addr);
     msg!(
-        "MessageSent: src_chain_id={}, dst_chain_id={}, remote_gmp_endpoint_addr={}, dst_addr={}, nonce={}, payload_len={}, payload_hex={}",
+        "MessageSent: src_chain_id={}, dst_chain_id={}, remote_gmp_endpoint_addr={}, dst_addr={}, nonce={}, payload_len={}",
         config.chain_id,
         dst_chain_id,
         endpoint_addr_pubkey,
         hex_encode(&dst_addr),
         nonce,
-        payload.len(),
-        hex_encode(&payload)
+        payload.len()
     );
 
     Ok(())
@@ -594,22 +656,29 @@ fn process_deliver_message(
     }
 
     // Load config
-    let _config = ConfigAccount::try_from_slice(&config_account.data.borrow())
-        .map_err(|_| GmpError::AccountNotInitialized)?;
+    let config = ConfigAccount::unpack(&config_account.data.borrow())?;
 
     // Verify relay is authorized
-    let (relay_pda, _) =
-        Pubkey::find_program_address(&[seeds::RELAY_SEED, relay_signer.key.as_ref()], program_id);
-
-    if relay_account.key != &relay_pda {
-        return Err(GmpError::InvalidPda.into());
-    }
-
-    let relay_data = RelayAccount::try_from_slice(&relay_account.data.borrow())
-        .map_err(|_| GmpError::AccountNotInitialized)?;
+    verify_authorized_relay(program_id, relay_account, relay_signer)?;
+
+    // Verify co-signing relays until the quorum is met. Each co-signer is passed as a
+    // (relay PDA, relay signer) pair directly after the fixed accounts.
+    let mut relay_signers = vec![*relay_signer.key];
+    for _ in 1..config.relay_threshold {
+        let cosigner_account = next_account_info(account_info_iter)
+            .map_err(|_| GmpError::InsufficientRelaySignatures)?;
+        let cosigner = next_account_info(account_info_iter)
+            .map_err(|_| GmpError::InsufficientRelaySignatures)?;
+
+        if !cosigner.is_signer {
+            return Err(GmpError::InsufficientRelaySignatures.into());
+        }
+        if relay_signers.contains(cosigner.key) {
+            return Err(GmpError::DuplicateRelaySigner.into());
+        }
 
-    if !relay_data.is_authorized {
-        return Err(GmpError::UnauthorizedRelay.into());
+        verify_authorized_relay(program_id, cosigner_account, cosigner)?;
+        relay_signers.push(*cosigner.key);
     }
 
     // Verify remote GMP endpoint
@@ -777,9 +846,9 @@ fn process_deliver_message(
             );
 
             // Remaining accounts are for intent_escrow's GmpReceiveFulfillmentProof:
-            // requirements(w), escrow(w), vault(w), solver_token(w), gmp_config(r), gmp_caller(s), token_program
-            if remaining_accounts.len() < 7 {
-                msg!("Insufficient remaining accounts for FulfillmentProof routing: need 7, got {}", remaining_accounts.len());
+            // requirements(w), escrow(w), vault(w), solver_token(w), gmp_config(r), gmp_caller(s), token_program, state(r)
+            if remaining_accounts.len() < 8 {
+                msg!("Insufficient remaining accounts for FulfillmentProof routing: need 8, got {}", remaining_accounts.len());
                 return Err(GmpError::InvalidAccountCount.into());
             }
 
@@ -810,6 +879,29 @@ fn process_deliver_message(
     Ok(())
 }
 
+/// Verify that a signer is an authorized relay via its relay account PDA.
+fn verify_authorized_relay(
+    program_id: &Pubkey,
+    relay_account: &AccountInfo,
+    relay_signer: &AccountInfo,
+) -> ProgramResult {
+    let (relay_pda, _) =
+        Pubkey::find_program_address(&[seeds::RELAY_SEED, relay_signer.key.as_ref()], program_id);
+
+    if relay_account.key != &relay_pda {
+        return Err(GmpError::InvalidPda.into());
+    }
+
+    let relay_data = RelayAccount::try_from_slice(&relay_account.data.borrow())
+        .map_err(|_| GmpError::AccountNotInitialized)?;
+
+    if !relay_data.is_authorized {
+        return Err(GmpError::UnauthorizedRelay.into());
+    }
+
+    Ok(())
+}
+
 /// Helper to invoke GmpReceive on a destination program.
 fn invoke_gmp_receive(
     program_id: &Pubkey,
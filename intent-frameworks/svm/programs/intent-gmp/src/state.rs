@@ -7,9 +7,12 @@
 //! - ConfigAccount = 1, RelayAccount = 2, RemoteGmpEndpoint = 3, etc.
 //! - On read, verify discriminator matches expected type before trusting data.
 
+use base64::{engine::general_purpose::STANDARD, Engine};
 use borsh::{BorshDeserialize, BorshSerialize};
 use solana_program::pubkey::Pubkey;
 
+use crate::error::GmpError;
+
 /// Program configuration account.
 /// PDA seeds: ["config"]
 #[derive(BorshSerialize, BorshDeserialize, Debug, Clone, PartialEq, Eq)]
@@ -20,22 +23,62 @@ pub struct ConfigAccount {
     pub admin: Pubkey,
     /// This chain's endpoint ID (e.g., Solana devnet = 30168)
     pub chain_id: u32,
+    /// Number of distinct authorized relays that must co-sign a DeliverMessage (M of N)
+    pub relay_threshold: u8,
     /// Bump seed for PDA derivation
     pub bump: u8,
 }
 
 impl ConfigAccount {
     pub const DISCRIMINATOR: u8 = 1;
-    pub const SIZE: usize = 1 + 32 + 4 + 1; // 38 bytes
+    pub const SIZE: usize = 1 + 32 + 4 + 1 + 1; // 39 bytes
+    /// Size of config accounts initialized before `relay_threshold` existed.
+    pub const LEGACY_SIZE: usize = 1 + 32 + 4 + 1; // discriminator + admin + chain_id + bump
+    /// Highest accepted `relay_threshold`. The integrated-gmp relay signs deliveries
+    /// alone, so a higher threshold would block every delivery until it co-signs.
+    pub const MAX_RELAY_THRESHOLD: u8 = 1;
 
+    /// Creates a config requiring a single relay signature (threshold 1).
     pub fn new(admin: Pubkey, chain_id: u32, bump: u8) -> Self {
         Self {
             discriminator: Self::DISCRIMINATOR,
             admin,
             chain_id,
+            relay_threshold: 1,
             bump,
         }
     }
+
+    /// Deserialize config account data, reading a legacy 38-byte account as a
+    /// relay threshold of 1.
+    pub fn unpack(data: &[u8]) -> Result<Self, GmpError> {
+        if data.len() == Self::LEGACY_SIZE {
+            // The bump follows chain_id directly in the legacy layout
+            let bump_offset = Self::LEGACY_SIZE - 1;
+            let mut upgraded = data[..bump_offset].to_vec();
+            upgraded.push(1);
+            upgraded.push(data[bump_offset]);
+            return Self::try_from_slice(&upgraded).map_err(|_| GmpError::AccountNotInitialized);
+        }
+        Self::try_from_slice(data).map_err(|_| GmpError::AccountNotInitialized)
+    }
+
+    /// Serialize into config account data of either layout.
+    ///
+    /// A legacy account has no threshold slot, so it can only store a threshold of 1.
+    pub fn pack(&self, data: &mut [u8]) -> Result<(), GmpError> {
+        let bytes = borsh::to_vec(self).map_err(|_| GmpError::InvalidInstructionData)?;
+        match data.len() {
+            Self::SIZE => data.copy_from_slice(&bytes),
+            Self::LEGACY_SIZE if self.relay_threshold == 1 => {
+                let bump_offset = Self::LEGACY_SIZE - 1;
+                data[..bump_offset].copy_from_slice(&bytes[..bump_offset]);
+                data[bump_offset] = self.bump;
+            }
+            _ => return Err(GmpError::AccountNotInitialized),
+        }
+        Ok(())
+    }
 }
 
 /// Authorized relay account.
@@ -251,6 +294,45 @@ impl MessageAccount {
     }
 }
 
+/// Structured `MessageSent` log emitted by `Send`.
+///
+/// Logged as `MessageSentV1:<base64(borsh(event))>` for log-based indexers. The
+/// relay reads the `MessageAccount` PDA instead, which remains the record of the
+/// message. Any change to the layout must ship under a new version prefix.
+#[derive(BorshSerialize, BorshDeserialize, Debug, Clone, PartialEq, Eq)]
+pub struct MessageSentEvent {
+    /// Source chain endpoint ID (this chain)
+    pub src_chain_id: u32,
+    /// Destination chain endpoint ID
+    pub dst_chain_id: u32,
+    /// Sequence number assigned by the outbound nonce counter
+    pub nonce: u64,
+    /// Destination address (32 bytes, zero-padded)
+    pub dst_addr: [u8; 32],
+    /// Remote GMP endpoint address (32 bytes — the GMP endpoint on the sending chain)
+    pub remote_gmp_endpoint_addr: [u8; 32],
+    /// GMP message payload
+    pub payload: Vec<u8>,
+}
+
+impl MessageSentEvent {
+    pub const LOG_PREFIX: &'static str = "MessageSentV1:";
+
+    /// Encode the event as a log line: prefix followed by base64 Borsh bytes.
+    pub fn to_log_line(&self) -> std::io::Result<String> {
+        let bytes = borsh::to_vec(self)?;
+        Ok(format!("{}{}", Self::LOG_PREFIX, STANDARD.encode(bytes)))
+    }
+
+    /// Parse an event from a log line, with or without the runtime's `Program log: ` prefix.
+    /// Returns `None` for any other line or a blob that does not decode.
+    pub fn parse_log_line(line: &str) -> Option<Self> {
+        let (_, encoded) = line.split_once(Self::LOG_PREFIX)?;
+        let bytes = STANDARD.decode(encoded.trim()).ok()?;
+        Self::try_from_slice(&bytes).ok()
+    }
+}
+
 /// Seeds for PDA derivation
 pub mod seeds {
     pub const CONFIG_SEED: &[u8] = b"config";
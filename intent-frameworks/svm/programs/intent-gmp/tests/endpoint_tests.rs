@@ -7,7 +7,7 @@ use borsh::BorshDeserialize;
 use intent_gmp::{
     instruction::NativeGmpInstruction,
     state::{
-        ConfigAccount, DeliveredMessage, OutboundNonceAccount, RelayAccount,
+        ConfigAccount, DeliveredMessage, MessageSentEvent, OutboundNonceAccount, RelayAccount,
         RoutingConfig, RemoteGmpEndpoint,
     },
     GmpError,
@@ -256,7 +256,9 @@ fn test_config_account_serialization() {
     assert_eq!(decoded.discriminator, ConfigAccount::DISCRIMINATOR);
     assert_eq!(decoded.admin, original_admin);
     assert_eq!(decoded.chain_id, original_chain_id);
+    assert_eq!(decoded.relay_threshold, 1);
     assert_eq!(decoded.bump, original_bump);
+    assert_eq!(encoded.len(), ConfigAccount::SIZE);
 }
 
 // 9. Test: RelayAccount serialization roundtrip
@@ -374,6 +376,9 @@ fn test_error_codes_unique() {
         GmpError::UnknownRemoteGmpEndpoint,
         GmpError::AlreadyDelivered,
         GmpError::InvalidDiscriminator,
+        GmpError::InvalidRelayThreshold,
+        GmpError::InsufficientRelaySignatures,
+        GmpError::DuplicateRelaySigner,
     ];
 
     let codes: Vec<u32> = errors.iter().map(|e| e.clone() as u32).collect();
@@ -386,6 +391,72 @@ fn test_error_codes_unique() {
     );
 }
 
+// ============================================================================
+// EVENT LOG TESTS
+// ============================================================================
+
+// 57. Test: MessageSentEvent log line roundtrip
+// Verifies that a MessageSentEvent encoded as a MessageSentV1 log line parses back unchanged, including behind the runtime's "Program log: " prefix, and that other lines are ignored.
+// Why: Relays decode this line instead of splitting the human-readable log; any mismatch would deliver a corrupted message.
+#[test]
+fn test_message_sent_event_log_roundtrip() {
+    let event = MessageSentEvent {
+        src_chain_id: DUMMY_CHAIN_ID_SVM,
+        dst_chain_id: DUMMY_CHAIN_ID_MVM,
+        nonce: 42,
+        dst_addr: dummy_dst_addr(),
+        remote_gmp_endpoint_addr: dummy_remote_gmp_endpoint_addr(),
+        payload: dummy_payload(),
+    };
+
+    let line = event.to_log_line().unwrap();
+    assert!(line.starts_with(MessageSentEvent::LOG_PREFIX));
+    assert_eq!(MessageSentEvent::parse_log_line(&line), Some(event.clone()));
+    assert_eq!(
+        MessageSentEvent::parse_log_line(&format!("Program log: {}", line)),
+        Some(event)
+    );
+
+    assert_eq!(
+        MessageSentEvent::parse_log_line("Program log: MessageSent: src_chain_id=1, nonce=42"),
+        None
+    );
+    assert_eq!(
+        MessageSentEvent::parse_log_line("Program log: MessageSentV1:not-base64!"),
+        None
+    );
+}
+
+// ============================================================================
+// STATE MIGRATION TESTS
+// ============================================================================
+
+// 59. Test: Legacy ConfigAccount unpacks with relay threshold 1
+// Verifies that a 38-byte config written before relay_threshold existed unpacks with threshold 1 and its bump intact, and packs back into the legacy layout.
+// Why: Deployed configs keep the old size; reading them with the new layout would fail every admin and delivery instruction.
+#[test]
+fn test_config_account_legacy_unpack() {
+    let config = ConfigAccount::new(Pubkey::new_from_array([0x66; 32]), DUMMY_CHAIN_ID_SVM, 253);
+
+    // Legacy layout: discriminator, admin, chain_id, bump
+    let mut legacy = vec![ConfigAccount::DISCRIMINATOR];
+    legacy.extend_from_slice(config.admin.as_ref());
+    legacy.extend_from_slice(&config.chain_id.to_le_bytes());
+    legacy.push(config.bump);
+    assert_eq!(legacy.len(), ConfigAccount::LEGACY_SIZE);
+
+    let decoded = ConfigAccount::unpack(&legacy).unwrap();
+    assert_eq!(decoded, config);
+
+    let mut repacked = vec![0u8; ConfigAccount::LEGACY_SIZE];
+    decoded.pack(&mut repacked).unwrap();
+    assert_eq!(repacked, legacy);
+
+    let mut too_high = decoded;
+    too_high.relay_threshold = 2;
+    assert!(too_high.pack(&mut repacked).is_err());
+}
+
 // ============================================================================
 // INTEGRATION TESTS (require solana-program-test runtime)
 // ============================================================================
@@ -413,8 +484,9 @@ fn test_error_codes_unique() {
 mod integration {
     use borsh::{BorshDeserialize, BorshSerialize};
     use intent_gmp::{
+        error::GmpError,
         instruction::NativeGmpInstruction,
-        state::{seeds, DeliveredMessage, MessageAccount, OutboundNonceAccount},
+        state::{seeds, ConfigAccount, DeliveredMessage, MessageAccount, OutboundNonceAccount, RelayAccount},
     };
     use solana_program::instruction::{AccountMeta, Instruction};
     use solana_program_test::{processor, ProgramTest, ProgramTestContext};
@@ -465,6 +537,41 @@ mod integration {
         Ok(())
     }
 
+    // Forwarding receiver program ID (checks the order of forwarded accounts)
+    fn mock_forwarding_receiver_id() -> Pubkey {
+        solana_sdk::pubkey!("MockFwd111111111111111111111111111111111111")
+    }
+
+    // Placeholder key for the forwarded account at `index` (index 5 is the relay signer)
+    fn forwarded_account_key(index: usize) -> Pubkey {
+        Pubkey::new_from_array([0xE0 + index as u8; 32])
+    }
+
+    // Forwarding receiver processor - accepts only the FulfillmentProof account layout
+    // (requirements, escrow, vault, solver_token writable; gmp_config; gmp_caller signer; token_program; state)
+    fn mock_forwarding_receiver_process(
+        _program_id: &Pubkey,
+        accounts: &[solana_program::account_info::AccountInfo],
+        _instruction_data: &[u8],
+    ) -> solana_program::entrypoint::ProgramResult {
+        if accounts.len() != 8 {
+            return Err(solana_program::program_error::ProgramError::NotEnoughAccountKeys);
+        }
+        for (index, account) in accounts.iter().enumerate() {
+            let in_place = if index == 5 {
+                account.is_signer
+            } else {
+                *account.key == forwarded_account_key(index) && account.is_writable == (index < 4)
+            };
+            if !in_place {
+                solana_program::msg!("MockForwardingReceiver: unexpected account at index {}", index);
+                return Err(solana_program::program_error::ProgramError::InvalidAccountData);
+            }
+        }
+        solana_program::msg!("MockForwardingReceiver: accounts received in order");
+        Ok(())
+    }
+
     // Build ProgramTest with intent-gmp and mock receivers
     fn program_test() -> ProgramTest {
         let mut pt = ProgramTest::new(
@@ -476,6 +583,8 @@ mod integration {
         pt.add_program("mock_receiver", mock_receiver_id(), processor!(mock_receiver_process));
         // Add mock escrow receiver for routing tests (simulates intent_escrow)
         pt.add_program("mock_escrow_receiver", mock_escrow_receiver_id(), processor!(mock_escrow_receiver_process));
+        // Add forwarding receiver for account-order tests (simulates intent_escrow's FulfillmentProof handler)
+        pt.add_program("mock_forwarding_receiver", mock_forwarding_receiver_id(), processor!(mock_forwarding_receiver_process));
         pt
     }
 
@@ -549,6 +658,30 @@ mod integration {
         }
     }
 
+    // Helper: create SetRelayThreshold instruction
+    fn create_set_relay_threshold_ix(program_id: Pubkey, admin: Pubkey, threshold: u8) -> Instruction {
+        let (config_pda, _) = Pubkey::find_program_address(&[seeds::CONFIG_SEED], &program_id);
+        Instruction {
+            program_id,
+            accounts: vec![
+                AccountMeta::new(config_pda, false),
+                AccountMeta::new_readonly(admin, true),
+            ],
+            data: NativeGmpInstruction::SetRelayThreshold { threshold }.try_to_vec().unwrap(),
+        }
+    }
+
+    // Helper: append co-signing relays to a DeliverMessage instruction
+    // Each co-signer is a (relay PDA, relay signer) pair placed after the fixed accounts.
+    fn with_cosigners(mut ix: Instruction, program_id: Pubkey, cosigners: &[Pubkey]) -> Instruction {
+        for cosigner in cosigners {
+            let (relay_pda, _) = Pubkey::find_program_address(&[seeds::RELAY_SEED, cosigner.as_ref()], &program_id);
+            ix.accounts.push(AccountMeta::new_readonly(relay_pda, false));
+            ix.accounts.push(AccountMeta::new_readonly(*cosigner, true));
+        }
+        ix
+    }
+
     // Helper: create SetRemoteGmpEndpointAddr instruction
     fn create_set_remote_gmp_endpoint_addr_ix(
         program_id: Pubkey,
@@ -1261,7 +1394,57 @@ mod integration {
     }
 
     // #34: test_add_relay — N/A for SVM (EVM-specific relay management)
-    // #35: test_remove_relay — N/A for SVM (EVM-specific relay management)
+    // 35. Test: Removed relay can no longer deliver
+    // Verifies that after RemoveRelay the relay PDA is deauthorized and DeliverMessage from that relay fails with UnauthorizedRelay.
+    // Why: Rotating out a compromised relay must take effect immediately, not leave a stale authorization.
+    #[tokio::test]
+    async fn test_remove_relay() {
+        let pt = program_test();
+        let mut context = pt.start_with_context().await;
+        let admin = context.payer.insecure_clone();
+        let relay = Keypair::new();
+        let program_id = gmp_program_id();
+
+        // Fund relay
+        let fund_ix = solana_sdk::system_instruction::transfer(&admin.pubkey(), &relay.pubkey(), 1_000_000_000);
+        send_tx(&mut context, &admin, &[fund_ix], &[]).await.unwrap();
+
+        // Initialize, add relay, set remote GMP endpoint
+        let init_ix = create_initialize_ix(program_id, admin.pubkey(), admin.pubkey(), CHAIN_ID_SVM);
+        let add_relay_ix = create_add_relay_ix(program_id, admin.pubkey(), admin.pubkey(), relay.pubkey());
+        let remote_gmp_endpoint_addr = [0x35; 32];
+        let set_remote_gmp_endpoint_ix = create_set_remote_gmp_endpoint_addr_ix(program_id, admin.pubkey(), admin.pubkey(), CHAIN_ID_MVM, remote_gmp_endpoint_addr);
+        send_tx(&mut context, &admin, &[init_ix, add_relay_ix, set_remote_gmp_endpoint_ix], &[]).await.unwrap();
+
+        // Relay delivers while authorized
+        let mut payload = vec![0x01]; // msg_type
+        payload.extend_from_slice(&[0x35; 32]); // intent_id
+        let deliver_ix = create_deliver_message_ix(program_id, relay.pubkey(), relay.pubkey(), mock_receiver_id(), CHAIN_ID_MVM, remote_gmp_endpoint_addr, payload);
+        send_tx(&mut context, &relay, &[deliver_ix], &[]).await.unwrap();
+
+        // Admin removes the relay
+        let remove_relay_ix = create_remove_relay_ix(program_id, admin.pubkey(), relay.pubkey());
+        send_tx(&mut context, &admin, &[remove_relay_ix], &[]).await.unwrap();
+
+        let (relay_pda, _) = Pubkey::find_program_address(&[seeds::RELAY_SEED, relay.pubkey().as_ref()], &program_id);
+        let relay_account: RelayAccount = read_account(&mut context, relay_pda).await;
+        assert!(!relay_account.is_authorized, "Relay should be deauthorized");
+
+        // The same relay can no longer deliver
+        let mut payload = vec![0x01]; // msg_type
+        payload.extend_from_slice(&[0x36; 32]); // intent_id
+        let deliver_ix = create_deliver_message_ix(program_id, relay.pubkey(), relay.pubkey(), mock_receiver_id(), CHAIN_ID_MVM, remote_gmp_endpoint_addr, payload);
+        let err = send_tx(&mut context, &relay, &[deliver_ix], &[]).await.unwrap_err().unwrap();
+
+        assert_eq!(
+            err,
+            solana_sdk::transaction::TransactionError::InstructionError(
+                0,
+                solana_sdk::instruction::InstructionError::Custom(GmpError::UnauthorizedRelay as u32)
+            )
+        );
+    }
+
     // #36: test_reject_duplicate_relay — N/A for SVM (EVM-specific relay management)
     // #37: test_reject_removing_non_existent_relay — N/A for SVM (EVM-specific relay management)
     // #38: test_deliver_intent_requirements_stores_in_both_handlers — N/A for SVM (MVM-specific dual handler routing)
@@ -1314,9 +1497,9 @@ mod integration {
         payload.extend_from_slice(&0u64.to_be_bytes()); // timestamp
         assert_eq!(payload.len(), 81, "FulfillmentProof payload should be 81 bytes");
 
-        // Create mock remaining accounts (7 accounts for GmpReceiveFulfillmentProof)
-        // In production: requirements, escrow, vault, solver_token, gmp_config, gmp_caller, token_program
-        // For test: we just need 7 placeholder accounts with deterministic addresses
+        // Create mock remaining accounts (8 accounts for GmpReceiveFulfillmentProof)
+        // In production: requirements, escrow, vault, solver_token, gmp_config, gmp_caller, token_program, state
+        // For test: we just need 8 placeholder accounts with deterministic addresses
         let remaining_accounts = vec![
             AccountMeta::new(Pubkey::new_from_array([0xD1; 32]), false),        // 0: requirements (writable)
             AccountMeta::new(Pubkey::new_from_array([0xD2; 32]), false),        // 1: escrow (writable)
@@ -1325,6 +1508,7 @@ mod integration {
             AccountMeta::new_readonly(Pubkey::new_from_array([0xD5; 32]), false), // 4: gmp_config
             AccountMeta::new_readonly(relay.pubkey(), true),      // 5: gmp_caller (signer)
             AccountMeta::new_readonly(Pubkey::new_from_array([0xD6; 32]), false), // 6: token_program
+            AccountMeta::new_readonly(Pubkey::new_from_array([0xD7; 32]), false), // 7: state
         ];
 
         // Deliver FulfillmentProof message - should route to intent_escrow (mock_escrow_receiver)
@@ -1355,7 +1539,7 @@ mod integration {
 
     // 41. Test: FulfillmentProof fails with insufficient accounts
     // Verifies that FulfillmentProof routing fails when fewer than the required number of remaining accounts are provided.
-    // Why: GmpReceiveFulfillmentProof requires 7 accounts for token transfer. The GMP endpoint
+    // Why: GmpReceiveFulfillmentProof requires 8 accounts for token transfer. The GMP endpoint
     // must validate account count before attempting CPI.
     #[tokio::test]
     async fn test_fulfillment_proof_fails_with_insufficient_accounts() {
@@ -1393,7 +1577,7 @@ mod integration {
         payload.extend_from_slice(&0u64.to_be_bytes()); // amount
         payload.extend_from_slice(&0u64.to_be_bytes()); // timestamp
 
-        // Only provide 3 accounts instead of required 7
+        // Only provide 3 accounts instead of required 8
         let insufficient_accounts = vec![
             AccountMeta::new(Pubkey::new_from_array([0xE1; 32]), false),
             AccountMeta::new(Pubkey::new_from_array([0xE2; 32]), false),
@@ -1417,6 +1601,161 @@ mod integration {
         assert!(result.is_err(), "Should fail with insufficient accounts for FulfillmentProof");
     }
 
+    // Helper: initialize the endpoint with three authorized relays and a relay threshold
+    // SetRelayThreshold is capped at 1 while the relay cannot co-sign, so the quorum
+    // is written into the config account directly to exercise the on-chain check.
+    async fn setup_relay_quorum(
+        context: &mut ProgramTestContext,
+        relays: &[Keypair; 3],
+        threshold: u8,
+        remote_gmp_endpoint_addr: [u8; 32],
+    ) {
+        let admin = context.payer.insecure_clone();
+        let program_id = gmp_program_id();
+
+        let mut ixs = vec![create_initialize_ix(program_id, admin.pubkey(), admin.pubkey(), CHAIN_ID_SVM)];
+        for relay in relays {
+            ixs.push(create_add_relay_ix(program_id, admin.pubkey(), admin.pubkey(), relay.pubkey()));
+        }
+        ixs.push(create_set_remote_gmp_endpoint_addr_ix(program_id, admin.pubkey(), admin.pubkey(), CHAIN_ID_MVM, remote_gmp_endpoint_addr));
+        send_tx(context, &admin, &ixs, &[]).await.unwrap();
+
+        let (config_pda, _) = Pubkey::find_program_address(&[seeds::CONFIG_SEED], &program_id);
+        let mut account = context.banks_client.get_account(config_pda).await.unwrap().unwrap();
+        let mut config = ConfigAccount::try_from_slice(&account.data).unwrap();
+        config.relay_threshold = threshold;
+        config.pack(&mut account.data).unwrap();
+        context.set_account(&config_pda, &solana_sdk::account::AccountSharedData::from(account));
+    }
+
+    // 51. Test: 2-of-3 relay quorum delivers message
+    // Verifies that DeliverMessage succeeds when the threshold is 2 and a second authorized relay co-signs.
+    // Why: A quorum removes the single relay as a point of trust; a valid quorum must still deliver.
+    #[tokio::test]
+    async fn test_deliver_message_relay_quorum_met() {
+        let pt = program_test();
+        let mut context = pt.start_with_context().await;
+        let program_id = gmp_program_id();
+        let relays = [Keypair::new(), Keypair::new(), Keypair::new()];
+        let remote_gmp_endpoint_addr = [0x51; 32];
+        setup_relay_quorum(&mut context, &relays, 2, remote_gmp_endpoint_addr).await;
+
+        let config: ConfigAccount = read_account(&mut context, Pubkey::find_program_address(&[seeds::CONFIG_SEED], &program_id).0).await;
+        assert_eq!(config.relay_threshold, 2);
+
+        let mut payload = vec![0x01]; // msg_type
+        payload.extend_from_slice(&[0x51; 32]); // intent_id
+        let deliver_ix = create_deliver_message_ix(
+            program_id,
+            relays[0].pubkey(),
+            context.payer.pubkey(),
+            mock_receiver_id(),
+            CHAIN_ID_MVM,
+            remote_gmp_endpoint_addr,
+            payload,
+        );
+        let deliver_ix = with_cosigners(deliver_ix, program_id, &[relays[2].pubkey()]);
+        let payer = context.payer.insecure_clone();
+        send_tx(&mut context, &payer, &[deliver_ix], &[&relays[0], &relays[2]]).await.unwrap();
+
+        let (delivered_pda, _) = Pubkey::find_program_address(
+            &[seeds::DELIVERED_SEED, &[0x51u8; 32][..], &[0x01]],
+            &program_id,
+        );
+        let delivered: DeliveredMessage = read_account(&mut context, delivered_pda).await;
+        assert_eq!(delivered.discriminator, DeliveredMessage::DISCRIMINATOR, "Message should have been delivered");
+    }
+
+    // 52. Test: 1-of-3 signatures rejected when threshold is 2
+    // Verifies that DeliverMessage fails when only one relay signs and the threshold is 2.
+    // Why: A single relay must not be able to deliver messages once a quorum is configured.
+    #[tokio::test]
+    async fn test_deliver_message_relay_quorum_not_met() {
+        let pt = program_test();
+        let mut context = pt.start_with_context().await;
+        let program_id = gmp_program_id();
+        let relays = [Keypair::new(), Keypair::new(), Keypair::new()];
+        let remote_gmp_endpoint_addr = [0x52; 32];
+        setup_relay_quorum(&mut context, &relays, 2, remote_gmp_endpoint_addr).await;
+
+        let mut payload = vec![0x01]; // msg_type
+        payload.extend_from_slice(&[0x52; 32]); // intent_id
+        let deliver_ix = create_deliver_message_ix(
+            program_id,
+            relays[0].pubkey(),
+            context.payer.pubkey(),
+            mock_receiver_id(),
+            CHAIN_ID_MVM,
+            remote_gmp_endpoint_addr,
+            payload,
+        );
+        let payer = context.payer.insecure_clone();
+        let result = send_tx(&mut context, &payer, &[deliver_ix], &[&relays[0]]).await;
+        assert!(result.is_err(), "Single relay signature should not meet a 2-of-3 quorum");
+    }
+
+    // 53. Test: Duplicate relay signature rejected
+    // Verifies that DeliverMessage fails when the same relay is supplied as both primary signer and co-signer.
+    // Why: Counting one relay twice would let a single relay satisfy the quorum on its own.
+    #[tokio::test]
+    async fn test_deliver_message_rejects_duplicate_relay_signer() {
+        let pt = program_test();
+        let mut context = pt.start_with_context().await;
+        let program_id = gmp_program_id();
+        let relays = [Keypair::new(), Keypair::new(), Keypair::new()];
+        let remote_gmp_endpoint_addr = [0x53; 32];
+        setup_relay_quorum(&mut context, &relays, 2, remote_gmp_endpoint_addr).await;
+
+        let mut payload = vec![0x01]; // msg_type
+        payload.extend_from_slice(&[0x53; 32]); // intent_id
+        let deliver_ix = create_deliver_message_ix(
+            program_id,
+            relays[0].pubkey(),
+            context.payer.pubkey(),
+            mock_receiver_id(),
+            CHAIN_ID_MVM,
+            remote_gmp_endpoint_addr,
+            payload,
+        );
+        let deliver_ix = with_cosigners(deliver_ix, program_id, &[relays[0].pubkey()]);
+        let payer = context.payer.insecure_clone();
+        let result = send_tx(&mut context, &payer, &[deliver_ix], &[&relays[0]]).await;
+        assert!(result.is_err(), "Same relay must not count twice toward the quorum");
+    }
+
+    // 58. Test: SetRelayThreshold rejects thresholds above the supported maximum
+    // Verifies that SetRelayThreshold fails with InvalidRelayThreshold for 0 and for 2, and that threshold 1 is accepted.
+    // Why: The integrated-gmp relay signs deliveries alone; a threshold it cannot meet would halt every delivery.
+    #[tokio::test]
+    async fn test_set_relay_threshold_rejects_above_max() {
+        let pt = program_test();
+        let mut context = pt.start_with_context().await;
+        let admin = context.payer.insecure_clone();
+        let program_id = gmp_program_id();
+
+        let init_ix = create_initialize_ix(program_id, admin.pubkey(), admin.pubkey(), CHAIN_ID_SVM);
+        send_tx(&mut context, &admin, &[init_ix], &[]).await.unwrap();
+
+        for threshold in [0, ConfigAccount::MAX_RELAY_THRESHOLD + 1] {
+            let ix = create_set_relay_threshold_ix(program_id, admin.pubkey(), threshold);
+            let err = send_tx(&mut context, &admin, &[ix], &[]).await.unwrap_err().unwrap();
+            assert_eq!(
+                err,
+                solana_sdk::transaction::TransactionError::InstructionError(
+                    0,
+                    solana_sdk::instruction::InstructionError::Custom(GmpError::InvalidRelayThreshold as u32)
+                ),
+                "threshold {} should be rejected",
+                threshold
+            );
+        }
+
+        let ix = create_set_relay_threshold_ix(program_id, admin.pubkey(), ConfigAccount::MAX_RELAY_THRESHOLD);
+        send_tx(&mut context, &admin, &[ix], &[]).await.unwrap();
+        let config: ConfigAccount = read_account(&mut context, Pubkey::find_program_address(&[seeds::CONFIG_SEED], &program_id).0).await;
+        assert_eq!(config.relay_threshold, ConfigAccount::MAX_RELAY_THRESHOLD);
+    }
+
     // #42: test_deliver_fulfillment_proof_routes — N/A for SVM (EVM-specific handler routing)
     // #43: test_reject_unknown_message_type — N/A for SVM (EVM-specific message type validation)
     // #44: test_fulfillment_proof_requires_escrow_handler — N/A for SVM (EVM-specific handler requirement)
@@ -1426,4 +1765,148 @@ mod integration {
     // #48: test_set_escrow_handler — N/A for SVM (EVM-specific handler configuration)
     // #49: test_set_outflow_handler — N/A for SVM (EVM-specific handler configuration)
     // #50: test_route_to_both_handlers — N/A for SVM (EVM-specific dual handler routing)
+
+    // ========================================================================
+    // OUTBOUND NONCE PERSISTENCE TESTS
+    // ========================================================================
+
+    // 54. Test: Send rejects a reinitialized nonce account
+    // Verifies that Send fails with InvalidDiscriminator when the existing nonce_out account has been zeroed, instead of restarting the sequence at 0.
+    // Why: A reset counter would reassign nonces already used by earlier MessageAccounts, breaking relay dedup.
+    #[tokio::test]
+    async fn test_send_rejects_reinitialized_nonce_account() {
+        let pt = program_test();
+        let mut context = pt.start_with_context().await;
+        let admin = context.payer.insecure_clone();
+        let program_id = gmp_program_id();
+
+        let init_ix = create_initialize_ix(program_id, admin.pubkey(), admin.pubkey(), CHAIN_ID_SVM);
+        send_tx(&mut context, &admin, &[init_ix], &[]).await.unwrap();
+
+        let dst_addr = [0xab; 32];
+        let remote_gmp_endpoint_addr = program_id.to_bytes();
+        let send_ix = create_send_ix(program_id, admin.pubkey(), admin.pubkey(), CHAIN_ID_MVM, dst_addr, remote_gmp_endpoint_addr, vec![0x01], 0);
+        send_tx(&mut context, &admin, &[send_ix], &[]).await.unwrap();
+
+        // Wipe the counter in place, as a botched upgrade or reinit would
+        let (nonce_pda, _) = Pubkey::find_program_address(&[seeds::NONCE_OUT_SEED], &program_id);
+        let mut account = context.banks_client.get_account(nonce_pda).await.unwrap().unwrap();
+        account.data.iter_mut().for_each(|byte| *byte = 0);
+        context.set_account(&nonce_pda, &solana_sdk::account::AccountSharedData::from(account));
+
+        context.warp_to_slot(100).unwrap();
+        let send_ix = create_send_ix(program_id, admin.pubkey(), admin.pubkey(), CHAIN_ID_MVM, dst_addr, remote_gmp_endpoint_addr, vec![0x02], 0);
+        let err = send_tx(&mut context, &admin, &[send_ix], &[]).await.unwrap_err().unwrap();
+
+        assert_eq!(
+            err,
+            solana_sdk::transaction::TransactionError::InstructionError(
+                0,
+                solana_sdk::instruction::InstructionError::Custom(GmpError::InvalidDiscriminator as u32)
+            )
+        );
+    }
+
+    // 55. Test: Outbound nonce increases monotonically across sends
+    // Verifies that each Send assigns the next nonce to its MessageAccount and advances the persisted counter by exactly one.
+    // Why: The relay dedups and derives message PDAs by nonce; any repeat or gap would drop or duplicate messages.
+    #[tokio::test]
+    async fn test_send_nonce_increases_monotonically() {
+        let pt = program_test();
+        let mut context = pt.start_with_context().await;
+        let admin = context.payer.insecure_clone();
+        let program_id = gmp_program_id();
+
+        let init_ix = create_initialize_ix(program_id, admin.pubkey(), admin.pubkey(), CHAIN_ID_SVM);
+        send_tx(&mut context, &admin, &[init_ix], &[]).await.unwrap();
+
+        let dst_addr = [0xab; 32];
+        let remote_gmp_endpoint_addr = program_id.to_bytes();
+        let (nonce_pda, _) = Pubkey::find_program_address(&[seeds::NONCE_OUT_SEED], &program_id);
+        for expected in 0u64..3 {
+            context.warp_to_slot(100 * (expected + 1)).unwrap();
+            let send_ix = create_send_ix(program_id, admin.pubkey(), admin.pubkey(), CHAIN_ID_MVM, dst_addr, remote_gmp_endpoint_addr, vec![expected as u8], expected);
+            send_tx(&mut context, &admin, &[send_ix], &[]).await.unwrap();
+
+            let (message_pda, _) = Pubkey::find_program_address(
+                &[seeds::MESSAGE_SEED, &expected.to_le_bytes()], &program_id);
+            let message: MessageAccount = read_account(&mut context, message_pda).await;
+            assert_eq!(message.nonce, expected);
+            let counter: OutboundNonceAccount = read_account(&mut context, nonce_pda).await;
+            assert_eq!(counter.nonce, expected + 1, "Counter must advance by one per send");
+        }
+    }
+
+    // ========================================================================
+    // DESTINATION ACCOUNT FORWARDING TESTS
+    // ========================================================================
+
+    // 56. Test: DeliverMessage forwards additional accounts to the destination in order
+    // Verifies that the accounts after the fixed DeliverMessage accounts reach the destination handler in the given order with their signer/writable flags, and that a swapped order is rejected by the handler.
+    // Why: The escrow's FulfillmentProof handler reads its accounts positionally; any reordering would release funds from or into the wrong account.
+    #[tokio::test]
+    async fn test_deliver_message_forwards_accounts_in_order() {
+        let pt = program_test();
+        let mut context = pt.start_with_context().await;
+        let admin = context.payer.insecure_clone();
+        let relay = Keypair::new();
+        let program_id = gmp_program_id();
+
+        let fund_ix = solana_sdk::system_instruction::transfer(&admin.pubkey(), &relay.pubkey(), 1_000_000_000);
+        send_tx(&mut context, &admin, &[fund_ix], &[]).await.unwrap();
+
+        let init_ix = create_initialize_ix(program_id, admin.pubkey(), admin.pubkey(), CHAIN_ID_SVM);
+        let add_relay_ix = create_add_relay_ix(program_id, admin.pubkey(), admin.pubkey(), relay.pubkey());
+        let remote_gmp_endpoint_addr = [0x99; 32];
+        let set_remote_gmp_endpoint_ix = create_set_remote_gmp_endpoint_addr_ix(program_id, admin.pubkey(), admin.pubkey(), CHAIN_ID_MVM, remote_gmp_endpoint_addr);
+        let set_routing_ix = create_set_routing_ix(program_id, admin.pubkey(), admin.pubkey(), mock_receiver_id(), mock_forwarding_receiver_id());
+        send_tx(&mut context, &admin, &[init_ix, add_relay_ix, set_remote_gmp_endpoint_ix, set_routing_ix], &[]).await.unwrap();
+
+        // FulfillmentProof payload: [type(1)] [intent_id(32)] [solver_addr(32)] [amount(8)] [timestamp(8)]
+        let mut payload = vec![0x03];
+        payload.extend_from_slice(&[0xAC; 32]);
+        payload.extend_from_slice(&[0xBB; 32]);
+        payload.extend_from_slice(&0u64.to_be_bytes());
+        payload.extend_from_slice(&0u64.to_be_bytes());
+
+        let forwarded_accounts = |escrow_index: usize, vault_index: usize| {
+            vec![
+                AccountMeta::new(forwarded_account_key(0), false),                // 0: requirements
+                AccountMeta::new(forwarded_account_key(escrow_index), false),     // 1: escrow
+                AccountMeta::new(forwarded_account_key(vault_index), false),      // 2: vault
+                AccountMeta::new(forwarded_account_key(3), false),                // 3: solver_token
+                AccountMeta::new_readonly(forwarded_account_key(4), false),       // 4: gmp_config
+                AccountMeta::new_readonly(relay.pubkey(), true),                  // 5: gmp_caller
+                AccountMeta::new_readonly(forwarded_account_key(6), false),       // 6: token_program
+                AccountMeta::new_readonly(forwarded_account_key(7), false),       // 7: state
+            ]
+        };
+        let deliver_ix = |accounts: Vec<AccountMeta>| {
+            create_deliver_message_with_routing_ix(
+                program_id,
+                relay.pubkey(),
+                relay.pubkey(),
+                mock_receiver_id(),
+                mock_forwarding_receiver_id(),
+                CHAIN_ID_MVM,
+                remote_gmp_endpoint_addr,
+                payload.clone(),
+                accounts,
+            )
+        };
+
+        // Escrow and vault swapped: the destination handler must see the wrong order and fail
+        let result = send_tx(&mut context, &relay, &[deliver_ix(forwarded_accounts(2, 1))], &[]).await;
+        assert!(result.is_err(), "Destination handler should reject swapped accounts");
+
+        context.warp_to_slot(100).unwrap();
+        send_tx(&mut context, &relay, &[deliver_ix(forwarded_accounts(1, 2))], &[]).await.unwrap();
+
+        let (delivered_pda, _) = Pubkey::find_program_address(
+            &[seeds::DELIVERED_SEED, &payload[1..33], &[0x03]],
+            &program_id,
+        );
+        let delivered: DeliveredMessage = read_account(&mut context, delivered_pda).await;
+        assert_eq!(delivered.discriminator, DeliveredMessage::DISCRIMINATOR);
+    }
 }
@@ -38,3 +38,19 @@ pub fn emit_requirements_duplicate(intent_id: &[u8; 32]) {
         hex::encode(intent_id)
     );
 }
+
+/// Emitted when the admin updates the trusted hub chain ID and GMP endpoint address.
+pub fn emit_hub_config_updated(
+    old_hub_chain_id: u32,
+    old_hub_gmp_endpoint_addr: &[u8; 32],
+    new_hub_chain_id: u32,
+    new_hub_gmp_endpoint_addr: &[u8; 32],
+) {
+    msg!(
+        "HubConfigUpdated: old_hub_chain_id={}, old_hub_addr={}, new_hub_chain_id={}, new_hub_addr={}",
+        old_hub_chain_id,
+        hex::encode(old_hub_gmp_endpoint_addr),
+        new_hub_chain_id,
+        hex::encode(new_hub_gmp_endpoint_addr)
+    );
+}
@@ -19,7 +19,8 @@ use solana_program::{
 
 use crate::error::OutflowError;
 use crate::events::{
-    emit_fulfillment_succeeded, emit_requirements_duplicate, emit_requirements_received,
+    emit_fulfillment_succeeded, emit_hub_config_updated, emit_requirements_duplicate,
+    emit_requirements_received,
 };
 use crate::instruction::OutflowInstruction;
 use crate::state::{seeds, ConfigAccount, IntentRequirementsAccount};
@@ -170,8 +171,8 @@ fn process_gmp_receive(
         return Err(OutflowError::InvalidGmpMessage.into());
     }
 
-    // Decode IntentRequirements from payload
-    let requirements = IntentRequirements::decode(payload)
+    // Decode IntentRequirements (and optional payout address) from payload
+    let (requirements, payout_addr) = IntentRequirements::decode_with_payout(payload)
         .map_err(|_| OutflowError::InvalidGmpMessage)?;
 
     // Derive requirements PDA
@@ -215,9 +216,9 @@ fn process_gmp_receive(
         ]],
     )?;
 
-    // Convert addresses from GMP format (32 bytes) to Pubkey
-    let recipient_addr = Pubkey::try_from(&requirements.requester_addr[..])
-        .map_err(|_| OutflowError::InvalidGmpMessage)?;
+    // Convert addresses from GMP format (32 bytes) to Pubkey.
+    // A payout address, when present, replaces the requester as the recipient.
+    let recipient_addr = Pubkey::new_from_array(payout_addr.unwrap_or(requirements.requester_addr));
     let token_mint = Pubkey::try_from(&requirements.token_addr[..])
         .map_err(|_| OutflowError::InvalidGmpMessage)?;
     let authorized_solver = Pubkey::try_from(&requirements.solver_addr[..])
@@ -447,13 +448,17 @@ fn process_update_hub_config(
     }
 
     // Update config
+    let old_hub_chain_id = config.hub_chain_id;
+    let old_hub_gmp_endpoint_addr = config.hub_gmp_endpoint_addr;
     config.hub_chain_id = hub_chain_id;
     config.hub_gmp_endpoint_addr = hub_gmp_endpoint_addr;
     config.serialize(&mut &mut config_account.data.borrow_mut()[..])?;
 
-    msg!(
-        "OutflowValidator hub config updated: hub_chain_id={}",
+    emit_hub_config_updated(
+        old_hub_chain_id,
+        &old_hub_gmp_endpoint_addr,
         hub_chain_id,
+        &hub_gmp_endpoint_addr,
     );
     Ok(())
 }
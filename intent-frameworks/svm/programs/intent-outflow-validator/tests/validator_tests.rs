@@ -1201,8 +1201,8 @@ async fn test_fulfill_intent_succeeds() {
 // ============================================================================
 
 // 19. Test: UpdateHubConfig succeeds with valid admin
-// Verifies that the admin can update hub_chain_id and hub_gmp_endpoint_addr.
-// Why: Allows reconfiguring the outflow validator when hub addresses change.
+// Verifies that the admin can update hub_chain_id and hub_gmp_endpoint_addr, and that a HubConfigUpdated event records the old and new values.
+// Why: Allows reconfiguring the outflow validator when hub addresses change; the event gives operators an audit trail of trust changes.
 #[tokio::test]
 async fn test_update_hub_config_succeeds() {
     let pt = program_test();
@@ -1238,7 +1238,32 @@ async fn test_update_hub_config_succeeds() {
         new_chain_id,
         new_hub_addr,
     );
-    send_tx(&mut context, &admin, &[update_ix], &[]).await.unwrap();
+    let blockhash = context.banks_client.get_latest_blockhash().await.unwrap();
+    let tx = Transaction::new_signed_with_payer(
+        &[update_ix],
+        Some(&admin.pubkey()),
+        &[&admin],
+        blockhash,
+    );
+    let result = context
+        .banks_client
+        .process_transaction_with_metadata(tx)
+        .await
+        .unwrap();
+    assert!(result.result.is_ok());
+
+    let logs = result.metadata.unwrap().log_messages;
+    let event = logs
+        .iter()
+        .find(|log| log.contains("HubConfigUpdated:"))
+        .expect("HubConfigUpdated event should be emitted");
+    assert!(event.contains(&format!("old_hub_chain_id={}", HUB_CHAIN_ID)));
+    assert!(event.contains(&format!(
+        "old_hub_addr={}",
+        hex::encode(hub_gmp_endpoint_addr())
+    )));
+    assert!(event.contains(&format!("new_hub_chain_id={}", new_chain_id)));
+    assert!(event.contains(&format!("new_hub_addr={}", hex::encode(new_hub_addr))));
 
     // Verify config was updated
     let config: ConfigAccount = read_account(&mut context, config_pda).await;
@@ -1432,3 +1457,100 @@ async fn test_gmp_receive_rejects_unsigned_gmp_caller() {
     let result = send_tx(&mut context, &admin, &[gmp_receive_ix], &[]).await;
     assert!(result.is_err(), "Unsigned gmp_caller must be rejected");
 }
+
+// ============================================================================
+// PAYOUT ADDRESS TESTS
+// ============================================================================
+
+// 23. Test: FulfillIntent pays the payout address instead of the requester
+// Verifies that when IntentRequirements carries a payout address, it is stored as the recipient; a fulfillment to the requester's token account is rejected and one to the payout address succeeds.
+// Why: Some intents designate a payout address on the connected chain; the validator must enforce that address, not the requester's.
+#[tokio::test]
+async fn test_fulfill_intent_payout_addr_overrides_requester() {
+    let pt = program_test_with_spl_and_gmp();
+    let mut context = pt.start_with_context().await;
+    let admin = context.payer.insecure_clone();
+    let program_id = outflow_program_id();
+    let solver = Keypair::new();
+    let intent_id = test_intent_id();
+    let requester = Keypair::new();
+    let payout = Keypair::new();
+    let fulfillment_amount = 500_000u64;
+
+    initialize_gmp_endpoint(&mut context, &admin, SVM_CHAIN_ID).await;
+
+    // Create mint and token accounts
+    let mint = create_mint(&mut context, &admin, &admin.pubkey(), 6).await;
+    let solver_token = create_token_account(&mut context, &admin, &mint, &solver.pubkey()).await;
+    let requester_token = create_token_account(&mut context, &admin, &mint, &requester.pubkey()).await;
+    let payout_token = create_token_account(&mut context, &admin, &mint, &payout.pubkey()).await;
+    mint_tokens(&mut context, &admin, &mint, &admin, &solver_token, 1_000_000).await;
+
+    // Initialize and deliver requirements with a payout address
+    let init_ix = create_initialize_ix(
+        program_id,
+        admin.pubkey(),
+        gmp_endpoint_id(),
+        HUB_CHAIN_ID,
+        hub_gmp_endpoint_addr(),
+    );
+    send_tx(&mut context, &admin, &[init_ix], &[]).await.unwrap();
+
+    let requirements = IntentRequirements {
+        intent_id,
+        requester_addr: requester.pubkey().to_bytes(),
+        amount_required: fulfillment_amount,
+        token_addr: mint.to_bytes(),
+        solver_addr: [0u8; 32],
+        expiry: FAR_FUTURE_EXPIRY,
+    };
+    let payload = requirements.encode_with_payout(&payout.pubkey().to_bytes()).to_vec();
+    let gmp_receive_ix = create_gmp_receive_ix(
+        program_id,
+        admin.pubkey(),
+        HUB_CHAIN_ID,
+        hub_gmp_endpoint_addr(),
+        payload,
+        intent_id,
+    );
+    send_tx(&mut context, &admin, &[gmp_receive_ix], &[]).await.unwrap();
+
+    let (requirements_pda, _) = Pubkey::find_program_address(
+        &[seeds::REQUIREMENTS_SEED, &intent_id],
+        &program_id,
+    );
+    let stored: IntentRequirementsAccount = read_account(&mut context, requirements_pda).await;
+    assert_eq!(stored.recipient_addr, payout.pubkey(), "Payout address should be the stored recipient");
+
+    // Fulfilling to the requester is rejected
+    let fulfill_ix = create_fulfill_intent_ix_with_gmp(
+        program_id,
+        solver.pubkey(),
+        solver_token,
+        requester_token,
+        mint,
+        gmp_endpoint_id(),
+        intent_id,
+        admin.pubkey(),
+        HUB_CHAIN_ID,
+    );
+    let result = send_tx(&mut context, &admin, &[fulfill_ix], &[&solver]).await;
+    assert!(result.is_err(), "Fulfillment to the requester should be rejected when a payout address is set");
+
+    // Fulfilling to the payout address succeeds
+    let fulfill_ix = create_fulfill_intent_ix_with_gmp(
+        program_id,
+        solver.pubkey(),
+        solver_token,
+        payout_token,
+        mint,
+        gmp_endpoint_id(),
+        intent_id,
+        admin.pubkey(),
+        HUB_CHAIN_ID,
+    );
+    send_tx(&mut context, &admin, &[fulfill_ix], &[&solver]).await.unwrap();
+
+    assert_eq!(get_token_balance(&mut context, payout_token).await, fulfillment_amount);
+    assert_eq!(get_token_balance(&mut context, requester_token).await, 0);
+}
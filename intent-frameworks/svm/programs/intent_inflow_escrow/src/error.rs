@@ -77,6 +77,51 @@ pub enum EscrowError {
 
     #[error("Intent has expired")]
     IntentExpired,
+
+    #[error("Invalid escrow status transition")]
+    InvalidEscrowStatus,
+
+    #[error("Reserved solver does not match requirements")]
+    SolverMismatch,
+
+    #[error("Invalid clock skew tolerance")]
+    InvalidClockSkewTolerance,
+
+    #[error("Vault balance does not match the released amount")]
+    VaultBalanceMismatch,
+
+    #[error("Escrow expiry is not in the future")]
+    InvalidExpiry,
+
+    #[error("Vault holds no tokens beyond the escrow amount")]
+    NoVaultExcess,
+
+    #[error("Invalid token mint account")]
+    InvalidMint,
+
+    #[error("Fee-on-transfer mints are not supported")]
+    TransferFeeMint,
+
+    #[error("New expiry is not later than the current expiry")]
+    ExpiryNotExtended,
+
+    #[error("Mint has a Token-2022 extension the escrow does not support")]
+    UnsupportedMintExtension,
+
+    #[error("Source or recipient account does not match the escrow")]
+    InvalidRecipient,
+
+    #[error("Requester token account mint does not match the escrow token mint")]
+    TokenMintMismatch,
+
+    #[error("Expiry duration is shorter than the minimum")]
+    ExpiryTooShort,
+
+    #[error("Expiry duration is longer than the maximum")]
+    ExpiryTooLong,
+
+    #[error("Requirements have not been fulfilled")]
+    NotFulfilled,
 }
 
 impl From<EscrowError> for ProgramError {
@@ -0,0 +1,54 @@
+//! Event definitions for the inflow escrow program.
+//!
+//! Events are emitted via solana_program::msg! and can be parsed from transaction logs.
+
+use solana_program::{msg, pubkey::Pubkey};
+
+/// Emitted when escrowed funds are released to the solver.
+///
+/// Emitted by every release path (Claim and the FulfillmentProof auto-release).
+/// `approver` is the approver stored in the program state at release time.
+/// `approval_hash` is the SHA-256 of the approval: the FulfillmentProof payload
+/// on auto-release, or the stored requirements it marked fulfilled on Claim.
+/// Indexers can thereby attribute each release to a specific approval.
+pub fn emit_escrow_claimed(
+    intent_id: &[u8; 32],
+    solver_token_account: &Pubkey,
+    amount: u64,
+    approver: &Pubkey,
+    approval_hash: &[u8; 32],
+) {
+    msg!(
+        "EscrowClaimed: intent_id={}, solver_token={}, amount={}, approver={}, approval_hash={}",
+        hex::encode(intent_id),
+        solver_token_account,
+        amount,
+        approver,
+        hex::encode(approval_hash)
+    );
+}
+
+/// Emitted when an expired escrow is cancelled and refunded.
+///
+/// `recipient` is the account actually refunded: the refund recipient chosen at
+/// creation, or the requester's account when none was set.
+pub fn emit_escrow_cancelled(intent_id: &[u8; 32], recipient: &Pubkey, amount: u64) {
+    msg!(
+        "EscrowCancelled: intent_id={}, recipient={}, amount={}",
+        hex::encode(intent_id),
+        recipient,
+        amount
+    );
+}
+
+/// Emitted when the requester extends an escrow's expiry.
+///
+/// Off-chain monitors use this to reschedule their expiry timers.
+pub fn emit_escrow_expiry_extended(intent_id: &[u8; 32], old_expiry: i64, new_expiry: i64) {
+    msg!(
+        "EscrowExpiryExtended: intent_id={}, old_expiry={}, new_expiry={}",
+        hex::encode(intent_id),
+        old_expiry,
+        new_expiry
+    );
+}
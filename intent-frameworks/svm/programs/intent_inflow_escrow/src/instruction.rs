@@ -1,8 +1,17 @@
 //! Instruction definitions
 
 use borsh::{BorshDeserialize, BorshSerialize};
+use gmp_common::messages::{IntentRequirements, INTENT_REQUIREMENTS_SIZE};
 use solana_program::pubkey::Pubkey;
 
+use crate::error::EscrowError;
+
+/// Maximum number of intents in one GmpReceiveRequirementsBatch payload.
+///
+/// Each item costs 145 payload bytes plus a 32-byte account key, so this keeps
+/// the transaction under Solana's 1232-byte packet limit.
+pub const MAX_REQUIREMENTS_BATCH_SIZE: usize = 4;
+
 #[derive(BorshSerialize, BorshDeserialize, Debug, Clone)]
 pub enum EscrowInstruction {
     /// Initialize the escrow program with approver pubkey
@@ -34,6 +43,7 @@ pub enum EscrowInstruction {
     /// 4. `[]` GMP config account (PDA)
     /// 5. `[signer]` GMP endpoint or relay (trusted caller)
     /// 6. `[]` Token program
+    /// 7. `[]` State account (PDA) - supplies the approver recorded in `EscrowClaimed`
     GmpReceive {
         /// Source chain ID
         src_chain_id: u32,
@@ -61,6 +71,11 @@ pub enum EscrowInstruction {
     /// Create a new escrow and deposit funds atomically.
     /// Validates escrow against stored GMP requirements and sends EscrowConfirmation to hub.
     /// Expiry is always taken from the hub-provided requirements (no local default).
+    /// The requester always authorizes the token transfer; a separate fee payer may fund rent.
+    /// Native SOL escrows (requirements token is the zero address) pass the system program as
+    /// the mint and token program and the requester's wallet as the requester token account;
+    /// the vault is then a system-owned PDA holding the lamports above its rent-exempt reserve.
+    /// An optional `refund_recipient` redirects the cancel refund away from the requester.
     ///
     /// Accounts expected:
     /// 0. `[writable]` Escrow account (PDA)
@@ -69,16 +84,24 @@ pub enum EscrowInstruction {
     /// 3. `[writable]` Requester token account
     /// 4. `[writable]` Escrow vault (PDA)
     /// 5. `[]` Reserved solver
-    /// 6. `[]` Token program
+    /// 6. `[]` Token program (SPL Token or Token-2022; must own the mint)
     /// 7. `[]` System program
     /// 8. `[]` Rent sysvar
     /// 9. `[writable]` Requirements account (PDA) - validates against GMP requirements
-    /// 10. `[optional]` GMP config account (PDA) - required if sending EscrowConfirmation
-    /// 11. `[optional]` GMP endpoint program - required if sending EscrowConfirmation
-    /// 12+ `[optional]` Additional accounts for GMP endpoint CPI
+    /// 10. `[writable, signer, optional]` Fee payer for escrow/vault rent - defaults to the
+    ///     requester; recognized by its signer flag
+    /// 11. `[optional]` GMP config account (PDA) - required if sending EscrowConfirmation
+    /// 12. `[optional]` GMP endpoint program - required if sending EscrowConfirmation
+    /// 13+ `[optional]` Additional accounts for GMP endpoint CPI
+    ///
+    /// Without a fee payer the GMP accounts shift down by one: GMP config at 10,
+    /// GMP endpoint program at 11, and endpoint CPI accounts from 12.
     CreateEscrow {
         intent_id: [u8; 32],
         amount: u64,
+        /// Account refunded on cancel instead of the requester: a wallet for native SOL
+        /// escrows, a token account of the escrow mint otherwise
+        refund_recipient: Option<Pubkey>,
     },
 
     /// Claim escrow funds (GMP mode - no signature required)
@@ -93,19 +116,24 @@ pub enum EscrowInstruction {
     /// 2. `[writable]` Escrow vault (PDA)
     /// 3. `[writable]` Solver token account
     /// 4. `[]` Token program
+    /// 5. `[]` State account (PDA) - its clock skew tolerance bounds the claim window;
+    ///    its approver is recorded in `EscrowClaimed`
     Claim { intent_id: [u8; 32] },
 
     /// Cancel escrow and return funds to requester (only after expiry)
     ///
-    /// Only the admin can cancel. Funds always return to the original requester.
+    /// Only the admin can cancel. Funds return to the refund recipient set at creation,
+    /// or to the original requester when none was set. A token refund account must
+    /// hold the escrow mint.
     ///
     /// Accounts expected:
     /// 0. `[writable]` Escrow account (PDA)
     /// 1. `[writable, signer]` Admin
     /// 2. `[writable]` Escrow vault (PDA)
-    /// 3. `[writable]` Requester token account
+    /// 3. `[writable]` Refund account (refund recipient, or requester token account)
     /// 4. `[]` Token program
     /// 5. `[]` GMP config account (PDA)
+    /// 6. `[]` State account (PDA) - its clock skew tolerance bounds the cancel window
     Cancel { intent_id: [u8; 32] },
 
     /// Receive intent requirements from hub via GMP
@@ -137,6 +165,7 @@ pub enum EscrowInstruction {
     /// 4. `[]` GMP config account (PDA)
     /// 5. `[signer]` GMP endpoint or relay (trusted caller)
     /// 6. `[]` Token program
+    /// 7. `[]` State account (PDA) - supplies the approver recorded in `EscrowClaimed`
     GmpReceiveFulfillmentProof {
         /// Source chain ID
         src_chain_id: u32,
@@ -145,4 +174,104 @@ pub enum EscrowInstruction {
         /// GMP payload (FulfillmentProof message)
         payload: Vec<u8>,
     },
+
+    /// Set the clock skew tolerance applied to escrow expiry (admin only)
+    ///
+    /// Widens the claim window and delays the cancel window by the same amount,
+    /// so validator clock drift near expiry cannot make a claim and a cancel
+    /// both valid. Must be between 0 and `EscrowState::MAX_CLOCK_SKEW_TOLERANCE`.
+    /// A state account created before the tolerance existed is resized to
+    /// `EscrowState::LEN`, with the admin paying the additional rent.
+    ///
+    /// Accounts expected:
+    /// 0. `[writable]` State account (PDA)
+    /// 1. `[writable, signer]` Admin
+    /// 2. `[]` GMP config account (PDA)
+    /// 3. `[]` System program
+    SetClockSkewTolerance {
+        /// Tolerance in seconds (0 = exact expiry)
+        tolerance_secs: i64,
+    },
+
+    /// Receive several intent requirements from hub via GMP in one transaction
+    ///
+    /// Payload is a 1-byte count followed by that many encoded IntentRequirements
+    /// (see `encode_requirements_batch`). Source validation and decoding fail the
+    /// whole batch; items whose requirements already exist are skipped.
+    ///
+    /// Accounts expected:
+    /// 0. `[]` GMP config account (PDA)
+    /// 1. `[signer]` GMP endpoint or relay (trusted caller)
+    /// 2. `[signer]` Payer
+    /// 3. `[]` System program
+    /// 4.. `[writable]` Requirements account (PDA) for each item, in payload order
+    GmpReceiveRequirementsBatch {
+        /// Source chain ID
+        src_chain_id: u32,
+        /// Source address (hub GMP endpoint address)
+        remote_gmp_endpoint_addr: [u8; 32],
+        /// GMP payload (count-prefixed IntentRequirements messages)
+        payload: Vec<u8>,
+    },
+
+    /// Sweep vault tokens beyond the escrow amount to a recovery account (admin only)
+    ///
+    /// Tokens sent directly to the vault are never moved by claim or cancel, which
+    /// release exactly `escrow.amount`. This transfers the vault balance above
+    /// `escrow.amount` (the whole balance once the escrow is released) and leaves
+    /// the escrow itself untouched.
+    ///
+    /// Accounts expected:
+    /// 0. `[]` Escrow account (PDA)
+    /// 1. `[signer]` Admin
+    /// 2. `[writable]` Escrow vault (PDA)
+    /// 3. `[writable]` Recovery token account
+    /// 4. `[]` Token program
+    /// 5. `[]` GMP config account (PDA)
+    SweepVaultExcess { intent_id: [u8; 32] },
+
+    /// Extend the expiry of an active escrow (requester only)
+    ///
+    /// Sets `expiry = now + new_duration`. The new expiry must be later than the
+    /// current one, so the requester can give a solver more time but never
+    /// shorten the window a solver is already working against. An escrow past
+    /// its claim deadline (expiry plus clock skew tolerance) cannot be extended.
+    ///
+    /// Accounts expected:
+    /// 0. `[writable]` Escrow account (PDA)
+    /// 1. `[signer]` Requester
+    /// 2. `[]` State account (PDA)
+    ExtendExpiry {
+        intent_id: [u8; 32],
+        /// Seconds from the current block time until the new expiry, within
+        /// `[Escrow::MIN_EXPIRY_DURATION, Escrow::MAX_EXPIRY_DURATION]`
+        new_duration: i64,
+    },
+}
+
+/// Encode a GmpReceiveRequirementsBatch payload.
+pub fn encode_requirements_batch(batch: &[IntentRequirements]) -> Vec<u8> {
+    let mut buf = Vec::with_capacity(1 + batch.len() * INTENT_REQUIREMENTS_SIZE);
+    buf.push(batch.len() as u8);
+    for requirements in batch {
+        buf.extend_from_slice(&requirements.encode());
+    }
+    buf
+}
+
+/// Decode a GmpReceiveRequirementsBatch payload.
+pub fn decode_requirements_batch(data: &[u8]) -> Result<Vec<IntentRequirements>, EscrowError> {
+    let (&count, items) = data.split_first().ok_or(EscrowError::InvalidGmpMessage)?;
+    let count = count as usize;
+    if count == 0
+        || count > MAX_REQUIREMENTS_BATCH_SIZE
+        || items.len() != count * INTENT_REQUIREMENTS_SIZE
+    {
+        return Err(EscrowError::InvalidGmpMessage);
+    }
+
+    items
+        .chunks_exact(INTENT_REQUIREMENTS_SIZE)
+        .map(|item| IntentRequirements::decode(item).map_err(|_| EscrowError::InvalidGmpMessage))
+        .collect()
 }
@@ -4,9 +4,12 @@
 //! Funds are held in escrow and released to solvers when approver signature checks out.
 
 pub mod error;
+pub mod events;
 pub mod instruction;
+pub mod pda;
 pub mod processor;
 pub mod state;
+pub mod token_extensions;
 
 #[cfg(not(feature = "no-entrypoint"))]
 mod entrypoint;
@@ -16,4 +19,4 @@ pub use solana_program;
 // Re-export for tests
 pub use error::EscrowError;
 pub use instruction::EscrowInstruction;
-pub use state::{Escrow, EscrowState, GmpConfig, StoredIntentRequirements};
+pub use state::{Escrow, EscrowState, EscrowStatus, GmpConfig, StoredIntentRequirements};
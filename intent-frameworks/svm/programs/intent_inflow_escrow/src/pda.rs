@@ -0,0 +1,24 @@
+//! PDA derivation helpers
+//!
+//! Single source of truth for per-intent account addresses. The program and
+//! off-chain tooling (CLI, tests) derive escrow, vault, and requirements PDAs
+//! through these functions so seed changes only need to happen here.
+
+use solana_program::pubkey::Pubkey;
+
+use crate::state::seeds;
+
+/// Derive the escrow PDA and bump for an intent: `["escrow", intent_id]`
+pub fn escrow_pda(program_id: &Pubkey, intent_id: &[u8; 32]) -> (Pubkey, u8) {
+    Pubkey::find_program_address(&[seeds::ESCROW_SEED, intent_id], program_id)
+}
+
+/// Derive the escrow vault PDA and bump for an intent: `["vault", intent_id]`
+pub fn vault_pda(program_id: &Pubkey, intent_id: &[u8; 32]) -> (Pubkey, u8) {
+    Pubkey::find_program_address(&[seeds::VAULT_SEED, intent_id], program_id)
+}
+
+/// Derive the stored requirements PDA and bump for an intent: `["requirements", intent_id]`
+pub fn requirements_pda(program_id: &Pubkey, intent_id: &[u8; 32]) -> (Pubkey, u8) {
+    Pubkey::find_program_address(&[seeds::REQUIREMENTS_SEED, intent_id], program_id)
+}
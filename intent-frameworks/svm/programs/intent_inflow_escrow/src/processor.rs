@@ -7,23 +7,27 @@ use solana_program::{
     account_info::{next_account_info, AccountInfo},
     clock::Clock,
     entrypoint::ProgramResult,
+    hash::hash,
     msg,
     program::{invoke, invoke_signed},
     program_error::ProgramError,
     program_pack::Pack,
     pubkey::Pubkey,
     rent::Rent,
-    system_instruction,
+    system_instruction, system_program,
     sysvar::Sysvar,
 };
-use spl_token::state::Account as TokenAccount;
+use spl_token::state::{Account as TokenAccount, Mint};
 
 use gmp_common::messages::{EscrowConfirmation, FulfillmentProof, IntentRequirements};
 
 use crate::{
     error::EscrowError,
-    instruction::EscrowInstruction,
-    state::{seeds, Escrow, EscrowState, GmpConfig, StoredIntentRequirements},
+    events,
+    instruction::{decode_requirements_batch, EscrowInstruction},
+    pda,
+    state::{seeds, Escrow, EscrowState, EscrowStatus, GmpConfig, StoredIntentRequirements},
+    token_extensions,
 };
 
 pub struct Processor;
@@ -88,9 +92,16 @@ impl Processor {
             EscrowInstruction::CreateEscrow {
                 intent_id,
                 amount,
+                refund_recipient,
             } => {
                 msg!("Instruction: CreateEscrow");
-                Self::process_create_escrow(program_id, accounts, intent_id, amount)
+                Self::process_create_escrow(
+                    program_id,
+                    accounts,
+                    intent_id,
+                    amount,
+                    refund_recipient,
+                )
             }
             EscrowInstruction::Claim { intent_id } => {
                 msg!("Instruction: Claim - intent_id={:?}", &intent_id[..8]);
@@ -128,6 +139,35 @@ impl Processor {
                     payload,
                 )
             }
+            EscrowInstruction::SetClockSkewTolerance { tolerance_secs } => {
+                msg!("Instruction: SetClockSkewTolerance");
+                Self::process_set_clock_skew_tolerance(program_id, accounts, tolerance_secs)
+            }
+            EscrowInstruction::GmpReceiveRequirementsBatch {
+                src_chain_id,
+                remote_gmp_endpoint_addr,
+                payload,
+            } => {
+                msg!("Instruction: GmpReceiveRequirementsBatch");
+                Self::process_gmp_receive_requirements_batch(
+                    program_id,
+                    accounts,
+                    src_chain_id,
+                    remote_gmp_endpoint_addr,
+                    payload,
+                )
+            }
+            EscrowInstruction::SweepVaultExcess { intent_id } => {
+                msg!("Instruction: SweepVaultExcess");
+                Self::process_sweep_vault_excess(program_id, accounts, intent_id)
+            }
+            EscrowInstruction::ExtendExpiry {
+                intent_id,
+                new_duration,
+            } => {
+                msg!("Instruction: ExtendExpiry");
+                Self::process_extend_expiry(program_id, accounts, intent_id, new_duration)
+            }
         }
     }
 
@@ -266,6 +306,7 @@ impl Processor {
         accounts: &[AccountInfo],
         intent_id: [u8; 32],
         amount: u64,
+        refund_recipient: Option<Pubkey>,
     ) -> ProgramResult {
         let account_info_iter = &mut accounts.iter();
         let escrow_account = next_account_info(account_info_iter)?;
@@ -279,6 +320,12 @@ impl Processor {
         let _rent_sysvar = next_account_info(account_info_iter)?;
         // Requirements account (mandatory) - validates against stored GMP requirements
         let requirements_account = next_account_info(account_info_iter)?;
+        // Optional fee payer for sponsored transactions. Only a payer can sign at this
+        // position (the GMP config PDA that otherwise follows never signs).
+        let payer = match account_info_iter.as_slice().first() {
+            Some(account) if account.is_signer => next_account_info(account_info_iter)?,
+            _ => requester,
+        };
 
         // Validate inputs
         if amount == 0 {
@@ -290,12 +337,54 @@ impl Processor {
         if !requester.is_signer {
             return Err(ProgramError::MissingRequiredSignature);
         }
+        // Native SOL escrows pass the system program as mint and token program, and
+        // the requester's wallet as the source account
+        let native = *token_mint.key == Pubkey::default();
+        if native {
+            if *token_program.key != system_program::id() {
+                msg!(
+                    "Native SOL escrow expects the system program, got {}",
+                    token_program.key
+                );
+                return Err(ProgramError::IncorrectProgramId);
+            }
+            if requester_token_account.key != requester.key {
+                return Err(EscrowError::InvalidRecipient.into());
+            }
+        } else {
+            // The vault lives under the mint's token program (classic SPL Token or Token-2022)
+            if !token_extensions::is_supported_token_program(token_program.key) {
+                msg!("Unsupported token program {}", token_program.key);
+                return Err(ProgramError::IncorrectProgramId);
+            }
+            if token_mint.owner != token_program.key {
+                msg!(
+                    "Mint {} is not owned by token program {}",
+                    token_mint.key,
+                    token_program.key
+                );
+                return Err(EscrowError::InvalidMint.into());
+            }
+            // Reject a source account of another mint before any account is created
+            let source_mint = {
+                let source_data = requester_token_account.data.borrow();
+                let base = source_data
+                    .get(..TokenAccount::LEN)
+                    .ok_or(EscrowError::TokenMintMismatch)?;
+                TokenAccount::unpack_from_slice(base)?.mint
+            };
+            if source_mint != *token_mint.key {
+                msg!(
+                    "Source token account mint {} does not match escrow mint {}",
+                    source_mint,
+                    token_mint.key
+                );
+                return Err(EscrowError::TokenMintMismatch.into());
+            }
+        }
 
         // Validate requirements account PDA
-        let (req_pda, _) = Pubkey::find_program_address(
-            &[seeds::REQUIREMENTS_SEED, &intent_id],
-            program_id,
-        );
+        let (req_pda, _) = pda::requirements_pda(program_id, &intent_id);
         if req_pda != *requirements_account.key {
             return Err(EscrowError::InvalidPda.into());
         }
@@ -315,17 +404,19 @@ impl Processor {
         if token_bytes != requirements.token_addr {
             return Err(EscrowError::TokenMismatch.into());
         }
+        // Validate reserved solver - must be the solver the hub authorized
+        if reserved_solver.key.to_bytes() != requirements.solver_addr {
+            return Err(EscrowError::SolverMismatch.into());
+        }
 
         // Derive escrow PDA
-        let (escrow_pda, escrow_bump) =
-            Pubkey::find_program_address(&[seeds::ESCROW_SEED, &intent_id], program_id);
+        let (escrow_pda, escrow_bump) = pda::escrow_pda(program_id, &intent_id);
         if escrow_pda != *escrow_account.key {
             return Err(EscrowError::InvalidPda.into());
         }
 
         // Derive vault PDA
-        let (vault_pda, vault_bump) =
-            Pubkey::find_program_address(&[seeds::VAULT_SEED, &intent_id], program_id);
+        let (vault_pda, vault_bump) = pda::vault_pda(program_id, &intent_id);
         if vault_pda != *escrow_vault.key {
             return Err(EscrowError::InvalidPda.into());
         }
@@ -333,7 +424,7 @@ impl Processor {
         // Check if escrow already exists
         if escrow_account.data_len() > 0 {
             // Account exists, try to deserialize it
-            if let Ok(existing_escrow) = Escrow::try_from_slice(&escrow_account.data.borrow()) {
+            if let Ok(existing_escrow) = Escrow::unpack(&escrow_account.data.borrow()) {
                 // Check if it's a valid escrow (has correct discriminator)
                 if existing_escrow.discriminator == Escrow::DISCRIMINATOR {
                     return Err(EscrowError::EscrowAlreadyExists.into());
@@ -350,75 +441,153 @@ impl Processor {
         } else {
             requirements.expiry as i64
         };
-        if clock.unix_timestamp > expiry {
-            return Err(EscrowError::IntentExpired.into());
+        // Invariant: an escrow must never be born at or past its expiry, or it
+        // would be cancellable immediately after creation. The MIN/MAX expiry
+        // duration bounds are not applied here: the hub already accepted this
+        // expiry (u64::MAX meaning none), and rejecting it would strand the intent.
+        if expiry <= clock.unix_timestamp {
+            msg!(
+                "Invalid expiry: expiry={} is not after now={}",
+                expiry,
+                clock.unix_timestamp
+            );
+            return Err(EscrowError::InvalidExpiry.into());
+        }
+
+        if !native {
+            // Releases move the vault with a plain Transfer, which these extensions prevent
+            if let Some(extension_type) = token_extensions::unsupported_mint_extension(
+                token_mint.owner,
+                &token_mint.data.borrow(),
+            )? {
+                // A transfer fee would deliver less than `amount` to the vault; the
+                // rate is only logged, never used to escrow a net amount
+                if extension_type == token_extensions::EXTENSION_TRANSFER_FEE_CONFIG {
+                    if let Some(fee) = token_extensions::mint_transfer_fee(
+                        token_mint.owner,
+                        &token_mint.data.borrow(),
+                        clock.epoch,
+                    )? {
+                        msg!(
+                            "Mint {} charges a transfer fee ({} bps, max {})",
+                            token_mint.key,
+                            fee.basis_points,
+                            fee.maximum_fee
+                        );
+                    }
+                    return Err(EscrowError::TransferFeeMint.into());
+                }
+                msg!(
+                    "Mint {} has unsupported Token-2022 extension type {}",
+                    token_mint.key,
+                    extension_type
+                );
+                return Err(EscrowError::UnsupportedMintExtension.into());
+            }
         }
 
-        // Create escrow account
+        // Create escrow account (rent funded by the payer, requester by default)
         let rent = Rent::get()?;
         let escrow_space = Escrow::LEN;
         let escrow_lamports = rent.minimum_balance(escrow_space);
 
         invoke_signed(
             &system_instruction::create_account(
-                requester.key,
+                payer.key,
                 escrow_account.key,
                 escrow_lamports,
                 escrow_space as u64,
                 program_id,
             ),
-            &[requester.clone(), escrow_account.clone(), system_program.clone()],
+            &[payer.clone(), escrow_account.clone(), system_program.clone()],
             &[&[seeds::ESCROW_SEED, &intent_id, &[escrow_bump]]],
         )?;
 
-        // Create vault token account
-        let vault_space = TokenAccount::LEN;
-        let vault_lamports = rent.minimum_balance(vault_space);
+        if native {
+            // Native SOL vault: a system-owned PDA holding its rent-exempt reserve plus
+            // `amount`. Funding by transfer also works if the PDA was pre-funded.
+            let reserve = rent
+                .minimum_balance(0)
+                .saturating_sub(escrow_vault.lamports());
+            if reserve > 0 {
+                invoke(
+                    &system_instruction::transfer(payer.key, escrow_vault.key, reserve),
+                    &[payer.clone(), escrow_vault.clone(), system_program.clone()],
+                )?;
+            }
+            invoke(
+                &system_instruction::transfer(requester.key, escrow_vault.key, amount),
+                &[
+                    requester.clone(),
+                    escrow_vault.clone(),
+                    system_program.clone(),
+                ],
+            )?;
+        } else {
+            let decimals = {
+                let mint_data = token_mint.data.borrow();
+                let base = mint_data.get(..Mint::LEN).ok_or(EscrowError::InvalidMint)?;
+                Mint::unpack_from_slice(base)?.decimals
+            };
 
-        invoke_signed(
-            &system_instruction::create_account(
-                requester.key,
-                escrow_vault.key,
-                vault_lamports,
-                vault_space as u64,
-                &spl_token::id(),
-            ),
-            &[requester.clone(), escrow_vault.clone(), system_program.clone()],
-            &[&[seeds::VAULT_SEED, &intent_id, &[vault_bump]]],
-        )?;
+            // Create vault token account
+            let vault_space = TokenAccount::LEN;
+            let vault_lamports = rent.minimum_balance(vault_space);
 
-        // Initialize vault token account
-        invoke_signed(
-            &spl_token::instruction::initialize_account3(
-                &spl_token::id(),
-                escrow_vault.key,
-                token_mint.key,
-                escrow_account.key, // escrow PDA is the authority
-            )?,
-            &[escrow_vault.clone(), token_mint.clone()],
-            &[&[seeds::VAULT_SEED, &intent_id, &[vault_bump]]],
-        )?;
+            invoke_signed(
+                &system_instruction::create_account(
+                    payer.key,
+                    escrow_vault.key,
+                    vault_lamports,
+                    vault_space as u64,
+                    token_program.key,
+                ),
+                &[payer.clone(), escrow_vault.clone(), system_program.clone()],
+                &[&[seeds::VAULT_SEED, &intent_id, &[vault_bump]]],
+            )?;
 
-        // Transfer tokens to vault
-        invoke(
-            &spl_token::instruction::transfer(
-                &spl_token::id(),
-                requester_token_account.key,
-                escrow_vault.key,
-                requester.key,
-                &[],
-                amount,
-            )?,
-            &[
-                requester_token_account.clone(),
-                escrow_vault.clone(),
-                requester.clone(),
-                token_program.clone(),
-            ],
-        )?;
+            // Initialize vault token account
+            invoke_signed(
+                &token_extensions::for_token_program(
+                    spl_token::instruction::initialize_account3(
+                        &spl_token::id(),
+                        escrow_vault.key,
+                        token_mint.key,
+                        escrow_account.key, // escrow PDA is the authority
+                    )?,
+                    token_program.key,
+                ),
+                &[escrow_vault.clone(), token_mint.clone()],
+                &[&[seeds::VAULT_SEED, &intent_id, &[vault_bump]]],
+            )?;
+
+            // Transfer tokens to vault
+            invoke(
+                &token_extensions::for_token_program(
+                    spl_token::instruction::transfer_checked(
+                        &spl_token::id(),
+                        requester_token_account.key,
+                        token_mint.key,
+                        escrow_vault.key,
+                        requester.key,
+                        &[],
+                        amount,
+                        decimals,
+                    )?,
+                    token_program.key,
+                ),
+                &[
+                    requester_token_account.clone(),
+                    token_mint.clone(),
+                    escrow_vault.clone(),
+                    requester.clone(),
+                    token_program.clone(),
+                ],
+            )?;
+        }
 
         // Initialize escrow state
-        let escrow = Escrow::new(
+        let mut escrow = Escrow::new(
             *requester.key,
             *token_mint.key,
             amount,
@@ -427,7 +596,10 @@ impl Processor {
             intent_id,
             escrow_bump,
         );
-        escrow.serialize(&mut &mut escrow_account.data.borrow_mut()[..])?;
+        if let Some(refund_recipient) = refund_recipient {
+            escrow.refund_recipient = refund_recipient;
+        }
+        escrow.pack(&mut escrow_account.data.borrow_mut())?;
 
         // Mark requirements as having escrow created and send EscrowConfirmation
         {
@@ -537,15 +709,25 @@ impl Processor {
         let escrow_vault = next_account_info(account_info_iter)?;
         let solver_token_account = next_account_info(account_info_iter)?;
         let token_program = next_account_info(account_info_iter)?;
+        let state_account = next_account_info(account_info_iter)?;
 
-        // Validate requirements PDA
-        let (req_pda, _) = Pubkey::find_program_address(
-            &[seeds::REQUIREMENTS_SEED, &intent_id],
+        Self::validate_release_accounts(
             program_id,
-        );
+            &intent_id,
+            escrow_account,
+            escrow_vault,
+            solver_token_account,
+            token_program,
+        )?;
+
+        // Validate requirements PDA
+        let (req_pda, _) = pda::requirements_pda(program_id, &intent_id);
         if req_pda != *requirements_account.key {
             return Err(EscrowError::InvalidPda.into());
         }
+        if requirements_account.owner != program_id {
+            return Err(EscrowError::InvalidAccountOwner.into());
+        }
 
         // Load and validate requirements
         let requirements =
@@ -554,56 +736,59 @@ impl Processor {
 
         // GMP mode: require fulfillment proof to have been received
         if !requirements.fulfilled {
-            return Err(EscrowError::AlreadyFulfilled.into()); // Not fulfilled yet
+            return Err(EscrowError::NotFulfilled.into());
         }
 
         // Deserialize escrow
-        let mut escrow = Escrow::try_from_slice(&escrow_account.data.borrow())?;
+        let mut escrow = Escrow::unpack(&escrow_account.data.borrow())?;
+
+        // Capture approval audit info before mutating state. The stored requirements
+        // record the hub's approval (the fulfilled flag set by the FulfillmentProof).
+        let state = Self::load_state(program_id, state_account)?;
+        let approval_hash = hash(&requirements_account.data.borrow()).to_bytes();
 
         // Validate escrow
         if escrow.intent_id != intent_id {
             return Err(EscrowError::EscrowDoesNotExist.into());
         }
-        if escrow.is_claimed {
-            return Err(EscrowError::EscrowAlreadyClaimed.into());
-        }
+        Self::ensure_escrow_token_matches(&escrow, &requirements)?;
+        Self::ensure_native_recipient(&escrow, solver_token_account, &escrow.reserved_solver)?;
+        // Claim is only permitted from Active
+        escrow.transition_to(EscrowStatus::Claimed)?;
         if escrow.amount == 0 {
             return Err(EscrowError::NoDeposit.into());
         }
 
+        let deadline = state.expiry_deadline(escrow.expiry);
         let clock = Clock::get()?;
-        if clock.unix_timestamp > escrow.expiry {
+        if clock.unix_timestamp > deadline {
             return Err(EscrowError::EscrowExpired.into());
         }
 
         // Transfer tokens from vault to solver
         let amount = escrow.amount;
-        let escrow_seeds = &[seeds::ESCROW_SEED, &intent_id[..], &[escrow.bump]];
-
-        invoke_signed(
-            &spl_token::instruction::transfer(
-                &spl_token::id(),
-                escrow_vault.key,
-                solver_token_account.key,
-                escrow_account.key,
-                &[],
-                amount,
-            )?,
-            &[
-                escrow_vault.clone(),
-                solver_token_account.clone(),
-                escrow_account.clone(),
-                token_program.clone(),
-            ],
-            &[escrow_seeds],
+        Self::transfer_from_vault(
+            program_id,
+            &escrow,
+            escrow_account,
+            escrow_vault,
+            solver_token_account,
+            token_program,
+            amount,
         )?;
 
         // Update escrow state
-        escrow.is_claimed = true;
         escrow.amount = 0;
-        escrow.serialize(&mut &mut escrow_account.data.borrow_mut()[..])?;
+        escrow.pack(&mut escrow_account.data.borrow_mut())?;
 
         msg!("Escrow claimed: intent_id={:?}, amount={}", &intent_id[..8], amount);
+        events::emit_escrow_claimed(
+            &intent_id,
+            solver_token_account.key,
+            amount,
+            &state.approver,
+            &approval_hash,
+        );
         Ok(())
     }
 
@@ -616,20 +801,30 @@ impl Processor {
         let escrow_account = next_account_info(account_info_iter)?;
         let caller = next_account_info(account_info_iter)?;
         let escrow_vault = next_account_info(account_info_iter)?;
-        let requester_token_account = next_account_info(account_info_iter)?;
+        let refund_account = next_account_info(account_info_iter)?;
         let token_program = next_account_info(account_info_iter)?;
         let gmp_config_account = next_account_info(account_info_iter)?;
+        let state_account = next_account_info(account_info_iter)?;
+
+        Self::validate_release_accounts(
+            program_id,
+            &intent_id,
+            escrow_account,
+            escrow_vault,
+            refund_account,
+            token_program,
+        )?;
 
         // Deserialize escrow
-        let mut escrow = Escrow::try_from_slice(&escrow_account.data.borrow())?;
+        let mut escrow = Escrow::unpack(&escrow_account.data.borrow())?;
 
         // Validate
         if escrow.intent_id != intent_id {
             return Err(EscrowError::EscrowDoesNotExist.into());
         }
-        if escrow.is_claimed {
-            return Err(EscrowError::EscrowAlreadyClaimed.into());
-        }
+        Self::ensure_refund_recipient(&escrow, refund_account)?;
+        // Cancel is only permitted from Active or Expired
+        escrow.transition_to(EscrowStatus::Cancelled)?;
         if escrow.amount == 0 {
             return Err(EscrowError::NoDeposit.into());
         }
@@ -649,39 +844,471 @@ impl Processor {
             return Err(EscrowError::UnauthorizedCaller.into());
         }
 
+        let state = Self::load_state(program_id, state_account)?;
+        let deadline = state.expiry_deadline(escrow.expiry);
         let clock = Clock::get()?;
-        if clock.unix_timestamp <= escrow.expiry {
+        if clock.unix_timestamp <= deadline {
             return Err(EscrowError::EscrowNotExpiredYet.into());
         }
 
-        // Transfer tokens back to requester
+        // Transfer tokens back to the refund recipient
         let amount = escrow.amount;
-        let escrow_seeds = &[seeds::ESCROW_SEED, &intent_id[..], &[escrow.bump]];
-
-        invoke_signed(
-            &spl_token::instruction::transfer(
-                &spl_token::id(),
-                escrow_vault.key,
-                requester_token_account.key,
-                escrow_account.key,
-                &[],
-                amount,
-            )?,
-            &[
-                escrow_vault.clone(),
-                requester_token_account.clone(),
-                escrow_account.clone(),
-                token_program.clone(),
-            ],
-            &[escrow_seeds],
+        Self::transfer_from_vault(
+            program_id,
+            &escrow,
+            escrow_account,
+            escrow_vault,
+            refund_account,
+            token_program,
+            amount,
         )?;
 
         // Update escrow state
-        escrow.is_claimed = true;
         escrow.amount = 0;
-        escrow.serialize(&mut &mut escrow_account.data.borrow_mut()[..])?;
+        escrow.pack(&mut escrow_account.data.borrow_mut())?;
+
+        events::emit_escrow_cancelled(&intent_id, refund_account.key, amount);
+        Ok(())
+    }
+
+    /// Transfer vault tokens above `escrow.amount` to a recovery account (GMP config admin only).
+    fn process_sweep_vault_excess(
+        program_id: &Pubkey,
+        accounts: &[AccountInfo],
+        intent_id: [u8; 32],
+    ) -> ProgramResult {
+        let account_info_iter = &mut accounts.iter();
+        let escrow_account = next_account_info(account_info_iter)?;
+        let admin = next_account_info(account_info_iter)?;
+        let escrow_vault = next_account_info(account_info_iter)?;
+        let recovery_token_account = next_account_info(account_info_iter)?;
+        let token_program = next_account_info(account_info_iter)?;
+        let gmp_config_account = next_account_info(account_info_iter)?;
+
+        Self::validate_release_accounts(
+            program_id,
+            &intent_id,
+            escrow_account,
+            escrow_vault,
+            recovery_token_account,
+            token_program,
+        )?;
+
+        if !admin.is_signer {
+            return Err(ProgramError::MissingRequiredSignature);
+        }
+        let (config_pda, _) = Pubkey::find_program_address(&[seeds::GMP_CONFIG_SEED], program_id);
+        if config_pda != *gmp_config_account.key {
+            return Err(EscrowError::InvalidPda.into());
+        }
+        let config = GmpConfig::try_from_slice(&gmp_config_account.data.borrow())
+            .map_err(|_| EscrowError::AccountNotInitialized)?;
+        if config.admin != *admin.key {
+            return Err(EscrowError::UnauthorizedCaller.into());
+        }
+
+        let escrow = Escrow::unpack(&escrow_account.data.borrow())?;
+        if escrow.intent_id != intent_id {
+            return Err(EscrowError::EscrowDoesNotExist.into());
+        }
+
+        // escrow.amount is 0 once claimed or cancelled, so the whole balance is excess
+        let excess = Self::vault_balance(escrow_vault)?.saturating_sub(escrow.amount);
+        if excess == 0 {
+            return Err(EscrowError::NoVaultExcess.into());
+        }
+
+        Self::transfer_from_vault(
+            program_id,
+            &escrow,
+            escrow_account,
+            escrow_vault,
+            recovery_token_account,
+            token_program,
+            excess,
+        )?;
+
+        msg!(
+            "Vault excess swept: intent_id={:?}, amount={}",
+            &intent_id[..8],
+            excess
+        );
+        Ok(())
+    }
+
+    /// Push an active escrow's expiry to `now + new_duration` (requester only).
+    /// `new_duration` must lie within `[MIN_EXPIRY_DURATION, MAX_EXPIRY_DURATION]`.
+    fn process_extend_expiry(
+        program_id: &Pubkey,
+        accounts: &[AccountInfo],
+        intent_id: [u8; 32],
+        new_duration: i64,
+    ) -> ProgramResult {
+        let account_info_iter = &mut accounts.iter();
+        let escrow_account = next_account_info(account_info_iter)?;
+        let requester = next_account_info(account_info_iter)?;
+        let state_account = next_account_info(account_info_iter)?;
+
+        if !requester.is_signer {
+            return Err(ProgramError::MissingRequiredSignature);
+        }
+
+        let (escrow_pda, _) = pda::escrow_pda(program_id, &intent_id);
+        if escrow_account.owner != program_id {
+            return Err(EscrowError::InvalidAccountOwner.into());
+        }
+        if escrow_pda != *escrow_account.key {
+            return Err(EscrowError::InvalidPda.into());
+        }
+
+        let mut escrow = Escrow::unpack(&escrow_account.data.borrow())?;
+        if escrow.intent_id != intent_id {
+            return Err(EscrowError::EscrowDoesNotExist.into());
+        }
+        if escrow.requester != *requester.key {
+            return Err(EscrowError::UnauthorizedCaller.into());
+        }
+        match escrow.status {
+            EscrowStatus::Active => {}
+            EscrowStatus::Claimed => return Err(EscrowError::EscrowAlreadyClaimed.into()),
+            _ => return Err(EscrowError::InvalidEscrowStatus.into()),
+        }
+
+        // Once the claim window has closed the escrow is only cancellable;
+        // extending it would revive an expiry the solver already lost
+        let state = Self::load_state(program_id, state_account)?;
+        let clock = Clock::get()?;
+        if clock.unix_timestamp > state.expiry_deadline(escrow.expiry) {
+            return Err(EscrowError::EscrowExpired.into());
+        }
+        let new_expiry = clock
+            .unix_timestamp
+            .checked_add(new_duration)
+            .ok_or(EscrowError::InvalidExpiry)?;
+        if new_expiry <= escrow.expiry {
+            msg!(
+                "Expiry not extended: new_expiry={} is not after current expiry={}",
+                new_expiry,
+                escrow.expiry
+            );
+            return Err(EscrowError::ExpiryNotExtended.into());
+        }
+        if new_duration < Escrow::MIN_EXPIRY_DURATION {
+            msg!(
+                "Expiry duration {} is below the minimum of {}",
+                new_duration,
+                Escrow::MIN_EXPIRY_DURATION
+            );
+            return Err(EscrowError::ExpiryTooShort.into());
+        }
+        if new_duration > Escrow::MAX_EXPIRY_DURATION {
+            msg!(
+                "Expiry duration {} is above the maximum of {}",
+                new_duration,
+                Escrow::MAX_EXPIRY_DURATION
+            );
+            return Err(EscrowError::ExpiryTooLong.into());
+        }
+
+        let old_expiry = escrow.expiry;
+        escrow.expiry = new_expiry;
+        escrow.pack(&mut escrow_account.data.borrow_mut())?;
+
+        events::emit_escrow_expiry_extended(&intent_id, old_expiry, new_expiry);
+        Ok(())
+    }
+
+    /// Set the clock skew tolerance stored in the program state (GMP config admin only).
+    fn process_set_clock_skew_tolerance(
+        program_id: &Pubkey,
+        accounts: &[AccountInfo],
+        tolerance_secs: i64,
+    ) -> ProgramResult {
+        let account_info_iter = &mut accounts.iter();
+        let state_account = next_account_info(account_info_iter)?;
+        let admin = next_account_info(account_info_iter)?;
+        let gmp_config_account = next_account_info(account_info_iter)?;
+        let system_program = next_account_info(account_info_iter)?;
+
+        if !admin.is_signer {
+            return Err(ProgramError::MissingRequiredSignature);
+        }
+
+        let (state_pda, _) = Pubkey::find_program_address(&[seeds::STATE_SEED], program_id);
+        if state_pda != *state_account.key {
+            return Err(EscrowError::InvalidPda.into());
+        }
+        if *system_program.key != system_program::id() {
+            return Err(ProgramError::IncorrectProgramId);
+        }
+        let (config_pda, _) =
+            Pubkey::find_program_address(&[seeds::GMP_CONFIG_SEED], program_id);
+        if config_pda != *gmp_config_account.key {
+            return Err(EscrowError::InvalidPda.into());
+        }
+        let config = GmpConfig::try_from_slice(&gmp_config_account.data.borrow())
+            .map_err(|_| EscrowError::AccountNotInitialized)?;
+        if config.admin != *admin.key {
+            return Err(EscrowError::UnauthorizedCaller.into());
+        }
+
+        if !(0..=EscrowState::MAX_CLOCK_SKEW_TOLERANCE).contains(&tolerance_secs) {
+            return Err(EscrowError::InvalidClockSkewTolerance.into());
+        }
+
+        let mut state = EscrowState::unpack(&state_account.data.borrow())?;
+
+        // Migrate a legacy state account to the layout that stores the tolerance;
+        // the admin funds the extra rent.
+        if state_account.data_len() < EscrowState::LEN {
+            let rent = Rent::get()?;
+            let shortfall = rent
+                .minimum_balance(EscrowState::LEN)
+                .saturating_sub(state_account.lamports());
+            if shortfall > 0 {
+                invoke(
+                    &system_instruction::transfer(admin.key, state_account.key, shortfall),
+                    &[admin.clone(), state_account.clone(), system_program.clone()],
+                )?;
+            }
+            state_account.realloc(EscrowState::LEN, false)?;
+            msg!("State account migrated to {} bytes", EscrowState::LEN);
+        }
+
+        state.clock_skew_tolerance = tolerance_secs;
+        state.pack(&mut state_account.data.borrow_mut())?;
+
+        msg!("Clock skew tolerance set to {}s", tolerance_secs);
+        Ok(())
+    }
+
+    /// Load the program state after checking its PDA.
+    /// Releases and cancels require the state account, so the caller cannot choose
+    /// whether the clock skew tolerance applies or which approver is recorded.
+    fn load_state(
+        program_id: &Pubkey,
+        state_account: &AccountInfo,
+    ) -> Result<EscrowState, ProgramError> {
+        let (state_pda, _) = Pubkey::find_program_address(&[seeds::STATE_SEED], program_id);
+        if state_pda != *state_account.key {
+            return Err(EscrowError::InvalidPda.into());
+        }
+        let state = EscrowState::unpack(&state_account.data.borrow())?;
+        Ok(state)
+    }
+
+    /// Re-verify at release time that the escrowed mint is the token the hub required.
+    /// Guards against requirements overwritten after creation or an escrow created
+    /// outside the requirements flow releasing the wrong token.
+    fn ensure_escrow_token_matches(
+        escrow: &Escrow,
+        requirements: &StoredIntentRequirements,
+    ) -> ProgramResult {
+        if escrow.token_mint.to_bytes() != requirements.token_addr {
+            msg!(
+                "Token mismatch at release: escrow mint={}, required token={}",
+                escrow.token_mint,
+                Pubkey::new_from_array(requirements.token_addr)
+            );
+            return Err(EscrowError::TokenMismatch.into());
+        }
+        Ok(())
+    }
+
+    /// Native SOL is paid straight to a wallet, so the recipient must be the wallet the
+    /// escrow names (reserved solver on release, requester on cancel).
+    fn ensure_native_recipient(
+        escrow: &Escrow,
+        recipient: &AccountInfo,
+        expected: &Pubkey,
+    ) -> ProgramResult {
+        if escrow.is_native() && recipient.key != expected {
+            msg!(
+                "Native SOL recipient {} does not match expected wallet {}",
+                recipient.key,
+                expected
+            );
+            return Err(EscrowError::InvalidRecipient.into());
+        }
+        Ok(())
+    }
+
+    /// Check that a cancel refunds the account chosen at creation, or the requester
+    /// when none was chosen, and that a token refund account holds the escrow mint.
+    fn ensure_refund_recipient(escrow: &Escrow, refund_account: &AccountInfo) -> ProgramResult {
+        match escrow.refund_recipient() {
+            Some(expected) if *refund_account.key != expected => {
+                msg!(
+                    "Refund account {} does not match refund recipient {}",
+                    refund_account.key,
+                    expected
+                );
+                return Err(EscrowError::InvalidRecipient.into());
+            }
+            Some(_) => {}
+            None => Self::ensure_native_recipient(escrow, refund_account, &escrow.requester)?,
+        }
+        if !escrow.is_native() {
+            let refund_mint = {
+                let refund_data = refund_account.data.borrow();
+                let base = refund_data
+                    .get(..TokenAccount::LEN)
+                    .ok_or(EscrowError::TokenMintMismatch)?;
+                TokenAccount::unpack_from_slice(base)?.mint
+            };
+            if refund_mint != escrow.token_mint {
+                msg!(
+                    "Refund account mint {} does not match escrow mint {}",
+                    refund_mint,
+                    escrow.token_mint
+                );
+                return Err(EscrowError::TokenMintMismatch.into());
+            }
+        }
+        Ok(())
+    }
+
+    /// Check the positional accounts of a release (claim, cancel, or auto-release)
+    /// before any state is read, so a swapped or substituted account fails with a
+    /// specific error instead of deep inside the token CPI.
+    fn validate_release_accounts(
+        program_id: &Pubkey,
+        intent_id: &[u8; 32],
+        escrow_account: &AccountInfo,
+        escrow_vault: &AccountInfo,
+        recipient_token_account: &AccountInfo,
+        token_program: &AccountInfo,
+    ) -> ProgramResult {
+        // Native SOL escrows release through the system program
+        if *token_program.key != system_program::id()
+            && !token_extensions::is_supported_token_program(token_program.key)
+        {
+            msg!("Expected an SPL token program, got {}", token_program.key);
+            return Err(ProgramError::IncorrectProgramId);
+        }
+
+        let (escrow_pda, _) = pda::escrow_pda(program_id, intent_id);
+        if escrow_account.owner != program_id {
+            msg!(
+                "Escrow account {} is not owned by this program",
+                escrow_account.key
+            );
+            return Err(EscrowError::InvalidAccountOwner.into());
+        }
+        if escrow_pda != *escrow_account.key {
+            msg!(
+                "Escrow account {} is not the escrow PDA",
+                escrow_account.key
+            );
+            return Err(EscrowError::InvalidPda.into());
+        }
+
+        let (vault_pda, _) = pda::vault_pda(program_id, intent_id);
+        if escrow_vault.owner != token_program.key {
+            msg!(
+                "Escrow vault {} is not owned by {}",
+                escrow_vault.key,
+                token_program.key
+            );
+            return Err(EscrowError::InvalidAccountOwner.into());
+        }
+        if vault_pda != *escrow_vault.key {
+            msg!("Escrow vault {} is not the vault PDA", escrow_vault.key);
+            return Err(EscrowError::InvalidPda.into());
+        }
+
+        if recipient_token_account.owner != token_program.key {
+            msg!(
+                "Recipient {} is not owned by {}",
+                recipient_token_account.key,
+                token_program.key
+            );
+            return Err(EscrowError::InvalidAccountOwner.into());
+        }
+        Ok(())
+    }
+
+    /// Move `amount` from an escrow vault to `recipient` and confirm the vault was debited.
+    ///
+    /// Token vaults are token accounts whose authority is the escrow PDA. Native SOL
+    /// vaults are system-owned PDAs that sign for themselves.
+    fn transfer_from_vault<'a>(
+        program_id: &Pubkey,
+        escrow: &Escrow,
+        escrow_account: &AccountInfo<'a>,
+        escrow_vault: &AccountInfo<'a>,
+        recipient: &AccountInfo<'a>,
+        token_program: &AccountInfo<'a>,
+        amount: u64,
+    ) -> ProgramResult {
+        let vault_balance_before = Self::vault_balance(escrow_vault)?;
+        if escrow.is_native() {
+            let (_, vault_bump) = pda::vault_pda(program_id, &escrow.intent_id);
+            invoke_signed(
+                &system_instruction::transfer(escrow_vault.key, recipient.key, amount),
+                &[
+                    escrow_vault.clone(),
+                    recipient.clone(),
+                    token_program.clone(),
+                ],
+                &[&[seeds::VAULT_SEED, &escrow.intent_id[..], &[vault_bump]]],
+            )?;
+        } else {
+            invoke_signed(
+                &token_extensions::for_token_program(
+                    spl_token::instruction::transfer(
+                        &spl_token::id(),
+                        escrow_vault.key,
+                        recipient.key,
+                        escrow_account.key,
+                        &[],
+                        amount,
+                    )?,
+                    token_program.key,
+                ),
+                &[
+                    escrow_vault.clone(),
+                    recipient.clone(),
+                    escrow_account.clone(),
+                    token_program.clone(),
+                ],
+                &[&[seeds::ESCROW_SEED, &escrow.intent_id[..], &[escrow.bump]]],
+            )?;
+        }
+        Self::verify_vault_debited(escrow_vault, vault_balance_before, amount)
+    }
+
+    /// Read the balance of an escrow vault: SPL tokens, or for a native SOL vault
+    /// the lamports above its rent-exempt reserve.
+    fn vault_balance(vault: &AccountInfo) -> Result<u64, ProgramError> {
+        if *vault.owner == system_program::id() {
+            let reserve = Rent::get()?.minimum_balance(0);
+            return Ok(vault.lamports().saturating_sub(reserve));
+        }
+        Ok(TokenAccount::unpack(&vault.data.borrow())?.amount)
+    }
 
-        msg!("Escrow cancelled: intent_id={:?}, amount={}", &intent_id[..8], amount);
+    /// Confirm a release debited the vault by exactly `amount`.
+    /// A different delta (partial or fee-on-transfer) would leave funds stranded in the vault.
+    /// Tokens donated to the vault beyond the escrow amount remain (see `SweepVaultExcess`)
+    /// and do not block release.
+    fn verify_vault_debited(
+        vault: &AccountInfo,
+        balance_before: u64,
+        amount: u64,
+    ) -> ProgramResult {
+        let expected = balance_before
+            .checked_sub(amount)
+            .ok_or(EscrowError::VaultBalanceMismatch)?;
+        let actual = Self::vault_balance(vault)?;
+        if actual != expected {
+            msg!(
+                "WARNING: vault balance after release is {}, expected {}",
+                actual,
+                expected
+            );
+            return Err(EscrowError::VaultBalanceMismatch.into());
+        }
         Ok(())
     }
 
@@ -702,6 +1329,93 @@ impl Processor {
         let payer = next_account_info(account_info_iter)?;
         let system_program = next_account_info(account_info_iter)?;
 
+        Self::validate_hub_source(
+            program_id,
+            gmp_config_account,
+            gmp_caller,
+            src_chain_id,
+            &remote_gmp_endpoint_addr,
+        )?;
+
+        // Decode the GMP message
+        let requirements = IntentRequirements::decode(&payload)
+            .map_err(|_| EscrowError::InvalidGmpMessage)?;
+
+        Self::store_requirements(
+            program_id,
+            requirements_account,
+            payer,
+            system_program,
+            &requirements,
+            src_chain_id,
+        )
+    }
+
+    /// Process GmpReceiveRequirementsBatch instruction.
+    ///
+    /// Source and payload are validated up front, so a bad batch fails as a whole;
+    /// items whose requirements already exist are skipped individually.
+    fn process_gmp_receive_requirements_batch(
+        program_id: &Pubkey,
+        accounts: &[AccountInfo],
+        src_chain_id: u32,
+        remote_gmp_endpoint_addr: [u8; 32],
+        payload: Vec<u8>,
+    ) -> ProgramResult {
+        let account_info_iter = &mut accounts.iter();
+        let gmp_config_account = next_account_info(account_info_iter)?;
+        let gmp_caller = next_account_info(account_info_iter)?;
+        let payer = next_account_info(account_info_iter)?;
+        let system_program = next_account_info(account_info_iter)?;
+
+        Self::validate_hub_source(
+            program_id,
+            gmp_config_account,
+            gmp_caller,
+            src_chain_id,
+            &remote_gmp_endpoint_addr,
+        )?;
+
+        let batch = decode_requirements_batch(&payload)?;
+
+        // One requirements PDA per item, in payload order
+        let requirements_accounts = account_info_iter.as_slice();
+        if requirements_accounts.len() != batch.len() {
+            msg!(
+                "Requirements batch account count mismatch: expected {}, got {}",
+                batch.len(),
+                requirements_accounts.len()
+            );
+            return Err(ProgramError::NotEnoughAccountKeys);
+        }
+
+        for (requirements, requirements_account) in batch.iter().zip(requirements_accounts) {
+            Self::store_requirements(
+                program_id,
+                requirements_account,
+                payer,
+                system_program,
+                requirements,
+                src_chain_id,
+            )?;
+        }
+
+        msg!(
+            "IntentRequirementsBatchReceived: count={}, src_chain_id={}",
+            batch.len(),
+            src_chain_id
+        );
+        Ok(())
+    }
+
+    /// Check that a GMP delivery comes from a trusted caller on behalf of the hub.
+    fn validate_hub_source(
+        program_id: &Pubkey,
+        gmp_config_account: &AccountInfo,
+        gmp_caller: &AccountInfo,
+        src_chain_id: u32,
+        remote_gmp_endpoint_addr: &[u8; 32],
+    ) -> ProgramResult {
         // GMP caller must be a signer (trusted relay or endpoint)
         if !gmp_caller.is_signer {
             return Err(EscrowError::UnauthorizedGmpSource.into());
@@ -728,20 +1442,25 @@ impl Processor {
         }
 
         // Validate source address matches hub GMP endpoint
-        if remote_gmp_endpoint_addr != config.hub_gmp_endpoint_addr {
+        if *remote_gmp_endpoint_addr != config.hub_gmp_endpoint_addr {
             msg!("Invalid source address: not hub GMP endpoint");
             return Err(EscrowError::UnauthorizedGmpSource.into());
         }
 
-        // Decode the GMP message
-        let requirements = IntentRequirements::decode(&payload)
-            .map_err(|_| EscrowError::InvalidGmpMessage)?;
+        Ok(())
+    }
 
+    /// Create the requirements PDA for one intent, or skip it if it already exists.
+    fn store_requirements(
+        program_id: &Pubkey,
+        requirements_account: &AccountInfo,
+        payer: &AccountInfo,
+        system_program: &AccountInfo,
+        requirements: &IntentRequirements,
+        src_chain_id: u32,
+    ) -> ProgramResult {
         // Derive requirements PDA
-        let (req_pda, req_bump) = Pubkey::find_program_address(
-            &[seeds::REQUIREMENTS_SEED, &requirements.intent_id],
-            program_id,
-        );
+        let (req_pda, req_bump) = pda::requirements_pda(program_id, &requirements.intent_id);
         if req_pda != *requirements_account.key {
             return Err(EscrowError::InvalidPda.into());
         }
@@ -814,50 +1533,37 @@ impl Processor {
         let gmp_config_account = next_account_info(account_info_iter)?;
         let gmp_caller = next_account_info(account_info_iter)?;
         let token_program = next_account_info(account_info_iter)?;
+        let state_account = next_account_info(account_info_iter)?;
 
-        // GMP caller must be a signer (trusted relay or endpoint)
-        if !gmp_caller.is_signer {
-            return Err(EscrowError::UnauthorizedGmpSource.into());
-        }
-
-        // Load and validate GMP config
-        let (config_pda, _) =
-            Pubkey::find_program_address(&[seeds::GMP_CONFIG_SEED], program_id);
-        if config_pda != *gmp_config_account.key {
-            return Err(EscrowError::InvalidPda.into());
-        }
-
-        let config = GmpConfig::try_from_slice(&gmp_config_account.data.borrow())
-            .map_err(|_| EscrowError::AccountNotInitialized)?;
-
-        // Validate source chain matches hub GMP endpoint
-        if src_chain_id != config.hub_chain_id {
-            msg!(
-                "Invalid source chain: expected {}, got {}",
-                config.hub_chain_id,
-                src_chain_id
-            );
-            return Err(EscrowError::UnauthorizedGmpSource.into());
-        }
-
-        // Validate source address matches hub GMP endpoint
-        if remote_gmp_endpoint_addr != config.hub_gmp_endpoint_addr {
-            msg!("Invalid source address: not hub GMP endpoint");
-            return Err(EscrowError::UnauthorizedGmpSource.into());
-        }
+        Self::validate_hub_source(
+            program_id,
+            gmp_config_account,
+            gmp_caller,
+            src_chain_id,
+            &remote_gmp_endpoint_addr,
+        )?;
 
         // Decode the GMP message
         let proof = FulfillmentProof::decode(&payload)
             .map_err(|_| EscrowError::InvalidGmpMessage)?;
 
         // Validate requirements account
-        let (req_pda, _) = Pubkey::find_program_address(
-            &[seeds::REQUIREMENTS_SEED, &proof.intent_id],
-            program_id,
-        );
+        let (req_pda, _) = pda::requirements_pda(program_id, &proof.intent_id);
         if req_pda != *requirements_account.key {
             return Err(EscrowError::InvalidPda.into());
         }
+        if requirements_account.owner != program_id {
+            return Err(EscrowError::InvalidAccountOwner.into());
+        }
+
+        Self::validate_release_accounts(
+            program_id,
+            &proof.intent_id,
+            escrow_account,
+            escrow_vault,
+            solver_token_account,
+            token_program,
+        )?;
 
         let mut requirements =
             StoredIntentRequirements::try_from_slice(&requirements_account.data.borrow())
@@ -868,44 +1574,38 @@ impl Processor {
         }
 
         // Load escrow
-        let mut escrow = Escrow::try_from_slice(&escrow_account.data.borrow())?;
+        let mut escrow = Escrow::unpack(&escrow_account.data.borrow())?;
+
+        // Capture approval audit info before mutating state
+        let approver = Self::load_state(program_id, state_account)?.approver;
+        let approval_hash = hash(&payload).to_bytes();
 
         if escrow.intent_id != proof.intent_id {
             return Err(EscrowError::EscrowDoesNotExist.into());
         }
-        if escrow.is_claimed {
-            return Err(EscrowError::EscrowAlreadyClaimed.into());
-        }
+        Self::ensure_escrow_token_matches(&escrow, &requirements)?;
+        Self::ensure_native_recipient(&escrow, solver_token_account, &escrow.reserved_solver)?;
+        // Release is only permitted from Active
+        escrow.transition_to(EscrowStatus::Claimed)?;
         if escrow.amount == 0 {
             return Err(EscrowError::NoDeposit.into());
         }
 
         // Transfer tokens from vault to solver
         let amount = escrow.amount;
-        let escrow_seeds = &[seeds::ESCROW_SEED, &proof.intent_id[..], &[escrow.bump]];
-
-        invoke_signed(
-            &spl_token::instruction::transfer(
-                &spl_token::id(),
-                escrow_vault.key,
-                solver_token_account.key,
-                escrow_account.key,
-                &[],
-                amount,
-            )?,
-            &[
-                escrow_vault.clone(),
-                solver_token_account.clone(),
-                escrow_account.clone(),
-                token_program.clone(),
-            ],
-            &[escrow_seeds],
+        Self::transfer_from_vault(
+            program_id,
+            &escrow,
+            escrow_account,
+            escrow_vault,
+            solver_token_account,
+            token_program,
+            amount,
         )?;
 
         // Update states
-        escrow.is_claimed = true;
         escrow.amount = 0;
-        escrow.serialize(&mut &mut escrow_account.data.borrow_mut()[..])?;
+        escrow.pack(&mut escrow_account.data.borrow_mut())?;
 
         requirements.fulfilled = true;
         requirements.serialize(&mut &mut requirements_account.data.borrow_mut()[..])?;
@@ -915,6 +1615,13 @@ impl Processor {
             &proof.intent_id[..8],
             amount
         );
+        events::emit_escrow_claimed(
+            &proof.intent_id,
+            solver_token_account.key,
+            amount,
+            &approver,
+            &approval_hash,
+        );
         Ok(())
     }
 }
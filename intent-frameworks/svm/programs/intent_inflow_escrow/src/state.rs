@@ -3,6 +3,8 @@
 use borsh::{BorshDeserialize, BorshSerialize};
 use solana_program::pubkey::Pubkey;
 
+use crate::error::EscrowError;
+
 /// Global escrow state containing the authorized approver
 #[derive(BorshSerialize, BorshDeserialize, Debug, Clone)]
 pub struct EscrowState {
@@ -10,17 +12,104 @@ pub struct EscrowState {
     pub discriminator: [u8; 8],
     /// Authorized approver public key that can approve releases
     pub approver: Pubkey,
+    /// Seconds of clock skew tolerated around escrow expiry (0 = exact expiry).
+    /// Claims stay open until `expiry + tolerance`; cancels open after it.
+    pub clock_skew_tolerance: i64,
 }
 
 impl EscrowState {
     pub const DISCRIMINATOR: [u8; 8] = [0x45, 0x53, 0x43, 0x52, 0x4f, 0x57, 0x53, 0x54]; // "ESCROWST"
-    pub const LEN: usize = 8 + 32; // discriminator + approver pubkey
+    pub const LEN: usize = 8 + 32 + 8; // discriminator + approver pubkey + clock_skew_tolerance
+    /// Size of state accounts initialized before `clock_skew_tolerance` existed.
+    pub const LEGACY_LEN: usize = 8 + 32; // discriminator + approver pubkey
+    /// Upper bound for `clock_skew_tolerance`; skew beyond this is not clock drift.
+    pub const MAX_CLOCK_SKEW_TOLERANCE: i64 = 300;
 
     pub fn new(approver: Pubkey) -> Self {
         Self {
             discriminator: Self::DISCRIMINATOR,
             approver,
+            clock_skew_tolerance: 0,
+        }
+    }
+
+    /// Deserialize state account data, reading a legacy 40-byte account as a
+    /// tolerance of 0.
+    pub fn unpack(data: &[u8]) -> Result<Self, EscrowError> {
+        let state = if data.len() == Self::LEGACY_LEN {
+            let mut padded = data.to_vec();
+            padded.extend_from_slice(&0i64.to_le_bytes());
+            Self::try_from_slice(&padded)
+        } else {
+            Self::try_from_slice(data)
+        }
+        .map_err(|_| EscrowError::AccountNotInitialized)?;
+        if state.discriminator != Self::DISCRIMINATOR {
+            return Err(EscrowError::AccountNotInitialized);
+        }
+        Ok(state)
+    }
+
+    /// Serialize into state account data of either layout.
+    ///
+    /// A legacy account only holds the approver, so it cannot store a non-zero
+    /// tolerance until it is resized to `LEN`.
+    pub fn pack(&self, data: &mut [u8]) -> Result<(), EscrowError> {
+        let bytes = self
+            .try_to_vec()
+            .map_err(|_| EscrowError::InvalidInstructionData)?;
+        match data.len() {
+            Self::LEN => data.copy_from_slice(&bytes),
+            Self::LEGACY_LEN if self.clock_skew_tolerance == 0 => {
+                data.copy_from_slice(&bytes[..Self::LEGACY_LEN])
+            }
+            _ => return Err(EscrowError::AccountNotInitialized),
         }
+        Ok(())
+    }
+
+    /// Last timestamp at which an escrow with `expiry` may still be claimed.
+    /// Cancellation is allowed strictly after this instant, so the two windows never overlap.
+    pub fn expiry_deadline(&self, expiry: i64) -> i64 {
+        expiry.saturating_add(self.clock_skew_tolerance)
+    }
+}
+
+/// Lifecycle status of an escrow
+///
+/// Serialized as a single byte in the same slot the former `is_claimed` flag
+/// used, so `Active`/`Claimed` keep their previous on-chain encoding (0/1).
+///
+/// The mapping is lossy for escrows written before `status` existed: the old
+/// program also set `is_claimed = 1` on cancel, so a legacy cancelled escrow
+/// reads as `Claimed`. Only the stored requirements tell them apart (`fulfilled`
+/// is set on release, never on cancel).
+#[derive(BorshSerialize, BorshDeserialize, Debug, Clone, Copy, PartialEq, Eq)]
+pub enum EscrowStatus {
+    /// Funds are deposited and awaiting fulfillment or cancellation
+    Active,
+    /// Funds were released to the solver (or, for a legacy escrow, cancelled)
+    Claimed,
+    /// Funds were returned to the requester by the admin
+    Cancelled,
+    /// Expiry passed without a claim (reserved for expiry-driven flows)
+    Expired,
+    /// Funds of an expired escrow were recovered (reserved for expiry-driven flows)
+    Recovered,
+}
+
+impl EscrowStatus {
+    /// Returns true if the escrow may move from this status to `next`.
+    pub fn can_transition_to(self, next: EscrowStatus) -> bool {
+        use EscrowStatus::*;
+        matches!(
+            (self, next),
+            (Active, Claimed)
+                | (Active, Cancelled)
+                | (Active, Expired)
+                | (Expired, Cancelled)
+                | (Expired, Recovered)
+        )
     }
 }
 
@@ -35,8 +124,8 @@ pub struct Escrow {
     pub token_mint: Pubkey,
     /// Amount deposited
     pub amount: u64,
-    /// Whether funds have been claimed
-    pub is_claimed: bool,
+    /// Lifecycle status (replaces the former `is_claimed` flag)
+    pub status: EscrowStatus,
     /// Expiry timestamp (contract-defined)
     pub expiry: i64,
     /// Solver address that receives funds when escrow is claimed
@@ -45,11 +134,22 @@ pub struct Escrow {
     pub intent_id: [u8; 32],
     /// PDA bump seed
     pub bump: u8,
+    /// Account that receives the refund on cancel (the requester's wallet for native SOL,
+    /// a token account of `token_mint` otherwise). `Pubkey::default()` = not set, refunds
+    /// go to the requester.
+    pub refund_recipient: Pubkey,
 }
 
 impl Escrow {
     pub const DISCRIMINATOR: [u8; 8] = [0x45, 0x53, 0x43, 0x52, 0x4f, 0x57, 0x44, 0x41]; // "ESCROWDA"
-    pub const LEN: usize = 8 + 32 + 32 + 8 + 1 + 8 + 32 + 32 + 1; // 154 bytes
+    pub const LEN: usize = 8 + 32 + 32 + 8 + 1 + 8 + 32 + 32 + 1 + 32; // 186 bytes
+    /// Size of escrow accounts created before `refund_recipient` existed.
+    pub const LEGACY_LEN: usize = 8 + 32 + 32 + 8 + 1 + 8 + 32 + 32 + 1; // 154 bytes
+    /// Shortest expiry window a requester may set; anything shorter is unclaimable in practice.
+    /// Bounds `ExtendExpiry` only; creation takes the hub's absolute expiry as is.
+    pub const MIN_EXPIRY_DURATION: i64 = 120;
+    /// Longest expiry window a requester may set, so a bad duration cannot lock funds for months.
+    pub const MAX_EXPIRY_DURATION: i64 = 30 * 24 * 60 * 60;
 
     pub fn new(
         requester: Pubkey,
@@ -65,12 +165,67 @@ impl Escrow {
             requester,
             token_mint,
             amount,
-            is_claimed: false,
+            status: EscrowStatus::Active,
             expiry,
             reserved_solver,
             intent_id,
             bump,
+            refund_recipient: Pubkey::default(),
+        }
+    }
+
+    /// Deserialize escrow account data, reading a legacy 154-byte account as
+    /// having no refund recipient. Its status byte is the former `is_claimed`
+    /// flag, which decodes as `Active`/`Claimed`.
+    pub fn unpack(data: &[u8]) -> Result<Self, EscrowError> {
+        if data.len() == Self::LEGACY_LEN {
+            let mut padded = data.to_vec();
+            padded.extend_from_slice(Pubkey::default().as_ref());
+            Self::try_from_slice(&padded)
+        } else {
+            Self::try_from_slice(data)
+        }
+        .map_err(|_| EscrowError::AccountNotInitialized)
+    }
+
+    /// Serialize into escrow account data of either layout.
+    ///
+    /// A legacy account has no room for a refund recipient, so it can only be
+    /// written back while none is set.
+    pub fn pack(&self, data: &mut [u8]) -> Result<(), EscrowError> {
+        let bytes = self
+            .try_to_vec()
+            .map_err(|_| EscrowError::InvalidInstructionData)?;
+        match data.len() {
+            Self::LEN => data.copy_from_slice(&bytes),
+            Self::LEGACY_LEN if self.refund_recipient().is_none() => {
+                data.copy_from_slice(&bytes[..Self::LEGACY_LEN])
+            }
+            _ => return Err(EscrowError::AccountNotInitialized),
+        }
+        Ok(())
+    }
+
+    /// Returns the refund account chosen at creation, if any.
+    pub fn refund_recipient(&self) -> Option<Pubkey> {
+        (self.refund_recipient != Pubkey::default()).then_some(self.refund_recipient)
+    }
+
+    /// Returns true if the escrow holds native SOL (`token_mint` is the default pubkey).
+    pub fn is_native(&self) -> bool {
+        self.token_mint == Pubkey::default()
+    }
+
+    /// Moves the escrow to `next`, rejecting transitions the state machine does not allow.
+    pub fn transition_to(&mut self, next: EscrowStatus) -> Result<(), EscrowError> {
+        if !self.status.can_transition_to(next) {
+            return Err(match self.status {
+                EscrowStatus::Claimed => EscrowError::EscrowAlreadyClaimed,
+                _ => EscrowError::InvalidEscrowStatus,
+            });
         }
+        self.status = next;
+        Ok(())
     }
 }
 
@@ -0,0 +1,139 @@
+//! Token-2022 mint extension parsing
+//!
+//! The escrow accepts mints owned by either the classic SPL Token program or
+//! Token-2022. Release instructions do not carry the mint, so escrowed tokens
+//! are moved with a plain `Transfer`, which Token-2022 only allows for mints
+//! without fee or hook extensions. These helpers read a mint's extension TLV
+//! data directly (no spl-token-2022 dependency) so `create_escrow` can reject
+//! such mints explicitly instead of failing inside the token CPI.
+//!
+//! Transfer fees are detection only: a fee-on-transfer mint is rejected, never
+//! escrowed at a net amount.
+
+use solana_program::{instruction::Instruction, pubkey, pubkey::Pubkey};
+
+use crate::error::EscrowError;
+
+/// Token-2022 program ID.
+pub const TOKEN_2022_PROGRAM_ID: Pubkey = pubkey!("TokenzQdBNbLqP5VEhdkAS6EPFLC1PHnBqCXEpPxuEb");
+
+/// Size of the base mint state shared with classic SPL Token.
+const BASE_MINT_LEN: usize = 82;
+/// Extensions start after the base state padded to the token account size,
+/// followed by a one-byte account type.
+const ACCOUNT_TYPE_OFFSET: usize = 165;
+/// Account type byte marking a mint.
+const ACCOUNT_TYPE_MINT: u8 = 1;
+/// TLV extension type of `TransferFeeConfig`.
+pub const EXTENSION_TRANSFER_FEE_CONFIG: u16 = 1;
+/// Mint extensions the escrow cannot hold: `TransferFeeConfig` (needs `TransferChecked`
+/// and a fee-aware vault), `DefaultAccountState` (vault may start frozen),
+/// `NonTransferable`, `PermanentDelegate` (could drain the vault), `TransferHook`
+/// (needs extra accounts on every transfer) and `Pausable`.
+const UNSUPPORTED_MINT_EXTENSIONS: [u16; 6] = [1, 6, 9, 12, 14, 26];
+/// `TransferFeeConfig` layout: two 32-byte authorities, the withheld amount,
+/// then the older and newer `TransferFee` entries.
+const TRANSFER_FEE_CONFIG_LEN: usize = 32 + 32 + 8 + 2 * TRANSFER_FEE_LEN;
+/// `TransferFee` layout: epoch (u64), maximum fee (u64), basis points (u16).
+const TRANSFER_FEE_LEN: usize = 8 + 8 + 2;
+
+/// Transfer fee in effect for a Token-2022 mint.
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub struct TransferFee {
+    /// Fee rate in basis points of the transferred amount
+    pub basis_points: u16,
+    /// Cap on the fee for a single transfer, in base units
+    pub maximum_fee: u64,
+}
+
+/// Returns whether `program_id` is a token program the escrow can hold tokens in.
+pub fn is_supported_token_program(program_id: &Pubkey) -> bool {
+    *program_id == spl_token::id() || *program_id == TOKEN_2022_PROGRAM_ID
+}
+
+/// Point an instruction built by the classic SPL Token builders at `token_program`.
+///
+/// Token-2022 shares the classic instruction layout, but the `spl_token`
+/// builders only accept the classic program ID.
+pub fn for_token_program(mut instruction: Instruction, token_program: &Pubkey) -> Instruction {
+    instruction.program_id = *token_program;
+    instruction
+}
+
+/// Find the first mint extension the escrow cannot hold.
+///
+/// # Returns
+///
+/// * `Ok(None)` - Classic SPL Token mint, or a Token-2022 mint with only supported extensions
+/// * `Ok(Some(extension_type))` - The TLV type of the first unsupported extension
+/// * `Err(EscrowError::InvalidMint)` - Extension data is truncated or malformed
+pub fn unsupported_mint_extension(
+    mint_owner: &Pubkey,
+    mint_data: &[u8],
+) -> Result<Option<u16>, EscrowError> {
+    Ok(mint_extensions(mint_owner, mint_data)?
+        .into_iter()
+        .map(|(extension_type, _)| extension_type)
+        .find(|extension_type| UNSUPPORTED_MINT_EXTENSIONS.contains(extension_type)))
+}
+
+/// Read the transfer fee a mint charges in `epoch`.
+///
+/// # Returns
+///
+/// * `Ok(None)` - Classic SPL Token mint, or a Token-2022 mint without a `TransferFeeConfig`
+/// * `Ok(Some(fee))` - The fee in effect for `epoch` (may be zero basis points)
+/// * `Err(EscrowError::InvalidMint)` - Extension data is truncated or malformed
+pub fn mint_transfer_fee(
+    mint_owner: &Pubkey,
+    mint_data: &[u8],
+    epoch: u64,
+) -> Result<Option<TransferFee>, EscrowError> {
+    let Some((_, value)) = mint_extensions(mint_owner, mint_data)?
+        .into_iter()
+        .find(|(extension_type, _)| *extension_type == EXTENSION_TRANSFER_FEE_CONFIG)
+    else {
+        return Ok(None);
+    };
+    if value.len() != TRANSFER_FEE_CONFIG_LEN {
+        return Err(EscrowError::InvalidMint);
+    }
+    let older = &value[72..72 + TRANSFER_FEE_LEN];
+    let newer = &value[72 + TRANSFER_FEE_LEN..];
+    let newer_epoch = u64::from_le_bytes(newer[0..8].try_into().unwrap());
+    let fee = if epoch >= newer_epoch { newer } else { older };
+    Ok(Some(TransferFee {
+        maximum_fee: u64::from_le_bytes(fee[8..16].try_into().unwrap()),
+        basis_points: u16::from_le_bytes([fee[16], fee[17]]),
+    }))
+}
+
+/// Split a Token-2022 mint's extension data into (type, value) entries.
+/// Classic SPL Token mints and Token-2022 mints without extensions have none.
+fn mint_extensions<'a>(
+    mint_owner: &Pubkey,
+    mint_data: &'a [u8],
+) -> Result<Vec<(u16, &'a [u8])>, EscrowError> {
+    if *mint_owner != TOKEN_2022_PROGRAM_ID || mint_data.len() <= BASE_MINT_LEN {
+        return Ok(Vec::new());
+    }
+    if mint_data.len() <= ACCOUNT_TYPE_OFFSET || mint_data[ACCOUNT_TYPE_OFFSET] != ACCOUNT_TYPE_MINT
+    {
+        return Err(EscrowError::InvalidMint);
+    }
+
+    let mut extensions = Vec::new();
+    let mut tlv = &mint_data[ACCOUNT_TYPE_OFFSET + 1..];
+    while tlv.len() >= 4 {
+        let extension_type = u16::from_le_bytes([tlv[0], tlv[1]]);
+        let length = u16::from_le_bytes([tlv[2], tlv[3]]) as usize;
+        // Type 0 marks unused trailing space
+        if extension_type == 0 {
+            break;
+        }
+        let value = tlv.get(4..4 + length).ok_or(EscrowError::InvalidMint)?;
+        extensions.push((extension_type, value));
+        tlv = &tlv[4 + length..];
+    }
+    Ok(extensions)
+}
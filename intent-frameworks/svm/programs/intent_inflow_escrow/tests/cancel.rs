@@ -1,20 +1,28 @@
 mod common;
 
 use common::{
-    create_cancel_ix, create_escrow_ix,
+    create_cancel_ix, create_escrow_ix, create_mint,
     create_gmp_receive_fulfillment_proof_ix, create_gmp_receive_requirements_ix,
-    create_set_gmp_config_ix, generate_intent_id, get_token_balance, program_test, read_escrow,
+    create_set_gmp_config_ix, create_sweep_vault_excess_ix, create_token_account,
+    generate_intent_id, get_token_balance, mint_to, program_test,
+    read_escrow,
     setup_basic_env, setup_gmp_requirements, setup_gmp_requirements_custom, test_program_id,
-    DUMMY_HUB_CHAIN_ID, DUMMY_HUB_GMP_ENDPOINT_ADDR,
+    TestEnv, DUMMY_HUB_CHAIN_ID, DUMMY_HUB_GMP_ENDPOINT_ADDR,
 };
+use borsh::BorshSerialize;
 use gmp_common::messages::{FulfillmentProof, IntentRequirements};
-use intent_inflow_escrow::state::seeds;
+use intent_inflow_escrow::error::EscrowError;
+use intent_inflow_escrow::instruction::EscrowInstruction;
+use intent_inflow_escrow::state::{seeds, Escrow, EscrowStatus};
+use solana_program_test::{BanksClientError, ProgramTestContext};
 use solana_sdk::{
+    account::AccountSharedData,
     clock::Clock,
+    instruction::InstructionError,
     pubkey::Pubkey,
     signature::{Keypair, Signer},
     sysvar,
-    transaction::Transaction,
+    transaction::{Transaction, TransactionError},
 };
 use bincode::deserialize;
 
@@ -188,7 +196,7 @@ async fn test_cancel_after_expiry() {
         .unwrap()
         .unwrap();
     let escrow = read_escrow(&escrow_account);
-    assert!(escrow.is_claimed);
+    assert_eq!(escrow.status, EscrowStatus::Cancelled);
     assert_eq!(escrow.amount, 0);
 }
 
@@ -624,3 +632,465 @@ async fn test_revert_if_already_cancelled() {
     let result = context.banks_client.process_transaction(cancel_tx2).await;
     assert!(result.is_err(), "Should fail - escrow already cancelled");
 }
+
+// ============================================================================
+// VAULT BALANCE TESTS
+// ============================================================================
+
+// 7. Test: Cancel Debits Exactly the Escrow Amount
+// Verifies that cancel succeeds when the vault holds extra donated tokens, refunds exactly
+// the escrow amount, and leaves the donation as the vault remainder.
+// Why: The post-transfer vault check must catch a short debit (partial or fee-on-transfer)
+// without letting anyone block a refund by sending dust to the vault.
+#[tokio::test]
+async fn test_cancel_debits_exactly_escrow_amount() {
+    let program_test = program_test();
+    let mut context = program_test.start_with_context().await;
+    let env = setup_basic_env(&mut context).await;
+
+    let intent_id = generate_intent_id();
+    let amount = 500_000u64;
+    let dust = 7u64;
+
+    let clock_account = context
+        .banks_client
+        .get_account(sysvar::clock::id())
+        .await
+        .unwrap()
+        .unwrap();
+    let clock: Clock = deserialize(&clock_account.data).unwrap();
+    let requirements_pda = setup_gmp_requirements(
+        &mut context,
+        &env,
+        intent_id,
+        amount,
+        (clock.unix_timestamp as u64) + 1,
+    )
+    .await;
+
+    let create_ix = create_escrow_ix(
+        env.program_id,
+        intent_id,
+        amount,
+        env.requester.pubkey(),
+        env.mint,
+        env.requester_token,
+        env.solver.pubkey(),
+        requirements_pda,
+    );
+    let blockhash = context.banks_client.get_latest_blockhash().await.unwrap();
+    let create_tx = Transaction::new_signed_with_payer(
+        &[create_ix],
+        Some(&env.requester.pubkey()),
+        &[&env.requester],
+        blockhash,
+    );
+    context.banks_client.process_transaction(create_tx).await.unwrap();
+
+    let (escrow_pda, _) =
+        Pubkey::find_program_address(&[seeds::ESCROW_SEED, &intent_id], &env.program_id);
+    let (vault_pda, _) =
+        Pubkey::find_program_address(&[seeds::VAULT_SEED, &intent_id], &env.program_id);
+
+    // Donate dust directly to the vault
+    let payer = context.payer.insecure_clone();
+    mint_to(&mut context, &payer, env.mint, &env.mint_authority, vault_pda, dust).await;
+    assert_eq!(get_token_balance(&mut context, vault_pda).await, amount + dust);
+
+    // Advance clock past expiry
+    let escrow_account = context
+        .banks_client
+        .get_account(escrow_pda)
+        .await
+        .unwrap()
+        .unwrap();
+    let escrow_data = read_escrow(&escrow_account);
+    let mut clock: Clock = deserialize(
+        &context
+            .banks_client
+            .get_account(sysvar::clock::id())
+            .await
+            .unwrap()
+            .unwrap()
+            .data,
+    )
+    .unwrap();
+    clock.unix_timestamp = escrow_data.expiry + 1;
+    context.set_sysvar(&clock);
+
+    let requester_balance_before = get_token_balance(&mut context, env.requester_token).await;
+    let cancel_ix = create_cancel_ix(
+        env.program_id,
+        intent_id,
+        env.requester.pubkey(),
+        env.requester_token,
+        escrow_pda,
+        vault_pda,
+        env.gmp_config_pda,
+    );
+    let blockhash = context.banks_client.get_latest_blockhash().await.unwrap();
+    let cancel_tx = Transaction::new_signed_with_payer(
+        &[cancel_ix],
+        Some(&env.requester.pubkey()),
+        &[&env.requester],
+        blockhash,
+    );
+    context.banks_client.process_transaction(cancel_tx).await.unwrap();
+
+    // Exactly the escrow amount was refunded; the donation stays in the vault
+    assert_eq!(
+        get_token_balance(&mut context, env.requester_token).await,
+        requester_balance_before + amount
+    );
+    assert_eq!(get_token_balance(&mut context, vault_pda).await, dust);
+}
+
+// 8. Test: Sweep Recovers Tokens Sent Directly to the Vault
+// Verifies that the admin can sweep tokens donated to the vault to a recovery account, that
+// the sweep leaves the escrow amount in the vault, and that a second sweep finds no excess.
+// Why: Claim and cancel release exactly the escrow amount, so extra tokens would otherwise be
+// stranded in the vault for good.
+#[tokio::test]
+async fn test_sweep_vault_excess_recovers_donated_tokens() {
+    let program_test = program_test();
+    let mut context = program_test.start_with_context().await;
+    let env = setup_basic_env(&mut context).await;
+
+    let intent_id = generate_intent_id();
+    let amount = 500_000u64;
+    let donation = 1_234u64;
+
+    let requirements_pda =
+        setup_gmp_requirements(&mut context, &env, intent_id, amount, u64::MAX).await;
+    let create_ix = create_escrow_ix(
+        env.program_id,
+        intent_id,
+        amount,
+        env.requester.pubkey(),
+        env.mint,
+        env.requester_token,
+        env.solver.pubkey(),
+        requirements_pda,
+    );
+    let blockhash = context.banks_client.get_latest_blockhash().await.unwrap();
+    let create_tx = Transaction::new_signed_with_payer(
+        &[create_ix],
+        Some(&env.requester.pubkey()),
+        &[&env.requester],
+        blockhash,
+    );
+    context.banks_client.process_transaction(create_tx).await.unwrap();
+
+    let (escrow_pda, _) =
+        Pubkey::find_program_address(&[seeds::ESCROW_SEED, &intent_id], &env.program_id);
+    let (vault_pda, _) =
+        Pubkey::find_program_address(&[seeds::VAULT_SEED, &intent_id], &env.program_id);
+
+    // Send extra tokens directly to the vault
+    let payer = context.payer.insecure_clone();
+    mint_to(&mut context, &payer, env.mint, &env.mint_authority, vault_pda, donation).await;
+    let recovery_owner = Keypair::new();
+    let recovery_token =
+        create_token_account(&mut context, &payer, env.mint, recovery_owner.pubkey()).await;
+
+    let sweep_ix = create_sweep_vault_excess_ix(
+        env.program_id,
+        intent_id,
+        env.requester.pubkey(),
+        recovery_token,
+        escrow_pda,
+        vault_pda,
+        env.gmp_config_pda,
+    );
+    let blockhash = context.banks_client.get_latest_blockhash().await.unwrap();
+    let sweep_tx = Transaction::new_signed_with_payer(
+        &[sweep_ix.clone()],
+        Some(&env.requester.pubkey()),
+        &[&env.requester],
+        blockhash,
+    );
+    context.banks_client.process_transaction(sweep_tx).await.unwrap();
+
+    // Only the donation moved; the escrowed funds are still locked
+    assert_eq!(get_token_balance(&mut context, recovery_token).await, donation);
+    assert_eq!(get_token_balance(&mut context, vault_pda).await, amount);
+    let escrow_account = context
+        .banks_client
+        .get_account(escrow_pda)
+        .await
+        .unwrap()
+        .unwrap();
+    let escrow_data = read_escrow(&escrow_account);
+    assert_eq!(escrow_data.amount, amount);
+    assert_eq!(escrow_data.status, EscrowStatus::Active);
+
+    // Nothing left to sweep
+    let payer = context.payer.insecure_clone();
+    let blockhash = context.banks_client.get_latest_blockhash().await.unwrap();
+    let sweep_again_tx = Transaction::new_signed_with_payer(
+        &[sweep_ix],
+        Some(&payer.pubkey()),
+        &[&payer, &env.requester],
+        blockhash,
+    );
+    let result = context.banks_client.process_transaction(sweep_again_tx).await;
+    assert!(result.is_err(), "Should fail - vault holds no excess");
+}
+
+// ============================================================================
+// REFUND RECIPIENT TESTS
+// ============================================================================
+// CreateEscrow may name a refund account other than the requester's; cancel
+// must then refund that account and no other.
+
+// Helper: Create an escrow with an optional refund recipient and move the clock past
+// its expiry. Returns (escrow_pda, vault_pda).
+async fn create_expired_escrow(
+    context: &mut ProgramTestContext,
+    env: &TestEnv,
+    intent_id: [u8; 32],
+    amount: u64,
+    refund_recipient: Option<Pubkey>,
+) -> (Pubkey, Pubkey) {
+    let clock: Clock = deserialize(
+        &context
+            .banks_client
+            .get_account(sysvar::clock::id())
+            .await
+            .unwrap()
+            .unwrap()
+            .data,
+    )
+    .unwrap();
+    let expiry = clock.unix_timestamp + 1;
+    let requirements_pda =
+        setup_gmp_requirements(context, env, intent_id, amount, expiry as u64).await;
+
+    let mut create_ix = create_escrow_ix(
+        env.program_id,
+        intent_id,
+        amount,
+        env.requester.pubkey(),
+        env.mint,
+        env.requester_token,
+        env.solver.pubkey(),
+        requirements_pda,
+    );
+    create_ix.data = EscrowInstruction::CreateEscrow {
+        intent_id,
+        amount,
+        refund_recipient,
+    }
+    .try_to_vec()
+    .unwrap();
+    let blockhash = context.banks_client.get_latest_blockhash().await.unwrap();
+    let create_tx = Transaction::new_signed_with_payer(
+        &[create_ix],
+        Some(&env.requester.pubkey()),
+        &[&env.requester],
+        blockhash,
+    );
+    context
+        .banks_client
+        .process_transaction(create_tx)
+        .await
+        .unwrap();
+
+    let mut clock = clock;
+    clock.unix_timestamp = expiry + 1;
+    context.set_sysvar(&clock);
+
+    let (escrow_pda, _) =
+        Pubkey::find_program_address(&[seeds::ESCROW_SEED, &intent_id], &env.program_id);
+    let (vault_pda, _) =
+        Pubkey::find_program_address(&[seeds::VAULT_SEED, &intent_id], &env.program_id);
+    (escrow_pda, vault_pda)
+}
+
+// Helper: Cancel `intent_id` as the admin (= requester in basic env), refunding `refund_token`
+async fn cancel_to(
+    context: &mut ProgramTestContext,
+    env: &TestEnv,
+    intent_id: [u8; 32],
+    refund_token: Pubkey,
+) -> Result<(), BanksClientError> {
+    let (escrow_pda, _) =
+        Pubkey::find_program_address(&[seeds::ESCROW_SEED, &intent_id], &env.program_id);
+    let (vault_pda, _) =
+        Pubkey::find_program_address(&[seeds::VAULT_SEED, &intent_id], &env.program_id);
+    let cancel_ix = create_cancel_ix(
+        env.program_id,
+        intent_id,
+        env.requester.pubkey(),
+        refund_token,
+        escrow_pda,
+        vault_pda,
+        env.gmp_config_pda,
+    );
+    let blockhash = context.banks_client.get_latest_blockhash().await.unwrap();
+    let cancel_tx = Transaction::new_signed_with_payer(
+        &[cancel_ix],
+        Some(&env.requester.pubkey()),
+        &[&env.requester],
+        blockhash,
+    );
+    context.banks_client.process_transaction(cancel_tx).await
+}
+
+// 9. Test: Cancel Refunds the Requester When No Refund Recipient Is Set
+// Verifies that an escrow created without a refund recipient stores none and that cancel
+// refunds the requester's token account.
+// Why: Existing clients do not set a refund recipient and must keep today's refund path.
+#[tokio::test]
+async fn test_cancel_refunds_requester_by_default() {
+    let program_test = program_test();
+    let mut context = program_test.start_with_context().await;
+    let env = setup_basic_env(&mut context).await;
+
+    let intent_id = generate_intent_id();
+    let amount = 500_000u64;
+    let (escrow_pda, vault_pda) =
+        create_expired_escrow(&mut context, &env, intent_id, amount, None).await;
+    let escrow_account = context
+        .banks_client
+        .get_account(escrow_pda)
+        .await
+        .unwrap()
+        .unwrap();
+    assert_eq!(read_escrow(&escrow_account).refund_recipient(), None);
+
+    let requester_balance_before = get_token_balance(&mut context, env.requester_token).await;
+    cancel_to(&mut context, &env, intent_id, env.requester_token)
+        .await
+        .unwrap();
+
+    assert_eq!(
+        get_token_balance(&mut context, env.requester_token).await,
+        requester_balance_before + amount
+    );
+    assert_eq!(get_token_balance(&mut context, vault_pda).await, 0);
+}
+
+// 10. Test: Cancel Refunds the Refund Recipient Set at Creation
+// Verifies that cancel refunds the token account named as refund recipient, and that the
+// requester's own token account is rejected with InvalidRecipient.
+// Why: A requester depositing from a hot wallet can route refunds to a cold wallet; the
+// stored choice must not be bypassed by passing a different account at cancel time.
+#[tokio::test]
+async fn test_cancel_refunds_refund_recipient() {
+    let program_test = program_test();
+    let mut context = program_test.start_with_context().await;
+    let env = setup_basic_env(&mut context).await;
+    let payer = context.payer.insecure_clone();
+
+    let cold_wallet = Keypair::new();
+    let cold_token =
+        create_token_account(&mut context, &payer, env.mint, cold_wallet.pubkey()).await;
+
+    let intent_id = generate_intent_id();
+    let amount = 500_000u64;
+    let (escrow_pda, vault_pda) =
+        create_expired_escrow(&mut context, &env, intent_id, amount, Some(cold_token)).await;
+    let escrow_account = context
+        .banks_client
+        .get_account(escrow_pda)
+        .await
+        .unwrap()
+        .unwrap();
+    assert_eq!(
+        read_escrow(&escrow_account).refund_recipient(),
+        Some(cold_token)
+    );
+
+    let result = cancel_to(&mut context, &env, intent_id, env.requester_token).await;
+    assert_eq!(
+        result.unwrap_err().unwrap(),
+        TransactionError::InstructionError(
+            0,
+            InstructionError::Custom(EscrowError::InvalidRecipient as u32)
+        )
+    );
+
+    cancel_to(&mut context, &env, intent_id, cold_token)
+        .await
+        .unwrap();
+    assert_eq!(get_token_balance(&mut context, cold_token).await, amount);
+    assert_eq!(get_token_balance(&mut context, vault_pda).await, 0);
+}
+
+// 11. Test: Cancel Rejects a Refund Recipient of Another Mint
+// Verifies that cancel fails with TokenMintMismatch when the refund recipient set at
+// creation is a token account of a different mint.
+// Why: A refund recipient is chosen before the escrow is funded; a wrong-mint account
+// must fail with a specific error rather than inside the token transfer.
+#[tokio::test]
+async fn test_cancel_rejects_refund_recipient_of_another_mint() {
+    let program_test = program_test();
+    let mut context = program_test.start_with_context().await;
+    let env = setup_basic_env(&mut context).await;
+    let payer = context.payer.insecure_clone();
+
+    let other_mint = create_mint(&mut context, &payer, &env.mint_authority, 6).await;
+    let other_token =
+        create_token_account(&mut context, &payer, other_mint, env.requester.pubkey()).await;
+
+    let intent_id = generate_intent_id();
+    let (_, vault_pda) =
+        create_expired_escrow(&mut context, &env, intent_id, 500_000, Some(other_token)).await;
+
+    let result = cancel_to(&mut context, &env, intent_id, other_token).await;
+    assert_eq!(
+        result.unwrap_err().unwrap(),
+        TransactionError::InstructionError(
+            0,
+            InstructionError::Custom(EscrowError::TokenMintMismatch as u32)
+        )
+    );
+    assert_eq!(get_token_balance(&mut context, vault_pda).await, 500_000);
+}
+
+// 12. Test: Legacy Escrow Without a Refund Recipient Cancels to the Requester
+// Verifies that a 154-byte escrow account written before refund_recipient existed reads
+// as having no refund recipient, cancels to the requester, and keeps its size.
+// Why: Escrows created before the upgrade must stay cancellable; reading them with the
+// current 186-byte layout would fail and lock their funds.
+#[tokio::test]
+async fn test_cancel_legacy_escrow_without_refund_recipient() {
+    let program_test = program_test();
+    let mut context = program_test.start_with_context().await;
+    let env = setup_basic_env(&mut context).await;
+
+    let intent_id = generate_intent_id();
+    let amount = 500_000u64;
+    let (escrow_pda, vault_pda) =
+        create_expired_escrow(&mut context, &env, intent_id, amount, None).await;
+    let mut escrow_account = context
+        .banks_client
+        .get_account(escrow_pda)
+        .await
+        .unwrap()
+        .unwrap();
+    escrow_account.data.truncate(Escrow::LEGACY_LEN);
+    context.set_account(&escrow_pda, &AccountSharedData::from(escrow_account));
+
+    let requester_balance_before = get_token_balance(&mut context, env.requester_token).await;
+    cancel_to(&mut context, &env, intent_id, env.requester_token)
+        .await
+        .unwrap();
+
+    assert_eq!(
+        get_token_balance(&mut context, env.requester_token).await,
+        requester_balance_before + amount
+    );
+    assert_eq!(get_token_balance(&mut context, vault_pda).await, 0);
+    let escrow_account = context
+        .banks_client
+        .get_account(escrow_pda)
+        .await
+        .unwrap()
+        .unwrap();
+    assert_eq!(escrow_account.data.len(), Escrow::LEGACY_LEN);
+    let escrow = read_escrow(&escrow_account);
+    assert_eq!(escrow.status, EscrowStatus::Cancelled);
+    assert_eq!(escrow.refund_recipient(), None);
+}
@@ -1,13 +1,25 @@
 mod common;
 
+use bincode::deserialize;
+use borsh::{BorshDeserialize, BorshSerialize};
 use common::{
-    create_escrow_ix, create_gmp_receive_fulfillment_proof_ix, create_gmp_receive_requirements_ix,
-    generate_intent_id, get_token_balance, program_test, read_escrow, read_requirements,
-    setup_basic_env, DUMMY_HUB_CHAIN_ID, DUMMY_HUB_GMP_ENDPOINT_ADDR,
+    create_cancel_ix, create_claim_ix, create_escrow_ix, create_gmp_receive_fulfillment_proof_ix,
+    create_gmp_receive_requirements_ix, generate_intent_id, get_token_balance, program_test,
+    read_escrow, read_requirements, setup_basic_env, setup_gmp_requirements, DUMMY_HUB_CHAIN_ID,
+    DUMMY_HUB_GMP_ENDPOINT_ADDR,
 };
 use gmp_common::messages::{FulfillmentProof, IntentRequirements};
-use intent_inflow_escrow::state::seeds;
-use solana_sdk::{pubkey::Pubkey, signature::Signer, transaction::Transaction};
+use intent_inflow_escrow::error::EscrowError;
+use intent_inflow_escrow::state::{seeds, EscrowStatus, StoredIntentRequirements};
+use solana_sdk::{
+    account::AccountSharedData,
+    clock::Clock,
+    instruction::InstructionError,
+    pubkey::Pubkey,
+    signature::Signer,
+    sysvar,
+    transaction::{Transaction, TransactionError},
+};
 
 // ============================================================================
 // GMP CLAIM TESTS
@@ -154,7 +166,7 @@ async fn test_claim_with_valid_fulfillment_proof() {
         .unwrap()
         .unwrap();
     let escrow = read_escrow(&escrow_account);
-    assert!(!escrow.is_claimed);
+    assert_eq!(escrow.status, EscrowStatus::Active);
     assert_eq!(escrow.amount, amount);
 
     // Step 3: Receive fulfillment proof via GMP (this auto-releases the escrow)
@@ -200,7 +212,7 @@ async fn test_claim_with_valid_fulfillment_proof() {
         .unwrap()
         .unwrap();
     let escrow = read_escrow(&escrow_account);
-    assert!(escrow.is_claimed);
+    assert_eq!(escrow.status, EscrowStatus::Claimed);
     assert_eq!(escrow.amount, 0);
 
     // Verify requirements marked as fulfilled
@@ -383,13 +395,123 @@ async fn test_prevent_double_fulfillment() {
     assert!(result.is_err(), "Should fail - already fulfilled");
 }
 
-// 4. Test: Escrow already claimed rejection
-// Verifies that fulfillment fails if escrow was already claimed.
+// 4. Test: Escrow already cancelled rejection
+// Verifies that fulfillment fails if the escrow was already released via cancel.
+// Why: Claim is only permitted from Active. A cancelled escrow has returned its funds
+// to the requester and must not be released a second time.
 #[tokio::test]
 async fn test_revert_if_escrow_already_claimed() {
-    // This is effectively the same as test_prevent_double_fulfillment
-    // because GmpReceiveFulfillmentProof marks both requirements.fulfilled and escrow.is_claimed
-    // The test above covers this case.
+    let program_test = program_test();
+    let mut context = program_test.start_with_context().await;
+    let env = setup_basic_env(&mut context).await;
+
+    let intent_id = generate_intent_id();
+    let amount = 500_000u64;
+
+    let clock_account = context
+        .banks_client
+        .get_account(sysvar::clock::id())
+        .await
+        .unwrap()
+        .unwrap();
+    let clock: Clock = deserialize(&clock_account.data).unwrap();
+    let expiry = (clock.unix_timestamp as u64) + 1;
+    let requirements_pda =
+        setup_gmp_requirements(&mut context, &env, intent_id, amount, expiry).await;
+
+    let (escrow_pda, _) =
+        Pubkey::find_program_address(&[seeds::ESCROW_SEED, &intent_id], &env.program_id);
+    let (vault_pda, _) =
+        Pubkey::find_program_address(&[seeds::VAULT_SEED, &intent_id], &env.program_id);
+
+    let create_ix = create_escrow_ix(
+        env.program_id,
+        intent_id,
+        amount,
+        env.requester.pubkey(),
+        env.mint,
+        env.requester_token,
+        env.solver.pubkey(),
+        requirements_pda,
+    );
+    let blockhash = context.banks_client.get_latest_blockhash().await.unwrap();
+    let create_tx = Transaction::new_signed_with_payer(
+        &[create_ix],
+        Some(&env.requester.pubkey()),
+        &[&env.requester],
+        blockhash,
+    );
+    context.banks_client.process_transaction(create_tx).await.unwrap();
+
+    // Advance past expiry and cancel as admin (= requester in basic env)
+    let mut clock: Clock = deserialize(
+        &context
+            .banks_client
+            .get_account(sysvar::clock::id())
+            .await
+            .unwrap()
+            .unwrap()
+            .data,
+    )
+    .unwrap();
+    clock.unix_timestamp = expiry as i64 + 1;
+    context.set_sysvar(&clock);
+
+    let cancel_ix = create_cancel_ix(
+        env.program_id,
+        intent_id,
+        env.requester.pubkey(),
+        env.requester_token,
+        escrow_pda,
+        vault_pda,
+        env.gmp_config_pda,
+    );
+    let blockhash = context.banks_client.get_latest_blockhash().await.unwrap();
+    let cancel_tx = Transaction::new_signed_with_payer(
+        &[cancel_ix],
+        Some(&env.requester.pubkey()),
+        &[&env.requester],
+        blockhash,
+    );
+    context.banks_client.process_transaction(cancel_tx).await.unwrap();
+
+    let escrow_account = context
+        .banks_client
+        .get_account(escrow_pda)
+        .await
+        .unwrap()
+        .unwrap();
+    assert_eq!(read_escrow(&escrow_account).status, EscrowStatus::Cancelled);
+
+    // Fulfillment proof for the cancelled escrow must be rejected
+    let gmp_caller = context.payer.insecure_clone();
+    let proof_payload =
+        create_fulfillment_proof_payload(intent_id, &env.solver.pubkey(), amount, 12345);
+    let gmp_receive_proof_ix = create_gmp_receive_fulfillment_proof_ix(
+        env.program_id,
+        requirements_pda,
+        escrow_pda,
+        vault_pda,
+        env.solver_token,
+        env.gmp_config_pda, // PDA - must be derived, cannot be a DUMMY constant
+        gmp_caller.pubkey(),
+        DUMMY_HUB_CHAIN_ID,
+        DUMMY_HUB_GMP_ENDPOINT_ADDR,
+        proof_payload,
+    );
+    let blockhash = context.banks_client.get_latest_blockhash().await.unwrap();
+    let tx = Transaction::new_signed_with_payer(
+        &[gmp_receive_proof_ix],
+        Some(&gmp_caller.pubkey()),
+        &[&gmp_caller],
+        blockhash,
+    );
+
+    let result = context.banks_client.process_transaction(tx).await;
+    assert!(result.is_err(), "Should fail - escrow already cancelled");
+
+    let solver_balance = get_token_balance(&mut context, env.solver_token).await;
+    assert_eq!(solver_balance, 0);
 }
 
 // 5. Test: Non-existent escrow rejection
@@ -472,3 +594,344 @@ async fn test_revert_if_escrow_does_not_exist() {
     let result = context.banks_client.process_transaction(tx).await;
     assert!(result.is_err(), "Should fail - escrow doesn't exist");
 }
+
+// ============================================================================
+// ESCROW STATUS TRANSITIONS
+// ============================================================================
+
+// 6. Test: Escrow status transitions
+// Verifies that EscrowStatus only permits Active -> Claimed/Cancelled/Expired and
+// Expired -> Cancelled/Recovered, and that terminal statuses accept no transition.
+// Why: Every instruction guards on these transitions. A permissive entry would let
+// a claimed or cancelled escrow be released again.
+#[test]
+fn test_escrow_status_transitions() {
+    use EscrowStatus::*;
+    let all = [Active, Claimed, Cancelled, Expired, Recovered];
+    let allowed = [
+        (Active, Claimed),
+        (Active, Cancelled),
+        (Active, Expired),
+        (Expired, Cancelled),
+        (Expired, Recovered),
+    ];
+
+    for from in all {
+        for to in all {
+            assert_eq!(
+                from.can_transition_to(to),
+                allowed.contains(&(from, to)),
+                "unexpected result for {:?} -> {:?}",
+                from,
+                to
+            );
+        }
+    }
+}
+
+// ============================================================================
+// CLAIM-TIME TOKEN CROSS-CHECK
+// ============================================================================
+
+// 7. Test: Claim rejects an escrow whose mint diverges from the requirements token
+// Verifies that Claim fails with TokenMismatch when the stored requirements name a
+// different token than the escrow holds, and that the vault keeps its funds.
+// Why: If requirements were overwritten after creation, a release must not pay out a
+// token the hub never asked for.
+#[tokio::test]
+async fn test_claim_rejects_token_mismatch_with_requirements() {
+    let program_test = program_test();
+    let mut context = program_test.start_with_context().await;
+    let env = setup_basic_env(&mut context).await;
+
+    let intent_id = generate_intent_id();
+    let amount = 500_000u64;
+    let requirements_pda =
+        setup_gmp_requirements(&mut context, &env, intent_id, amount, u64::MAX).await;
+
+    let create_ix = create_escrow_ix(
+        env.program_id,
+        intent_id,
+        amount,
+        env.requester.pubkey(),
+        env.mint,
+        env.requester_token,
+        env.solver.pubkey(),
+        requirements_pda,
+    );
+    let blockhash = context.banks_client.get_latest_blockhash().await.unwrap();
+    let create_tx = Transaction::new_signed_with_payer(
+        &[create_ix],
+        Some(&env.requester.pubkey()),
+        &[&env.requester],
+        blockhash,
+    );
+    context.banks_client.process_transaction(create_tx).await.unwrap();
+
+    // Diverge the requirements token from the escrow mint and mark them fulfilled,
+    // so only the token cross-check stands between the claim and a release
+    let mut account = context
+        .banks_client
+        .get_account(requirements_pda)
+        .await
+        .unwrap()
+        .unwrap();
+    let mut requirements = StoredIntentRequirements::try_from_slice(&account.data).unwrap();
+    requirements.token_addr = Pubkey::new_unique().to_bytes();
+    requirements.fulfilled = true;
+    requirements.serialize(&mut &mut account.data[..]).unwrap();
+    context.set_account(&requirements_pda, &AccountSharedData::from(account));
+
+    let (escrow_pda, _) =
+        Pubkey::find_program_address(&[seeds::ESCROW_SEED, &intent_id], &env.program_id);
+    let (vault_pda, _) =
+        Pubkey::find_program_address(&[seeds::VAULT_SEED, &intent_id], &env.program_id);
+    let claim_ix = create_claim_ix(
+        env.program_id,
+        intent_id,
+        escrow_pda,
+        requirements_pda,
+        vault_pda,
+        env.solver_token,
+    );
+    let blockhash = context.banks_client.get_latest_blockhash().await.unwrap();
+    let claim_tx = Transaction::new_signed_with_payer(
+        &[claim_ix],
+        Some(&env.solver.pubkey()),
+        &[&env.solver],
+        blockhash,
+    );
+    let err = context
+        .banks_client
+        .process_transaction(claim_tx)
+        .await
+        .unwrap_err()
+        .unwrap();
+
+    assert_eq!(
+        err,
+        TransactionError::InstructionError(
+            0,
+            InstructionError::Custom(EscrowError::TokenMismatch as u32)
+        )
+    );
+    assert_eq!(get_token_balance(&mut context, vault_pda).await, amount);
+}
+
+// ============================================================================
+// CLAIM BEFORE FULFILLMENT
+// ============================================================================
+
+// 8. Test: Claim before the fulfillment proof is rejected
+// Verifies that an explicit Claim fails with NotFulfilled while the requirements are not yet marked
+// fulfilled, leaves the escrow Active with its funds, and that the later
+// GmpReceiveFulfillmentProof still releases the escrow to the solver.
+// Why: Claim must only pay out once the hub has proven fulfillment. An early Claim
+// must not release funds or leave the escrow in a state the proof cannot release.
+#[tokio::test]
+async fn test_revert_claim_before_fulfillment_proof() {
+    let program_test = program_test();
+    let mut context = program_test.start_with_context().await;
+    let env = setup_basic_env(&mut context).await;
+
+    let intent_id = generate_intent_id();
+    let amount = 500_000u64;
+    let requirements_pda =
+        setup_gmp_requirements(&mut context, &env, intent_id, amount, u64::MAX).await;
+
+    let create_ix = create_escrow_ix(
+        env.program_id,
+        intent_id,
+        amount,
+        env.requester.pubkey(),
+        env.mint,
+        env.requester_token,
+        env.solver.pubkey(),
+        requirements_pda,
+    );
+    let blockhash = context.banks_client.get_latest_blockhash().await.unwrap();
+    let create_tx = Transaction::new_signed_with_payer(
+        &[create_ix],
+        Some(&env.requester.pubkey()),
+        &[&env.requester],
+        blockhash,
+    );
+    context.banks_client.process_transaction(create_tx).await.unwrap();
+
+    let (escrow_pda, _) =
+        Pubkey::find_program_address(&[seeds::ESCROW_SEED, &intent_id], &env.program_id);
+    let (vault_pda, _) =
+        Pubkey::find_program_address(&[seeds::VAULT_SEED, &intent_id], &env.program_id);
+
+    // Claim without a fulfillment proof
+    let claim_ix = create_claim_ix(
+        env.program_id,
+        intent_id,
+        escrow_pda,
+        requirements_pda,
+        vault_pda,
+        env.solver_token,
+    );
+    let blockhash = context.banks_client.get_latest_blockhash().await.unwrap();
+    let claim_tx = Transaction::new_signed_with_payer(
+        &[claim_ix],
+        Some(&env.solver.pubkey()),
+        &[&env.solver],
+        blockhash,
+    );
+    let err = context
+        .banks_client
+        .process_transaction(claim_tx)
+        .await
+        .unwrap_err()
+        .unwrap();
+
+    assert_eq!(
+        err,
+        TransactionError::InstructionError(
+            0,
+            InstructionError::Custom(EscrowError::NotFulfilled as u32)
+        )
+    );
+    assert_eq!(get_token_balance(&mut context, vault_pda).await, amount);
+    assert_eq!(get_token_balance(&mut context, env.solver_token).await, 0);
+    let escrow_account = context
+        .banks_client
+        .get_account(escrow_pda)
+        .await
+        .unwrap()
+        .unwrap();
+    assert_eq!(read_escrow(&escrow_account).status, EscrowStatus::Active);
+
+    // The fulfillment proof still releases the escrow afterwards
+    let gmp_caller = context.payer.insecure_clone();
+    let proof_ix = create_gmp_receive_fulfillment_proof_ix(
+        env.program_id,
+        requirements_pda,
+        escrow_pda,
+        vault_pda,
+        env.solver_token,
+        env.gmp_config_pda,
+        gmp_caller.pubkey(),
+        DUMMY_HUB_CHAIN_ID,
+        DUMMY_HUB_GMP_ENDPOINT_ADDR,
+        create_fulfillment_proof_payload(intent_id, &env.solver.pubkey(), amount, 12345),
+    );
+    let blockhash = context.banks_client.get_latest_blockhash().await.unwrap();
+    let proof_tx = Transaction::new_signed_with_payer(
+        &[proof_ix],
+        Some(&gmp_caller.pubkey()),
+        &[&gmp_caller],
+        blockhash,
+    );
+    context
+        .banks_client
+        .process_transaction(proof_tx)
+        .await
+        .unwrap();
+
+    assert_eq!(get_token_balance(&mut context, vault_pda).await, 0);
+    assert_eq!(
+        get_token_balance(&mut context, env.solver_token).await,
+        amount
+    );
+    let escrow_account = context
+        .banks_client
+        .get_account(escrow_pda)
+        .await
+        .unwrap()
+        .unwrap();
+    assert_eq!(read_escrow(&escrow_account).status, EscrowStatus::Claimed);
+}
+
+// ============================================================================
+// CLAIM EVENT
+// ============================================================================
+
+// 9. Test: Claim emits EscrowClaimed
+// Verifies that an explicit Claim logs an EscrowClaimed event carrying the approver
+// from the program state and the SHA-256 of the fulfilled requirements account.
+// Why: Every release path must leave an audit record, not only the fulfillment-proof
+// auto-release.
+#[tokio::test]
+async fn test_claim_emits_escrow_claimed_event() {
+    let program_test = program_test();
+    let mut context = program_test.start_with_context().await;
+    let env = setup_basic_env(&mut context).await;
+
+    let intent_id = generate_intent_id();
+    let amount = 500_000u64;
+    let requirements_pda =
+        setup_gmp_requirements(&mut context, &env, intent_id, amount, u64::MAX).await;
+
+    let create_ix = create_escrow_ix(
+        env.program_id,
+        intent_id,
+        amount,
+        env.requester.pubkey(),
+        env.mint,
+        env.requester_token,
+        env.solver.pubkey(),
+        requirements_pda,
+    );
+    let blockhash = context.banks_client.get_latest_blockhash().await.unwrap();
+    let create_tx = Transaction::new_signed_with_payer(
+        &[create_ix],
+        Some(&env.requester.pubkey()),
+        &[&env.requester],
+        blockhash,
+    );
+    context.banks_client.process_transaction(create_tx).await.unwrap();
+
+    // Record the hub's approval as the fulfillment proof would, without releasing
+    let mut account = context
+        .banks_client
+        .get_account(requirements_pda)
+        .await
+        .unwrap()
+        .unwrap();
+    let mut requirements = StoredIntentRequirements::try_from_slice(&account.data).unwrap();
+    requirements.fulfilled = true;
+    requirements.serialize(&mut &mut account.data[..]).unwrap();
+    let expected_hash = solana_sdk::hash::hash(&account.data).to_bytes();
+    context.set_account(&requirements_pda, &AccountSharedData::from(account));
+
+    let (escrow_pda, _) =
+        Pubkey::find_program_address(&[seeds::ESCROW_SEED, &intent_id], &env.program_id);
+    let (vault_pda, _) =
+        Pubkey::find_program_address(&[seeds::VAULT_SEED, &intent_id], &env.program_id);
+    let claim_ix = create_claim_ix(
+        env.program_id,
+        intent_id,
+        escrow_pda,
+        requirements_pda,
+        vault_pda,
+        env.solver_token,
+    );
+    let blockhash = context.banks_client.get_latest_blockhash().await.unwrap();
+    let claim_tx = Transaction::new_signed_with_payer(
+        &[claim_ix],
+        Some(&env.solver.pubkey()),
+        &[&env.solver],
+        blockhash,
+    );
+    let result = context
+        .banks_client
+        .process_transaction_with_metadata(claim_tx)
+        .await
+        .unwrap();
+    assert!(result.result.is_ok());
+    assert_eq!(get_token_balance(&mut context, env.solver_token).await, amount);
+
+    let logs = result.metadata.unwrap().log_messages;
+    let event = logs
+        .iter()
+        .find(|log| log.contains("EscrowClaimed:"))
+        .expect("EscrowClaimed event should be emitted");
+
+    assert!(event.contains(&format!("intent_id={}", hex::encode(intent_id))));
+    assert!(event.contains(&format!("solver_token={}", env.solver_token)));
+    assert!(event.contains(&format!("amount={}", amount)));
+    assert!(event.contains(&format!("approver={}", env.approver.pubkey())));
+    assert!(event.contains(&format!("approval_hash={}", hex::encode(expected_hash))));
+}
@@ -255,6 +255,7 @@ pub fn create_escrow_ix(
         data: EscrowInstruction::CreateEscrow {
             intent_id,
             amount,
+            refund_recipient: None,
         }
         .try_to_vec()
         .unwrap(),
@@ -270,6 +271,7 @@ pub fn create_claim_ix(
     vault_pda: Pubkey,
     solver_token: Pubkey,
 ) -> Instruction {
+    let (state_pda, _) = Pubkey::find_program_address(&[seeds::STATE_SEED], &program_id);
     Instruction {
         program_id,
         accounts: vec![
@@ -278,6 +280,7 @@ pub fn create_claim_ix(
             AccountMeta::new(vault_pda, false),
             AccountMeta::new(solver_token, false),
             AccountMeta::new_readonly(spl_token::id(), false),
+            AccountMeta::new_readonly(state_pda, false),
         ],
         data: EscrowInstruction::Claim { intent_id }.try_to_vec().unwrap(),
     }
@@ -293,6 +296,7 @@ pub fn create_cancel_ix(
     vault_pda: Pubkey,
     gmp_config_pda: Pubkey,
 ) -> Instruction {
+    let (state_pda, _) = Pubkey::find_program_address(&[seeds::STATE_SEED], &program_id);
     Instruction {
         program_id,
         accounts: vec![
@@ -302,6 +306,7 @@ pub fn create_cancel_ix(
             AccountMeta::new(requester_token, false),
             AccountMeta::new_readonly(spl_token::id(), false),
             AccountMeta::new_readonly(gmp_config_pda, false),
+            AccountMeta::new_readonly(state_pda, false),
         ],
         data: EscrowInstruction::Cancel { intent_id }
             .try_to_vec()
@@ -309,6 +314,32 @@ pub fn create_cancel_ix(
     }
 }
 
+// Helper: Build a SweepVaultExcess instruction (admin-only, requires gmp_config for auth)
+pub fn create_sweep_vault_excess_ix(
+    program_id: Pubkey,
+    intent_id: [u8; 32],
+    admin: Pubkey,
+    recovery_token: Pubkey,
+    escrow_pda: Pubkey,
+    vault_pda: Pubkey,
+    gmp_config_pda: Pubkey,
+) -> Instruction {
+    Instruction {
+        program_id,
+        accounts: vec![
+            AccountMeta::new_readonly(escrow_pda, false),
+            AccountMeta::new_readonly(admin, true),
+            AccountMeta::new(vault_pda, false),
+            AccountMeta::new(recovery_token, false),
+            AccountMeta::new_readonly(spl_token::id(), false),
+            AccountMeta::new_readonly(gmp_config_pda, false),
+        ],
+        data: EscrowInstruction::SweepVaultExcess { intent_id }
+            .try_to_vec()
+            .unwrap(),
+    }
+}
+
 // Helper: Build a SetGmpConfig instruction
 pub fn create_set_gmp_config_ix(
     program_id: Pubkey,
@@ -335,6 +366,53 @@ pub fn create_set_gmp_config_ix(
     }
 }
 
+// Helper: Build a SetClockSkewTolerance instruction (admin-only, requires gmp_config for auth)
+pub fn create_set_clock_skew_tolerance_ix(
+    program_id: Pubkey,
+    state_pda: Pubkey,
+    admin: Pubkey,
+    gmp_config_pda: Pubkey,
+    tolerance_secs: i64,
+) -> Instruction {
+    Instruction {
+        program_id,
+        accounts: vec![
+            AccountMeta::new(state_pda, false),
+            AccountMeta::new(admin, true),
+            AccountMeta::new_readonly(gmp_config_pda, false),
+            AccountMeta::new_readonly(solana_sdk::system_program::id(), false),
+        ],
+        data: EscrowInstruction::SetClockSkewTolerance { tolerance_secs }
+            .try_to_vec()
+            .unwrap(),
+    }
+}
+
+// Helper: Build an ExtendExpiry instruction (requester-only)
+pub fn create_extend_expiry_ix(
+    program_id: Pubkey,
+    intent_id: [u8; 32],
+    escrow_pda: Pubkey,
+    requester: Pubkey,
+    new_duration: i64,
+) -> Instruction {
+    let (state_pda, _) = Pubkey::find_program_address(&[seeds::STATE_SEED], &program_id);
+    Instruction {
+        program_id,
+        accounts: vec![
+            AccountMeta::new(escrow_pda, false),
+            AccountMeta::new_readonly(requester, true),
+            AccountMeta::new_readonly(state_pda, false),
+        ],
+        data: EscrowInstruction::ExtendExpiry {
+            intent_id,
+            new_duration,
+        }
+        .try_to_vec()
+        .unwrap(),
+    }
+}
+
 // Helper: Build an GmpReceiveRequirements instruction
 pub fn create_gmp_receive_requirements_ix(
     program_id: Pubkey,
@@ -365,6 +443,41 @@ pub fn create_gmp_receive_requirements_ix(
     }
 }
 
+// Helper: Build a GmpReceiveRequirementsBatch instruction (one requirements PDA per item)
+pub fn create_gmp_receive_requirements_batch_ix(
+    program_id: Pubkey,
+    requirements_pdas: &[Pubkey],
+    gmp_config_pda: Pubkey,
+    gmp_caller: Pubkey,
+    payer: Pubkey,
+    src_chain_id: u32,
+    remote_gmp_endpoint_addr: [u8; 32],
+    payload: Vec<u8>,
+) -> Instruction {
+    let mut accounts = vec![
+        AccountMeta::new_readonly(gmp_config_pda, false),
+        AccountMeta::new_readonly(gmp_caller, true),
+        AccountMeta::new(payer, true),
+        AccountMeta::new_readonly(solana_sdk::system_program::id(), false),
+    ];
+    accounts.extend(
+        requirements_pdas
+            .iter()
+            .map(|pda| AccountMeta::new(*pda, false)),
+    );
+    Instruction {
+        program_id,
+        accounts,
+        data: EscrowInstruction::GmpReceiveRequirementsBatch {
+            src_chain_id,
+            remote_gmp_endpoint_addr,
+            payload,
+        }
+        .try_to_vec()
+        .unwrap(),
+    }
+}
+
 // Helper: Build an GmpReceiveFulfillmentProof instruction
 pub fn create_gmp_receive_fulfillment_proof_ix(
     program_id: Pubkey,
@@ -378,6 +491,7 @@ pub fn create_gmp_receive_fulfillment_proof_ix(
     remote_gmp_endpoint_addr: [u8; 32],
     payload: Vec<u8>,
 ) -> Instruction {
+    let (state_pda, _) = Pubkey::find_program_address(&[seeds::STATE_SEED], &program_id);
     Instruction {
         program_id,
         accounts: vec![
@@ -388,6 +502,7 @@ pub fn create_gmp_receive_fulfillment_proof_ix(
             AccountMeta::new_readonly(gmp_config_pda, false),
             AccountMeta::new_readonly(gmp_caller, true),
             AccountMeta::new_readonly(spl_token::id(), false),
+            AccountMeta::new_readonly(state_pda, false),
         ],
         data: EscrowInstruction::GmpReceiveFulfillmentProof {
             src_chain_id,
@@ -444,6 +559,7 @@ pub fn create_gmp_receive_generic_fulfillment_ix(
     remote_gmp_endpoint_addr: [u8; 32],
     payload: Vec<u8>,
 ) -> Instruction {
+    let (state_pda, _) = Pubkey::find_program_address(&[seeds::STATE_SEED], &program_id);
     Instruction {
         program_id,
         accounts: vec![
@@ -454,6 +570,7 @@ pub fn create_gmp_receive_generic_fulfillment_ix(
             AccountMeta::new_readonly(gmp_config_pda, false),
             AccountMeta::new_readonly(gmp_caller, true),
             AccountMeta::new_readonly(spl_token::id(), false),
+            AccountMeta::new_readonly(state_pda, false),
         ],
         data: EscrowInstruction::GmpReceive {
             src_chain_id,
@@ -467,12 +584,12 @@ pub fn create_gmp_receive_generic_fulfillment_ix(
 
 // Helper: Read escrow state from account data
 pub fn read_escrow(account: &solana_sdk::account::Account) -> Escrow {
-    Escrow::try_from_slice(&account.data).unwrap()
+    Escrow::unpack(&account.data).unwrap()
 }
 
 // Helper: Read global state from account data
 pub fn read_state(account: &solana_sdk::account::Account) -> EscrowState {
-    EscrowState::try_from_slice(&account.data).unwrap()
+    EscrowState::unpack(&account.data).unwrap()
 }
 
 // Helper: Read stored intent requirements from account data
@@ -1,13 +1,22 @@
 mod common;
 
 use common::{
-    create_cancel_ix, create_escrow_ix, create_mint, create_set_gmp_config_ix, create_token_account,
-    generate_intent_id, initialize_program, mint_to, program_test, read_escrow, send_tx,
-    setup_basic_env, setup_gmp_requirements, setup_gmp_requirements_custom, DUMMY_HUB_CHAIN_ID,
+    create_cancel_ix, create_claim_ix, create_escrow_ix, create_gmp_receive_fulfillment_proof_ix,
+    create_mint, create_set_gmp_config_ix, create_token_account, generate_intent_id,
+    initialize_program, mint_to, program_test, read_escrow, send_tx, setup_basic_env,
+    setup_gmp_requirements, setup_gmp_requirements_custom, TestEnv, DUMMY_HUB_CHAIN_ID,
     DUMMY_HUB_GMP_ENDPOINT_ADDR,
 };
+use gmp_common::messages::FulfillmentProof;
+use intent_inflow_escrow::error::EscrowError;
 use intent_inflow_escrow::state::seeds;
-use solana_sdk::{pubkey::Pubkey, signature::Signer, transaction::Transaction};
+use solana_program_test::ProgramTestContext;
+use solana_sdk::{
+    instruction::{Instruction, InstructionError},
+    pubkey::Pubkey,
+    signature::{Keypair, Signer},
+    transaction::{Transaction, TransactionError},
+};
 
 // 1. Test: Zero Amount Rejection
 // Verifies that createEscrow reverts when amount is zero.
@@ -386,3 +395,254 @@ async fn test_reject_if_requester_has_insufficient_balance() {
     // Token transfer error
     assert!(result.is_err(), "Should have thrown an error");
 }
+
+// ============================================================================
+// ACCOUNT ORDERING TESTS
+// ============================================================================
+// Release instructions read accounts positionally. These tests pass swapped or
+// substituted accounts and expect a specific error from the upfront account
+// validation rather than a failure inside the token CPI.
+
+// Create a funded, active escrow and return (intent_id, escrow_pda, vault_pda, requirements_pda)
+async fn create_active_escrow(
+    context: &mut ProgramTestContext,
+    env: &TestEnv,
+) -> ([u8; 32], Pubkey, Pubkey, Pubkey) {
+    let intent_id = generate_intent_id();
+    let amount = 500_000u64;
+    let requirements_pda = setup_gmp_requirements(context, env, intent_id, amount, u64::MAX).await;
+    let ix = create_escrow_ix(
+        env.program_id,
+        intent_id,
+        amount,
+        env.requester.pubkey(),
+        env.mint,
+        env.requester_token,
+        env.solver.pubkey(),
+        requirements_pda,
+    );
+    let blockhash = context.banks_client.get_latest_blockhash().await.unwrap();
+    let tx = Transaction::new_signed_with_payer(
+        &[ix],
+        Some(&env.requester.pubkey()),
+        &[&env.requester],
+        blockhash,
+    );
+    context.banks_client.process_transaction(tx).await.unwrap();
+
+    let (escrow_pda, _) =
+        Pubkey::find_program_address(&[seeds::ESCROW_SEED, &intent_id], &env.program_id);
+    let (vault_pda, _) =
+        Pubkey::find_program_address(&[seeds::VAULT_SEED, &intent_id], &env.program_id);
+    (intent_id, escrow_pda, vault_pda, requirements_pda)
+}
+
+// Submit a single instruction and return the transaction error it fails with
+async fn expect_tx_error(
+    context: &mut ProgramTestContext,
+    ix: Instruction,
+    signer: &Keypair,
+) -> TransactionError {
+    let blockhash = context.banks_client.get_latest_blockhash().await.unwrap();
+    let tx =
+        Transaction::new_signed_with_payer(&[ix], Some(&signer.pubkey()), &[signer], blockhash);
+    context
+        .banks_client
+        .process_transaction(tx)
+        .await
+        .unwrap_err()
+        .unwrap()
+}
+
+// 17. Test: Claim Rejects Swapped Vault And Solver Token Accounts
+// Verifies that Claim fails with InvalidPda when the escrow vault and solver token account are passed in each other's positions.
+// Why: Both are token accounts, so the swap is easy to make; without the vault PDA check the release would try to move funds the wrong way.
+#[tokio::test]
+async fn test_claim_rejects_swapped_vault_and_solver_token() {
+    let program_test = program_test();
+    let mut context = program_test.start_with_context().await;
+    let env = setup_basic_env(&mut context).await;
+    let (intent_id, escrow_pda, vault_pda, requirements_pda) =
+        create_active_escrow(&mut context, &env).await;
+
+    let ix = create_claim_ix(
+        env.program_id,
+        intent_id,
+        escrow_pda,
+        requirements_pda,
+        env.solver_token,
+        vault_pda,
+    );
+    let err = expect_tx_error(&mut context, ix, &env.solver).await;
+
+    assert_eq!(
+        err,
+        TransactionError::InstructionError(
+            0,
+            InstructionError::Custom(EscrowError::InvalidPda as u32)
+        )
+    );
+}
+
+// 18. Test: Claim Rejects A Non-Token Program In The Token Program Slot
+// Verifies that Claim fails with IncorrectProgramId when the token program account is not the SPL token program.
+// Why: The release CPI is signed with the escrow PDA; it must only ever be handed to the real token program.
+#[tokio::test]
+async fn test_claim_rejects_wrong_token_program() {
+    let program_test = program_test();
+    let mut context = program_test.start_with_context().await;
+    let env = setup_basic_env(&mut context).await;
+    let (intent_id, escrow_pda, vault_pda, requirements_pda) =
+        create_active_escrow(&mut context, &env).await;
+
+    let mut ix = create_claim_ix(
+        env.program_id,
+        intent_id,
+        escrow_pda,
+        requirements_pda,
+        vault_pda,
+        env.solver_token,
+    );
+    ix.accounts[4].pubkey = env.program_id;
+    let err = expect_tx_error(&mut context, ix, &env.solver).await;
+
+    assert_eq!(
+        err,
+        TransactionError::InstructionError(0, InstructionError::IncorrectProgramId)
+    );
+}
+
+// 19. Test: Cancel Rejects A Token Account In The Escrow Slot
+// Verifies that Cancel fails with InvalidAccountOwner when a token account is substituted for the escrow account.
+// Why: The escrow account authorizes the vault transfer; accepting an account this program does not own would let callers forge escrow state.
+#[tokio::test]
+async fn test_cancel_rejects_substituted_escrow_account() {
+    let program_test = program_test();
+    let mut context = program_test.start_with_context().await;
+    let env = setup_basic_env(&mut context).await;
+    let (intent_id, _escrow_pda, vault_pda, _requirements_pda) =
+        create_active_escrow(&mut context, &env).await;
+
+    let admin = context.payer.insecure_clone();
+    let ix = create_cancel_ix(
+        env.program_id,
+        intent_id,
+        admin.pubkey(),
+        env.requester_token,
+        env.solver_token,
+        vault_pda,
+        env.gmp_config_pda,
+    );
+    let err = expect_tx_error(&mut context, ix, &admin).await;
+
+    assert_eq!(
+        err,
+        TransactionError::InstructionError(
+            0,
+            InstructionError::Custom(EscrowError::InvalidAccountOwner as u32)
+        )
+    );
+}
+
+// 20. Test: Fulfillment Proof Rejects Swapped Vault And Solver Token Accounts
+// Verifies that GmpReceiveFulfillmentProof fails with InvalidPda when the vault and solver token account are swapped, and the escrow stays funded.
+// Why: This is the primary release path; a relay building accounts in the wrong order must not get as far as the token transfer.
+#[tokio::test]
+async fn test_fulfillment_proof_rejects_swapped_vault_and_solver_token() {
+    let program_test = program_test();
+    let mut context = program_test.start_with_context().await;
+    let env = setup_basic_env(&mut context).await;
+    let (intent_id, escrow_pda, vault_pda, requirements_pda) =
+        create_active_escrow(&mut context, &env).await;
+
+    let payload = FulfillmentProof {
+        intent_id,
+        solver_addr: env.solver.pubkey().to_bytes(),
+        amount_fulfilled: 500_000,
+        timestamp: 12345,
+    }
+    .encode()
+    .to_vec();
+    let gmp_caller = context.payer.insecure_clone();
+    let ix = create_gmp_receive_fulfillment_proof_ix(
+        env.program_id,
+        requirements_pda,
+        escrow_pda,
+        env.solver_token,
+        vault_pda,
+        env.gmp_config_pda,
+        gmp_caller.pubkey(),
+        env.hub_chain_id,
+        env.hub_gmp_endpoint_addr,
+        payload,
+    );
+    let err = expect_tx_error(&mut context, ix, &gmp_caller).await;
+
+    assert_eq!(
+        err,
+        TransactionError::InstructionError(
+            0,
+            InstructionError::Custom(EscrowError::InvalidPda as u32)
+        )
+    );
+    let escrow_account = context
+        .banks_client
+        .get_account(escrow_pda)
+        .await
+        .unwrap()
+        .unwrap();
+    assert_eq!(read_escrow(&escrow_account).amount, 500_000);
+}
+
+// 21. Test: Create Escrow Rejects A Source Token Account Of Another Mint
+// Verifies that CreateEscrow fails with TokenMintMismatch when the requester token account holds a different mint, and no escrow account is created.
+// Why: The vault is created for the escrow mint; a mismatched source must be rejected upfront rather than surfacing as an opaque token program error after the escrow and vault are allocated.
+#[tokio::test]
+async fn test_create_escrow_rejects_source_of_another_mint() {
+    let program_test = program_test();
+    let mut context = program_test.start_with_context().await;
+    let env = setup_basic_env(&mut context).await;
+    let payer = context.payer.insecure_clone();
+
+    let intent_id = generate_intent_id();
+    let amount = 500_000u64;
+    let requirements_pda =
+        setup_gmp_requirements(&mut context, &env, intent_id, amount, u64::MAX).await;
+
+    let other_mint = create_mint(&mut context, &payer, &env.mint_authority, 6).await;
+    let other_token =
+        create_token_account(&mut context, &payer, other_mint, env.requester.pubkey()).await;
+    mint_to(
+        &mut context,
+        &payer,
+        other_mint,
+        &env.mint_authority,
+        other_token,
+        amount,
+    )
+    .await;
+
+    let ix = create_escrow_ix(
+        env.program_id,
+        intent_id,
+        amount,
+        env.requester.pubkey(),
+        env.mint,
+        other_token,
+        env.solver.pubkey(),
+        requirements_pda,
+    );
+    let err = expect_tx_error(&mut context, ix, &env.requester).await;
+
+    assert_eq!(
+        err,
+        TransactionError::InstructionError(
+            0,
+            InstructionError::Custom(EscrowError::TokenMintMismatch as u32)
+        )
+    );
+    let (escrow_pda, _) =
+        Pubkey::find_program_address(&[seeds::ESCROW_SEED, &intent_id], &env.program_id);
+    let escrow_account = context.banks_client.get_account(escrow_pda).await.unwrap();
+    assert!(escrow_account.is_none());
+}
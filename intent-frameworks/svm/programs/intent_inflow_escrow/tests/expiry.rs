@@ -1,18 +1,27 @@
 mod common;
 
+use borsh::{BorshDeserialize, BorshSerialize};
 use common::{
-    create_cancel_ix, create_escrow_ix, create_gmp_receive_fulfillment_proof_ix,
-    generate_intent_id, get_token_balance, program_test, read_escrow, setup_basic_env,
-    setup_gmp_requirements, DUMMY_HUB_CHAIN_ID, DUMMY_HUB_GMP_ENDPOINT_ADDR,
+    create_cancel_ix, create_claim_ix, create_escrow_ix, create_extend_expiry_ix,
+    create_gmp_receive_fulfillment_proof_ix, create_set_clock_skew_tolerance_ix,
+    generate_intent_id, get_token_balance, program_test, read_escrow, read_state,
+    setup_basic_env, setup_gmp_requirements, TestEnv, DUMMY_HUB_CHAIN_ID,
+    DUMMY_HUB_GMP_ENDPOINT_ADDR,
 };
 use gmp_common::messages::FulfillmentProof;
-use intent_inflow_escrow::state::seeds;
+use intent_inflow_escrow::error::EscrowError;
+use intent_inflow_escrow::state::{
+    seeds, Escrow, EscrowState, EscrowStatus, StoredIntentRequirements,
+};
+use solana_program_test::{BanksClientError, ProgramTestContext};
 use solana_sdk::{
+    account::AccountSharedData,
     clock::Clock,
+    instruction::InstructionError,
     pubkey::Pubkey,
-    signature::Signer,
+    signature::{Keypair, Signer},
     sysvar,
-    transaction::Transaction,
+    transaction::{Transaction, TransactionError},
 };
 use bincode::deserialize;
 
@@ -153,7 +162,7 @@ async fn test_allow_admin_to_cancel_expired_escrow() {
     let vault_balance = get_token_balance(&mut context, vault_pda).await;
     assert_eq!(vault_balance, 0);
 
-    // Verify escrow state (isClaimed = true after cancel)
+    // Verify escrow state (Cancelled after cancel)
     let escrow_account = context
         .banks_client
         .get_account(escrow_pda)
@@ -161,7 +170,7 @@ async fn test_allow_admin_to_cancel_expired_escrow() {
         .unwrap()
         .unwrap();
     let escrow = read_escrow(&escrow_account);
-    assert!(escrow.is_claimed);
+    assert_eq!(escrow.status, EscrowStatus::Cancelled);
 }
 
 // 2. Test: Expiry Timestamp Validation
@@ -237,8 +246,8 @@ async fn test_verify_expiry_timestamp_is_stored_correctly() {
     // Verify amount
     assert_eq!(escrow.amount, amount);
 
-    // Verify isClaimed = false
-    assert!(!escrow.is_claimed);
+    // Verify status is still Active
+    assert_eq!(escrow.status, EscrowStatus::Active);
 
     // Verify expiry: comes directly from requirements (block_time + 120)
     let expected_expiry = block_time + 120;
@@ -377,6 +386,801 @@ async fn test_expired_escrow_can_be_fulfilled_via_gmp() {
         .unwrap()
         .unwrap();
     let escrow = read_escrow(&escrow_account);
-    assert!(escrow.is_claimed);
+    assert_eq!(escrow.status, EscrowStatus::Claimed);
     assert_eq!(escrow.amount, 0);
 }
+
+// ============================================================================
+// CLOCK SKEW TOLERANCE TESTS
+// ============================================================================
+// The state's clock_skew_tolerance moves the claim/cancel boundary from
+// `expiry` to `expiry + tolerance`: claims are allowed up to and including the
+// boundary, cancels only strictly after it, so the windows never overlap.
+
+const SKEW_TOLERANCE: i64 = 30;
+const SKEW_TEST_AMOUNT: u64 = 100_000;
+
+// Helper: Read the current on-chain clock
+async fn current_clock(context: &mut ProgramTestContext) -> Clock {
+    let clock_account = context
+        .banks_client
+        .get_account(sysvar::clock::id())
+        .await
+        .unwrap()
+        .unwrap();
+    deserialize(&clock_account.data).unwrap()
+}
+
+// Helper: Set the on-chain clock to `unix_timestamp`
+async fn set_clock(context: &mut ProgramTestContext, unix_timestamp: i64) {
+    let mut clock = current_clock(context).await;
+    clock.unix_timestamp = unix_timestamp;
+    context.set_sysvar(&clock);
+}
+
+// Helper: Set the clock skew tolerance as the admin (requester in basic env)
+async fn set_skew_tolerance(
+    context: &mut ProgramTestContext,
+    env: &TestEnv,
+    admin: &Keypair,
+    tolerance_secs: i64,
+) -> Result<(), BanksClientError> {
+    let ix = create_set_clock_skew_tolerance_ix(
+        env.program_id,
+        env.state_pda,
+        admin.pubkey(),
+        env.gmp_config_pda,
+        tolerance_secs,
+    );
+    let blockhash = context.banks_client.get_latest_blockhash().await.unwrap();
+    let tx = Transaction::new_signed_with_payer(
+        &[ix],
+        Some(&admin.pubkey()),
+        &[admin],
+        blockhash,
+    );
+    context.banks_client.process_transaction(tx).await
+}
+
+// Helper: Create an escrow expiring 100s from now and return (intent_id, expiry)
+async fn create_skew_test_escrow(
+    context: &mut ProgramTestContext,
+    env: &TestEnv,
+) -> ([u8; 32], i64) {
+    let intent_id = generate_intent_id();
+    let now = current_clock(context).await.unix_timestamp;
+    let requirements_pda = setup_gmp_requirements(
+        context,
+        env,
+        intent_id,
+        SKEW_TEST_AMOUNT,
+        (now as u64) + 100,
+    )
+    .await;
+
+    let create_ix = create_escrow_ix(
+        env.program_id,
+        intent_id,
+        SKEW_TEST_AMOUNT,
+        env.requester.pubkey(),
+        env.mint,
+        env.requester_token,
+        env.solver.pubkey(),
+        requirements_pda,
+    );
+    let blockhash = context.banks_client.get_latest_blockhash().await.unwrap();
+    let create_tx = Transaction::new_signed_with_payer(
+        &[create_ix],
+        Some(&env.requester.pubkey()),
+        &[&env.requester],
+        blockhash,
+    );
+    context.banks_client.process_transaction(create_tx).await.unwrap();
+
+    let (escrow_pda, _) =
+        Pubkey::find_program_address(&[seeds::ESCROW_SEED, &intent_id], &env.program_id);
+    let escrow_account = context
+        .banks_client
+        .get_account(escrow_pda)
+        .await
+        .unwrap()
+        .unwrap();
+    (intent_id, read_escrow(&escrow_account).expiry)
+}
+
+// Helper: Mark stored requirements as fulfilled without auto-releasing, so the
+// standalone Claim instruction can be exercised.
+async fn mark_requirements_fulfilled(
+    context: &mut ProgramTestContext,
+    env: &TestEnv,
+    intent_id: [u8; 32],
+) {
+    let (requirements_pda, _) = Pubkey::find_program_address(
+        &[seeds::REQUIREMENTS_SEED, &intent_id],
+        &env.program_id,
+    );
+    let mut account = context
+        .banks_client
+        .get_account(requirements_pda)
+        .await
+        .unwrap()
+        .unwrap();
+    let mut requirements = StoredIntentRequirements::try_from_slice(&account.data).unwrap();
+    requirements.fulfilled = true;
+    requirements
+        .serialize(&mut &mut account.data[..])
+        .unwrap();
+    context.set_account(&requirements_pda, &AccountSharedData::from(account));
+}
+
+// Helper: Create a fulfilled escrow and claim it at `expiry + offset`
+async fn claim_at_offset(
+    context: &mut ProgramTestContext,
+    env: &TestEnv,
+    offset: i64,
+) -> Result<(), BanksClientError> {
+    let (intent_id, expiry) = create_skew_test_escrow(context, env).await;
+    mark_requirements_fulfilled(context, env, intent_id).await;
+    set_clock(context, expiry + offset).await;
+
+    let (escrow_pda, _) =
+        Pubkey::find_program_address(&[seeds::ESCROW_SEED, &intent_id], &env.program_id);
+    let (vault_pda, _) =
+        Pubkey::find_program_address(&[seeds::VAULT_SEED, &intent_id], &env.program_id);
+    let (requirements_pda, _) = Pubkey::find_program_address(
+        &[seeds::REQUIREMENTS_SEED, &intent_id],
+        &env.program_id,
+    );
+    let claim_ix = create_claim_ix(
+        env.program_id,
+        intent_id,
+        escrow_pda,
+        requirements_pda,
+        vault_pda,
+        env.solver_token,
+    );
+
+    let blockhash = context.banks_client.get_latest_blockhash().await.unwrap();
+    let tx = Transaction::new_signed_with_payer(
+        &[claim_ix],
+        Some(&env.solver.pubkey()),
+        &[&env.solver],
+        blockhash,
+    );
+    context.banks_client.process_transaction(tx).await
+}
+
+// Helper: Create an escrow and cancel it as admin at `expiry + offset`
+async fn cancel_at_offset(
+    context: &mut ProgramTestContext,
+    env: &TestEnv,
+    offset: i64,
+) -> Result<(), BanksClientError> {
+    let (intent_id, expiry) = create_skew_test_escrow(context, env).await;
+    set_clock(context, expiry + offset).await;
+
+    let (escrow_pda, _) =
+        Pubkey::find_program_address(&[seeds::ESCROW_SEED, &intent_id], &env.program_id);
+    let (vault_pda, _) =
+        Pubkey::find_program_address(&[seeds::VAULT_SEED, &intent_id], &env.program_id);
+    let cancel_ix = create_cancel_ix(
+        env.program_id,
+        intent_id,
+        env.requester.pubkey(),
+        env.requester_token,
+        escrow_pda,
+        vault_pda,
+        env.gmp_config_pda,
+    );
+
+    let blockhash = context.banks_client.get_latest_blockhash().await.unwrap();
+    let tx = Transaction::new_signed_with_payer(
+        &[cancel_ix],
+        Some(&env.requester.pubkey()),
+        &[&env.requester],
+        blockhash,
+    );
+    context.banks_client.process_transaction(tx).await
+}
+
+// 4. Test: Default Clock Skew Tolerance Keeps Exact Expiry
+// Verifies that the tolerance defaults to 0, so claim is allowed at expiry and cancel only after it.
+// Why: Deployments that never configure a tolerance must keep the previous expiry behavior.
+#[tokio::test]
+async fn test_default_clock_skew_tolerance_keeps_exact_expiry() {
+    let program_test = program_test();
+    let mut context = program_test.start_with_context().await;
+    let env = setup_basic_env(&mut context).await;
+
+    let state_account = context
+        .banks_client
+        .get_account(env.state_pda)
+        .await
+        .unwrap()
+        .unwrap();
+    assert_eq!(read_state(&state_account).clock_skew_tolerance, 0);
+
+    assert!(claim_at_offset(&mut context, &env, 0).await.is_ok());
+    assert!(claim_at_offset(&mut context, &env, 1).await.is_err());
+    assert!(cancel_at_offset(&mut context, &env, 0).await.is_err());
+    assert!(cancel_at_offset(&mut context, &env, 1).await.is_ok());
+}
+
+// 5. Test: Clock Skew Tolerance Widens Claim Window
+// Verifies that with a tolerance, claims succeed at expiry - tolerance, expiry and
+// expiry + tolerance, and fail one second later.
+// Why: A validator clock slightly ahead must not reject a solver's claim that is valid on the hub.
+#[tokio::test]
+async fn test_clock_skew_tolerance_widens_claim_window() {
+    let program_test = program_test();
+    let mut context = program_test.start_with_context().await;
+    let env = setup_basic_env(&mut context).await;
+    let admin = env.requester.insecure_clone();
+    set_skew_tolerance(&mut context, &env, &admin, SKEW_TOLERANCE)
+        .await
+        .unwrap();
+
+    assert!(claim_at_offset(&mut context, &env, -SKEW_TOLERANCE).await.is_ok());
+    assert!(claim_at_offset(&mut context, &env, 0).await.is_ok());
+    assert!(claim_at_offset(&mut context, &env, SKEW_TOLERANCE).await.is_ok());
+    assert!(
+        claim_at_offset(&mut context, &env, SKEW_TOLERANCE + 1)
+            .await
+            .is_err(),
+        "Claim after expiry + tolerance should be rejected"
+    );
+}
+
+// 6. Test: Clock Skew Tolerance Delays Cancel Window
+// Verifies that with a tolerance, cancels fail at expiry - tolerance, expiry and
+// expiry + tolerance, and succeed one second later.
+// Why: Cancel must not open while a claim is still allowed, or both could be valid at once.
+#[tokio::test]
+async fn test_clock_skew_tolerance_delays_cancel_window() {
+    let program_test = program_test();
+    let mut context = program_test.start_with_context().await;
+    let env = setup_basic_env(&mut context).await;
+    let admin = env.requester.insecure_clone();
+    set_skew_tolerance(&mut context, &env, &admin, SKEW_TOLERANCE)
+        .await
+        .unwrap();
+
+    assert!(cancel_at_offset(&mut context, &env, -SKEW_TOLERANCE).await.is_err());
+    assert!(cancel_at_offset(&mut context, &env, 0).await.is_err());
+    assert!(cancel_at_offset(&mut context, &env, SKEW_TOLERANCE).await.is_err());
+    assert!(
+        cancel_at_offset(&mut context, &env, SKEW_TOLERANCE + 1)
+            .await
+            .is_ok(),
+        "Cancel after expiry + tolerance should succeed"
+    );
+}
+
+// 7. Test: Clock Skew Tolerance Authorization and Bounds
+// Verifies that only the GMP config admin can set the tolerance and that negative or
+// oversized values are rejected.
+// Why: The tolerance shifts when funds can be refunded, so it must be admin-controlled and small.
+#[tokio::test]
+async fn test_clock_skew_tolerance_requires_admin_and_bounds() {
+    let program_test = program_test();
+    let mut context = program_test.start_with_context().await;
+    let env = setup_basic_env(&mut context).await;
+    let admin = env.requester.insecure_clone();
+    let non_admin = env.solver.insecure_clone();
+
+    assert!(set_skew_tolerance(&mut context, &env, &non_admin, SKEW_TOLERANCE)
+        .await
+        .is_err());
+    assert!(set_skew_tolerance(&mut context, &env, &admin, -1)
+        .await
+        .is_err());
+    assert!(set_skew_tolerance(
+        &mut context,
+        &env,
+        &admin,
+        EscrowState::MAX_CLOCK_SKEW_TOLERANCE + 1
+    )
+    .await
+    .is_err());
+
+    set_skew_tolerance(&mut context, &env, &admin, EscrowState::MAX_CLOCK_SKEW_TOLERANCE)
+        .await
+        .unwrap();
+    let state_account = context
+        .banks_client
+        .get_account(env.state_pda)
+        .await
+        .unwrap()
+        .unwrap();
+    assert_eq!(
+        read_state(&state_account).clock_skew_tolerance,
+        EscrowState::MAX_CLOCK_SKEW_TOLERANCE
+    );
+}
+
+// 8. Test: Escrow Creation Rejects Non-Future Expiry
+// Verifies that create_escrow fails with InvalidExpiry when the requirements expiry
+// equals or precedes the current block time.
+// Why: An escrow born at or past its expiry would be cancellable immediately, so
+// creation must require a strictly future expiry, reported with a single error code.
+#[tokio::test]
+async fn test_create_escrow_rejects_non_future_expiry() {
+    let program_test = program_test();
+    let mut context = program_test.start_with_context().await;
+    let env = setup_basic_env(&mut context).await;
+
+    let amount = 1_000_000u64;
+
+    let clock_account = context
+        .banks_client
+        .get_account(sysvar::clock::id())
+        .await
+        .unwrap()
+        .unwrap();
+    let mut clock: Clock = deserialize(&clock_account.data).unwrap();
+    let expiry = clock.unix_timestamp + 120;
+
+    let at_expiry_id = generate_intent_id();
+    let past_expiry_id = generate_intent_id();
+    let at_expiry_pda =
+        setup_gmp_requirements(&mut context, &env, at_expiry_id, amount, expiry as u64).await;
+    let past_expiry_pda =
+        setup_gmp_requirements(&mut context, &env, past_expiry_id, amount, expiry as u64).await;
+
+    // Exactly at the expiry, then strictly past it
+    for (now, intent_id, requirements_pda) in [
+        (expiry, at_expiry_id, at_expiry_pda),
+        (expiry + 1, past_expiry_id, past_expiry_pda),
+    ] {
+        clock.unix_timestamp = now;
+        context.set_sysvar(&clock);
+
+        let ix = create_escrow_ix(
+            env.program_id,
+            intent_id,
+            amount,
+            env.requester.pubkey(),
+            env.mint,
+            env.requester_token,
+            env.solver.pubkey(),
+            requirements_pda,
+        );
+        let blockhash = context.banks_client.get_latest_blockhash().await.unwrap();
+        let tx = Transaction::new_signed_with_payer(
+            &[ix],
+            Some(&env.requester.pubkey()),
+            &[&env.requester],
+            blockhash,
+        );
+        let err = context
+            .banks_client
+            .process_transaction(tx)
+            .await
+            .unwrap_err()
+            .unwrap();
+
+        assert_eq!(
+            err,
+            TransactionError::InstructionError(
+                0,
+                InstructionError::Custom(EscrowError::InvalidExpiry as u32)
+            )
+        );
+    }
+}
+
+// ============================================================================
+// EXPIRY EXTENSION TESTS
+// ============================================================================
+// The requester may push an active escrow's expiry to `now + new_duration`,
+// but never earlier than the current expiry.
+
+// Helper: Send ExtendExpiry for `intent_id`, naming `requester` and signing with `signer`
+async fn extend_expiry(
+    context: &mut ProgramTestContext,
+    env: &TestEnv,
+    intent_id: [u8; 32],
+    requester: Pubkey,
+    signer: &Keypair,
+    new_duration: i64,
+) -> Result<(), BanksClientError> {
+    let (escrow_pda, _) =
+        Pubkey::find_program_address(&[seeds::ESCROW_SEED, &intent_id], &env.program_id);
+    let mut ix = create_extend_expiry_ix(
+        env.program_id,
+        intent_id,
+        escrow_pda,
+        requester,
+        new_duration,
+    );
+    if requester != signer.pubkey() {
+        ix.accounts[1].is_signer = false;
+    }
+    let blockhash = context.banks_client.get_latest_blockhash().await.unwrap();
+    let tx =
+        Transaction::new_signed_with_payer(&[ix], Some(&signer.pubkey()), &[signer], blockhash);
+    context.banks_client.process_transaction(tx).await
+}
+
+// Helper: Assert a transaction failed with the given escrow error
+fn assert_escrow_error(result: Result<(), BanksClientError>, expected: EscrowError) {
+    assert_eq!(
+        result.unwrap_err().unwrap(),
+        TransactionError::InstructionError(0, InstructionError::Custom(expected as u32))
+    );
+}
+
+// 9. Test: Requester Can Extend Expiry
+// Verifies that ExtendExpiry sets expiry to now + new_duration and that cancel stays
+// blocked past the original expiry.
+// Why: A solver that needs more time must not have the escrow refunded under it once
+// the requester has granted an extension.
+#[tokio::test]
+async fn test_requester_can_extend_expiry() {
+    let program_test = program_test();
+    let mut context = program_test.start_with_context().await;
+    let env = setup_basic_env(&mut context).await;
+    let requester = env.requester.insecure_clone();
+
+    let (intent_id, old_expiry) = create_skew_test_escrow(&mut context, &env).await;
+    let now = current_clock(&mut context).await.unix_timestamp;
+    extend_expiry(
+        &mut context,
+        &env,
+        intent_id,
+        requester.pubkey(),
+        &requester,
+        500,
+    )
+    .await
+    .unwrap();
+
+    let (escrow_pda, _) =
+        Pubkey::find_program_address(&[seeds::ESCROW_SEED, &intent_id], &env.program_id);
+    let escrow_account = context
+        .banks_client
+        .get_account(escrow_pda)
+        .await
+        .unwrap()
+        .unwrap();
+    let escrow = read_escrow(&escrow_account);
+    assert_eq!(escrow.expiry, now + 500);
+    assert!(escrow.expiry > old_expiry);
+    assert_eq!(escrow.status, EscrowStatus::Active);
+
+    // Cancel at the original expiry + 1 is now too early
+    set_clock(&mut context, old_expiry + 1).await;
+    let (vault_pda, _) =
+        Pubkey::find_program_address(&[seeds::VAULT_SEED, &intent_id], &env.program_id);
+    let cancel_ix = create_cancel_ix(
+        env.program_id,
+        intent_id,
+        requester.pubkey(),
+        env.requester_token,
+        escrow_pda,
+        vault_pda,
+        env.gmp_config_pda,
+    );
+    let blockhash = context.banks_client.get_latest_blockhash().await.unwrap();
+    let tx = Transaction::new_signed_with_payer(
+        &[cancel_ix],
+        Some(&requester.pubkey()),
+        &[&requester],
+        blockhash,
+    );
+    assert_escrow_error(
+        context.banks_client.process_transaction(tx).await,
+        EscrowError::EscrowNotExpiredYet,
+    );
+}
+
+// 10. Test: Extend Expiry Requires the Requester
+// Verifies that ExtendExpiry fails when another account signs, and when the requester
+// is named but has not signed.
+// Why: Only the depositor may change how long their funds stay locked.
+#[tokio::test]
+async fn test_extend_expiry_requires_requester() {
+    let program_test = program_test();
+    let mut context = program_test.start_with_context().await;
+    let env = setup_basic_env(&mut context).await;
+    let solver = env.solver.insecure_clone();
+
+    let (intent_id, _) = create_skew_test_escrow(&mut context, &env).await;
+
+    assert_escrow_error(
+        extend_expiry(&mut context, &env, intent_id, solver.pubkey(), &solver, 500).await,
+        EscrowError::UnauthorizedCaller,
+    );
+
+    let result = extend_expiry(
+        &mut context,
+        &env,
+        intent_id,
+        env.requester.pubkey(),
+        &solver,
+        500,
+    )
+    .await;
+    assert_eq!(
+        result.unwrap_err().unwrap(),
+        TransactionError::InstructionError(0, InstructionError::MissingRequiredSignature)
+    );
+}
+
+// 11. Test: Extend Expiry Rejects Shortening and Released Escrows
+// Verifies that ExtendExpiry fails with ExpiryNotExtended when now + new_duration is not
+// after the current expiry, and with EscrowAlreadyClaimed once the escrow is claimed.
+// Why: Shortening the expiry would let the escrow be refunded while a solver is still
+// fulfilling, and a released escrow has no expiry left to extend.
+#[tokio::test]
+async fn test_extend_expiry_rejects_shortening_and_released_escrow() {
+    let program_test = program_test();
+    let mut context = program_test.start_with_context().await;
+    let env = setup_basic_env(&mut context).await;
+    let requester = env.requester.insecure_clone();
+
+    // Escrow expires 100s from now, so a 10s or 100s duration is not an extension
+    let (intent_id, _) = create_skew_test_escrow(&mut context, &env).await;
+    for new_duration in [10, 100, -1] {
+        assert_escrow_error(
+            extend_expiry(
+                &mut context,
+                &env,
+                intent_id,
+                requester.pubkey(),
+                &requester,
+                new_duration,
+            )
+            .await,
+            EscrowError::ExpiryNotExtended,
+        );
+    }
+
+    let (claimed_intent_id, _) = create_skew_test_escrow(&mut context, &env).await;
+    mark_requirements_fulfilled(&mut context, &env, claimed_intent_id).await;
+    let (escrow_pda, _) =
+        Pubkey::find_program_address(&[seeds::ESCROW_SEED, &claimed_intent_id], &env.program_id);
+    let (vault_pda, _) =
+        Pubkey::find_program_address(&[seeds::VAULT_SEED, &claimed_intent_id], &env.program_id);
+    let (requirements_pda, _) = Pubkey::find_program_address(
+        &[seeds::REQUIREMENTS_SEED, &claimed_intent_id],
+        &env.program_id,
+    );
+    let claim_ix = create_claim_ix(
+        env.program_id,
+        claimed_intent_id,
+        escrow_pda,
+        requirements_pda,
+        vault_pda,
+        env.solver_token,
+    );
+    let blockhash = context.banks_client.get_latest_blockhash().await.unwrap();
+    let tx = Transaction::new_signed_with_payer(
+        &[claim_ix],
+        Some(&env.solver.pubkey()),
+        &[&env.solver],
+        blockhash,
+    );
+    context.banks_client.process_transaction(tx).await.unwrap();
+
+    assert_escrow_error(
+        extend_expiry(
+            &mut context,
+            &env,
+            claimed_intent_id,
+            requester.pubkey(),
+            &requester,
+            500,
+        )
+        .await,
+        EscrowError::EscrowAlreadyClaimed,
+    );
+}
+
+// Helper: Read the stored expiry of the escrow for `intent_id`
+async fn escrow_expiry(
+    context: &mut ProgramTestContext,
+    env: &TestEnv,
+    intent_id: [u8; 32],
+) -> i64 {
+    let (escrow_pda, _) =
+        Pubkey::find_program_address(&[seeds::ESCROW_SEED, &intent_id], &env.program_id);
+    let escrow_account = context
+        .banks_client
+        .get_account(escrow_pda)
+        .await
+        .unwrap()
+        .unwrap();
+    read_escrow(&escrow_account).expiry
+}
+
+// 12. Test: Extend Expiry Enforces the Minimum Duration
+// Verifies that ExtendExpiry fails with ExpiryTooShort at MIN_EXPIRY_DURATION - 1 and
+// succeeds at exactly MIN_EXPIRY_DURATION.
+// Why: An expiry a few seconds out leaves no solver time to fulfill, so the escrow
+// could only ever be cancelled.
+#[tokio::test]
+async fn test_extend_expiry_enforces_min_duration() {
+    let program_test = program_test();
+    let mut context = program_test.start_with_context().await;
+    let env = setup_basic_env(&mut context).await;
+    let requester = env.requester.insecure_clone();
+
+    // Escrow expires 100s from now, so MIN - 1 is still a later expiry
+    let (intent_id, old_expiry) = create_skew_test_escrow(&mut context, &env).await;
+    assert_escrow_error(
+        extend_expiry(
+            &mut context,
+            &env,
+            intent_id,
+            requester.pubkey(),
+            &requester,
+            Escrow::MIN_EXPIRY_DURATION - 1,
+        )
+        .await,
+        EscrowError::ExpiryTooShort,
+    );
+    assert_eq!(
+        escrow_expiry(&mut context, &env, intent_id).await,
+        old_expiry
+    );
+
+    let now = current_clock(&mut context).await.unix_timestamp;
+    extend_expiry(
+        &mut context,
+        &env,
+        intent_id,
+        requester.pubkey(),
+        &requester,
+        Escrow::MIN_EXPIRY_DURATION,
+    )
+    .await
+    .unwrap();
+    assert_eq!(
+        escrow_expiry(&mut context, &env, intent_id).await,
+        now + Escrow::MIN_EXPIRY_DURATION
+    );
+}
+
+// 13. Test: Extend Expiry Enforces the Maximum Duration
+// Verifies that ExtendExpiry fails with ExpiryTooLong at MAX_EXPIRY_DURATION + 1 and
+// succeeds at exactly MAX_EXPIRY_DURATION.
+// Why: A mistyped duration must not lock the requester's funds for months.
+#[tokio::test]
+async fn test_extend_expiry_enforces_max_duration() {
+    let program_test = program_test();
+    let mut context = program_test.start_with_context().await;
+    let env = setup_basic_env(&mut context).await;
+    let requester = env.requester.insecure_clone();
+
+    let (intent_id, old_expiry) = create_skew_test_escrow(&mut context, &env).await;
+    assert_escrow_error(
+        extend_expiry(
+            &mut context,
+            &env,
+            intent_id,
+            requester.pubkey(),
+            &requester,
+            Escrow::MAX_EXPIRY_DURATION + 1,
+        )
+        .await,
+        EscrowError::ExpiryTooLong,
+    );
+    assert_eq!(
+        escrow_expiry(&mut context, &env, intent_id).await,
+        old_expiry
+    );
+
+    let now = current_clock(&mut context).await.unix_timestamp;
+    extend_expiry(
+        &mut context,
+        &env,
+        intent_id,
+        requester.pubkey(),
+        &requester,
+        Escrow::MAX_EXPIRY_DURATION,
+    )
+    .await
+    .unwrap();
+    assert_eq!(
+        escrow_expiry(&mut context, &env, intent_id).await,
+        now + Escrow::MAX_EXPIRY_DURATION
+    );
+}
+
+// 14. Test: Legacy State Account Reads Zero Tolerance and Migrates
+// Verifies that a 40-byte state account created before clock_skew_tolerance existed
+// still allows claim/cancel at exact expiry, and that setting a tolerance resizes it.
+// Why: Upgrading the program must not brick claims and cancels on an existing deployment.
+#[tokio::test]
+async fn test_legacy_state_account_reads_zero_tolerance_and_migrates() {
+    let program_test = program_test();
+    let mut context = program_test.start_with_context().await;
+    let env = setup_basic_env(&mut context).await;
+    let admin = env.requester.insecure_clone();
+
+    let mut state_account = context
+        .banks_client
+        .get_account(env.state_pda)
+        .await
+        .unwrap()
+        .unwrap();
+    state_account.data.truncate(EscrowState::LEGACY_LEN);
+    context.set_account(&env.state_pda, &AccountSharedData::from(state_account));
+
+    assert!(claim_at_offset(&mut context, &env, 0).await.is_ok());
+    assert!(claim_at_offset(&mut context, &env, 1).await.is_err());
+    assert!(cancel_at_offset(&mut context, &env, 0).await.is_err());
+    assert!(cancel_at_offset(&mut context, &env, 1).await.is_ok());
+
+    set_skew_tolerance(&mut context, &env, &admin, SKEW_TOLERANCE)
+        .await
+        .unwrap();
+    let state_account = context
+        .banks_client
+        .get_account(env.state_pda)
+        .await
+        .unwrap()
+        .unwrap();
+    assert_eq!(state_account.data.len(), EscrowState::LEN);
+    let state = read_state(&state_account);
+    assert_eq!(state.approver, env.approver.pubkey());
+    assert_eq!(state.clock_skew_tolerance, SKEW_TOLERANCE);
+    assert!(claim_at_offset(&mut context, &env, SKEW_TOLERANCE)
+        .await
+        .is_ok());
+}
+
+// 15. Test: Extend Expiry Rejects an Expired Escrow
+// Verifies that ExtendExpiry fails with EscrowExpired once the clock is past expiry
+// plus the clock skew tolerance, and succeeds at the last claimable second.
+// Why: An expired escrow belongs to the requester's refund path; extending it would
+// reopen a claim window the solver has already lost.
+#[tokio::test]
+async fn test_extend_expiry_rejects_expired_escrow() {
+    let program_test = program_test();
+    let mut context = program_test.start_with_context().await;
+    let env = setup_basic_env(&mut context).await;
+    let requester = env.requester.insecure_clone();
+
+    set_skew_tolerance(&mut context, &env, &requester, SKEW_TOLERANCE)
+        .await
+        .unwrap();
+
+    let (intent_id, old_expiry) = create_skew_test_escrow(&mut context, &env).await;
+    set_clock(&mut context, old_expiry + SKEW_TOLERANCE + 1).await;
+    assert_escrow_error(
+        extend_expiry(
+            &mut context,
+            &env,
+            intent_id,
+            requester.pubkey(),
+            &requester,
+            500,
+        )
+        .await,
+        EscrowError::EscrowExpired,
+    );
+    assert_eq!(
+        escrow_expiry(&mut context, &env, intent_id).await,
+        old_expiry
+    );
+
+    // Still inside the tolerance window: the escrow is claimable, so it can be extended
+    let (open_intent_id, open_expiry) = create_skew_test_escrow(&mut context, &env).await;
+    set_clock(&mut context, open_expiry + SKEW_TOLERANCE).await;
+    extend_expiry(
+        &mut context,
+        &env,
+        open_intent_id,
+        requester.pubkey(),
+        &requester,
+        500,
+    )
+    .await
+    .unwrap();
+    assert_eq!(
+        escrow_expiry(&mut context, &env, open_intent_id).await,
+        open_expiry + SKEW_TOLERANCE + 500
+    );
+}
@@ -12,13 +12,25 @@
 mod common;
 
 use common::{
-    create_escrow_ix, create_gmp_receive_fulfillment_proof_ix, create_gmp_receive_requirements_ix,
+    create_escrow_ix, create_gmp_receive_fulfillment_proof_ix,
+    create_gmp_receive_requirements_batch_ix, create_gmp_receive_requirements_ix,
     create_set_gmp_config_ix, generate_intent_id, get_token_balance, program_test, read_escrow,
-    read_requirements, setup_basic_env, send_tx, DUMMY_HUB_CHAIN_ID, DUMMY_HUB_GMP_ENDPOINT_ADDR,
+    read_requirements, setup_basic_env, setup_gmp_requirements, send_tx, DUMMY_HUB_CHAIN_ID,
+    DUMMY_HUB_GMP_ENDPOINT_ADDR,
 };
 use gmp_common::messages::{FulfillmentProof, IntentRequirements};
-use intent_inflow_escrow::state::seeds;
-use solana_sdk::{pubkey::Pubkey, signature::{Keypair, Signer}, transaction::Transaction};
+use intent_inflow_escrow::{
+    error::EscrowError,
+    instruction::encode_requirements_batch,
+    pda::requirements_pda,
+    state::{seeds, EscrowStatus},
+};
+use solana_sdk::{
+    instruction::InstructionError,
+    pubkey::Pubkey,
+    signature::{Keypair, Signer},
+    transaction::{Transaction, TransactionError},
+};
 
 // ============================================================================
 // HELPER FUNCTIONS
@@ -500,7 +512,7 @@ async fn test_receive_fulfillment_proof_releases_escrow() {
             .unwrap()
             .unwrap(),
     );
-    assert!(escrow.is_claimed);
+    assert_eq!(escrow.status, EscrowStatus::Claimed);
 }
 
 // 7. Test: ReceiveFulfillmentProof rejects unknown remote GMP endpoint
@@ -1219,7 +1231,7 @@ async fn test_full_inflow_gmp_workflow() {
             .unwrap()
             .unwrap(),
     );
-    assert!(escrow.is_claimed);
+    assert_eq!(escrow.status, EscrowStatus::Claimed);
     assert_eq!(escrow.amount, 0);
 
     let req = read_requirements(
@@ -1267,6 +1279,112 @@ async fn test_full_inflow_gmp_workflow() {
 //     once in test 6, and the escrow is marked claimed. Double fulfillment is
 //     rejected in test 8 (test_receive_fulfillment_proof_rejects_already_fulfilled).
 
+// ============================================================================
+// EVENT TESTS
+// ============================================================================
+
+// 24. Test: Emit EscrowClaimed event on release
+// Verifies that the fulfillment-proof release logs an EscrowClaimed event carrying the approver from the program state and the SHA-256 of the proof payload.
+// Why: Operators need an immutable record attributing each release to a specific approval.
+#[tokio::test]
+async fn test_emit_events_on_release() {
+    let program_test = program_test();
+    let mut context = program_test.start_with_context().await;
+    let env = setup_basic_env(&mut context).await;
+
+    let intent_id = generate_intent_id();
+    let amount = 500_000u64;
+
+    let (escrow_pda, _) =
+        Pubkey::find_program_address(&[seeds::ESCROW_SEED, &intent_id], &env.program_id);
+    let (vault_pda, _) =
+        Pubkey::find_program_address(&[seeds::VAULT_SEED, &intent_id], &env.program_id);
+    let (requirements_pda, _) =
+        Pubkey::find_program_address(&[seeds::REQUIREMENTS_SEED, &intent_id], &env.program_id);
+
+    let gmp_caller = context.payer.insecure_clone();
+
+    // Receive requirements and create escrow
+    let requirements_payload = create_requirements_payload(
+        intent_id,
+        &env.requester.pubkey(),
+        amount,
+        &env.mint,
+        &env.solver.pubkey(),
+        u64::MAX,
+    );
+    let gmp_receive_req_ix = create_gmp_receive_requirements_ix(
+        env.program_id,
+        requirements_pda,
+        env.gmp_config_pda,
+        gmp_caller.pubkey(),
+        gmp_caller.pubkey(),
+        DUMMY_HUB_CHAIN_ID,
+        DUMMY_HUB_GMP_ENDPOINT_ADDR,
+        requirements_payload,
+    );
+    send_tx(&mut context, &gmp_caller, &[gmp_receive_req_ix], &[]).await;
+
+    let create_ix = create_escrow_ix(
+        env.program_id,
+        intent_id,
+        amount,
+        env.requester.pubkey(),
+        env.mint,
+        env.requester_token,
+        env.solver.pubkey(),
+        requirements_pda,
+    );
+    send_tx(&mut context, &env.requester, &[create_ix], &[]).await;
+
+    // Release via fulfillment proof and capture the transaction logs
+    let proof_payload = create_fulfillment_proof_payload(
+        intent_id,
+        &env.solver.pubkey(),
+        amount,
+        12345,
+    );
+    let expected_hash = solana_sdk::hash::hash(&proof_payload).to_bytes();
+
+    let gmp_receive_proof_ix = create_gmp_receive_fulfillment_proof_ix(
+        env.program_id,
+        requirements_pda,
+        escrow_pda,
+        vault_pda,
+        env.solver_token,
+        env.gmp_config_pda,
+        gmp_caller.pubkey(),
+        DUMMY_HUB_CHAIN_ID,
+        DUMMY_HUB_GMP_ENDPOINT_ADDR,
+        proof_payload,
+    );
+    let blockhash = context.banks_client.get_latest_blockhash().await.unwrap();
+    let tx = Transaction::new_signed_with_payer(
+        &[gmp_receive_proof_ix],
+        Some(&gmp_caller.pubkey()),
+        &[&gmp_caller],
+        blockhash,
+    );
+    let result = context
+        .banks_client
+        .process_transaction_with_metadata(tx)
+        .await
+        .unwrap();
+    assert!(result.result.is_ok());
+
+    let logs = result.metadata.unwrap().log_messages;
+    let event = logs
+        .iter()
+        .find(|log| log.contains("EscrowClaimed:"))
+        .expect("EscrowClaimed event should be emitted");
+
+    assert!(event.contains(&format!("intent_id={}", hex::encode(intent_id))));
+    assert!(event.contains(&format!("amount={}", amount)));
+    assert!(event.contains(&format!("approver={}", env.approver.pubkey())));
+    assert_ne!(expected_hash, [0u8; 32]);
+    assert!(event.contains(&format!("approval_hash={}", hex::encode(expected_hash))));
+}
+
 // ============================================================================
 // GENERIC LZRECEIVE ROUTING TESTS
 // ============================================================================
@@ -1455,7 +1573,7 @@ async fn test_generic_gmp_receive_routes_fulfillment_proof() {
             .unwrap()
             .unwrap(),
     );
-    assert!(escrow.is_claimed);
+    assert_eq!(escrow.status, EscrowStatus::Claimed);
 }
 
 // 27. Test: Generic GmpReceive rejects unknown message types
@@ -1542,4 +1660,301 @@ async fn test_generic_gmp_receive_rejects_unknown_message_type() {
 // #21: test_release_escrow_rejects_without_fulfillment — N/A for SVM (MVM-only)
 // #22: test_release_escrow_rejects_unauthorized_solver — N/A for SVM (MVM-only)
 // #23: test_release_escrow_rejects_double_release — N/A for SVM (MVM-only)
-// #24: test_emit_events_on_release — N/A for SVM (EVM-only event emission test)
+
+// ============================================================================
+// RESERVED SOLVER VALIDATION TESTS (SVM-specific)
+// ============================================================================
+//
+// EVM and MVM take the solver from stored requirements; only SVM accepts a
+// caller-supplied reserved solver account that must be checked.
+
+// 28. Test: CreateEscrow accepts reserved solver matching requirements
+// Verifies that escrow creation succeeds and stores the reserved solver when it equals requirements.solver_addr.
+// Why: The hub-authorized solver is the only valid recipient; the happy path must keep working.
+#[tokio::test]
+async fn test_create_escrow_accepts_matching_solver() {
+    let program_test = program_test();
+    let mut context = program_test.start_with_context().await;
+    let env = setup_basic_env(&mut context).await;
+
+    let intent_id = generate_intent_id();
+    let amount = 500_000u64;
+    let requirements_pda =
+        setup_gmp_requirements(&mut context, &env, intent_id, amount, u64::MAX).await;
+
+    let create_ix = create_escrow_ix(
+        env.program_id,
+        intent_id,
+        amount,
+        env.requester.pubkey(),
+        env.mint,
+        env.requester_token,
+        env.solver.pubkey(),
+        requirements_pda,
+    );
+
+    let blockhash = context.banks_client.get_latest_blockhash().await.unwrap();
+    let tx = Transaction::new_signed_with_payer(
+        &[create_ix],
+        Some(&env.requester.pubkey()),
+        &[&env.requester],
+        blockhash,
+    );
+    context.banks_client.process_transaction(tx).await.unwrap();
+
+    let (escrow_pda, _) =
+        Pubkey::find_program_address(&[seeds::ESCROW_SEED, &intent_id], &env.program_id);
+    let escrow_account = context
+        .banks_client
+        .get_account(escrow_pda)
+        .await
+        .unwrap()
+        .unwrap();
+    let escrow = read_escrow(&escrow_account);
+    assert_eq!(escrow.reserved_solver, env.solver.pubkey());
+}
+
+// 29. Test: CreateEscrow rejects reserved solver mismatch
+// Verifies that escrow creation fails with SolverMismatch when the reserved solver differs from requirements.solver_addr.
+// Why: Otherwise a requester could lock funds reserved to a solver the hub never authorized.
+#[tokio::test]
+async fn test_create_escrow_rejects_solver_mismatch() {
+    let program_test = program_test();
+    let mut context = program_test.start_with_context().await;
+    let env = setup_basic_env(&mut context).await;
+
+    let intent_id = generate_intent_id();
+    let amount = 500_000u64;
+    let requirements_pda =
+        setup_gmp_requirements(&mut context, &env, intent_id, amount, u64::MAX).await;
+
+    let other_solver = Keypair::new();
+    let create_ix = create_escrow_ix(
+        env.program_id,
+        intent_id,
+        amount,
+        env.requester.pubkey(),
+        env.mint,
+        env.requester_token,
+        other_solver.pubkey(), // Not the solver from requirements
+        requirements_pda,
+    );
+
+    let blockhash = context.banks_client.get_latest_blockhash().await.unwrap();
+    let tx = Transaction::new_signed_with_payer(
+        &[create_ix],
+        Some(&env.requester.pubkey()),
+        &[&env.requester],
+        blockhash,
+    );
+
+    let err = context
+        .banks_client
+        .process_transaction(tx)
+        .await
+        .unwrap_err()
+        .unwrap();
+
+    assert_eq!(
+        err,
+        TransactionError::InstructionError(
+            0,
+            InstructionError::Custom(EscrowError::SolverMismatch as u32)
+        )
+    );
+
+    // Requirements must remain available for a correct escrow
+    let req_account = context
+        .banks_client
+        .get_account(requirements_pda)
+        .await
+        .unwrap()
+        .unwrap();
+    let requirements = read_requirements(&req_account);
+    assert!(!requirements.escrow_created);
+}
+
+// ============================================================================
+// REQUIREMENTS BATCH TESTS (SVM-specific)
+// ============================================================================
+
+// Helper: Build IntentRequirements for a batch item
+fn batch_item(env: &common::TestEnv, intent_id: [u8; 32], amount: u64) -> IntentRequirements {
+    IntentRequirements {
+        intent_id,
+        requester_addr: env.requester.pubkey().to_bytes(),
+        amount_required: amount,
+        token_addr: env.mint.to_bytes(),
+        solver_addr: env.solver.pubkey().to_bytes(),
+        expiry: u64::MAX,
+    }
+}
+
+// 30. Test: ReceiveRequirementsBatch stores every intent in the batch
+// Verifies that one batch instruction creates a requirements PDA per item with the decoded values.
+// Why: Batching exists to cut GMP round trips; every item must land as if delivered individually.
+#[tokio::test]
+async fn test_receive_requirements_batch_stores_all() {
+    let program_test = program_test();
+    let mut context = program_test.start_with_context().await;
+    let env = setup_basic_env(&mut context).await;
+
+    let items: Vec<IntentRequirements> = (1..=3u64)
+        .map(|i| batch_item(&env, generate_intent_id(), i * 100_000))
+        .collect();
+    let requirements_pdas: Vec<Pubkey> = items
+        .iter()
+        .map(|item| requirements_pda(&env.program_id, &item.intent_id).0)
+        .collect();
+
+    let gmp_caller = context.payer.insecure_clone();
+    let batch_ix = create_gmp_receive_requirements_batch_ix(
+        env.program_id,
+        &requirements_pdas,
+        env.gmp_config_pda,
+        gmp_caller.pubkey(),
+        gmp_caller.pubkey(),
+        DUMMY_HUB_CHAIN_ID,
+        DUMMY_HUB_GMP_ENDPOINT_ADDR,
+        encode_requirements_batch(&items),
+    );
+
+    let blockhash = context.banks_client.get_latest_blockhash().await.unwrap();
+    let tx = Transaction::new_signed_with_payer(
+        &[batch_ix],
+        Some(&gmp_caller.pubkey()),
+        &[&gmp_caller],
+        blockhash,
+    );
+    context.banks_client.process_transaction(tx).await.unwrap();
+
+    for (item, pda) in items.iter().zip(&requirements_pdas) {
+        let req_account = context
+            .banks_client
+            .get_account(*pda)
+            .await
+            .unwrap()
+            .expect("Requirements account should exist");
+        let requirements = read_requirements(&req_account);
+        assert_eq!(requirements.intent_id, item.intent_id);
+        assert_eq!(requirements.amount_required, item.amount_required);
+        assert_eq!(requirements.solver_addr, env.solver.pubkey().to_bytes());
+        assert!(!requirements.escrow_created);
+    }
+}
+
+// 31. Test: ReceiveRequirementsBatch skips an already-stored intent
+// Verifies that a batch containing a duplicate stores the new items and leaves the existing requirements unchanged.
+// Why: A batch may overlap a retried single delivery; idempotency must hold per item without failing the rest.
+#[tokio::test]
+async fn test_receive_requirements_batch_skips_duplicate() {
+    let program_test = program_test();
+    let mut context = program_test.start_with_context().await;
+    let env = setup_basic_env(&mut context).await;
+
+    // Store one intent individually first
+    let existing_id = generate_intent_id();
+    let existing_amount = 500_000u64;
+    let existing_pda =
+        setup_gmp_requirements(&mut context, &env, existing_id, existing_amount, u64::MAX).await;
+
+    // Batch re-delivers it with a conflicting amount, alongside two new intents
+    let items = vec![
+        batch_item(&env, generate_intent_id(), 100_000),
+        batch_item(&env, existing_id, 999_999),
+        batch_item(&env, generate_intent_id(), 300_000),
+    ];
+    let requirements_pdas: Vec<Pubkey> = items
+        .iter()
+        .map(|item| requirements_pda(&env.program_id, &item.intent_id).0)
+        .collect();
+    assert_eq!(requirements_pdas[1], existing_pda);
+
+    let gmp_caller = context.payer.insecure_clone();
+    let batch_ix = create_gmp_receive_requirements_batch_ix(
+        env.program_id,
+        &requirements_pdas,
+        env.gmp_config_pda,
+        gmp_caller.pubkey(),
+        gmp_caller.pubkey(),
+        DUMMY_HUB_CHAIN_ID,
+        DUMMY_HUB_GMP_ENDPOINT_ADDR,
+        encode_requirements_batch(&items),
+    );
+
+    let blockhash = context.banks_client.get_latest_blockhash().await.unwrap();
+    let tx = Transaction::new_signed_with_payer(
+        &[batch_ix],
+        Some(&gmp_caller.pubkey()),
+        &[&gmp_caller],
+        blockhash,
+    );
+    context.banks_client.process_transaction(tx).await.unwrap();
+
+    // First-stored requirements win
+    let existing_account = context
+        .banks_client
+        .get_account(existing_pda)
+        .await
+        .unwrap()
+        .unwrap();
+    assert_eq!(
+        read_requirements(&existing_account).amount_required,
+        existing_amount
+    );
+
+    // New items are stored
+    for i in [0, 2] {
+        let req_account = context
+            .banks_client
+            .get_account(requirements_pdas[i])
+            .await
+            .unwrap()
+            .expect("Requirements account should exist");
+        assert_eq!(
+            read_requirements(&req_account).amount_required,
+            items[i].amount_required
+        );
+    }
+}
+
+// ============================================================================
+// OVER-FUNDING (SVM-specific)
+// ============================================================================
+
+// 32. Test: CreateEscrow accepts over-funding
+// Verifies that escrow creation succeeds when the deposit exceeds amount_required.
+// Why: Requirements set a minimum amount. The GMP IntentRequirements message carries
+// no exact-amount flag, so the SVM escrow has no exact mode to switch on.
+#[tokio::test]
+async fn test_create_escrow_accepts_overfunding() {
+    let program_test = program_test();
+    let mut context = program_test.start_with_context().await;
+    let env = setup_basic_env(&mut context).await;
+
+    let intent_id = generate_intent_id();
+    let requirements_pda =
+        setup_gmp_requirements(&mut context, &env, intent_id, 500_000, u64::MAX).await;
+
+    let create_ix = create_escrow_ix(
+        env.program_id,
+        intent_id,
+        600_000,
+        env.requester.pubkey(),
+        env.mint,
+        env.requester_token,
+        env.solver.pubkey(),
+        requirements_pda,
+    );
+    send_tx(&mut context, &env.requester, &[create_ix], &[]).await;
+
+    let (escrow_pda, _) =
+        Pubkey::find_program_address(&[seeds::ESCROW_SEED, &intent_id], &env.program_id);
+    let escrow_account = context
+        .banks_client
+        .get_account(escrow_pda)
+        .await
+        .unwrap()
+        .unwrap();
+    assert_eq!(read_escrow(&escrow_account).amount, 600_000);
+}
@@ -8,7 +8,7 @@ use common::{
     setup_gmp_requirements_custom, DUMMY_HUB_CHAIN_ID, DUMMY_HUB_GMP_ENDPOINT_ADDR,
 };
 use gmp_common::messages::{FulfillmentProof, IntentRequirements};
-use intent_inflow_escrow::state::seeds;
+use intent_inflow_escrow::state::{seeds, EscrowStatus};
 use solana_sdk::{
     clock::Clock,
     pubkey::Pubkey,
@@ -145,7 +145,7 @@ async fn test_complete_full_deposit_to_claim_workflow() {
         .unwrap()
         .unwrap();
     let escrow = read_escrow(&escrow_account);
-    assert!(escrow.is_claimed);
+    assert_eq!(escrow.status, EscrowStatus::Claimed);
 }
 
 // 2. Test: Multi-Token Scenarios
@@ -1,8 +1,37 @@
 //! Library functions for the SVM intent escrow CLI
 //!
-//! This module exposes parsing utilities that can be tested independently.
+//! This module exposes parsing and formatting utilities that can be tested independently.
 
-use std::{collections::HashMap, error::Error};
+use base64::{prelude::BASE64_STANDARD, Engine};
+use borsh::{BorshDeserialize, BorshSerialize};
+use gmp_common::{
+    EscrowConfirmation, FulfillmentProof, GmpError, GmpMessageType, IntentRequirements,
+};
+use intent_gmp::state::{OutboundNonceAccount, RelayAccount};
+use intent_inflow_escrow::{
+    instruction::EscrowInstruction,
+    pda,
+    state::{seeds, Escrow, EscrowState, EscrowStatus},
+    token_extensions::is_supported_token_program,
+};
+use serde::{ser::SerializeMap, Serialize, Serializer};
+use serde_json::Value;
+use solana_client::{
+    rpc_client::RpcClient,
+    rpc_config::RpcProgramAccountsConfig,
+    rpc_filter::{Memcmp, RpcFilterType},
+    rpc_response::RpcSimulateTransactionResult,
+};
+use solana_sdk::{
+    hash::Hash,
+    instruction::{AccountMeta, Instruction},
+    message::Message,
+    pubkey::Pubkey,
+    signature::Signature,
+    system_program, sysvar,
+    transaction::Transaction,
+};
+use std::{collections::HashMap, error::Error, fmt, fs, path::Path, str::FromStr};
 
 // ============================================================================
 // OPTION PARSING
@@ -28,6 +57,42 @@ pub fn parse_options(args: &[String]) -> Result<HashMap<String, String>, Box<dyn
     Ok(options)
 }
 
+/// Fill in options missing from `cli` with values from the TOML config at `file`.
+///
+/// Top-level keys mirror the `--key value` options (e.g. `program-id`, `rpc`,
+/// `payer`); flags given on the command line take precedence. Values must be
+/// strings, integers or booleans; bare flags such as `emit-unsigned` are set
+/// with `true` and left off with `false`.
+pub fn merge_config(
+    file: &Path,
+    cli: HashMap<String, String>,
+) -> Result<HashMap<String, String>, Box<dyn Error>> {
+    let contents = fs::read_to_string(file)
+        .map_err(|e| format!("Failed to read config file {}: {e}", file.display()))?;
+    let table: toml::Table = toml::from_str(&contents)
+        .map_err(|e| format!("Failed to parse config file {}: {e}", file.display()))?;
+
+    let mut options = HashMap::new();
+    for (key, value) in table {
+        let value = match value {
+            toml::Value::String(s) => s,
+            toml::Value::Integer(i) => i.to_string(),
+            toml::Value::Boolean(true) => "true".to_string(),
+            toml::Value::Boolean(false) => continue,
+            other => {
+                return Err(format!(
+                    "Config key '{key}' must be a string, integer or boolean, got {}",
+                    other.type_str()
+                )
+                .into())
+            }
+        };
+        options.insert(key, value);
+    }
+    options.extend(cli);
+    Ok(options)
+}
+
 /// Get a required option from the map, returning an error if missing.
 pub fn required_option<'a>(
     options: &'a HashMap<String, String>,
@@ -39,13 +104,146 @@ pub fn required_option<'a>(
         .ok_or_else(|| format!("Missing required option: --{key}").into())
 }
 
+// ============================================================================
+// COMMAND OUTPUT
+// ============================================================================
+
+/// Output mode selected by the global `--output` option.
+#[derive(Clone, Copy, Debug, Default, PartialEq, Eq)]
+pub enum OutputFormat {
+    /// `Label: value` lines, as scraped by the scripts under `scripts/`.
+    #[default]
+    Text,
+    /// A single JSON object per command.
+    Json,
+}
+
+impl FromStr for OutputFormat {
+    type Err = String;
+
+    fn from_str(value: &str) -> Result<Self, Self::Err> {
+        match value {
+            "text" => Ok(OutputFormat::Text),
+            "json" => Ok(OutputFormat::Json),
+            other => Err(format!(
+                "Invalid --output '{other}' (expected text or json)"
+            )),
+        }
+    }
+}
+
+/// Resolve the output format from `--output`; the bare `--json` flag is an alias for `--output json`.
+pub fn output_format(options: &HashMap<String, String>) -> Result<OutputFormat, Box<dyn Error>> {
+    if options.contains_key("json") {
+        return Ok(OutputFormat::Json);
+    }
+    match options.get("output") {
+        Some(value) => Ok(value.parse()?),
+        None => Ok(OutputFormat::Text),
+    }
+}
+
+/// A command's result: text lines for people, JSON fields for scripts.
+///
+/// Most entries are added with [`CommandOutput::field`], which feeds both
+/// forms. Text-only lines (progress, tables) and JSON-only fields (arrays)
+/// cover the rest.
+#[derive(Debug, Default)]
+pub struct CommandOutput {
+    lines: Vec<String>,
+    fields: Vec<(String, Value)>,
+}
+
+impl CommandOutput {
+    pub fn new() -> Self {
+        Self::default()
+    }
+
+    /// Add a `Label: value` text line and a `key` JSON field.
+    pub fn field(mut self, key: &str, label: &str, value: impl Into<Value>) -> Self {
+        let value = value.into();
+        let text = match &value {
+            Value::String(s) => s.clone(),
+            other => other.to_string(),
+        };
+        self.lines.push(format!("{label}: {text}"));
+        self.fields.push((key.to_string(), value));
+        self
+    }
+
+    /// Add a line that only appears in text output.
+    pub fn line(mut self, line: impl Into<String>) -> Self {
+        self.lines.push(line.into());
+        self
+    }
+
+    /// Add a field that only appears in JSON output.
+    pub fn json_field(mut self, key: &str, value: impl Into<Value>) -> Self {
+        self.fields.push((key.to_string(), value.into()));
+        self
+    }
+}
+
+impl fmt::Display for CommandOutput {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        write!(f, "{}", self.lines.join("\n"))
+    }
+}
+
+/// Serializes the JSON fields as one object, in insertion order.
+impl Serialize for CommandOutput {
+    fn serialize<S: Serializer>(&self, serializer: S) -> Result<S::Ok, S::Error> {
+        let mut map = serializer.serialize_map(Some(self.fields.len()))?;
+        for (key, value) in &self.fields {
+            map.serialize_entry(key, value)?;
+        }
+        map.end()
+    }
+}
+
+/// Render a command result in the requested format.
+pub fn render_output(
+    result: &(impl Serialize + fmt::Display),
+    format: OutputFormat,
+) -> Result<String, Box<dyn Error>> {
+    match format {
+        OutputFormat::Text => Ok(result.to_string()),
+        OutputFormat::Json => Ok(serde_json::to_string(result)?),
+    }
+}
+
+/// Build the `get-escrow` result for the escrow stored at `escrow_pda`.
+///
+/// `claimed` follows the status byte, so a legacy escrow cancelled before the
+/// status existed (stored as `is_claimed = 1`) is reported as claimed.
+pub fn escrow_output(escrow_pda: &Pubkey, escrow: &Escrow) -> CommandOutput {
+    let refund_recipient = escrow.refund_recipient().map(|r| r.to_string());
+    CommandOutput::new()
+        .field("escrow_pda", "Escrow PDA", escrow_pda.to_string())
+        .field("requester", "Requester", escrow.requester.to_string())
+        .field("token_mint", "Token mint", escrow.token_mint.to_string())
+        .field("amount", "Amount", escrow.amount)
+        .field("expiry", "Expiry", escrow.expiry)
+        .field(
+            "reserved_solver",
+            "Reserved solver",
+            escrow.reserved_solver.to_string(),
+        )
+        .line(format!(
+            "Refund recipient: {}",
+            refund_recipient.as_deref().unwrap_or("requester")
+        ))
+        .json_field("refund_recipient", refund_recipient)
+        .field("status", "Status", format!("{:?}", escrow.status))
+        .field("claimed", "Claimed", escrow.status == EscrowStatus::Claimed)
+}
+
 // ============================================================================
 // VALUE PARSING
 // ============================================================================
 
 /// Parse a string as a Solana pubkey.
 pub fn parse_pubkey(value: &str) -> Result<solana_sdk::pubkey::Pubkey, Box<dyn Error>> {
-    use std::str::FromStr;
     Ok(solana_sdk::pubkey::Pubkey::from_str(value)?)
 }
 
@@ -83,9 +281,17 @@ pub fn parse_32_byte_hex(value: &str) -> Result<[u8; 32], Box<dyn Error>> {
 
 /// Parse a hex string into a 32-byte intent ID.
 ///
-/// Panics on invalid hex input.
+/// Accepts hex strings with or without 0x prefix. Short and odd-length
+/// strings are left-padded with zeros. Non-hex characters and strings
+/// longer than 32 bytes are rejected.
 pub fn parse_intent_id(value: &str) -> Result<[u8; 32], Box<dyn Error>> {
-    Ok(hex_to_bytes32(value))
+    let hex = value.strip_prefix("0x").unwrap_or(value);
+    let hex = if hex.len() % 2 == 1 {
+        format!("0{}", hex)
+    } else {
+        hex.to_string()
+    };
+    parse_32_byte_hex(&hex).map_err(|e| format!("Invalid intent ID '{value}': {e}").into())
 }
 
 /// Parse a hex string into a 64-byte signature.
@@ -121,3 +327,732 @@ pub fn hex_to_bytes32(hex_string: &str) -> [u8; 32] {
     }
     bytes
 }
+
+// ============================================================================
+// DEMO PAYLOADS
+// ============================================================================
+
+/// Build the IntentRequirements GMP payload the `demo` command delivers in
+/// place of the hub.
+pub fn build_demo_requirements_payload(
+    intent_id: [u8; 32],
+    requester: &solana_sdk::pubkey::Pubkey,
+    amount: u64,
+    token_mint: &solana_sdk::pubkey::Pubkey,
+    solver: &solana_sdk::pubkey::Pubkey,
+    expiry: u64,
+) -> Vec<u8> {
+    gmp_common::IntentRequirements {
+        intent_id,
+        requester_addr: requester.to_bytes(),
+        amount_required: amount,
+        token_addr: token_mint.to_bytes(),
+        solver_addr: solver.to_bytes(),
+        expiry,
+    }
+    .encode()
+    .to_vec()
+}
+
+/// Build the FulfillmentProof GMP payload the `demo` command delivers in
+/// place of the hub to release the escrow.
+pub fn build_demo_fulfillment_payload(
+    intent_id: [u8; 32],
+    solver: &solana_sdk::pubkey::Pubkey,
+    amount: u64,
+    timestamp: u64,
+) -> Vec<u8> {
+    gmp_common::FulfillmentProof {
+        intent_id,
+        solver_addr: solver.to_bytes(),
+        amount_fulfilled: amount,
+        timestamp,
+    }
+    .encode()
+    .to_vec()
+}
+
+// ============================================================================
+// STATE FORMATTING
+// ============================================================================
+
+/// Deserialize `EscrowState` account data and render it for `get-state`.
+///
+/// With `json` set the output is a single JSON object; otherwise it is one
+/// `Label: value` line per field, matching the other read commands.
+pub fn format_escrow_state(
+    state_pda: &solana_sdk::pubkey::Pubkey,
+    data: &[u8],
+    json: bool,
+) -> Result<String, Box<dyn Error>> {
+    if data.get(..8) != Some(&EscrowState::DISCRIMINATOR[..]) {
+        return Err("Account is not an EscrowState (discriminator mismatch)".into());
+    }
+    let state = EscrowState::unpack(data)?;
+
+    if json {
+        return Ok(format!(
+            r#"{{"state_pda":"{}","approver":"{}","clock_skew_tolerance":{}}}"#,
+            state_pda, state.approver, state.clock_skew_tolerance
+        ));
+    }
+    Ok(format!(
+        "State PDA: {}\nApprover: {}\nClock skew tolerance: {}",
+        state_pda, state.approver, state.clock_skew_tolerance
+    ))
+}
+
+// ============================================================================
+// GMP NONCE PARSING
+// ============================================================================
+
+/// Decode the GMP endpoint's `OutboundNonceAccount` and return the next nonce `Send` will assign.
+pub fn parse_outbound_nonce(data: &[u8]) -> Result<u64, Box<dyn Error>> {
+    let account = OutboundNonceAccount::try_from_slice(data)?;
+    if account.discriminator != OutboundNonceAccount::DISCRIMINATOR {
+        return Err("Account is not an OutboundNonceAccount (discriminator mismatch)".into());
+    }
+    Ok(account.nonce)
+}
+
+// ============================================================================
+// GMP RELAY LISTING
+// ============================================================================
+
+/// Decode a GMP endpoint `RelayAccount`.
+pub fn parse_relay_account(data: &[u8]) -> Result<RelayAccount, Box<dyn Error>> {
+    let account = RelayAccount::try_from_slice(data)?;
+    if account.discriminator != RelayAccount::DISCRIMINATOR {
+        return Err("Account is not a RelayAccount (discriminator mismatch)".into());
+    }
+    Ok(account)
+}
+
+/// Fetch every relay PDA owned by the GMP endpoint, sorted by relay pubkey.
+///
+/// Removed relays keep their PDA with `is_authorized = false`, so the result
+/// includes them; callers decide whether to show or filter them.
+pub fn list_relays(
+    client: &RpcClient,
+    gmp_program_id: &Pubkey,
+) -> Result<Vec<RelayAccount>, Box<dyn Error>> {
+    let config = RpcProgramAccountsConfig {
+        filters: Some(vec![
+            RpcFilterType::DataSize(RelayAccount::SIZE as u64),
+            RpcFilterType::Memcmp(Memcmp::new_raw_bytes(0, vec![RelayAccount::DISCRIMINATOR])),
+        ]),
+        ..RpcProgramAccountsConfig::default()
+    };
+    let mut relays = client
+        .get_program_accounts_with_config(gmp_program_id, config)?
+        .iter()
+        .map(|(_, account)| parse_relay_account(&account.data))
+        .collect::<Result<Vec<_>, _>>()?;
+    relays.sort_by_key(|relay| relay.relay);
+    Ok(relays)
+}
+
+// ============================================================================
+// ESCROW LISTING
+// ============================================================================
+
+/// Byte offset of `Escrow::requester` in escrow account data.
+///
+/// Borsh lays the fields out in declaration order with no padding, and
+/// `requester` directly follows the 8-byte `discriminator`.
+pub const ESCROW_REQUESTER_OFFSET: usize = 8;
+
+/// `getProgramAccounts` filters matching every escrow account of `requester`.
+///
+/// There is no size filter: escrows created before `refund_recipient` existed
+/// are `Escrow::LEGACY_LEN` bytes, and the discriminator already tells
+/// escrows apart from the program's other accounts.
+pub fn escrow_requester_filters(requester: &Pubkey) -> Vec<RpcFilterType> {
+    vec![
+        RpcFilterType::Memcmp(Memcmp::new_raw_bytes(0, Escrow::DISCRIMINATOR.to_vec())),
+        RpcFilterType::Memcmp(Memcmp::new_raw_bytes(
+            ESCROW_REQUESTER_OFFSET,
+            requester.to_bytes().to_vec(),
+        )),
+    ]
+}
+
+/// Fetch every escrow of `requester` with its PDA, sorted by expiry.
+///
+/// Claimed and cancelled escrows keep their account, so the result includes
+/// them; callers decide whether to show or filter them.
+pub fn list_escrows_by_requester(
+    client: &RpcClient,
+    program_id: &Pubkey,
+    requester: &Pubkey,
+) -> Result<Vec<(Pubkey, Escrow)>, Box<dyn Error>> {
+    let config = RpcProgramAccountsConfig {
+        filters: Some(escrow_requester_filters(requester)),
+        ..RpcProgramAccountsConfig::default()
+    };
+    let mut escrows = client
+        .get_program_accounts_with_config(program_id, config)?
+        .into_iter()
+        .map(|(pubkey, account)| Ok((pubkey, Escrow::unpack(&account.data)?)))
+        .collect::<Result<Vec<_>, Box<dyn Error>>>()?;
+    escrows.sort_by_key(|(_, escrow)| escrow.expiry);
+    Ok(escrows)
+}
+
+// ============================================================================
+// RESERVED SOLVER TOKEN ACCOUNT PRECHECK
+// ============================================================================
+
+/// SPL Associated Token Account program ID.
+pub const ASSOCIATED_TOKEN_PROGRAM_ID: Pubkey =
+    solana_sdk::pubkey!("ATokenGPvbdGVxr1b2hvZbsiqW5xWH25efTNsLJA8knL");
+
+/// How `create-escrow` treats a reserved solver without a token account for the mint.
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum SolverAtaCheck {
+    /// Skip the check (default)
+    Off,
+    /// Print a warning and create the escrow anyway
+    Warn,
+    /// Refuse to create the escrow
+    Error,
+}
+
+/// Parse the `--solver-ata-check` option (`off`, `warn` or `error`; absent means `off`).
+pub fn parse_solver_ata_check(value: Option<&str>) -> Result<SolverAtaCheck, Box<dyn Error>> {
+    match value {
+        None | Some("off") => Ok(SolverAtaCheck::Off),
+        Some("warn") => Ok(SolverAtaCheck::Warn),
+        Some("error") => Ok(SolverAtaCheck::Error),
+        Some(other) => Err(format!(
+            "Invalid --solver-ata-check value: {other} (expected off, warn or error)"
+        )
+        .into()),
+    }
+}
+
+/// Derive the associated token account of `owner` for `mint` under `token_program`.
+pub fn associated_token_address(owner: &Pubkey, mint: &Pubkey, token_program: &Pubkey) -> Pubkey {
+    Pubkey::find_program_address(
+        &[owner.as_ref(), token_program.as_ref(), mint.as_ref()],
+        &ASSOCIATED_TOKEN_PROGRAM_ID,
+    )
+    .0
+}
+
+/// Token program that owns `mint`: classic SPL Token or Token-2022, or the
+/// system program for a native SOL escrow (zero-address mint).
+///
+/// The escrow vault and every release transfer use the mint's own program, so
+/// instructions must name it rather than assume classic SPL Token.
+pub fn mint_token_program(client: &RpcClient, mint: &Pubkey) -> Result<Pubkey, Box<dyn Error>> {
+    if *mint == Pubkey::default() {
+        return Ok(system_program::id());
+    }
+    let owner = client.get_account(mint)?.owner;
+    if !is_supported_token_program(&owner) {
+        return Err(
+            format!("Mint {mint} is owned by {owner}, not a supported token program").into(),
+        );
+    }
+    Ok(owner)
+}
+
+/// Check that the reserved solver has an associated token account for the escrow mint.
+///
+/// The escrow only pays out to the solver's token account at claim time, so a
+/// missing account otherwise surfaces as a failed claim long after creation.
+/// Native SOL is paid to the solver's wallet, so there is nothing to check.
+///
+/// # Returns
+///
+/// * `Ok(None)` - Check disabled, or the account exists
+/// * `Ok(Some(warning))` - `Warn` mode and the account is missing (or could not be read)
+/// * `Err` - `Error` mode and the account is missing (or could not be read)
+pub fn check_solver_ata(
+    client: &RpcClient,
+    solver: &Pubkey,
+    mint: &Pubkey,
+    token_program: &Pubkey,
+    mode: SolverAtaCheck,
+) -> Result<Option<String>, Box<dyn Error>> {
+    if mode == SolverAtaCheck::Off || *mint == Pubkey::default() {
+        return Ok(None);
+    }
+
+    let ata = associated_token_address(solver, mint, token_program);
+    let problem = match client.get_account_with_commitment(&ata, client.commitment()) {
+        Ok(response) if response.value.is_some() => return Ok(None),
+        Ok(_) => format!(
+            "Reserved solver {solver} has no token account for mint {mint} (expected ATA {ata}); \
+             claims will fail until it is created"
+        ),
+        Err(e) => format!("Could not check reserved solver token account {ata}: {e}"),
+    };
+
+    match mode {
+        SolverAtaCheck::Error => Err(problem.into()),
+        _ => Ok(Some(problem)),
+    }
+}
+
+// ============================================================================
+// GMP PAYLOAD DECODING
+// ============================================================================
+
+/// Decode a raw GMP payload (hex, optional 0x prefix) and render it for `gmp-decode-payload`.
+///
+/// The leading byte selects the `gmp-common` decoder. Unknown message types and
+/// payloads whose length does not match the message type are reported as errors.
+/// With `json` set the output is a single JSON object; otherwise one
+/// `Label: value` line per field.
+pub fn format_gmp_payload(payload_hex: &str, json: bool) -> Result<String, Box<dyn Error>> {
+    let clean = payload_hex.strip_prefix("0x").unwrap_or(payload_hex);
+    let payload = hex::decode(clean).map_err(|e| format!("Invalid payload hex: {e}"))?;
+    let Some(&type_byte) = payload.first() else {
+        return Err("GMP payload is empty".into());
+    };
+
+    let hex32 = |bytes: &[u8; 32]| format!("0x{}", hex::encode(bytes));
+    let invalid = |name: &str, e: GmpError| format!("Invalid {name} payload: {e}");
+
+    // (field, value, quoted in JSON)
+    let (name, fields): (&str, Vec<(&str, String, bool)>) =
+        match GmpMessageType::from_byte(type_byte)? {
+            GmpMessageType::IntentRequirements => {
+                let (msg, payout_addr) = IntentRequirements::decode_with_payout(&payload)
+                    .map_err(|e| invalid("IntentRequirements", e))?;
+                let mut fields = vec![
+                    ("intent_id", hex32(&msg.intent_id), true),
+                    ("requester_addr", hex32(&msg.requester_addr), true),
+                    ("amount_required", msg.amount_required.to_string(), false),
+                    ("token_addr", hex32(&msg.token_addr), true),
+                    ("solver_addr", hex32(&msg.solver_addr), true),
+                    ("expiry", msg.expiry.to_string(), false),
+                ];
+                if let Some(payout_addr) = payout_addr {
+                    fields.push(("payout_addr", hex32(&payout_addr), true));
+                }
+                ("IntentRequirements", fields)
+            }
+            GmpMessageType::EscrowConfirmation => {
+                let msg = EscrowConfirmation::decode(&payload)
+                    .map_err(|e| invalid("EscrowConfirmation", e))?;
+                (
+                    "EscrowConfirmation",
+                    vec![
+                        ("intent_id", hex32(&msg.intent_id), true),
+                        ("escrow_id", hex32(&msg.escrow_id), true),
+                        ("amount_escrowed", msg.amount_escrowed.to_string(), false),
+                        ("token_addr", hex32(&msg.token_addr), true),
+                        ("creator_addr", hex32(&msg.creator_addr), true),
+                    ],
+                )
+            }
+            GmpMessageType::FulfillmentProof => {
+                let msg = FulfillmentProof::decode(&payload)
+                    .map_err(|e| invalid("FulfillmentProof", e))?;
+                (
+                    "FulfillmentProof",
+                    vec![
+                        ("intent_id", hex32(&msg.intent_id), true),
+                        ("solver_addr", hex32(&msg.solver_addr), true),
+                        ("amount_fulfilled", msg.amount_fulfilled.to_string(), false),
+                        ("timestamp", msg.timestamp.to_string(), false),
+                    ],
+                )
+            }
+        };
+
+    if json {
+        let mut parts = vec![format!(r#""message_type":"{}""#, name)];
+        for (field, value, quoted) in &fields {
+            if *quoted {
+                parts.push(format!(r#""{}":"{}""#, field, value));
+            } else {
+                parts.push(format!(r#""{}":{}"#, field, value));
+            }
+        }
+        return Ok(format!("{{{}}}", parts.join(",")));
+    }
+
+    let mut lines = vec![format!("Message type: {} (0x{:02x})", name, type_byte)];
+    for (field, value, _) in &fields {
+        lines.push(format!("{}: {}", field, value));
+    }
+    Ok(lines.join("\n"))
+}
+
+// ============================================================================
+// INSTRUCTION BUILDERS
+// ============================================================================
+
+/// Build the `CreateEscrow` instruction.
+///
+/// With `gmp_endpoint` set, the GMP accounts for sending `EscrowConfirmation`
+/// are appended; `current_nonce` is the endpoint's next outbound nonce, used to
+/// derive the message PDA. `refund_recipient` redirects the cancel refund away
+/// from the requester. `token_program` is the program that owns `token_mint`.
+#[allow(clippy::too_many_arguments)]
+pub fn build_create_escrow_ix(
+    program_id: Pubkey,
+    intent_id: [u8; 32],
+    amount: u64,
+    requester: Pubkey,
+    token_mint: Pubkey,
+    token_program: Pubkey,
+    requester_token: Pubkey,
+    reserved_solver: Pubkey,
+    refund_recipient: Option<Pubkey>,
+    gmp_endpoint: Option<Pubkey>,
+    hub_chain_id: u32,
+    current_nonce: u64,
+) -> Result<Instruction, Box<dyn Error>> {
+    let (escrow_pda, _escrow_bump) = pda::escrow_pda(&program_id, &intent_id);
+    let (vault_pda, _vault_bump) = pda::vault_pda(&program_id, &intent_id);
+
+    let mut accounts = vec![
+        AccountMeta::new(escrow_pda, false),
+        AccountMeta::new(requester, true),
+        AccountMeta::new_readonly(token_mint, false),
+        AccountMeta::new(requester_token, false),
+        AccountMeta::new(vault_pda, false),
+        AccountMeta::new_readonly(reserved_solver, false),
+        AccountMeta::new_readonly(token_program, false),
+        AccountMeta::new_readonly(solana_sdk::system_program::id(), false),
+        AccountMeta::new_readonly(sysvar::rent::id(), false),
+    ];
+
+    // Add requirements PDA (account 9) - always include for GMP validation
+    let (requirements_pda, _) = pda::requirements_pda(&program_id, &intent_id);
+    accounts.push(AccountMeta::new(requirements_pda, false));
+
+    // If GMP endpoint is provided, add accounts for EscrowConfirmation
+    if let Some(gmp_program) = gmp_endpoint {
+        // Account 10: GMP config PDA (from intent_escrow)
+        let (gmp_config_pda, _) =
+            Pubkey::find_program_address(&[seeds::GMP_CONFIG_SEED], &program_id);
+        accounts.push(AccountMeta::new_readonly(gmp_config_pda, false));
+
+        // Account 11: GMP endpoint program
+        accounts.push(AccountMeta::new_readonly(gmp_program, false));
+
+        // Accounts 12+: GMP Send CPI accounts
+        // GMP Send expects: config, nonce_out, sender, payer, system_program, message_account
+        let (gmp_endpoint_config, _) = Pubkey::find_program_address(&[b"config"], &gmp_program);
+        let (gmp_nonce_out, _) = Pubkey::find_program_address(&[b"nonce_out"], &gmp_program);
+        let nonce_bytes = current_nonce.to_le_bytes();
+        let (gmp_message, _) =
+            Pubkey::find_program_address(&[b"message", &nonce_bytes], &gmp_program);
+
+        accounts.push(AccountMeta::new_readonly(gmp_endpoint_config, false)); // GMP config
+        accounts.push(AccountMeta::new(gmp_nonce_out, false)); // GMP nonce (writable for init/update)
+        accounts.push(AccountMeta::new_readonly(requester, true)); // sender (requester must sign)
+        accounts.push(AccountMeta::new(requester, true)); // payer (requester pays for account creation)
+        accounts.push(AccountMeta::new_readonly(
+            solana_sdk::system_program::id(),
+            false,
+        )); // system program
+        accounts.push(AccountMeta::new(gmp_message, false)); // message account (writable for creation)
+    }
+
+    Ok(Instruction {
+        program_id,
+        accounts,
+        data: EscrowInstruction::CreateEscrow {
+            intent_id,
+            amount,
+            refund_recipient,
+        }
+        .try_to_vec()?,
+    })
+}
+
+/// Build the `Claim` instruction.
+///
+/// Claims are authorized by the hub's `FulfillmentProof` already recorded in
+/// the requirements account, so no signature is attached. `token_program` is
+/// the program that owns the escrow mint.
+pub fn build_claim_ix(
+    program_id: Pubkey,
+    intent_id: [u8; 32],
+    solver_token: Pubkey,
+    token_program: Pubkey,
+) -> Result<Instruction, Box<dyn Error>> {
+    let (escrow_pda, _) = pda::escrow_pda(&program_id, &intent_id);
+    let (requirements_pda, _) = pda::requirements_pda(&program_id, &intent_id);
+    let (vault_pda, _) = pda::vault_pda(&program_id, &intent_id);
+    let (state_pda, _) = Pubkey::find_program_address(&[seeds::STATE_SEED], &program_id);
+
+    Ok(Instruction {
+        program_id,
+        accounts: vec![
+            AccountMeta::new(escrow_pda, false),
+            AccountMeta::new_readonly(requirements_pda, false),
+            AccountMeta::new(vault_pda, false),
+            AccountMeta::new(solver_token, false),
+            AccountMeta::new_readonly(token_program, false),
+            AccountMeta::new_readonly(state_pda, false),
+        ],
+        data: EscrowInstruction::Claim { intent_id }.try_to_vec()?,
+    })
+}
+
+// ============================================================================
+// OFFLINE SIGNING
+// ============================================================================
+
+/// Build the unsigned transaction message that `--emit-unsigned` prints.
+pub fn build_unsigned_message(
+    instructions: &[Instruction],
+    fee_payer: &Pubkey,
+    recent_blockhash: &Hash,
+) -> Message {
+    Message::new_with_blockhash(instructions, Some(fee_payer), recent_blockhash)
+}
+
+/// Encode a message as base64. These are the exact bytes each signer signs.
+pub fn encode_message(message: &Message) -> String {
+    BASE64_STANDARD.encode(message.serialize())
+}
+
+/// Decode a base64 message produced by `--emit-unsigned`.
+pub fn decode_message(encoded: &str) -> Result<Message, Box<dyn Error>> {
+    let bytes = BASE64_STANDARD.decode(encoded.trim())?;
+    Ok(bincode::deserialize(&bytes)?)
+}
+
+/// Render an unsigned message for `--emit-unsigned`: the base64 message,
+/// the signers in the order `submit-signed` expects their signatures, and
+/// the full accounts list.
+pub fn format_unsigned_message(message: &Message) -> String {
+    unsigned_message_output(message).to_string()
+}
+
+/// Build the `--emit-unsigned` result; see [`format_unsigned_message`] for the text form.
+pub fn unsigned_message_output(message: &Message) -> CommandOutput {
+    let num_signers = message.header.num_required_signatures as usize;
+    let signers: Vec<String> = message
+        .account_keys
+        .iter()
+        .take(num_signers)
+        .map(|key| key.to_string())
+        .collect();
+    let mut output = CommandOutput::new()
+        .field(
+            "unsigned_message",
+            "Unsigned message (base64)",
+            encode_message(message),
+        )
+        .field(
+            "recent_blockhash",
+            "Recent blockhash",
+            message.recent_blockhash.to_string(),
+        )
+        .line("Signers (sign in this order):");
+    for (index, key) in signers.iter().enumerate() {
+        output = output.line(format!("  {}: {}", index, key));
+    }
+    output = output.json_field("signers", signers).line("Accounts:");
+    let mut accounts = Vec::new();
+    for (index, key) in message.account_keys.iter().enumerate() {
+        let signer = message.is_signer(index);
+        let writable = message.is_maybe_writable(index, None);
+        let mut flags = Vec::new();
+        if signer {
+            flags.push("signer");
+        }
+        if writable {
+            flags.push("writable");
+        }
+        output = output.line(format!("  {}: {} [{}]", index, key, flags.join(", ")));
+        accounts.push(serde_json::json!({
+            "pubkey": key.to_string(),
+            "signer": signer,
+            "writable": writable,
+        }));
+    }
+    output.json_field("accounts", accounts)
+}
+
+/// Assemble a signed transaction for `submit-signed`.
+///
+/// `signatures` is a comma-separated list of base58 signatures, one per
+/// required signer, in the order printed by `--emit-unsigned`. Every
+/// signature is verified against the message before anything is sent.
+pub fn build_signed_transaction(
+    encoded_message: &str,
+    signatures: &str,
+) -> Result<Transaction, Box<dyn Error>> {
+    let message = decode_message(encoded_message)?;
+    let signatures = signatures
+        .split(',')
+        .map(|s| Signature::from_str(s.trim()))
+        .collect::<Result<Vec<_>, _>>()?;
+
+    let expected = message.header.num_required_signatures as usize;
+    if signatures.len() != expected {
+        return Err(format!(
+            "Expected {expected} signature(s) for this message, got {}",
+            signatures.len()
+        )
+        .into());
+    }
+
+    let transaction = Transaction {
+        signatures,
+        message,
+    };
+    transaction.verify().map_err(|_| {
+        "Signature verification failed: a signature does not match its signer or the message"
+    })?;
+    Ok(transaction)
+}
+
+// ============================================================================
+// TRANSACTION SIMULATION
+// ============================================================================
+
+/// Build the `--dry-run` result from a transaction simulation.
+///
+/// The error (if any) and compute units come first, followed by every
+/// program log line, so the failing instruction's log is visible as-is.
+pub fn simulation_output(result: &RpcSimulateTransactionResult) -> CommandOutput {
+    let mut output =
+        CommandOutput::new().field("success", "Simulation succeeded", result.err.is_none());
+    if let Some(err) = &result.err {
+        output = output.field("error", "Simulation error", err.to_string());
+    }
+    if let Some(units) = result.units_consumed {
+        output = output.field("units_consumed", "Compute units consumed", units);
+    }
+
+    let logs = result.logs.clone().unwrap_or_default();
+    output = output.line(if logs.is_empty() {
+        "Program logs: (none)"
+    } else {
+        "Program logs:"
+    });
+    for log in &logs {
+        output = output.line(format!("  {log}"));
+    }
+    output.json_field("logs", logs)
+}
+
+// ============================================================================
+// BATCH ESCROW CREATION
+// ============================================================================
+
+/// Largest serialized transaction the cluster accepts (one packet).
+pub const MAX_TRANSACTION_SIZE: usize = solana_sdk::packet::PACKET_DATA_SIZE;
+
+/// One escrow to create with `create-escrow-batch`.
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct EscrowBatchRow {
+    pub intent_id: [u8; 32],
+    pub amount: u64,
+    pub solver: Pubkey,
+}
+
+/// Parse a `create-escrow-batch` file of `intent_id,amount,solver` rows.
+///
+/// A file starting with `[` is read as a JSON array of objects with those
+/// keys; anything else as CSV. CSV blank lines and a leading
+/// `intent_id,amount,solver` header are skipped. Errors name the bad row.
+pub fn parse_escrow_batch(contents: &str) -> Result<Vec<EscrowBatchRow>, Box<dyn Error>> {
+    if contents.trim_start().starts_with('[') {
+        let rows: Vec<Value> = serde_json::from_str(contents)?;
+        return rows
+            .iter()
+            .enumerate()
+            .map(|(index, row)| {
+                let field = |key: &str| match row.get(key) {
+                    Some(Value::String(s)) => Ok(s.clone()),
+                    Some(Value::Number(n)) => Ok(n.to_string()),
+                    _ => Err(format!("Row {}: missing or invalid '{key}'", index + 1)),
+                };
+                parse_escrow_batch_row(
+                    index + 1,
+                    &field("intent_id")?,
+                    &field("amount")?,
+                    &field("solver")?,
+                )
+            })
+            .collect();
+    }
+
+    let mut rows = Vec::new();
+    for (index, line) in contents.lines().enumerate() {
+        let line = line.trim();
+        if line.is_empty() || (index == 0 && line.starts_with("intent_id")) {
+            continue;
+        }
+        let fields: Vec<&str> = line.split(',').map(str::trim).collect();
+        let [intent_id, amount, solver] = fields[..] else {
+            return Err(format!(
+                "Row {}: expected intent_id,amount,solver, got '{line}'",
+                index + 1
+            )
+            .into());
+        };
+        let row = parse_escrow_batch_row(index + 1, intent_id, amount, solver)?;
+        rows.push(row);
+    }
+    Ok(rows)
+}
+
+fn parse_escrow_batch_row(
+    row: usize,
+    intent_id: &str,
+    amount: &str,
+    solver: &str,
+) -> Result<EscrowBatchRow, Box<dyn Error>> {
+    let context = |e: Box<dyn Error>| format!("Row {row}: {e}");
+    Ok(EscrowBatchRow {
+        intent_id: parse_intent_id(intent_id).map_err(context)?,
+        amount: parse_u64(amount).map_err(context)?,
+        solver: parse_pubkey(solver).map_err(context)?,
+    })
+}
+
+/// Serialized size of an unsigned transaction carrying `instructions`, with
+/// room for one signature per required signer.
+pub fn transaction_size(instructions: &[Instruction], payer: &Pubkey) -> usize {
+    let tx = Transaction::new_unsigned(Message::new(instructions, Some(payer)));
+    bincode::serialized_size(&tx).map_or(usize::MAX, |size| size as usize)
+}
+
+/// Group `instructions` in order into transactions of at most `max_size` bytes.
+///
+/// Each group is filled greedily before the next one starts. Fails if a
+/// single instruction does not fit in a transaction on its own.
+pub fn split_into_transactions(
+    instructions: Vec<Instruction>,
+    payer: &Pubkey,
+    max_size: usize,
+) -> Result<Vec<Vec<Instruction>>, Box<dyn Error>> {
+    let mut groups: Vec<Vec<Instruction>> = Vec::new();
+    let mut current: Vec<Instruction> = Vec::new();
+    for instruction in instructions {
+        current.push(instruction);
+        if transaction_size(&current, payer) <= max_size {
+            continue;
+        }
+        // Close the current group and start the next one with this instruction
+        let overflow = current.pop().expect("just pushed");
+        if !current.is_empty() {
+            groups.push(std::mem::take(&mut current));
+        }
+        current.push(overflow);
+        if transaction_size(&current, payer) > max_size {
+            return Err(format!(
+                "Instruction does not fit in a {max_size}-byte transaction on its own"
+            )
+            .into());
+        }
+    }
+    if !current.is_empty() {
+        groups.push(current);
+    }
+    Ok(groups)
+}
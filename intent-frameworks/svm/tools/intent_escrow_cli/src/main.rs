@@ -1,11 +1,18 @@
 use borsh::{BorshDeserialize, BorshSerialize};
 use intent_inflow_escrow::{
     instruction::EscrowInstruction,
-    state::{seeds, Escrow, EscrowState, StoredIntentRequirements},
+    pda,
+    state::{seeds, Escrow, EscrowStatus, GmpConfig, StoredIntentRequirements},
 };
 use intent_escrow_cli::{
-    parse_32_byte_hex, parse_i64, parse_intent_id, parse_options, parse_signature, parse_u32,
-    parse_u64, required_option,
+    build_claim_ix, build_create_escrow_ix, build_demo_fulfillment_payload,
+    build_demo_requirements_payload, build_signed_transaction, build_unsigned_message,
+    check_solver_ata, escrow_output, format_escrow_state, format_gmp_payload,
+    list_escrows_by_requester, list_relays, merge_config, mint_token_program, output_format,
+    parse_32_byte_hex, parse_escrow_batch, parse_i64, parse_intent_id, parse_options,
+    parse_outbound_nonce, parse_solver_ata_check, parse_u32, parse_u64, render_output,
+    required_option, simulation_output, split_into_transactions, unsigned_message_output,
+    CommandOutput, EscrowBatchRow, OutputFormat, MAX_TRANSACTION_SIZE,
 };
 use intent_gmp::{
     instruction::NativeGmpInstruction,
@@ -15,18 +22,27 @@ use intent_outflow_validator::{
     instruction::OutflowInstruction,
     state::seeds as outflow_seeds,
 };
+use serde::Serialize;
+use serde_json::{json, Value};
 use solana_client::rpc_client::RpcClient;
 use solana_sdk::{
-    ed25519_instruction::new_ed25519_instruction_with_signature,
     instruction::{AccountMeta, Instruction},
     pubkey::Pubkey,
     signature::{read_keypair_file, Keypair, Signer},
-    sysvar,
     transaction::Transaction,
 };
 use solana_program::program_pack::Pack;
-use spl_token::state::Account as TokenAccount;
-use std::{collections::HashMap, env, error::Error, str::FromStr};
+use spl_token::state::{Account as TokenAccount, Mint};
+use std::{
+    collections::HashMap,
+    env,
+    error::Error,
+    fmt::Display,
+    fs,
+    path::Path,
+    str::FromStr,
+    time::{SystemTime, UNIX_EPOCH},
+};
 
 // ============================================================================
 // CLI ENTRYPOINT
@@ -47,7 +63,35 @@ fn run() -> Result<(), Box<dyn Error>> {
     }
 
     let command = args[0].as_str();
-    let options = parse_options(&args[1..])?;
+    // `--json`, `--emit-unsigned` and `--dry-run` are bare flags; strip them before the
+    // `--key value` option parsing
+    let json = args[1..].iter().any(|arg| arg == "--json");
+    let emit_unsigned = args[1..].iter().any(|arg| arg == "--emit-unsigned");
+    let dry_run = args[1..].iter().any(|arg| arg == "--dry-run");
+    let option_args: Vec<String> = args[1..]
+        .iter()
+        .filter(|arg| {
+            arg.as_str() != "--json"
+                && arg.as_str() != "--emit-unsigned"
+                && arg.as_str() != "--dry-run"
+        })
+        .cloned()
+        .collect();
+    let mut options = parse_options(&option_args)?;
+    if json {
+        options.insert("json".to_string(), "true".to_string());
+    }
+    if emit_unsigned {
+        options.insert("emit-unsigned".to_string(), "true".to_string());
+    }
+    if dry_run {
+        options.insert("dry-run".to_string(), "true".to_string());
+    }
+    if let Some(config_path) = options.remove("config") {
+        options = merge_config(Path::new(&config_path), options)?;
+    }
+    // Reject a bad --output before any transaction is sent
+    output_format(&options)?;
 
     let rpc_url = options
         .get("rpc")
@@ -59,6 +103,12 @@ fn run() -> Result<(), Box<dyn Error>> {
     if command == "get-token-balance" {
         return handle_get_token_balance(&client, &options);
     }
+    if command == "gmp-decode-payload" {
+        return handle_gmp_decode_payload(&options);
+    }
+    if command == "submit-signed" {
+        return handle_submit_signed(&client, &options);
+    }
 
     // GMP commands use --gmp-program-id
     if command == "gmp-init" {
@@ -85,6 +135,30 @@ fn run() -> Result<(), Box<dyn Error>> {
         return handle_gmp_add_relay(&client, &options, gmp_program_id);
     }
 
+    if command == "gmp-remove-relay" {
+        let gmp_program_id = match options.get("gmp-program-id") {
+            Some(value) => parse_pubkey(value)?,
+            None => {
+                eprintln!("Error: --gmp-program-id is required for '{}'", command);
+                print_usage();
+                std::process::exit(1);
+            }
+        };
+        return handle_gmp_remove_relay(&client, &options, gmp_program_id);
+    }
+
+    if command == "gmp-list-relays" {
+        let gmp_program_id = match options.get("gmp-program-id") {
+            Some(value) => parse_pubkey(value)?,
+            None => {
+                eprintln!("Error: --gmp-program-id is required for '{}'", command);
+                print_usage();
+                std::process::exit(1);
+            }
+        };
+        return handle_gmp_list_relays(&client, &options, gmp_program_id);
+    }
+
     if command == "gmp-set-remote-gmp-endpoint-addr" {
         let gmp_program_id = match options.get("gmp-program-id") {
             Some(value) => parse_pubkey(value)?,
@@ -109,6 +183,18 @@ fn run() -> Result<(), Box<dyn Error>> {
         return handle_gmp_set_routing(&client, &options, gmp_program_id);
     }
 
+    if command == "gmp-get-outbound-nonce" {
+        let gmp_program_id = match options.get("gmp-program-id") {
+            Some(value) => parse_pubkey(value)?,
+            None => {
+                eprintln!("Error: --gmp-program-id is required for '{}'", command);
+                print_usage();
+                std::process::exit(1);
+            }
+        };
+        return handle_gmp_get_outbound_nonce(&client, &options, gmp_program_id);
+    }
+
     // Outflow commands use --outflow-program-id
     if command == "outflow-init" {
         let outflow_program_id = match options.get("outflow-program-id") {
@@ -160,10 +246,14 @@ fn run() -> Result<(), Box<dyn Error>> {
     match command {
         "initialize" => handle_initialize(&client, &options, program_id),
         "create-escrow" => handle_create_escrow(&client, &options, program_id),
+        "create-escrow-batch" => handle_create_escrow_batch(&client, &options, program_id),
         "claim" => handle_claim(&client, &options, program_id),
         "cancel" => handle_cancel(&client, &options, program_id),
         "get-escrow" => handle_get_escrow(&client, &options, program_id),
+        "list-escrows" => handle_list_escrows(&client, &options, program_id),
+        "get-state" => handle_get_state(&client, &options, program_id),
         "has-requirements" => handle_has_requirements(&client, &options, program_id),
+        "demo" => handle_demo(&client, &options, program_id),
         _ => {
             print_usage();
             Ok(())
@@ -180,7 +270,7 @@ fn handle_initialize(
     options: &HashMap<String, String>,
     program_id: Pubkey,
 ) -> Result<(), Box<dyn Error>> {
-    let payer = read_keypair(options, "payer")?;
+    let payer = signer_pubkey(options, "payer")?;
     let approver = parse_pubkey(required_option(options, "approver")?)?;
 
     let (state_pda, _state_bump) =
@@ -190,16 +280,19 @@ fn handle_initialize(
         program_id,
         accounts: vec![
             AccountMeta::new(state_pda, false),
-            AccountMeta::new(payer.pubkey(), true),
+            AccountMeta::new(payer, true),
             AccountMeta::new_readonly(solana_sdk::system_program::id(), false),
         ],
         data: EscrowInstruction::Initialize { approver }.try_to_vec()?,
     };
 
-    let signature = send_tx(client, &[ix], &payer, &[])?;
-    println!("Initialize signature: {signature}");
-    println!("State PDA: {state_pda}");
-    Ok(())
+    let Some(signature) = submit_tx(client, options, &[ix], "payer", &[])? else {
+        return Ok(());
+    };
+    let output = CommandOutput::new()
+        .field("signature", "Initialize signature", signature.to_string())
+        .field("state_pda", "State PDA", state_pda.to_string());
+    emit(output, output_format(options)?)
 }
 
 fn handle_create_escrow(
@@ -207,8 +300,8 @@ fn handle_create_escrow(
     options: &HashMap<String, String>,
     program_id: Pubkey,
 ) -> Result<(), Box<dyn Error>> {
-    let payer = read_keypair(options, "payer")?;
-    let requester = read_keypair(options, "requester")?;
+    let payer = signer_pubkey(options, "payer")?;
+    let requester = signer_pubkey(options, "requester")?;
 
     let token_mint = parse_pubkey(required_option(options, "token-mint")?)?;
     let requester_token = parse_pubkey(required_option(options, "requester-token")?)?;
@@ -216,6 +309,25 @@ fn handle_create_escrow(
     let intent_id = parse_intent_id(required_option(options, "intent-id")?)?;
     let amount = parse_u64(required_option(options, "amount")?)?;
     // Expiry comes from hub-provided GMP requirements, not from CLI
+    let refund_recipient = options
+        .get("refund-recipient")
+        .map(|v| parse_pubkey(v))
+        .transpose()?;
+
+    let token_program = mint_token_program(client, &token_mint)?;
+
+    // Optional early check that the reserved solver can receive the escrowed tokens
+    let solver_ata_check =
+        parse_solver_ata_check(options.get("solver-ata-check").map(String::as_str))?;
+    if let Some(warning) = check_solver_ata(
+        client,
+        &solver,
+        &token_mint,
+        &token_program,
+        solver_ata_check,
+    )? {
+        eprintln!("[intent_escrow_cli] Warning: {warning}");
+    }
 
     // Optional GMP endpoint for sending EscrowConfirmation
     let gmp_endpoint = options
@@ -231,40 +343,146 @@ fn handle_create_escrow(
         .unwrap_or(1);
 
     // Read current global outbound nonce for message PDA derivation (0 if no messages sent yet)
-    let current_nonce = if let Some(gmp_program) = gmp_endpoint {
-        let (nonce_pda, _) =
-            Pubkey::find_program_address(&[b"nonce_out"], &gmp_program);
-        match client.get_account_data(&nonce_pda) {
-            Ok(data) if data.len() >= 9 => {
-                // OutboundNonceAccount: disc(1) + nonce(8)
-                u64::from_le_bytes(data[1..9].try_into().unwrap_or([0; 8]))
-            }
-            _ => 0,
-        }
-    } else {
-        0
-    };
+    let current_nonce = gmp_endpoint
+        .map(|gmp_program| read_outbound_nonce(client, &gmp_program))
+        .transpose()?
+        .unwrap_or(0);
 
     let create_ix = build_create_escrow_ix(
         program_id,
         intent_id,
         amount,
-        requester.pubkey(),
+        requester,
         token_mint,
+        token_program,
         requester_token,
         solver,
+        refund_recipient,
         gmp_endpoint,
         hub_chain_id,
         current_nonce,
     )?;
 
-    let signature = send_tx(client, &[create_ix], &payer, &[&requester])?;
-    let (escrow_pda, _) = Pubkey::find_program_address(&[seeds::ESCROW_SEED, &intent_id], &program_id);
-    let (vault_pda, _) = Pubkey::find_program_address(&[seeds::VAULT_SEED, &intent_id], &program_id);
+    let Some(signature) = submit_tx(client, options, &[create_ix], "payer", &["requester"])? else {
+        return Ok(());
+    };
+    let (escrow_pda, _) = pda::escrow_pda(&program_id, &intent_id);
+    let (vault_pda, _) = pda::vault_pda(&program_id, &intent_id);
+
+    let output = CommandOutput::new()
+        .field("signature", "Create escrow signature", signature.to_string())
+        .field("escrow_pda", "Escrow PDA", escrow_pda.to_string())
+        .field("vault_pda", "Vault PDA", vault_pda.to_string());
+    emit(output, output_format(options)?)
+}
 
-    println!("Create escrow signature: {signature}");
-    println!("Escrow PDA: {escrow_pda}");
-    println!("Vault PDA: {vault_pda}");
+/// Create every escrow listed in `--file`, packing as many `CreateEscrow`
+/// instructions per transaction as fit in the size limit.
+///
+/// Each transaction is atomic, so a failure fails every row it carries; later
+/// transactions are still sent. Every row is reported, and the command fails
+/// if any row did.
+fn handle_create_escrow_batch(
+    client: &RpcClient,
+    options: &HashMap<String, String>,
+    program_id: Pubkey,
+) -> Result<(), Box<dyn Error>> {
+    if options.contains_key("emit-unsigned") || options.contains_key("dry-run") {
+        return Err("create-escrow-batch does not support --emit-unsigned or --dry-run".into());
+    }
+    let payer = read_keypair(options, "payer")?;
+    let requester = read_keypair(options, "requester")?;
+    let token_mint = parse_pubkey(required_option(options, "token-mint")?)?;
+    let token_program = mint_token_program(client, &token_mint)?;
+    let requester_token = parse_pubkey(required_option(options, "requester-token")?)?;
+    let rows = parse_escrow_batch(&fs::read_to_string(required_option(options, "file")?)?)?;
+    let gmp_endpoint = options
+        .get("gmp-endpoint")
+        .map(|v| parse_pubkey(v))
+        .transpose()?;
+    let hub_chain_id: u32 = options
+        .get("hub-chain-id")
+        .map(|v| parse_u32(v))
+        .transpose()?
+        .unwrap_or(1);
+
+    let build = |row: &EscrowBatchRow, nonce: u64| {
+        build_create_escrow_ix(
+            program_id,
+            row.intent_id,
+            row.amount,
+            requester.pubkey(),
+            token_mint,
+            token_program,
+            requester_token,
+            row.solver,
+            None,
+            gmp_endpoint,
+            hub_chain_id,
+            nonce,
+        )
+    };
+    // The GMP message PDA has the same size for every nonce, so group with nonce 0
+    let instructions = rows
+        .iter()
+        .map(|row| build(row, 0))
+        .collect::<Result<Vec<_>, _>>()?;
+    let groups = split_into_transactions(instructions, &payer.pubkey(), MAX_TRANSACTION_SIZE)?;
+
+    let mut output = CommandOutput::new();
+    let mut results = Vec::with_capacity(rows.len());
+    let mut failed = 0;
+    let mut start = 0;
+    for group in groups {
+        let group_rows = &rows[start..start + group.len()];
+        // Each escrow in the group sends one EscrowConfirmation, taking consecutive nonces
+        let instructions = match gmp_endpoint {
+            Some(gmp_program) => {
+                let nonce = read_outbound_nonce(client, &gmp_program)?;
+                group_rows
+                    .iter()
+                    .zip(nonce..)
+                    .map(|(row, nonce)| build(row, nonce))
+                    .collect::<Result<Vec<_>, _>>()?
+            }
+            None => group,
+        };
+        let outcome = send_tx(client, &instructions, &payer, &[&requester]);
+        for (offset, row) in group_rows.iter().enumerate() {
+            let number = start + offset + 1;
+            let intent_id = format!("0x{}", hex::encode(row.intent_id));
+            match &outcome {
+                Ok(signature) => {
+                    output =
+                        output.line(format!("Row {number} {intent_id}: created ({signature})"));
+                    results.push(json!({
+                        "row": number,
+                        "intent_id": intent_id,
+                        "signature": signature.to_string(),
+                    }));
+                }
+                Err(err) => {
+                    failed += 1;
+                    output = output.line(format!("Row {number} {intent_id}: failed: {err}"));
+                    results.push(json!({
+                        "row": number,
+                        "intent_id": intent_id,
+                        "error": err.to_string(),
+                    }));
+                }
+            }
+        }
+        start += group_rows.len();
+    }
+
+    let output = output
+        .json_field("rows", results)
+        .field("created", "Created", rows.len() - failed)
+        .field("failed", "Failed", failed);
+    emit(output, output_format(options)?)?;
+    if failed > 0 {
+        return Err(format!("{failed} of {} escrows failed", rows.len()).into());
+    }
     Ok(())
 }
 
@@ -273,38 +491,18 @@ fn handle_claim(
     options: &HashMap<String, String>,
     program_id: Pubkey,
 ) -> Result<(), Box<dyn Error>> {
-    let payer = read_keypair(options, "payer")?;
     let intent_id = parse_intent_id(required_option(options, "intent-id")?)?;
-    let signature = parse_signature(required_option(options, "signature")?)?;
     let solver_token = parse_pubkey(required_option(options, "solver-token")?)?;
+    let token_program = escrow_token_program(client, &program_id, &intent_id)?;
 
-    let (state_pda, _state_bump) =
-        Pubkey::find_program_address(&[seeds::STATE_SEED], &program_id);
-    let (escrow_pda, _) = Pubkey::find_program_address(&[seeds::ESCROW_SEED, &intent_id], &program_id);
-    let (vault_pda, _) = Pubkey::find_program_address(&[seeds::VAULT_SEED, &intent_id], &program_id);
-
-    let state_account = client.get_account(&state_pda)?;
-    let state = EscrowState::try_from_slice(&state_account.data)?;
+    let claim_ix = build_claim_ix(program_id, intent_id, solver_token, token_program)?;
 
-    let ed25519_ix = new_ed25519_instruction_with_signature(
-        &intent_id,
-        &signature,
-        &state.approver.to_bytes(),
-    );
-
-    let claim_ix = build_claim_ix(
-        program_id,
-        intent_id,
-        signature,
-        escrow_pda,
-        state_pda,
-        vault_pda,
-        solver_token,
-    )?;
-
-    let signature = send_tx(client, &[ed25519_ix, claim_ix], &payer, &[])?;
-    println!("Claim signature: {signature}");
-    Ok(())
+    let Some(signature) = submit_tx(client, options, &[claim_ix], "payer", &[])? else {
+        return Ok(());
+    };
+    let output =
+        CommandOutput::new().field("signature", "Claim signature", signature.to_string());
+    emit(output, output_format(options)?)
 }
 
 fn handle_cancel(
@@ -312,21 +510,20 @@ fn handle_cancel(
     options: &HashMap<String, String>,
     program_id: Pubkey,
 ) -> Result<(), Box<dyn Error>> {
-    let payer = read_keypair(options, "payer")?;
-    let admin = read_keypair(options, "admin")?;
+    let payer = signer_pubkey(options, "payer")?;
+    let admin = signer_pubkey(options, "admin")?;
     let requester_token = parse_pubkey(required_option(options, "requester-token")?)?;
     let intent_id = parse_intent_id(required_option(options, "intent-id")?)?;
+    let token_program = escrow_token_program(client, &program_id, &intent_id)?;
 
-    let cancel_ix = build_cancel_ix(
-        program_id,
-        intent_id,
-        admin.pubkey(),
-        requester_token,
-    )?;
+    let cancel_ix = build_cancel_ix(program_id, intent_id, admin, requester_token, token_program)?;
 
-    let signature = send_tx(client, &[cancel_ix], &payer, &[&admin])?;
-    println!("Cancel signature: {signature}");
-    Ok(())
+    let Some(signature) = submit_tx(client, options, &[cancel_ix], "payer", &["admin"])? else {
+        return Ok(());
+    };
+    let output =
+        CommandOutput::new().field("signature", "Cancel signature", signature.to_string());
+    emit(output, output_format(options)?)
 }
 
 fn handle_get_escrow(
@@ -335,17 +532,52 @@ fn handle_get_escrow(
     program_id: Pubkey,
 ) -> Result<(), Box<dyn Error>> {
     let intent_id = parse_intent_id(required_option(options, "intent-id")?)?;
-    let (escrow_pda, _) = Pubkey::find_program_address(&[seeds::ESCROW_SEED, &intent_id], &program_id);
+    let (escrow_pda, _) = pda::escrow_pda(&program_id, &intent_id);
     let account = client.get_account(&escrow_pda)?;
-    let escrow = Escrow::try_from_slice(&account.data)?;
-
-    println!("Escrow PDA: {escrow_pda}");
-    println!("Requester: {}", escrow.requester);
-    println!("Token mint: {}", escrow.token_mint);
-    println!("Amount: {}", escrow.amount);
-    println!("Expiry: {}", escrow.expiry);
-    println!("Reserved solver: {}", escrow.reserved_solver);
-    println!("Claimed: {}", escrow.is_claimed);
+    let escrow = Escrow::unpack(&account.data)?;
+    emit(escrow_output(&escrow_pda, &escrow), output_format(options)?)
+}
+
+fn handle_list_escrows(
+    client: &RpcClient,
+    options: &HashMap<String, String>,
+    program_id: Pubkey,
+) -> Result<(), Box<dyn Error>> {
+    let requester = parse_pubkey(required_option(options, "requester")?)?;
+    let escrows = list_escrows_by_requester(client, &program_id, &requester)?;
+    let mut output = CommandOutput::new().json_field("requester", requester.to_string());
+    if escrows.is_empty() {
+        output = output.line(format!("No escrows found for requester {requester}"));
+    }
+    let mut entries = Vec::new();
+    for (escrow_pda, escrow) in escrows {
+        let intent_id = format!("0x{}", hex::encode(escrow.intent_id));
+        let claimed = escrow.status == EscrowStatus::Claimed;
+        output = output.line(format!(
+            "Intent ID: {} | PDA: {} | Amount: {} | Expiry: {} | Status: {:?} | Claimed: {}",
+            intent_id, escrow_pda, escrow.amount, escrow.expiry, escrow.status, claimed
+        ));
+        entries.push(json!({
+            "intent_id": intent_id,
+            "escrow_pda": escrow_pda.to_string(),
+            "amount": escrow.amount,
+            "expiry": escrow.expiry,
+            "status": format!("{:?}", escrow.status),
+            "claimed": claimed,
+        }));
+    }
+    emit(output.json_field("escrows", entries), output_format(options)?)
+}
+
+fn handle_get_state(
+    client: &RpcClient,
+    options: &HashMap<String, String>,
+    program_id: Pubkey,
+) -> Result<(), Box<dyn Error>> {
+    let json = output_format(options)? == OutputFormat::Json;
+    let (state_pda, _) = Pubkey::find_program_address(&[seeds::STATE_SEED], &program_id);
+    let account = client.get_account(&state_pda)?;
+    println!("{}", format_escrow_state(&state_pda, &account.data, json)?);
     Ok(())
 }
 
@@ -355,23 +587,21 @@ fn handle_has_requirements(
     program_id: Pubkey,
 ) -> Result<(), Box<dyn Error>> {
     let intent_id = parse_intent_id(required_option(options, "intent-id")?)?;
-    let (req_pda, _) =
-        Pubkey::find_program_address(&[seeds::REQUIREMENTS_SEED, &intent_id], &program_id);
-    match client.get_account(&req_pda) {
+    let (req_pda, _) = pda::requirements_pda(&program_id, &intent_id);
+    let output = match client.get_account(&req_pda) {
         Ok(account) => {
             let req = StoredIntentRequirements::try_from_slice(&account.data)?;
-            println!("HasRequirements: true");
-            println!("Requirements PDA: {req_pda}");
-            println!("Amount required: {}", req.amount_required);
-            println!("Expiry: {}", req.expiry);
-            println!("Escrow created: {}", req.escrow_created);
-            println!("Fulfilled: {}", req.fulfilled);
-        }
-        Err(_) => {
-            println!("HasRequirements: false");
+            CommandOutput::new()
+                .field("has_requirements", "HasRequirements", true)
+                .field("requirements_pda", "Requirements PDA", req_pda.to_string())
+                .field("amount_required", "Amount required", req.amount_required)
+                .field("expiry", "Expiry", req.expiry)
+                .field("escrow_created", "Escrow created", req.escrow_created)
+                .field("fulfilled", "Fulfilled", req.fulfilled)
         }
-    }
-    Ok(())
+        Err(_) => CommandOutput::new().field("has_requirements", "HasRequirements", false),
+    };
+    emit(output, output_format(options)?)
 }
 
 fn handle_get_token_balance(
@@ -381,9 +611,24 @@ fn handle_get_token_balance(
     let token_account = parse_pubkey(required_option(options, "token-account")?)?;
     let account = client.get_account(&token_account)?;
     let token_state = TokenAccount::unpack(&account.data)?;
-    println!("Token account: {token_account}");
-    println!("Balance: {}", token_state.amount);
-    Ok(())
+    let output = CommandOutput::new()
+        .field("token_account", "Token account", token_account.to_string())
+        .field("balance", "Balance", token_state.amount);
+    emit(output, output_format(options)?)
+}
+
+fn handle_submit_signed(
+    client: &RpcClient,
+    options: &HashMap<String, String>,
+) -> Result<(), Box<dyn Error>> {
+    let message = required_option(options, "message")?;
+    let signatures = required_option(options, "signature")?;
+
+    let tx = build_signed_transaction(message, signatures)?;
+    let signature = client.send_and_confirm_transaction(&tx)?;
+    let output =
+        CommandOutput::new().field("signature", "Submitted signature", signature.to_string());
+    emit(output, output_format(options)?)
 }
 
 // ============================================================================
@@ -395,7 +640,7 @@ fn handle_escrow_set_gmp_config(
     options: &HashMap<String, String>,
     program_id: Pubkey,
 ) -> Result<(), Box<dyn Error>> {
-    let payer = read_keypair(options, "payer")?;
+    let payer = signer_pubkey(options, "payer")?;
     let hub_chain_id = parse_u32(required_option(options, "hub-chain-id")?)?;
     let hub_gmp_endpoint_addr = parse_32_byte_hex(required_option(options, "hub-address")?)?;
     let gmp_endpoint = parse_pubkey(required_option(options, "gmp-endpoint")?)?;
@@ -407,7 +652,7 @@ fn handle_escrow_set_gmp_config(
         program_id,
         accounts: vec![
             AccountMeta::new(gmp_config_pda, false),
-            AccountMeta::new(payer.pubkey(), true), // admin
+            AccountMeta::new(payer, true), // admin
             AccountMeta::new_readonly(solana_sdk::system_program::id(), false),
         ],
         data: EscrowInstruction::SetGmpConfig {
@@ -418,10 +663,301 @@ fn handle_escrow_set_gmp_config(
         .try_to_vec()?,
     };
 
-    let signature = send_tx(client, &[ix], &payer, &[])?;
-    println!("Escrow SetGmpConfig signature: {signature}");
-    println!("GMP Config PDA: {gmp_config_pda}");
-    Ok(())
+    let Some(signature) = submit_tx(client, options, &[ix], "payer", &[])? else {
+        return Ok(());
+    };
+    let output = CommandOutput::new()
+        .field("signature", "Escrow SetGmpConfig signature", signature.to_string())
+        .field("gmp_config_pda", "GMP Config PDA", gmp_config_pda.to_string());
+    emit(output, output_format(options)?)
+}
+
+// ============================================================================
+// DEMO COMMAND HANDLER
+// ============================================================================
+
+/// Runs the full escrow happy path against a local validator.
+///
+/// The verifier keypair stands in for the hub: it signs the GMP deliveries
+/// (IntentRequirements and FulfillmentProof) as the trusted relay, so the
+/// escrow is released without a live hub or relay.
+fn handle_demo(
+    client: &RpcClient,
+    options: &HashMap<String, String>,
+    program_id: Pubkey,
+) -> Result<(), Box<dyn Error>> {
+    let payer = read_keypair(options, "payer")?;
+    let verifier = read_keypair(options, "verifier")?;
+    let amount = options
+        .get("amount")
+        .map(|v| parse_u64(v))
+        .transpose()?
+        .unwrap_or(1_000_000);
+    let solver = options
+        .get("solver")
+        .map(|v| parse_pubkey(v))
+        .transpose()?
+        .unwrap_or_else(|| payer.pubkey());
+    let expiry_secs = options
+        .get("expiry-secs")
+        .map(|v| parse_u64(v))
+        .transpose()?
+        .unwrap_or(3600);
+
+    let (state_pda, _) = Pubkey::find_program_address(&[seeds::STATE_SEED], &program_id);
+    let (gmp_config_pda, _) =
+        Pubkey::find_program_address(&[seeds::GMP_CONFIG_SEED], &program_id);
+
+    // Steps report in text as they complete; the output is emitted once the demo succeeds
+    let mut output = CommandOutput::new();
+
+    // Step 1: initialize the program (skipped if state already exists)
+    if client.get_account(&state_pda).is_ok() {
+        output = output
+            .line(format!("[1/7] Initialize: state already exists at {state_pda}"))
+            .json_field("initialize_signature", Value::Null);
+    } else {
+        let ix = Instruction {
+            program_id,
+            accounts: vec![
+                AccountMeta::new(state_pda, false),
+                AccountMeta::new(payer.pubkey(), true),
+                AccountMeta::new_readonly(solana_sdk::system_program::id(), false),
+            ],
+            data: EscrowInstruction::Initialize {
+                approver: verifier.pubkey(),
+            }
+            .try_to_vec()?,
+        };
+        let signature = send_tx(client, &[ix], &payer, &[])?;
+        output = output.field(
+            "initialize_signature",
+            "[1/7] Initialize signature",
+            signature.to_string(),
+        );
+    }
+
+    // Step 2: configure GMP (reuses the existing hub source if already configured)
+    let (hub_chain_id, hub_gmp_endpoint_addr) = match client.get_account(&gmp_config_pda) {
+        Ok(account) => {
+            let config = GmpConfig::try_from_slice(&account.data)?;
+            output = output
+                .line(format!(
+                    "[2/7] GMP config: already set (hub_chain_id={})",
+                    config.hub_chain_id
+                ))
+                .json_field("set_gmp_config_signature", Value::Null);
+            (config.hub_chain_id, config.hub_gmp_endpoint_addr)
+        }
+        Err(_) => {
+            let hub_chain_id = options
+                .get("hub-chain-id")
+                .map(|v| parse_u32(v))
+                .transpose()?
+                .unwrap_or(1);
+            let hub_gmp_endpoint_addr = options
+                .get("hub-address")
+                .map(|v| parse_32_byte_hex(v))
+                .transpose()?
+                .unwrap_or([0u8; 32]);
+            let ix = Instruction {
+                program_id,
+                accounts: vec![
+                    AccountMeta::new(gmp_config_pda, false),
+                    AccountMeta::new(payer.pubkey(), true), // admin
+                    AccountMeta::new_readonly(solana_sdk::system_program::id(), false),
+                ],
+                data: EscrowInstruction::SetGmpConfig {
+                    hub_chain_id,
+                    hub_gmp_endpoint_addr,
+                    gmp_endpoint: verifier.pubkey(),
+                }
+                .try_to_vec()?,
+            };
+            let signature = send_tx(client, &[ix], &payer, &[])?;
+            output = output.field(
+                "set_gmp_config_signature",
+                "[2/7] SetGmpConfig signature",
+                signature.to_string(),
+            );
+            (hub_chain_id, hub_gmp_endpoint_addr)
+        }
+    };
+
+    // Step 3: create a test mint, token accounts, and fund the requester (payer)
+    let mint = Keypair::new();
+    let requester_token = Keypair::new();
+    let solver_token = Keypair::new();
+    let mint_rent = client.get_minimum_balance_for_rent_exemption(Mint::LEN)?;
+    let account_rent = client.get_minimum_balance_for_rent_exemption(TokenAccount::LEN)?;
+
+    let setup_ixs = vec![
+        solana_sdk::system_instruction::create_account(
+            &payer.pubkey(),
+            &mint.pubkey(),
+            mint_rent,
+            Mint::LEN as u64,
+            &spl_token::id(),
+        ),
+        spl_token::instruction::initialize_mint(
+            &spl_token::id(),
+            &mint.pubkey(),
+            &payer.pubkey(),
+            None,
+            6,
+        )?,
+        solana_sdk::system_instruction::create_account(
+            &payer.pubkey(),
+            &requester_token.pubkey(),
+            account_rent,
+            TokenAccount::LEN as u64,
+            &spl_token::id(),
+        ),
+        spl_token::instruction::initialize_account(
+            &spl_token::id(),
+            &requester_token.pubkey(),
+            &mint.pubkey(),
+            &payer.pubkey(),
+        )?,
+        solana_sdk::system_instruction::create_account(
+            &payer.pubkey(),
+            &solver_token.pubkey(),
+            account_rent,
+            TokenAccount::LEN as u64,
+            &spl_token::id(),
+        ),
+        spl_token::instruction::initialize_account(
+            &spl_token::id(),
+            &solver_token.pubkey(),
+            &mint.pubkey(),
+            &solver,
+        )?,
+        spl_token::instruction::mint_to(
+            &spl_token::id(),
+            &mint.pubkey(),
+            &requester_token.pubkey(),
+            &payer.pubkey(),
+            &[],
+            amount,
+        )?,
+    ];
+    let signature = send_tx(
+        client,
+        &setup_ixs,
+        &payer,
+        &[&mint, &requester_token, &solver_token],
+    )?;
+    output = output
+        .field("mint_signature", "[3/7] Mint test tokens signature", signature.to_string())
+        .field("token_mint", "      Token mint", mint.pubkey().to_string())
+        .field(
+            "requester_token",
+            "      Requester token",
+            requester_token.pubkey().to_string(),
+        )
+        .field("solver_token", "      Solver token", solver_token.pubkey().to_string());
+
+    // Step 4: deliver IntentRequirements as the hub would
+    let intent_id = Keypair::new().pubkey().to_bytes();
+    let now = SystemTime::now().duration_since(UNIX_EPOCH)?.as_secs();
+    let requirements_payload = build_demo_requirements_payload(
+        intent_id,
+        &payer.pubkey(),
+        amount,
+        &mint.pubkey(),
+        &solver,
+        now + expiry_secs,
+    );
+    let (requirements_pda, _) = pda::requirements_pda(&program_id, &intent_id);
+    let requirements_ix = Instruction {
+        program_id,
+        accounts: vec![
+            AccountMeta::new(requirements_pda, false),
+            AccountMeta::new_readonly(gmp_config_pda, false),
+            AccountMeta::new_readonly(verifier.pubkey(), true), // trusted relay
+            AccountMeta::new(payer.pubkey(), true),
+            AccountMeta::new_readonly(solana_sdk::system_program::id(), false),
+        ],
+        data: EscrowInstruction::GmpReceiveRequirements {
+            src_chain_id: hub_chain_id,
+            remote_gmp_endpoint_addr: hub_gmp_endpoint_addr,
+            payload: requirements_payload,
+        }
+        .try_to_vec()?,
+    };
+    let signature = send_tx(client, &[requirements_ix], &payer, &[&verifier])?;
+    output = output
+        .field(
+            "requirements_signature",
+            "[4/7] Deliver requirements signature",
+            signature.to_string(),
+        )
+        .field("intent_id", "      Intent ID", format!("0x{}", hex::encode(intent_id)));
+
+    // Step 5: create the escrow (no EscrowConfirmation is sent back to a hub)
+    let create_ix = build_create_escrow_ix(
+        program_id,
+        intent_id,
+        amount,
+        payer.pubkey(),
+        mint.pubkey(),
+        spl_token::id(),
+        requester_token.pubkey(),
+        solver,
+        None,
+        None,
+        hub_chain_id,
+        0,
+    )?;
+    let signature = send_tx(client, &[create_ix], &payer, &[])?;
+    let (escrow_pda, _) = pda::escrow_pda(&program_id, &intent_id);
+    let (vault_pda, _) = pda::vault_pda(&program_id, &intent_id);
+    output = output
+        .field(
+            "create_escrow_signature",
+            "[5/7] Create escrow signature",
+            signature.to_string(),
+        )
+        .field("escrow_pda", "      Escrow PDA", escrow_pda.to_string());
+
+    // Step 6: deliver the FulfillmentProof signed by the verifier, releasing the escrow
+    let proof_payload = build_demo_fulfillment_payload(intent_id, &solver, amount, now);
+    let claim_ix = Instruction {
+        program_id,
+        accounts: vec![
+            AccountMeta::new(requirements_pda, false),
+            AccountMeta::new(escrow_pda, false),
+            AccountMeta::new(vault_pda, false),
+            AccountMeta::new(solver_token.pubkey(), false),
+            AccountMeta::new_readonly(gmp_config_pda, false),
+            AccountMeta::new_readonly(verifier.pubkey(), true), // trusted relay
+            AccountMeta::new_readonly(spl_token::id(), false),
+            AccountMeta::new_readonly(state_pda, false),
+        ],
+        data: EscrowInstruction::GmpReceiveFulfillmentProof {
+            src_chain_id: hub_chain_id,
+            remote_gmp_endpoint_addr: hub_gmp_endpoint_addr,
+            payload: proof_payload,
+        }
+        .try_to_vec()?,
+    };
+    let signature = send_tx(client, &[claim_ix], &payer, &[&verifier])?;
+    output = output.field(
+        "claim_signature",
+        "[6/7] Claim (fulfillment proof) signature",
+        signature.to_string(),
+    );
+
+    // Step 7: confirm the escrow ended claimed and the solver was paid
+    let escrow = Escrow::unpack(&client.get_account(&escrow_pda)?.data)?;
+    let solver_balance = TokenAccount::unpack(&client.get_account(&solver_token.pubkey())?.data)?.amount;
+    if escrow.status != EscrowStatus::Claimed {
+        return Err(format!("Demo failed: escrow status is {:?}", escrow.status).into());
+    }
+    let output = output
+        .field("status", "[7/7] Escrow status", format!("{:?}", escrow.status))
+        .field("solver_balance", "      Solver balance", solver_balance);
+    emit(output, output_format(options)?)
 }
 
 // ============================================================================
@@ -433,7 +969,7 @@ fn handle_gmp_init(
     options: &HashMap<String, String>,
     gmp_program_id: Pubkey,
 ) -> Result<(), Box<dyn Error>> {
-    let payer = read_keypair(options, "payer")?;
+    let payer = signer_pubkey(options, "payer")?;
     let chain_id = parse_u32(required_option(options, "chain-id")?)?;
 
     let (config_pda, _config_bump) =
@@ -443,17 +979,20 @@ fn handle_gmp_init(
         program_id: gmp_program_id,
         accounts: vec![
             AccountMeta::new(config_pda, false),
-            AccountMeta::new_readonly(payer.pubkey(), true), // admin
-            AccountMeta::new(payer.pubkey(), true),          // payer
+            AccountMeta::new_readonly(payer, true), // admin
+            AccountMeta::new(payer, true),          // payer
             AccountMeta::new_readonly(solana_sdk::system_program::id(), false),
         ],
         data: NativeGmpInstruction::Initialize { chain_id }.try_to_vec()?,
     };
 
-    let signature = send_tx(client, &[ix], &payer, &[])?;
-    println!("GMP Initialize signature: {signature}");
-    println!("Config PDA: {config_pda}");
-    Ok(())
+    let Some(signature) = submit_tx(client, options, &[ix], "payer", &[])? else {
+        return Ok(());
+    };
+    let output = CommandOutput::new()
+        .field("signature", "GMP Initialize signature", signature.to_string())
+        .field("config_pda", "Config PDA", config_pda.to_string());
+    emit(output, output_format(options)?)
 }
 
 fn handle_gmp_add_relay(
@@ -461,7 +1000,7 @@ fn handle_gmp_add_relay(
     options: &HashMap<String, String>,
     gmp_program_id: Pubkey,
 ) -> Result<(), Box<dyn Error>> {
-    let payer = read_keypair(options, "payer")?;
+    let payer = signer_pubkey(options, "payer")?;
     let relay_pubkey = parse_pubkey(required_option(options, "relay")?)?;
 
     let (config_pda, _) =
@@ -474,17 +1013,78 @@ fn handle_gmp_add_relay(
         accounts: vec![
             AccountMeta::new_readonly(config_pda, false),
             AccountMeta::new(relay_pda, false),
-            AccountMeta::new_readonly(payer.pubkey(), true), // admin
-            AccountMeta::new(payer.pubkey(), true),          // payer
+            AccountMeta::new_readonly(payer, true), // admin
+            AccountMeta::new(payer, true),          // payer
             AccountMeta::new_readonly(solana_sdk::system_program::id(), false),
         ],
         data: NativeGmpInstruction::AddRelay { relay: relay_pubkey }.try_to_vec()?,
     };
 
-    let signature = send_tx(client, &[ix], &payer, &[])?;
-    println!("GMP AddRelay signature: {signature}");
-    println!("Relay PDA: {relay_pda}");
-    Ok(())
+    let Some(signature) = submit_tx(client, options, &[ix], "payer", &[])? else {
+        return Ok(());
+    };
+    let output = CommandOutput::new()
+        .field("signature", "GMP AddRelay signature", signature.to_string())
+        .field("relay_pda", "Relay PDA", relay_pda.to_string());
+    emit(output, output_format(options)?)
+}
+
+fn handle_gmp_remove_relay(
+    client: &RpcClient,
+    options: &HashMap<String, String>,
+    gmp_program_id: Pubkey,
+) -> Result<(), Box<dyn Error>> {
+    let payer = signer_pubkey(options, "payer")?;
+    let relay_pubkey = parse_pubkey(required_option(options, "relay")?)?;
+
+    let (config_pda, _) =
+        Pubkey::find_program_address(&[gmp_seeds::CONFIG_SEED], &gmp_program_id);
+    let (relay_pda, _) =
+        Pubkey::find_program_address(&[gmp_seeds::RELAY_SEED, relay_pubkey.as_ref()], &gmp_program_id);
+
+    let ix = Instruction {
+        program_id: gmp_program_id,
+        accounts: vec![
+            AccountMeta::new_readonly(config_pda, false),
+            AccountMeta::new(relay_pda, false),
+            AccountMeta::new_readonly(payer, true), // admin
+        ],
+        data: NativeGmpInstruction::RemoveRelay { relay: relay_pubkey }.try_to_vec()?,
+    };
+
+    let Some(signature) = submit_tx(client, options, &[ix], "payer", &[])? else {
+        return Ok(());
+    };
+    let output = CommandOutput::new()
+        .field("signature", "GMP RemoveRelay signature", signature.to_string())
+        .field("relay_pda", "Relay PDA", relay_pda.to_string());
+    emit(output, output_format(options)?)
+}
+
+fn handle_gmp_list_relays(
+    client: &RpcClient,
+    options: &HashMap<String, String>,
+    gmp_program_id: Pubkey,
+) -> Result<(), Box<dyn Error>> {
+    let relays = list_relays(client, &gmp_program_id)?;
+    let mut output = CommandOutput::new();
+    if relays.is_empty() {
+        output = output.line("No relays registered");
+    }
+    let mut entries = Vec::new();
+    for relay in relays {
+        let status = if relay.is_authorized {
+            "authorized"
+        } else {
+            "removed"
+        };
+        output = output.line(format!("{}  {status}", relay.relay));
+        entries.push(json!({
+            "relay": relay.relay.to_string(),
+            "is_authorized": relay.is_authorized,
+        }));
+    }
+    emit(output.json_field("relays", entries), output_format(options)?)
 }
 
 fn handle_gmp_set_remote_gmp_endpoint_addr(
@@ -492,7 +1092,7 @@ fn handle_gmp_set_remote_gmp_endpoint_addr(
     options: &HashMap<String, String>,
     gmp_program_id: Pubkey,
 ) -> Result<(), Box<dyn Error>> {
-    let payer = read_keypair(options, "payer")?;
+    let payer = signer_pubkey(options, "payer")?;
     let src_chain_id = parse_u32(required_option(options, "src-chain-id")?)?;
     let addr = parse_32_byte_hex(required_option(options, "addr")?)?;
 
@@ -506,17 +1106,24 @@ fn handle_gmp_set_remote_gmp_endpoint_addr(
         accounts: vec![
             AccountMeta::new_readonly(config_pda, false),
             AccountMeta::new(remote_gmp_endpoint_pda, false),
-            AccountMeta::new_readonly(payer.pubkey(), true), // admin
-            AccountMeta::new(payer.pubkey(), true),          // payer
+            AccountMeta::new_readonly(payer, true), // admin
+            AccountMeta::new(payer, true),          // payer
             AccountMeta::new_readonly(solana_sdk::system_program::id(), false),
         ],
         data: NativeGmpInstruction::SetRemoteGmpEndpointAddr { src_chain_id, addr }.try_to_vec()?,
     };
 
-    let signature = send_tx(client, &[ix], &payer, &[])?;
-    println!("GMP SetRemoteGmpEndpointAddr signature: {signature}");
-    println!("Remote GMP endpoint PDA: {remote_gmp_endpoint_pda}");
-    Ok(())
+    let Some(signature) = submit_tx(client, options, &[ix], "payer", &[])? else {
+        return Ok(());
+    };
+    let output = CommandOutput::new()
+        .field("signature", "GMP SetRemoteGmpEndpointAddr signature", signature.to_string())
+        .field(
+            "remote_gmp_endpoint_pda",
+            "Remote GMP endpoint PDA",
+            remote_gmp_endpoint_pda.to_string(),
+        );
+    emit(output, output_format(options)?)
 }
 
 fn handle_gmp_set_routing(
@@ -524,7 +1131,7 @@ fn handle_gmp_set_routing(
     options: &HashMap<String, String>,
     gmp_program_id: Pubkey,
 ) -> Result<(), Box<dyn Error>> {
-    let payer = read_keypair(options, "payer")?;
+    let payer = signer_pubkey(options, "payer")?;
     let outflow_validator = parse_pubkey(required_option(options, "outflow-validator")?)?;
     let intent_escrow = parse_pubkey(required_option(options, "intent-escrow")?)?;
 
@@ -538,18 +1145,46 @@ fn handle_gmp_set_routing(
         accounts: vec![
             AccountMeta::new_readonly(config_pda, false),
             AccountMeta::new(routing_pda, false),
-            AccountMeta::new_readonly(payer.pubkey(), true), // admin
-            AccountMeta::new(payer.pubkey(), true),          // payer
+            AccountMeta::new_readonly(payer, true), // admin
+            AccountMeta::new(payer, true),          // payer
             AccountMeta::new_readonly(solana_sdk::system_program::id(), false),
         ],
         data: NativeGmpInstruction::SetRouting { outflow_validator, intent_escrow }.try_to_vec()?,
     };
 
-    let signature = send_tx(client, &[ix], &payer, &[])?;
-    println!("GMP SetRouting signature: {signature}");
-    println!("Routing PDA: {routing_pda}");
-    println!("Outflow validator: {outflow_validator}");
-    println!("Intent escrow: {intent_escrow}");
+    let Some(signature) = submit_tx(client, options, &[ix], "payer", &[])? else {
+        return Ok(());
+    };
+    let output = CommandOutput::new()
+        .field("signature", "GMP SetRouting signature", signature.to_string())
+        .field("routing_pda", "Routing PDA", routing_pda.to_string())
+        .field("outflow_validator", "Outflow validator", outflow_validator.to_string())
+        .field("intent_escrow", "Intent escrow", intent_escrow.to_string());
+    emit(output, output_format(options)?)
+}
+
+fn handle_gmp_get_outbound_nonce(
+    client: &RpcClient,
+    options: &HashMap<String, String>,
+    gmp_program_id: Pubkey,
+) -> Result<(), Box<dyn Error>> {
+    let (nonce_pda, _) =
+        Pubkey::find_program_address(&[gmp_seeds::NONCE_OUT_SEED], &gmp_program_id);
+    // The counter is created lazily by the first Send
+    let nonce = match client.get_account_data(&nonce_pda) {
+        Ok(data) => parse_outbound_nonce(&data)?,
+        Err(_) => 0,
+    };
+    let output = CommandOutput::new()
+        .field("nonce_pda", "Nonce PDA", nonce_pda.to_string())
+        .field("outbound_nonce", "Outbound nonce", nonce);
+    emit(output, output_format(options)?)
+}
+
+fn handle_gmp_decode_payload(options: &HashMap<String, String>) -> Result<(), Box<dyn Error>> {
+    let payload = required_option(options, "payload")?;
+    let json = output_format(options)? == OutputFormat::Json;
+    println!("{}", format_gmp_payload(payload, json)?);
     Ok(())
 }
 
@@ -562,7 +1197,7 @@ fn handle_outflow_init(
     options: &HashMap<String, String>,
     outflow_program_id: Pubkey,
 ) -> Result<(), Box<dyn Error>> {
-    let payer = read_keypair(options, "payer")?;
+    let payer = signer_pubkey(options, "payer")?;
     let gmp_endpoint = parse_pubkey(required_option(options, "gmp-endpoint")?)?;
     let hub_chain_id = parse_u32(required_option(options, "hub-chain-id")?)?;
     let hub_gmp_endpoint_addr = parse_32_byte_hex(required_option(options, "hub-address")?)?;
@@ -574,7 +1209,7 @@ fn handle_outflow_init(
         program_id: outflow_program_id,
         accounts: vec![
             AccountMeta::new(config_pda, false),
-            AccountMeta::new(payer.pubkey(), true), // admin/payer
+            AccountMeta::new(payer, true), // admin/payer
             AccountMeta::new_readonly(solana_sdk::system_program::id(), false),
         ],
         data: OutflowInstruction::Initialize {
@@ -585,10 +1220,13 @@ fn handle_outflow_init(
         .try_to_vec()?,
     };
 
-    let signature = send_tx(client, &[ix], &payer, &[])?;
-    println!("Outflow Initialize signature: {signature}");
-    println!("Config PDA: {config_pda}");
-    Ok(())
+    let Some(signature) = submit_tx(client, options, &[ix], "payer", &[])? else {
+        return Ok(());
+    };
+    let output = CommandOutput::new()
+        .field("signature", "Outflow Initialize signature", signature.to_string())
+        .field("config_pda", "Config PDA", config_pda.to_string());
+    emit(output, output_format(options)?)
 }
 
 fn handle_outflow_update_hub_config(
@@ -596,7 +1234,7 @@ fn handle_outflow_update_hub_config(
     options: &HashMap<String, String>,
     outflow_program_id: Pubkey,
 ) -> Result<(), Box<dyn Error>> {
-    let payer = read_keypair(options, "payer")?;
+    let payer = signer_pubkey(options, "payer")?;
     let hub_chain_id = parse_u32(required_option(options, "hub-chain-id")?)?;
     let hub_gmp_endpoint_addr = parse_32_byte_hex(required_option(options, "hub-address")?)?;
 
@@ -607,7 +1245,7 @@ fn handle_outflow_update_hub_config(
         program_id: outflow_program_id,
         accounts: vec![
             AccountMeta::new(config_pda, false),
-            AccountMeta::new_readonly(payer.pubkey(), true), // admin (signer)
+            AccountMeta::new_readonly(payer, true), // admin (signer)
         ],
         data: OutflowInstruction::UpdateHubConfig {
             hub_chain_id,
@@ -616,127 +1254,31 @@ fn handle_outflow_update_hub_config(
         .try_to_vec()?,
     };
 
-    let signature = send_tx(client, &[ix], &payer, &[])?;
-    println!("Outflow UpdateHubConfig signature: {signature}");
-    println!("Config PDA: {config_pda}");
-    Ok(())
+    let Some(signature) = submit_tx(client, options, &[ix], "payer", &[])? else {
+        return Ok(());
+    };
+    let output = CommandOutput::new()
+        .field("signature", "Outflow UpdateHubConfig signature", signature.to_string())
+        .field("config_pda", "Config PDA", config_pda.to_string());
+    emit(output, output_format(options)?)
 }
 
 // ============================================================================
 // INSTRUCTION BUILDERS
 // ============================================================================
 
-fn build_create_escrow_ix(
-    program_id: Pubkey,
-    intent_id: [u8; 32],
-    amount: u64,
-    requester: Pubkey,
-    token_mint: Pubkey,
-    requester_token: Pubkey,
-    reserved_solver: Pubkey,
-    gmp_endpoint: Option<Pubkey>,
-    hub_chain_id: u32,
-    current_nonce: u64,
-) -> Result<Instruction, Box<dyn Error>> {
-    let (escrow_pda, _escrow_bump) =
-        Pubkey::find_program_address(&[seeds::ESCROW_SEED, &intent_id], &program_id);
-    let (vault_pda, _vault_bump) =
-        Pubkey::find_program_address(&[seeds::VAULT_SEED, &intent_id], &program_id);
-
-    let mut accounts = vec![
-        AccountMeta::new(escrow_pda, false),
-        AccountMeta::new(requester, true),
-        AccountMeta::new_readonly(token_mint, false),
-        AccountMeta::new(requester_token, false),
-        AccountMeta::new(vault_pda, false),
-        AccountMeta::new_readonly(reserved_solver, false),
-        AccountMeta::new_readonly(spl_token::id(), false),
-        AccountMeta::new_readonly(solana_sdk::system_program::id(), false),
-        AccountMeta::new_readonly(sysvar::rent::id(), false),
-    ];
-
-    // Add requirements PDA (account 9) - always include for GMP validation
-    let (requirements_pda, _) =
-        Pubkey::find_program_address(&[seeds::REQUIREMENTS_SEED, &intent_id], &program_id);
-    accounts.push(AccountMeta::new(requirements_pda, false));
-
-    // If GMP endpoint is provided, add accounts for EscrowConfirmation
-    if let Some(gmp_program) = gmp_endpoint {
-        // Account 10: GMP config PDA (from intent_escrow)
-        let (gmp_config_pda, _) =
-            Pubkey::find_program_address(&[seeds::GMP_CONFIG_SEED], &program_id);
-        accounts.push(AccountMeta::new_readonly(gmp_config_pda, false));
-
-        // Account 11: GMP endpoint program
-        accounts.push(AccountMeta::new_readonly(gmp_program, false));
-
-        // Accounts 12+: GMP Send CPI accounts
-        // GMP Send expects: config, nonce_out, sender, payer, system_program, message_account
-        let (gmp_endpoint_config, _) =
-            Pubkey::find_program_address(&[b"config"], &gmp_program);
-        let (gmp_nonce_out, _) =
-            Pubkey::find_program_address(&[b"nonce_out"], &gmp_program);
-        let nonce_bytes = current_nonce.to_le_bytes();
-        let (gmp_message, _) =
-            Pubkey::find_program_address(&[b"message", &nonce_bytes], &gmp_program);
-
-        accounts.push(AccountMeta::new_readonly(gmp_endpoint_config, false)); // GMP config
-        accounts.push(AccountMeta::new(gmp_nonce_out, false)); // GMP nonce (writable for init/update)
-        accounts.push(AccountMeta::new_readonly(requester, true)); // sender (requester must sign)
-        accounts.push(AccountMeta::new(requester, true)); // payer (requester pays for account creation)
-        accounts.push(AccountMeta::new_readonly(solana_sdk::system_program::id(), false)); // system program
-        accounts.push(AccountMeta::new(gmp_message, false)); // message account (writable for creation)
-    }
-
-    Ok(Instruction {
-        program_id,
-        accounts,
-        data: EscrowInstruction::CreateEscrow {
-            intent_id,
-            amount,
-        }
-        .try_to_vec()?,
-    })
-}
-
-fn build_claim_ix(
-    program_id: Pubkey,
-    intent_id: [u8; 32],
-    _signature: [u8; 64],
-    escrow_pda: Pubkey,
-    state_pda: Pubkey,
-    vault_pda: Pubkey,
-    solver_token: Pubkey,
-) -> Result<Instruction, Box<dyn Error>> {
-    Ok(Instruction {
-        program_id,
-        accounts: vec![
-            AccountMeta::new(escrow_pda, false),
-            AccountMeta::new_readonly(state_pda, false),
-            AccountMeta::new(vault_pda, false),
-            AccountMeta::new(solver_token, false),
-            AccountMeta::new_readonly(sysvar::instructions::id(), false),
-            AccountMeta::new_readonly(spl_token::id(), false),
-        ],
-        data: EscrowInstruction::Claim {
-            intent_id,
-        }
-        .try_to_vec()?,
-    })
-}
-
 fn build_cancel_ix(
     program_id: Pubkey,
     intent_id: [u8; 32],
     admin: Pubkey,
     requester_token: Pubkey,
+    token_program: Pubkey,
 ) -> Result<Instruction, Box<dyn Error>> {
-    let (escrow_pda, _escrow_bump) =
-        Pubkey::find_program_address(&[seeds::ESCROW_SEED, &intent_id], &program_id);
-    let (vault_pda, _vault_bump) =
-        Pubkey::find_program_address(&[seeds::VAULT_SEED, &intent_id], &program_id);
+    let (escrow_pda, _escrow_bump) = pda::escrow_pda(&program_id, &intent_id);
+    let (vault_pda, _vault_bump) = pda::vault_pda(&program_id, &intent_id);
     let (gmp_config_pda, _) =
         Pubkey::find_program_address(&[seeds::GMP_CONFIG_SEED], &program_id);
+    let (state_pda, _) = Pubkey::find_program_address(&[seeds::STATE_SEED], &program_id);
 
     Ok(Instruction {
         program_id,
@@ -745,8 +1287,9 @@ fn build_cancel_ix(
             AccountMeta::new(admin, true),
             AccountMeta::new(vault_pda, false),
             AccountMeta::new(requester_token, false),
-            AccountMeta::new_readonly(spl_token::id(), false),
+            AccountMeta::new_readonly(token_program, false),
             AccountMeta::new_readonly(gmp_config_pda, false),
+            AccountMeta::new_readonly(state_pda, false),
         ],
         data: EscrowInstruction::Cancel { intent_id }.try_to_vec()?,
     })
@@ -756,12 +1299,76 @@ fn build_cancel_ix(
 // TRANSACTION HELPERS
 // ============================================================================
 
+/// Submit a transaction signed by the keypairs named by `payer_key` and `signer_keys`.
+///
+/// With `--emit-unsigned` nothing is signed or sent: the unsigned message and
+/// its accounts are printed for offline signing, and `None` is returned.
+/// With `--dry-run` the signed transaction is simulated instead of sent: the
+/// simulation logs and compute units are printed, and `None` is returned, or
+/// an error if the simulation failed.
+fn submit_tx(
+    client: &RpcClient,
+    options: &HashMap<String, String>,
+    instructions: &[Instruction],
+    payer_key: &str,
+    signer_keys: &[&str],
+) -> Result<Option<solana_sdk::signature::Signature>, Box<dyn Error>> {
+    if options.contains_key("emit-unsigned") {
+        let payer = signer_pubkey(options, payer_key)?;
+        let blockhash = client.get_latest_blockhash()?;
+        let message = build_unsigned_message(instructions, &payer, &blockhash);
+        emit(unsigned_message_output(&message), output_format(options)?)?;
+        return Ok(None);
+    }
+
+    let payer = read_keypair(options, payer_key)?;
+    let signers = signer_keys
+        .iter()
+        .map(|key| read_keypair(options, key))
+        .collect::<Result<Vec<_>, _>>()?;
+    let signer_refs: Vec<&Keypair> = signers.iter().collect();
+    if options.contains_key("dry-run") {
+        simulate_tx(client, options, instructions, &payer, &signer_refs)?;
+        return Ok(None);
+    }
+    send_tx(client, instructions, &payer, &signer_refs).map(Some)
+}
+
 fn send_tx(
     client: &RpcClient,
     instructions: &[Instruction],
     payer: &Keypair,
     signers: &[&Keypair],
 ) -> Result<solana_sdk::signature::Signature, Box<dyn Error>> {
+    let tx = sign_tx(client, instructions, payer, signers)?;
+    let signature = client.send_and_confirm_transaction(&tx)?;
+    Ok(signature)
+}
+
+/// Simulate the signed transaction and print its logs and compute units without broadcasting.
+fn simulate_tx(
+    client: &RpcClient,
+    options: &HashMap<String, String>,
+    instructions: &[Instruction],
+    payer: &Keypair,
+    signers: &[&Keypair],
+) -> Result<(), Box<dyn Error>> {
+    let tx = sign_tx(client, instructions, payer, signers)?;
+    let result = client.simulate_transaction(&tx)?.value;
+    emit(simulation_output(&result), output_format(options)?)?;
+    if let Some(err) = result.err {
+        return Err(format!("Simulation failed: {err}").into());
+    }
+    Ok(())
+}
+
+/// Sign `instructions` with the payer and any distinct extra signers against the latest blockhash.
+fn sign_tx(
+    client: &RpcClient,
+    instructions: &[Instruction],
+    payer: &Keypair,
+    signers: &[&Keypair],
+) -> Result<Transaction, Box<dyn Error>> {
     let blockhash = client.get_latest_blockhash()?;
     let mut all_signers = Vec::with_capacity(signers.len() + 1);
     all_signers.push(payer);
@@ -771,20 +1378,24 @@ fn send_tx(
         }
     }
 
-    let tx = Transaction::new_signed_with_payer(
+    Ok(Transaction::new_signed_with_payer(
         instructions,
         Some(&payer.pubkey()),
         &all_signers,
         blockhash,
-    );
-    let signature = client.send_and_confirm_transaction(&tx)?;
-    Ok(signature)
+    ))
 }
 
 // ============================================================================
 // LOCAL HELPERS
 // ============================================================================
 
+/// Print a command result to stdout in the selected output format.
+fn emit(result: impl Serialize + Display, format: OutputFormat) -> Result<(), Box<dyn Error>> {
+    println!("{}", render_output(&result, format)?);
+    Ok(())
+}
+
 fn read_keypair(
     options: &HashMap<String, String>,
     key: &str,
@@ -797,6 +1408,39 @@ fn parse_pubkey(value: &str) -> Result<Pubkey, Box<dyn Error>> {
     Ok(Pubkey::from_str(value)?)
 }
 
+/// Read the GMP endpoint's next outbound nonce (0 if no message was sent yet).
+fn read_outbound_nonce(client: &RpcClient, gmp_program: &Pubkey) -> Result<u64, Box<dyn Error>> {
+    let (nonce_pda, _) = Pubkey::find_program_address(&[b"nonce_out"], gmp_program);
+    match client.get_account_data(&nonce_pda) {
+        Ok(data) => parse_outbound_nonce(&data),
+        Err(_) => Ok(0),
+    }
+}
+
+/// Token program of the mint held by the escrow for `intent_id`.
+fn escrow_token_program(
+    client: &RpcClient,
+    program_id: &Pubkey,
+    intent_id: &[u8; 32],
+) -> Result<Pubkey, Box<dyn Error>> {
+    let (escrow_pda, _) = pda::escrow_pda(program_id, intent_id);
+    let escrow = Escrow::unpack(&client.get_account(&escrow_pda)?.data)?;
+    mint_token_program(client, &escrow.token_mint)
+}
+
+/// Resolve a signer option to its pubkey.
+///
+/// With `--emit-unsigned` the option may be a bare pubkey (e.g. a hardware
+/// wallet or multisig address); otherwise it must be a keypair file.
+fn signer_pubkey(options: &HashMap<String, String>, key: &str) -> Result<Pubkey, Box<dyn Error>> {
+    if options.contains_key("emit-unsigned") {
+        if let Ok(pubkey) = parse_pubkey(required_option(options, key)?) {
+            return Ok(pubkey);
+        }
+    }
+    Ok(read_keypair(options, key)?.pubkey())
+}
+
 // ============================================================================
 // USAGE
 // ============================================================================
@@ -806,7 +1450,12 @@ fn print_usage() {
         r#"SVM Intent Escrow CLI
 
 Usage:
-  intent_escrow_cli <command> [--option value]...
+  intent_escrow_cli <command> [--option value]... [--output <text|json>]
+
+  --output json prints each command's result as a single JSON object instead of text
+  (--json is an alias).
+  --config <path> loads options from a TOML file whose keys mirror the options
+  (e.g. program-id = "...", rpc = "...", payer = "..."); command-line options take precedence.
 
 Escrow Commands:
   initialize         --program-id <pubkey> --payer <keypair> --approver <pubkey> [--rpc <url>]
@@ -814,23 +1463,68 @@ Escrow Commands:
                          --hub-address <hex> --gmp-endpoint <pubkey> [--rpc <url>]
   create-escrow      --program-id <pubkey> --payer <keypair> --requester <keypair> --token-mint <pubkey>
                      --requester-token <pubkey> --solver <pubkey> --intent-id <hex> --amount <u64>
-                     [--expiry <i64>] [--gmp-endpoint <pubkey>] [--hub-chain-id <u32>] [--rpc <url>]
+                     [--expiry <i64>] [--gmp-endpoint <pubkey>] [--hub-chain-id <u32>]
+                     [--refund-recipient <pubkey>] [--solver-ata-check <off|warn|error>] [--rpc <url>]
                      Note: --gmp-endpoint enables sending EscrowConfirmation back to hub
+                     Note: --refund-recipient sends the cancel refund to that token account
+                     instead of the requester's
+                     Note: --solver-ata-check checks the solver's token account for the mint
+                     exists before creating (warn prints a warning, error aborts)
+                     Note: for native SOL pass the system program (all-zero address) as
+                     --token-mint and the requester's wallet as --requester-token
+  create-escrow-batch  --program-id <pubkey> --payer <keypair> --requester <keypair>
+                     --token-mint <pubkey> --requester-token <pubkey> --file <path>
+                     [--gmp-endpoint <pubkey>] [--hub-chain-id <u32>] [--rpc <url>]
+                     Note: --file is CSV (intent_id,amount,solver per line) or a JSON array
+                     of objects with those keys; escrows are packed into as few transactions
+                     as fit the 1232-byte limit and each row's result is reported
   claim              --program-id <pubkey> --payer <keypair> --solver-token <pubkey> --intent-id <hex>
-                     --signature <hex> [--rpc <url>]
+                     [--rpc <url>]
+                     Note: succeeds only after the hub's FulfillmentProof has been delivered;
+                     for a native SOL escrow pass the reserved solver's wallet as --solver-token
   cancel             --program-id <pubkey> --payer <keypair> --admin <keypair> --requester-token <pubkey>
                      --intent-id <hex> [--rpc <url>]
+                     Note: pass the escrow's refund recipient as --requester-token if one was set;
+                     for a native SOL escrow pass the wallet to refund
   get-escrow         --program-id <pubkey> --intent-id <hex> [--rpc <url>]
+  list-escrows       --program-id <pubkey> --requester <pubkey> [--rpc <url>]
+                     Note: lists every escrow of the requester, including claimed and cancelled ones
+  get-state          --program-id <pubkey> [--json] [--rpc <url>]
   has-requirements   --program-id <pubkey> --intent-id <hex> [--rpc <url>]
   get-token-balance  --token-account <pubkey> [--rpc <url>]
+  demo               --program-id <pubkey> --payer <keypair> --verifier <keypair> [--amount <u64>]
+                     [--solver <pubkey>] [--expiry-secs <u64>] [--hub-chain-id <u32>]
+                     [--hub-address <hex>] [--rpc <url>]
+                     Note: runs initialize, mint, create-escrow, and claim end to end; the
+                     verifier keypair signs GMP deliveries in place of the hub relay
 
 GMP Endpoint Commands:
   gmp-init           --gmp-program-id <pubkey> --payer <keypair> --chain-id <u32> [--rpc <url>]
   gmp-add-relay      --gmp-program-id <pubkey> --payer <keypair> --relay <pubkey> [--rpc <url>]
+  gmp-remove-relay   --gmp-program-id <pubkey> --payer <keypair> --relay <pubkey> [--rpc <url>]
+                     Note: payer must be the GMP admin; the relay can no longer deliver messages
+  gmp-list-relays    --gmp-program-id <pubkey> [--rpc <url>]
+                     Note: lists every relay PDA, including removed (deauthorized) relays
   gmp-set-remote-gmp-endpoint-addr  --gmp-program-id <pubkey> --payer <keypair> --src-chain-id <u32>
                           --addr <hex> [--rpc <url>]
   gmp-set-routing    --gmp-program-id <pubkey> --payer <keypair> --outflow-validator <pubkey>
                      --intent-escrow <pubkey> [--rpc <url>]
+  gmp-get-outbound-nonce  --gmp-program-id <pubkey> [--rpc <url>]
+                     Note: prints the next nonce Send will assign (0 if nothing sent yet)
+  gmp-decode-payload --payload <hex> [--json]
+                     Note: decodes a raw GMP payload (e.g. from a MessageSent log); no RPC needed
+
+Offline Signing:
+  --emit-unsigned    Flag for any write command except demo: prints the base64 unsigned message, its
+                     signers and its accounts instead of submitting. Signer options (--payer,
+                     --requester, --admin) may then be bare pubkeys. The message embeds a recent
+                     blockhash, so sign and submit it before the blockhash expires (~1 minute).
+  --dry-run          Flag for any write command except demo: signs and simulates the transaction
+                     instead of sending it, printing the program logs and compute units consumed.
+                     Exits with an error if the simulation fails.
+  submit-signed      --message <base64> --signature <base58>[,<base58>...] [--rpc <url>]
+                     Note: signatures in the signer order printed by --emit-unsigned; all are
+                     verified against the message before it is sent
 
 Outflow Validator Commands:
   outflow-init       --outflow-program-id <pubkey> --payer <keypair> --gmp-endpoint <pubkey>
@@ -36,6 +36,8 @@ export type EscrowAccount = {
   reservedSolver: PublicKey;
   intentId: Uint8Array;
   bump: number;
+  /** Account refunded on cancel, or null when refunds go to the requester */
+  refundRecipient: PublicKey | null;
 };
 
 // ============================================================================
@@ -172,6 +174,8 @@ export function parseEscrowAccount(data: Buffer): EscrowAccount {
   const reservedSolver = new PublicKey(data.slice(89, 121));
   const intentId = data.slice(121, 153);
   const bump = data.readUInt8(153);
+  const refundRecipientKey = new PublicKey(data.slice(154, 186));
+  const refundRecipient = refundRecipientKey.equals(PublicKey.default) ? null : refundRecipientKey;
 
   return {
     requester,
@@ -182,6 +186,7 @@ export function parseEscrowAccount(data: Buffer): EscrowAccount {
     reservedSolver,
     intentId,
     bump,
+    refundRecipient,
   };
 }
 
@@ -258,13 +263,21 @@ function encodeU64(value: bigint | number): Buffer {
   return buffer;
 }
 
-function encodeCreateEscrowData(intentId: string, amount: bigint): Buffer {
+function encodeCreateEscrowData(
+  intentId: string,
+  amount: bigint,
+  refundRecipient?: PublicKey,
+): Buffer {
   const intentIdBytes = Buffer.from(svmHexToBytes(intentId));
 
   return Buffer.concat([
     Buffer.from([3]), // EscrowInstruction::CreateEscrow (index 3: Initialize=0, GmpReceive=1, SetGmpConfig=2, CreateEscrow=3)
     intentIdBytes,
     encodeU64(amount),
+    // refund_recipient: Option<Pubkey> (0 = None, 1 + 32 bytes = Some)
+    refundRecipient
+      ? Buffer.concat([Buffer.from([1]), refundRecipient.toBuffer()])
+      : Buffer.from([0]),
   ]);
 }
 
@@ -299,6 +312,8 @@ export function buildCreateEscrowInstruction(params: {
   reservedSolver: PublicKey;
   programId: PublicKey;
   gmpParams?: CreateEscrowGmpParams;
+  /** Account refunded on cancel instead of the requester's token account */
+  refundRecipient?: PublicKey;
 }): TransactionInstruction {
   const programId = params.programId;
   const [escrowPda] = getEscrowPda(params.intentId, programId);
@@ -358,7 +373,7 @@ export function buildCreateEscrowInstruction(params: {
   return new TransactionInstruction({
     programId,
     keys,
-    data: encodeCreateEscrowData(params.intentId, params.amount),
+    data: encodeCreateEscrowData(params.intentId, params.amount, params.refundRecipient),
   });
 }
 
@@ -122,7 +122,7 @@ describe('parseEscrowAccount', () => {
   // Verifies that parseEscrowAccount correctly decodes raw escrow account data.
   // Why: UI needs a stable decoding of on-chain escrow data.
   it('should parse escrow account data into a structured object', () => {
-    const data = Buffer.alloc(154);
+    const data = Buffer.alloc(186);
     Buffer.from('intent00').copy(data, 0);
     Buffer.from(REQUESTER.toBytes()).copy(data, 8);
     Buffer.from(TOKEN_MINT.toBytes()).copy(data, 40);
@@ -142,6 +142,10 @@ describe('parseEscrowAccount', () => {
     expect(escrow.reservedSolver.toBase58()).toBe(SOLVER.toBase58());
     expect(Buffer.from(escrow.intentId).toString('hex')).toBe(INTENT_ID.slice(2));
     expect(escrow.bump).toBe(42);
+    expect(escrow.refundRecipient).toBeNull();
+
+    Buffer.from(REQUESTER.toBytes()).copy(data, 154);
+    expect(parseEscrowAccount(data).refundRecipient?.toBase58()).toBe(REQUESTER.toBase58());
   });
 });
 
@@ -170,7 +174,22 @@ describe('instruction builders', () => {
     expect(Buffer.from(instruction.data.subarray(1, 33))).toEqual(
       Buffer.from(svmHexToBytes(INTENT_ID))
     );
-    expect(instruction.data).toHaveLength(1 + 32 + 8);
+    expect(instruction.data).toHaveLength(1 + 32 + 8 + 1);
+    expect(instruction.data[41]).toBe(0); // refund_recipient = None
+
+    const withRefund = buildCreateEscrowInstruction({
+      intentId: INTENT_ID,
+      amount: BigInt(500),
+      requester: REQUESTER,
+      requesterToken: REQUESTER,
+      tokenMint: TOKEN_MINT,
+      reservedSolver: SOLVER,
+      programId: PROGRAM_ID,
+      refundRecipient: SOLVER,
+    });
+    expect(withRefund.data).toHaveLength(1 + 32 + 8 + 1 + 32);
+    expect(withRefund.data[41]).toBe(1);
+    expect(Buffer.from(withRefund.data.subarray(42, 74))).toEqual(SOLVER.toBuffer());
   });
 
   // 10. Test: Claim Instruction Layout
@@ -3,8 +3,10 @@
 //! Determines whether the solver should sign a draftintent based on:
 //! - Token pair validation (must be in configured supported pairs)
 //! - Exchange rate validation (offered amount must meet required rate for the pair)
+//! - Requester allowlist/denylist (optional)
 
-use std::collections::HashMap;
+use chain_clients_common::normalize_move_address;
+use std::collections::{HashMap, HashSet};
 
 /// Token pair identifier for exchange rate lookup
 #[derive(Debug, Clone, PartialEq, Eq, Hash)]
@@ -37,6 +39,10 @@ pub struct AcceptanceConfig {
     /// Key: TokenPair (offered_chain_id, offered_token, desired_chain_id, desired_token)
     /// Value: Exchange rate and fee info
     pub token_pairs: HashMap<TokenPair, TokenPairInfo>,
+    /// If non-empty, only these requesters are served (normalized addresses)
+    pub requester_allowlist: HashSet<String>,
+    /// Requesters that are never served (normalized addresses)
+    pub requester_denylist: HashSet<String>,
 }
 
 /// Draft-intent data from coordinator API
@@ -89,6 +95,32 @@ pub fn convert_base_fee_in_move_to_offered(base_fee_in_move: u64, move_rate: f64
     (base_fee_in_move as f64 * move_rate).ceil() as u64
 }
 
+/// Normalize a hub (Move) address for allowlist/denylist comparison.
+///
+/// Uses the canonical `0x` + 64 lowercase hex form, so `0x0A`, `0xa` and the
+/// 64-char padded form all compare equal.
+pub fn normalize_requester_addr(addr: &str) -> String {
+    normalize_move_address(addr)
+}
+
+/// Evaluate whether the solver serves a draft's requester.
+///
+/// Rejects requesters on the denylist, and requesters missing from the
+/// allowlist when the allowlist is non-empty. Both lists must already hold
+/// normalized addresses (see `normalize_requester_addr`).
+pub fn evaluate_requester_acceptance(requester_addr: &str, config: &AcceptanceConfig) -> AcceptanceResult {
+    let requester = normalize_requester_addr(requester_addr);
+
+    if config.requester_denylist.contains(&requester) {
+        return AcceptanceResult::Reject(format!("Requester {} is on the denylist", requester));
+    }
+    if !config.requester_allowlist.is_empty() && !config.requester_allowlist.contains(&requester) {
+        return AcceptanceResult::Reject(format!("Requester {} is not on the allowlist", requester));
+    }
+
+    AcceptanceResult::Accept
+}
+
 /// Evaluate whether to accept a draft intent
 pub fn evaluate_draft_acceptance(draft: &DraftintentData, config: &AcceptanceConfig) -> AcceptanceResult {
     // Create token pair key for lookup
@@ -28,8 +28,8 @@ use solver::{
     crypto::{get_private_key_from_profile, sign_intent_hash},
     api::run_acceptance_server,
     service::{
-        InflowService, IntentTracker, LiquidityMonitor, OutflowService, ReconciliationService,
-        SigningService, RECONCILE_INTERVAL_SECS,
+        CircuitBreaker, InflowService, IntentTracker, LiquidityMonitor, OutflowService,
+        ReconciliationService, SigningService, RECONCILE_INTERVAL_SECS,
     },
 };
 use std::sync::Arc;
@@ -326,9 +326,24 @@ async fn main() -> Result<()> {
 
         // Intent tracker loop (polls hub chain for created intents)
         _ = async {
+            let mut breaker = CircuitBreaker::new(
+                "Intent tracker",
+                config.service.circuit_breaker_failure_threshold,
+                config.service.circuit_breaker_cooldown_secs,
+            );
             loop {
-                if let Err(e) = tracker.poll_for_created_intents().await {
-                    error!("Intent tracker error: {}", e);
+                let current_time = std::time::SystemTime::now()
+                    .duration_since(std::time::UNIX_EPOCH)
+                    .unwrap()
+                    .as_secs();
+                if breaker.allow(current_time) {
+                    match tracker.poll_for_created_intents().await {
+                        Ok(_) => breaker.record_success(),
+                        Err(e) => {
+                            error!("Intent tracker error: {}", e);
+                            breaker.record_failure(current_time);
+                        }
+                    }
                 }
                 tokio::time::sleep(polling_interval).await;
             }
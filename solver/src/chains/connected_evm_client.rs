@@ -7,18 +7,24 @@
 
 use anyhow::{Context, Result};
 use sha3::{Digest, Keccak256};
+use std::collections::HashMap;
 use std::process::Command;
+use std::sync::Mutex;
 use tracing::{error, info};
 
-use super::tx_hash::extract_tx_hash;
+use super::fee_budget::{check_fee_budget, estimate_fee};
+use super::tx_hash::extract_labeled_tx_hash;
 
-use chain_clients_evm::EvmClient;
+use chain_clients_evm::{ChunkFailureStrategy, EvmClient};
 
 use crate::config::EvmChainConfig;
 
 // Re-export shared types from chain-clients-evm
 pub use chain_clients_evm::{normalize_evm_address, EscrowCreatedEvent};
 
+/// Line prefix the Hardhat scripts print before the submitted transaction hash
+const HARDHAT_TX_HASH_LABEL: &str = "Transaction hash:";
+
 /// Client for interacting with a connected EVM chain
 pub struct ConnectedEvmClient {
     /// Shared EVM JSON-RPC client for query operations
@@ -36,12 +42,24 @@ pub struct ConnectedEvmClient {
     gmp_endpoint_addr: Option<String>,
     /// Environment variable name containing the EVM private key for signing transactions
     private_key_env: String,
+    /// Maximum fee in wei for a single fulfillment (None = no cap)
+    max_fee_per_fulfillment: Option<u64>,
+    /// Gas units assumed per fulfillment when estimating its fee
+    fulfillment_gas_units: u64,
+    /// Blocks required on top of an escrow event before get_escrow_events returns it
+    confirmations: u64,
+    /// Token decimals by normalized token address (decimals never change, so entries never expire)
+    token_decimals_cache: Mutex<HashMap<String, u8>>,
 }
 
 impl ConnectedEvmClient {
     /// Creates a new connected EVM chain client
     pub fn new(config: &EvmChainConfig) -> Result<Self> {
-        let evm_client = EvmClient::new(&config.rpc_url, &config.escrow_contract_addr)?;
+        let evm_client = EvmClient::new_with_headers(
+            &config.rpc_url,
+            &config.escrow_contract_addr,
+            &config.rpc_headers,
+        )?;
 
         Ok(Self {
             evm_client,
@@ -51,6 +69,10 @@ impl ConnectedEvmClient {
             outflow_validator_addr: config.outflow_validator_addr.clone(),
             gmp_endpoint_addr: config.gmp_endpoint_addr.clone(),
             private_key_env: config.private_key_env.clone(),
+            max_fee_per_fulfillment: config.max_fee_per_fulfillment,
+            fulfillment_gas_units: config.fulfillment_gas_units,
+            confirmations: config.confirmations,
+            token_decimals_cache: Mutex::new(HashMap::new()),
         })
     }
 
@@ -63,14 +85,35 @@ impl ConnectedEvmClient {
         self.evm_client.get_block_number().await
     }
 
-    /// Queries the connected chain for EscrowCreated events
+    /// Returns the number of blocks required on top of an escrow event before it is returned
+    pub fn confirmations(&self) -> u64 {
+        self.confirmations
+    }
+
+    /// Queries the connected chain for confirmed EscrowCreated events
+    ///
+    /// Only events with `blockNumber <= current_block - confirmations` are returned:
+    /// `to_block` (or "latest" when `None`) is clamped to that confirmed block, and
+    /// `from_block` defaults to it. Events a reorg could still drop are withheld
+    /// until enough blocks have been mined on top of them.
     pub async fn get_escrow_events(
         &self,
         from_block: Option<u64>,
         to_block: Option<u64>,
     ) -> Result<Vec<EscrowCreatedEvent>> {
+        let current_block = self.get_block_number().await?;
+        let Some(confirmed_block) = current_block.checked_sub(self.confirmations) else {
+            return Ok(Vec::new());
+        };
+
+        let to_block = to_block.map_or(confirmed_block, |to| to.min(confirmed_block));
+        let from_block = from_block.unwrap_or(to_block);
+        if from_block > to_block {
+            return Ok(Vec::new());
+        }
+
         self.evm_client
-            .get_escrow_created_events(from_block, to_block)
+            .get_escrow_created_events(Some(from_block), Some(to_block), ChunkFailureStrategy::Bail)
             .await
     }
 
@@ -90,6 +133,31 @@ impl ConnectedEvmClient {
         self.evm_client.get_native_balance(account_addr).await
     }
 
+    /// Returns the decimals of an ERC20 token, querying decimals() only on first use.
+    ///
+    /// Fails for addresses that are not ERC20 tokens; failures are not cached.
+    pub async fn get_token_decimals(&self, token_addr: &str) -> Result<u8> {
+        let token_normalized = normalize_evm_address(token_addr)?;
+        if let Some(decimals) = self
+            .token_decimals_cache
+            .lock()
+            .unwrap()
+            .get(&token_normalized)
+        {
+            return Ok(*decimals);
+        }
+
+        let decimals = self
+            .evm_client
+            .get_token_decimals(&token_normalized)
+            .await?;
+        self.token_decimals_cache
+            .lock()
+            .unwrap()
+            .insert(token_normalized, decimals);
+        Ok(decimals)
+    }
+
     // ========================================================================
     // SOLVER-SPECIFIC METHODS (Hardhat scripts, outflow validation)
     // ========================================================================
@@ -171,7 +239,13 @@ impl ConnectedEvmClient {
         }
 
         let output_str = String::from_utf8_lossy(&output.stdout);
-        let tx_hash = extract_tx_hash(&output_str, "transfer-with-intent-id")?;
+        let tx_hash =
+            extract_labeled_tx_hash(&output_str, HARDHAT_TX_HASH_LABEL).with_context(|| {
+                format!(
+                    "Could not extract transaction hash from transfer-with-intent-id output: {}",
+                    output_str
+                )
+            })?;
         info!(
             action = "evm_transfer_success",
             intent_id = %intent_id,
@@ -301,6 +375,23 @@ impl ConnectedEvmClient {
         Ok(clean.ends_with('1'))
     }
 
+    /// Aborts if the estimated fulfillment fee exceeds `max_fee_per_fulfillment`.
+    ///
+    /// The estimate is the configured fulfillment gas units × the current
+    /// `eth_gasPrice`. Does nothing when no cap is configured.
+    pub async fn check_fulfillment_fee_budget(&self, intent_id: &str) -> Result<()> {
+        let Some(max_fee) = self.max_fee_per_fulfillment else {
+            return Ok(());
+        };
+        let gas_price = self
+            .evm_client
+            .gas_price()
+            .await
+            .context("Failed to estimate EVM gas price for fulfillment")?;
+        let estimated_fee = estimate_fee(self.fulfillment_gas_units, gas_price);
+        check_fee_budget(&self.network_name, intent_id, estimated_fee, max_fee)
+    }
+
     /// Fulfills an outflow intent on the EVM chain via IntentOutflowValidator.
     ///
     /// Calls the Hardhat script `fulfill-outflow-intent.js`.
@@ -379,7 +470,13 @@ impl ConnectedEvmClient {
         }
 
         let output_str = String::from_utf8_lossy(&output.stdout);
-        let tx_hash = extract_tx_hash(&output_str, "fulfill-outflow-intent")?;
+        let tx_hash =
+            extract_labeled_tx_hash(&output_str, HARDHAT_TX_HASH_LABEL).with_context(|| {
+                format!(
+                    "Could not extract transaction hash from fulfill-outflow-intent output: {}",
+                    output_str
+                )
+            })?;
         info!(
             action = "evm_fulfill_outflow_success",
             intent_id = %intent_id,
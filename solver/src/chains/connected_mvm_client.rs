@@ -9,8 +9,11 @@
 
 use anyhow::{Context, Result};
 use chain_clients_mvm::{normalize_hex_to_address, MvmClient};
+use std::collections::HashMap;
 use std::process::Command;
+use std::sync::Mutex;
 
+use super::fee_budget::{check_fee_budget, estimate_fee};
 use super::tx_hash::extract_tx_hash;
 
 use crate::config::MvmChainConfig;
@@ -23,6 +26,14 @@ pub struct ConnectedMvmClient {
     module_addr: String,
     /// CLI profile name
     profile: String,
+    /// Chain name (for fee budget logging)
+    name: String,
+    /// Maximum fee in octas for a single fulfillment (None = no cap)
+    max_fee_per_fulfillment: Option<u64>,
+    /// Gas units assumed per fulfillment when estimating its fee
+    fulfillment_gas_units: u64,
+    /// Token decimals by normalized metadata address (decimals never change, so entries never expire)
+    token_decimals_cache: Mutex<HashMap<String, u8>>,
 }
 
 impl ConnectedMvmClient {
@@ -37,14 +48,18 @@ impl ConnectedMvmClient {
     /// * `Ok(ConnectedMvmClient)` - Successfully created client
     /// * `Err(anyhow::Error)` - Failed to create client
     pub fn new(config: &MvmChainConfig) -> Result<Self> {
-        // MvmClient::new normalizes the URL (strips trailing /v1 if present)
-        let mvm_client = MvmClient::new(&config.rpc_url)
+        // MvmClient normalizes the URL (strips trailing /v1 if present)
+        let mvm_client = MvmClient::new_with_headers(&config.rpc_url, &config.rpc_headers)
             .context("Failed to create MVM client")?;
 
         Ok(Self {
             mvm_client,
             module_addr: config.module_addr.clone(),
             profile: config.profile.clone(),
+            name: config.name.clone(),
+            max_fee_per_fulfillment: config.max_fee_per_fulfillment,
+            fulfillment_gas_units: config.fulfillment_gas_units,
+            token_decimals_cache: Mutex::new(HashMap::new()),
         })
     }
 
@@ -61,6 +76,29 @@ impl ConnectedMvmClient {
             .await
     }
 
+    /// Returns the decimals of a fungible asset, reading its metadata only on first use.
+    ///
+    /// Delegates to `MvmClient::get_token_decimals`. Fails for addresses without
+    /// fungible asset metadata; failures are not cached.
+    pub async fn get_token_decimals(&self, token_metadata: &str) -> Result<u8> {
+        let metadata_addr = normalize_hex_to_address(token_metadata);
+        if let Some(decimals) = self
+            .token_decimals_cache
+            .lock()
+            .unwrap()
+            .get(&metadata_addr)
+        {
+            return Ok(*decimals);
+        }
+
+        let decimals = self.mvm_client.get_token_decimals(&metadata_addr).await?;
+        self.token_decimals_cache
+            .lock()
+            .unwrap()
+            .insert(metadata_addr, decimals);
+        Ok(decimals)
+    }
+
     /// Checks if outflow requirements have been delivered via GMP to the connected chain.
     ///
     /// Delegates to `MvmClient::has_outflow_requirements`.
@@ -169,6 +207,32 @@ impl ConnectedMvmClient {
         extract_tx_hash(&output_str, "transfer_with_intent_id")
     }
 
+    /// Aborts if the estimated fulfillment fee exceeds `max_fee_per_fulfillment`.
+    ///
+    /// The estimate is the configured fulfillment gas units × the node's gas
+    /// unit price estimate. Does nothing when no cap is configured.
+    ///
+    /// # Arguments
+    ///
+    /// * `intent_id` - Intent about to be fulfilled (for logging)
+    ///
+    /// # Returns
+    ///
+    /// * `Ok(())` - No cap configured, or estimate within the cap
+    /// * `Err(anyhow::Error)` - Estimate exceeds the cap, or gas price query failed
+    pub async fn check_fulfillment_fee_budget(&self, intent_id: &str) -> Result<()> {
+        let Some(max_fee) = self.max_fee_per_fulfillment else {
+            return Ok(());
+        };
+        let gas_price = self
+            .mvm_client
+            .estimate_gas_price()
+            .await
+            .context("Failed to estimate MVM gas price for fulfillment")?;
+        let estimated_fee = estimate_fee(self.fulfillment_gas_units, gas_price);
+        check_fee_budget(&self.name, intent_id, estimated_fee, max_fee)
+    }
+
     /// Fulfills an outflow intent via the GMP flow on the connected chain.
     ///
     /// Calls `outflow_validator::fulfill_intent` which:
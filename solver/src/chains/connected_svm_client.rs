@@ -24,8 +24,10 @@
 
 use anyhow::{Context, Result};
 use borsh::{BorshDeserialize, BorshSerialize};
+use chain_clients_common::build_header_map;
 use solana_client::client_error::{ClientError, ClientErrorKind};
-use solana_client::rpc_client::RpcClient;
+use solana_client::http_sender::HttpSender;
+use solana_client::rpc_client::{RpcClient, RpcClientConfig};
 use solana_client::rpc_request::RpcError;
 use solana_sdk::{
     commitment_config::CommitmentConfig,
@@ -42,7 +44,7 @@ use chain_clients_svm::SvmClient;
 use crate::config::SvmChainConfig;
 
 // Re-export shared types from chain-clients-svm
-pub use chain_clients_svm::EscrowEvent;
+pub use chain_clients_svm::{EscrowEvent, EscrowStatus};
 
 // Well-known program IDs from Solana mainnet/devnet docs.
 const SPL_TOKEN_PROGRAM_ID: &str = "TokenkegQfeZyiNwAJbNbGKPFXCWuBvf9Ss623VQ5DA";
@@ -59,11 +61,12 @@ pub struct EscrowAccount {
     pub requester: Pubkey,
     pub token_mint: Pubkey,
     pub amount: u64,
-    pub is_claimed: bool,
+    pub status: EscrowStatus,
     pub expiry: i64,
     pub reserved_solver: Pubkey,
     pub intent_id: [u8; 32],
     pub bump: u8,
+    pub refund_recipient: Pubkey,
 }
 
 #[derive(BorshSerialize, BorshDeserialize, Debug, Clone)]
@@ -113,16 +116,29 @@ impl ConnectedSvmClient {
     /// * `Ok(ConnectedSvmClient)` - Initialized client
     /// * `Err(anyhow::Error)` - Invalid config values
     pub fn new(config: &SvmChainConfig) -> Result<Self> {
-        let svm_client = SvmClient::new(&config.rpc_url, &config.escrow_program_id)
-            .context("Failed to create shared SVM client")?;
+        let svm_client = SvmClient::new_with_headers(
+            &config.rpc_url,
+            &config.escrow_program_id,
+            &config.rpc_headers,
+        )
+        .context("Failed to create shared SVM client")?;
 
         let program_id = Pubkey::from_str(&config.escrow_program_id)
             .context("Invalid SVM escrow_program_id")?;
 
-        let rpc_client = RpcClient::new_with_commitment(
-            config.rpc_url.clone(),
-            CommitmentConfig::confirmed(),
-        );
+        let rpc_client = if config.rpc_headers.is_empty() {
+            RpcClient::new_with_commitment(config.rpc_url.clone(), CommitmentConfig::confirmed())
+        } else {
+            // Transactions go through the Solana RPC client, so it needs the headers too
+            let http_client = reqwest::Client::builder()
+                .default_headers(build_header_map(&config.rpc_headers)?)
+                .build()
+                .context("Failed to create SVM RPC HTTP client")?;
+            RpcClient::new_sender(
+                HttpSender::new_with_client(config.rpc_url.clone(), http_client),
+                RpcClientConfig::with_commitment(CommitmentConfig::confirmed()),
+            )
+        };
 
         Ok(Self {
             svm_client,
@@ -234,7 +250,7 @@ impl ConnectedSvmClient {
 
     /// Checks if an inflow escrow has been released (auto-released when FulfillmentProof received).
     ///
-    /// Reads the escrow PDA account and checks the `is_claimed` field.
+    /// Reads the escrow PDA account and checks that `status` is `Claimed`.
     /// With GMP auto-release, when this returns true, tokens have already been transferred to solver.
     ///
     /// # Arguments
@@ -263,7 +279,7 @@ impl ConnectedSvmClient {
         let escrow = EscrowAccount::try_from_slice(&account_data)
             .context("Failed to parse escrow account data")?;
 
-        Ok(escrow.is_claimed)
+        Ok(escrow.status == EscrowStatus::Claimed)
     }
 
     /// Fulfills an outflow intent via the GMP flow on SVM.
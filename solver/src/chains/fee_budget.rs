@@ -0,0 +1,52 @@
+//! Per-fulfillment fee budget
+//!
+//! Shared by the MVM and EVM connected clients to abort an outflow fulfillment
+//! whose estimated fee exceeds the chain's `max_fee_per_fulfillment`, so gas
+//! spikes during congestion cannot push the solver into a loss.
+
+use anyhow::Result;
+use tracing::warn;
+
+/// Estimates a fulfillment fee as gas units × gas price.
+pub fn estimate_fee(gas_units: u64, gas_price: u64) -> u128 {
+    gas_units as u128 * gas_price as u128
+}
+
+/// Checks an estimated fulfillment fee against the chain's cap.
+///
+/// # Arguments
+///
+/// * `chain` - Chain name, for logging
+/// * `intent_id` - Intent being fulfilled, for logging
+/// * `estimated_fee` - Estimated fee in the chain's smallest native unit
+/// * `max_fee` - Configured `max_fee_per_fulfillment`
+///
+/// # Returns
+///
+/// * `Ok(())` - Estimate is within the cap
+/// * `Err(anyhow::Error)` - Estimate exceeds the cap; the fulfillment must not be submitted
+pub fn check_fee_budget(
+    chain: &str,
+    intent_id: &str,
+    estimated_fee: u128,
+    max_fee: u64,
+) -> Result<()> {
+    if estimated_fee > max_fee as u128 {
+        warn!(
+            action = "fulfillment_fee_over_budget",
+            chain = %chain,
+            intent_id = %intent_id,
+            estimated_fee = %estimated_fee,
+            max_fee = %max_fee,
+            "Aborting fulfillment: estimated fee exceeds max_fee_per_fulfillment"
+        );
+        anyhow::bail!(
+            "Estimated fulfillment fee {} on {} exceeds max_fee_per_fulfillment {} (intent {})",
+            estimated_fee,
+            chain,
+            max_fee,
+            intent_id
+        );
+    }
+    Ok(())
+}
@@ -4,8 +4,12 @@
 //! and call fulfillment functions.
 
 use anyhow::{Context, Result};
+use chain_clients_common::{build_header_map, normalize_intent_id, normalize_move_address};
+use chain_clients_mvm::deserialize_move_option_string;
+use futures::stream::{self, StreamExt};
 use reqwest::Client;
 use serde::{Deserialize, Serialize};
+use std::collections::{HashMap, HashSet};
 use std::process::Command;
 use std::time::Duration;
 
@@ -52,17 +56,14 @@ fn extract_transaction_hash(output: &str) -> Option<String> {
 // TYPE DEFINITIONS
 // ============================================================================
 
-/// Move VM Optional wrapper: {"vec": [value]} or {"vec": []}
-#[derive(Debug, Clone, Serialize, Deserialize)]
-pub struct MoveOption<T> {
-    pub vec: Vec<T>,
-}
+/// Transactions requested per page when scanning an account for intent events
+pub const EVENT_QUERY_PAGE_SIZE: u64 = 100;
 
-impl<T> MoveOption<T> {
-    pub fn into_option(mut self) -> Option<T> {
-        self.vec.pop()
-    }
-}
+/// Default cap on pages scanned per account in one `get_intent_events` call
+pub const DEFAULT_EVENT_QUERY_MAX_PAGES: usize = 10;
+
+/// One account's scan result: events, scanned transaction hashes, and the resume version
+type AccountIntentEvents = (Vec<IntentCreatedEvent>, Vec<String>, Option<u64>);
 
 /// Move VM Inner wrapper: {"inner": value}
 #[derive(Debug, Clone, Serialize, Deserialize)]
@@ -86,8 +87,9 @@ pub struct IntentCreatedEvent {
     /// Desired token metadata (wrapped in {"inner": "0x..."})
     pub desired_metadata: MoveInner,
     /// Desired metadata address for cross-chain tokens (optional)
-    #[serde(default)]
-    pub desired_metadata_addr: Option<MoveOption<String>>,
+    /// Move Option: {"vec": ["0x..."]} or {"vec": []}
+    #[serde(default, deserialize_with = "deserialize_move_option_string")]
+    pub desired_metadata_addr: Option<String>,
     /// Desired amount
     pub desired_amount: String,
     /// Desired chain ID
@@ -103,12 +105,13 @@ pub struct IntentCreatedEvent {
     #[serde(default)]
     pub revocable: Option<bool>,
     /// Reserved solver address (optional)
-    #[serde(default)]
-    pub reserved_solver: Option<MoveOption<String>>,
+    /// Move Option: {"vec": ["0x..."]} or {"vec": []}
+    #[serde(default, deserialize_with = "deserialize_move_option_string")]
+    pub reserved_solver: Option<String>,
     /// Requester address on the connected chain (for outflow intents)
-    /// Wrapped in Move Option: {"vec": ["0x..."]} or {"vec": []}
-    #[serde(default)]
-    pub requester_addr_connected_chain: Option<MoveOption<String>>,
+    /// Move Option: {"vec": ["0x..."]} or {"vec": []}
+    #[serde(default, deserialize_with = "deserialize_move_option_string")]
+    pub requester_addr_connected_chain: Option<String>,
 }
 
 /// Event emitted when an intent is fulfilled
@@ -140,6 +143,8 @@ pub struct HubChainClient {
     profile: String,
     /// E2E mode flag: if true, use aptos CLI with profiles; if false, use movement CLI with private keys
     e2e_mode: bool,
+    /// Maximum number of accounts queried concurrently in `get_intent_events`
+    event_query_concurrency: usize,
 }
 
 impl HubChainClient {
@@ -157,6 +162,7 @@ impl HubChainClient {
         let client = Client::builder()
             .timeout(Duration::from_secs(30))
             .no_proxy() // Avoid macOS system-configuration issues in tests
+            .default_headers(build_header_map(&config.rpc_headers)?)
             .build()
             .context("Failed to create HTTP client")?;
 
@@ -172,6 +178,7 @@ impl HubChainClient {
             module_addr: config.module_addr.clone(),
             profile: config.profile.clone(),
             e2e_mode: config.e2e_mode,
+            event_query_concurrency: config.event_query_concurrency,
         })
     }
 
@@ -180,121 +187,253 @@ impl HubChainClient {
     /// This queries known accounts for LimitOrderEvent and OracleLimitOrderEvent
     /// to detect when new intents are created.
     ///
+    /// Accounts are queried concurrently (bounded by `event_query_concurrency`).
+    /// Each account's transactions are read in pages of `EVENT_QUERY_PAGE_SIZE`
+    /// until a short page comes back or `max_pages` pages have been read.
+    /// A failed query for one account is logged and skipped so the remaining
+    /// accounts still return their events. Results are merged and de-duplicated
+    /// by intent ID and transaction hash.
+    ///
     /// # Arguments
     ///
     /// * `known_accounts` - List of account addresses to query
-    /// * `since_version` - Optional transaction version to start from (for pagination)
+    /// * `resume_versions` - Per account, the sequence number to start from (as returned
+    ///   by a previous call); accounts without an entry start at 0
+    /// * `processed_transactions` - Transaction hashes to skip (already parsed)
+    /// * `max_pages` - Maximum pages to read per account
     ///
     /// # Returns
     ///
-    /// * `Ok(Vec<IntentCreatedEvent>)` - List of intent creation events
+    /// * `Ok((events, tx_hashes, resume_versions))` - Intent creation events, scanned
+    ///   transaction hashes, and per account the sequence number that resumes after the
+    ///   last transaction seen (accounts that failed or returned nothing are omitted)
     /// * `Err(anyhow::Error)` - Failed to query events
     pub async fn get_intent_events(
         &self,
         known_accounts: &[String],
-        since_version: Option<u64>,
+        resume_versions: &HashMap<String, u64>,
         processed_transactions: Option<&std::collections::HashSet<String>>,
-    ) -> Result<(Vec<IntentCreatedEvent>, Vec<String>)> {
+        max_pages: usize,
+    ) -> Result<(Vec<IntentCreatedEvent>, Vec<String>, HashMap<String, u64>)> {
+        let results: Vec<(&String, Result<AccountIntentEvents>)> = stream::iter(known_accounts)
+            .map(|account| async move {
+                let start_version = resume_versions.get(account).copied().unwrap_or(0);
+                let result = self
+                    .get_account_intent_events(
+                        account,
+                        start_version,
+                        processed_transactions,
+                        max_pages,
+                    )
+                    .await;
+                (account, result)
+            })
+            .buffer_unordered(self.event_query_concurrency.max(1))
+            .collect()
+            .await;
+
         let mut events = Vec::new();
         let mut transaction_hashes = Vec::new();
+        let mut next_resume_versions = HashMap::new();
+        let mut seen_intent_ids = HashSet::new();
+        let mut seen_tx_hashes = HashSet::new();
+
+        for (account, result) in results {
+            match result {
+                Ok((account_events, account_tx_hashes, resume_version)) => {
+                    if let Some(version) = resume_version {
+                        next_resume_versions.insert(account.clone(), version);
+                    }
+                    for event in account_events {
+                        if seen_intent_ids.insert(normalize_intent_id(&event.intent_id)) {
+                            events.push(event);
+                        }
+                    }
+                    for tx_hash in account_tx_hashes {
+                        if seen_tx_hashes.insert(tx_hash.clone()) {
+                            transaction_hashes.push(tx_hash);
+                        }
+                    }
+                }
+                Err(e) => {
+                    tracing::warn!("Skipping intent events for account {}: {:#}", account, e);
+                }
+            }
+        }
 
-        for account in known_accounts {
-            let account_addr = account.strip_prefix("0x").unwrap_or(account);
-            let url = format!("{}/v1/accounts/{}/transactions", self.base_url, account_addr);
+        Ok((events, transaction_hashes, next_resume_versions))
+    }
 
-            tracing::trace!("Querying transactions from: {}", url);
+    /// Queries a single account's transactions for intent creation events, one page
+    /// at a time
+    ///
+    /// # Arguments
+    ///
+    /// * `account` - Account address to query
+    /// * `start_version` - Sequence number of the first transaction to read
+    /// * `processed_transactions` - Transaction hashes to skip (already parsed)
+    /// * `max_pages` - Maximum pages to read
+    ///
+    /// # Returns
+    ///
+    /// * `Ok((events, tx_hashes, resume_version))` - Intent creation events, scanned transaction
+    ///   hashes, and the sequence number that resumes after the last transaction seen
+    /// * `Err(anyhow::Error)` - Failed to query or parse the account's transactions
+    async fn get_account_intent_events(
+        &self,
+        account: &str,
+        start_version: u64,
+        processed_transactions: Option<&std::collections::HashSet<String>>,
+        max_pages: usize,
+    ) -> Result<AccountIntentEvents> {
+        let mut events = Vec::new();
+        let mut transaction_hashes = Vec::new();
+        let mut resume_version = None;
+        let mut start = start_version;
 
-            let mut query_params = vec![("limit", "100".to_string())];
-            if let Some(version) = since_version {
-                query_params.push(("start", version.to_string()));
-            }
+        for _ in 0..max_pages {
+            let transactions = match self.get_account_transactions_page(account, start).await? {
+                Some(transactions) => transactions,
+                None => break,
+            };
+            let page_len = transactions.len() as u64;
+            Self::collect_intent_events(
+                account,
+                &transactions,
+                processed_transactions,
+                &mut events,
+                &mut transaction_hashes,
+            );
 
-            let response = self
-                .client
-                .get(&url)
-                .query(&query_params)
-                .send()
-                .await
-                .context(format!("Failed to query transactions for account {}", account))?;
-
-            let status = response.status();
-            if !status.is_success() {
-                let error_body = response.text().await.unwrap_or_else(|e| format!("<failed to read body: {}>", e));
-                tracing::debug!("Query failed for account {}: HTTP {} - {}", account, status, error_body);
-                continue;
+            // Resume after the last transaction; fall back to counting if it has no sequence number
+            start = transactions
+                .last()
+                .and_then(|tx| tx.get("sequence_number"))
+                .and_then(|n| n.as_str())
+                .and_then(|n| n.parse::<u64>().ok())
+                .map(|n| n + 1)
+                .unwrap_or(start + page_len);
+            if page_len > 0 {
+                resume_version = Some(start);
+            }
+            if page_len < EVENT_QUERY_PAGE_SIZE {
+                break;
             }
+        }
 
-            let transactions: Vec<serde_json::Value> = response
-                .json()
-                .await
-                .context("Failed to parse transactions response")?;
+        Ok((events, transaction_hashes, resume_version))
+    }
 
-            // Count skipped vs new transactions
-            let mut _skipped_count = 0;
-            let mut new_count = 0;
-            
-            // Extract intent creation events from transactions
-            for tx in &transactions {
-                let tx_hash = tx.get("hash").and_then(|h| h.as_str()).unwrap_or("unknown");
-                
-                // Skip already-processed transactions
-                if let Some(processed) = processed_transactions {
-                    if processed.contains(tx_hash) {
-                        _skipped_count += 1;
-                        continue; // Skip this transaction - already processed
-                    }
+    /// Fetches one page of an account's transactions starting at `start`
+    ///
+    /// # Returns
+    ///
+    /// * `Ok(Some(transactions))` - The page (may be shorter than `EVENT_QUERY_PAGE_SIZE`)
+    /// * `Ok(None)` - The node answered with a non-success status (logged at debug)
+    /// * `Err(anyhow::Error)` - Failed to send the request or parse the response
+    async fn get_account_transactions_page(
+        &self,
+        account: &str,
+        start: u64,
+    ) -> Result<Option<Vec<serde_json::Value>>> {
+        let account_addr = account.strip_prefix("0x").unwrap_or(account);
+        let url = format!("{}/v1/accounts/{}/transactions", self.base_url, account_addr);
+
+        tracing::trace!("Querying transactions from: {} (start {})", url, start);
+
+        let query_params = [
+            ("limit", EVENT_QUERY_PAGE_SIZE.to_string()),
+            ("start", start.to_string()),
+        ];
+
+        let response = self
+            .client
+            .get(&url)
+            .query(&query_params)
+            .send()
+            .await
+            .context(format!("Failed to query transactions for account {}", account))?;
+
+        let status = response.status();
+        if !status.is_success() {
+            let error_body = response.text().await.unwrap_or_else(|e| format!("<failed to read body: {}>", e));
+            tracing::debug!("Query failed for account {}: HTTP {} - {}", account, status, error_body);
+            return Ok(None);
+        }
+
+        let transactions: Vec<serde_json::Value> = response
+            .json()
+            .await
+            .context("Failed to parse transactions response")?;
+        Ok(Some(transactions))
+    }
+
+    /// Extracts non-expired intent creation events from a page of transactions,
+    /// appending them and the scanned transaction hashes to the given lists
+    fn collect_intent_events(
+        account: &str,
+        transactions: &[serde_json::Value],
+        processed_transactions: Option<&std::collections::HashSet<String>>,
+        events: &mut Vec<IntentCreatedEvent>,
+        transaction_hashes: &mut Vec<String>,
+    ) {
+        let mut new_count = 0;
+
+        // Extract intent creation events from transactions
+        for tx in transactions {
+            let tx_hash = tx.get("hash").and_then(|h| h.as_str()).unwrap_or("unknown");
+
+            // Skip already-processed transactions
+            if let Some(processed) = processed_transactions {
+                if processed.contains(tx_hash) {
+                    continue;
                 }
-                new_count += 1;
-                
-                // Track this transaction hash (will be added to processed set after processing)
-                let tx_hash_owned = tx_hash.to_string();
-                
-                if let Some(tx_events) = tx.get("events").and_then(|e| e.as_array()) {
-                    for event_json in tx_events.iter() {
-                        let event_type = event_json
-                            .get("type")
-                            .and_then(|t| t.as_str())
-                            .unwrap_or("");
-
-                        // Check for LimitOrderEvent (inflow) or OracleLimitOrderEvent (outflow)
-                        // IMPORTANT: Check OracleLimitOrderEvent BEFORE LimitOrderEvent because
-                        // "OracleLimitOrderEvent".contains("LimitOrderEvent") is true!
-                        if event_type.contains("OracleLimitOrderEvent") || event_type.contains("LimitOrderEvent") {
-                            match serde_json::from_value::<IntentCreatedEvent>(
-                                event_json.get("data").cloned().unwrap_or(serde_json::Value::Null),
-                            ) {
-                                Ok(event_data) => {
-                                    // Check if event is expired before adding
-                                    let current_time = std::time::SystemTime::now()
-                                        .duration_since(std::time::UNIX_EPOCH)
-                                        .unwrap()
-                                        .as_secs();
-                                    if let Ok(expiry) = event_data.expiry_time.parse::<u64>() {
-                                        if expiry >= current_time {
-                                            tracing::info!("New intent: {} (expiry {} >= now {})",
-                                                event_data.intent_id, expiry, current_time);
-                                            events.push(event_data);
-                                        }
+            }
+            new_count += 1;
+
+            if let Some(tx_events) = tx.get("events").and_then(|e| e.as_array()) {
+                for event_json in tx_events.iter() {
+                    let event_type = event_json
+                        .get("type")
+                        .and_then(|t| t.as_str())
+                        .unwrap_or("");
+
+                    // Check for LimitOrderEvent (inflow) or OracleLimitOrderEvent (outflow)
+                    // IMPORTANT: Check OracleLimitOrderEvent BEFORE LimitOrderEvent because
+                    // "OracleLimitOrderEvent".contains("LimitOrderEvent") is true!
+                    if event_type.contains("OracleLimitOrderEvent") || event_type.contains("LimitOrderEvent") {
+                        match serde_json::from_value::<IntentCreatedEvent>(
+                            event_json.get("data").cloned().unwrap_or(serde_json::Value::Null),
+                        ) {
+                            Ok(event_data) => {
+                                // Check if event is expired before adding
+                                let current_time = std::time::SystemTime::now()
+                                    .duration_since(std::time::UNIX_EPOCH)
+                                    .unwrap()
+                                    .as_secs();
+                                if let Ok(expiry) = event_data.expiry_time.parse::<u64>() {
+                                    if expiry >= current_time {
+                                        tracing::info!("New intent: {} (expiry {} >= now {})",
+                                            event_data.intent_id, expiry, current_time);
+                                        events.push(event_data);
                                     }
                                 }
-                                Err(e) => {
-                                    tracing::warn!("Failed to parse intent event data: {} - event_type: {}", e, event_type);
-                                }
+                            }
+                            Err(e) => {
+                                tracing::warn!("Failed to parse intent event data: {} - event_type: {}", e, event_type);
                             }
                         }
                     }
                 }
-                
-                // Add transaction hash to processed list (even if no intent event found, to avoid re-parsing)
-                transaction_hashes.push(tx_hash_owned);
-            }
-            
-            if new_count > 0 {
-                tracing::debug!("Account {}: scanned {} new tx(s)", account, new_count);
             }
+
+            // Add transaction hash to processed list (even if no intent event found, to avoid re-parsing)
+            transaction_hashes.push(tx_hash.to_string());
         }
 
-        Ok((events, transaction_hashes))
+        if new_count > 0 {
+            tracing::debug!("Account {}: scanned {} new tx(s)", account, new_count);
+        }
     }
 
     /// Fulfills an inflow request intent
@@ -506,8 +645,7 @@ impl HubChainClient {
     pub async fn is_escrow_confirmed(&self, intent_id: &str) -> Result<bool> {
         // Normalize to 64-char hex: Move strips leading zeros from addresses in events,
         // producing odd-length hex that the Aptos REST API rejects.
-        let without_prefix = intent_id.strip_prefix("0x").unwrap_or(intent_id);
-        let intent_id_hex = format!("0x{:0>64}", without_prefix);
+        let intent_id_hex = normalize_move_address(intent_id);
 
         let view_url = format!("{}/v1/view", self.base_url);
         let request_body = serde_json::json!({
@@ -548,6 +686,62 @@ impl HubChainClient {
         anyhow::bail!("Unexpected response format from is_escrow_confirmed view function")
     }
 
+    /// Checks if an intent is still registered (open) on the hub chain.
+    ///
+    /// Calls the `intent_registry::is_intent_registered` view function. Intents are
+    /// unregistered when they are fulfilled or cancelled.
+    ///
+    /// # Arguments
+    ///
+    /// * `intent_addr` - On-chain intent object address
+    ///
+    /// # Returns
+    ///
+    /// * `Ok(bool)` - True if the intent is still registered
+    /// * `Err(anyhow::Error)` - Failed to query
+    pub async fn is_intent_registered(&self, intent_addr: &str) -> Result<bool> {
+        // Normalize to 64-char hex (Move may strip leading zeros from addresses)
+        let intent_addr_hex = normalize_move_address(intent_addr);
+
+        let view_url = format!("{}/v1/view", self.base_url);
+        let request_body = serde_json::json!({
+            "function": format!("{}::intent_registry::is_intent_registered", self.module_addr),
+            "type_arguments": [],
+            "arguments": [intent_addr_hex]
+        });
+
+        let response = self
+            .client
+            .post(&view_url)
+            .json(&request_body)
+            .send()
+            .await
+            .context("Failed to query intent registration")?;
+
+        let status = response.status();
+        if !status.is_success() {
+            let error_body = response.text().await.unwrap_or_else(|e| format!("<failed to read body: {}>", e));
+            anyhow::bail!(
+                "Failed to query intent registration: HTTP {} - {}",
+                status,
+                error_body
+            );
+        }
+
+        let result: Vec<serde_json::Value> = response
+            .json()
+            .await
+            .context("Failed to parse intent registration response")?;
+
+        if let Some(first_result) = result.first() {
+            if let Some(is_registered) = first_result.as_bool() {
+                return Ok(is_registered);
+            }
+        }
+
+        anyhow::bail!("Unexpected response format from is_intent_registered view function")
+    }
+
     /// Queries the fungible asset balance for an account on the hub chain.
     ///
     /// Uses the `0x1::primary_fungible_store::balance` view function.
@@ -652,8 +846,7 @@ impl HubChainClient {
     /// * `Ok(bool)` - True if FulfillmentProof was received (only meaningful for outflow)
     /// * `Err(anyhow::Error)` - Failed to query
     pub async fn is_fulfillment_proof_received(&self, intent_id: &str) -> Result<bool> {
-        let without_prefix = intent_id.strip_prefix("0x").unwrap_or(intent_id);
-        let intent_id_hex = format!("0x{:0>64}", without_prefix);
+        let intent_id_hex = normalize_move_address(intent_id);
 
         let view_url = format!("{}/v1/view", self.base_url);
         let request_body = serde_json::json!({
@@ -8,10 +8,13 @@ pub mod connected_mvm_client;
 pub mod connected_evm_client;
 pub mod connected_svm_client;
 pub mod tx_hash;
+pub mod fee_budget;
 
 // Re-export for convenience
 pub use hub::{HubChainClient, IntentCreatedEvent};
 pub use connected_mvm_client::ConnectedMvmClient;
 pub use connected_evm_client::{normalize_evm_address, ConnectedEvmClient, EscrowCreatedEvent};
-pub use connected_svm_client::{ConnectedSvmClient, EscrowAccount, EscrowEvent as SvmEscrowEvent};
+pub use connected_svm_client::{
+    ConnectedSvmClient, EscrowAccount, EscrowEvent as SvmEscrowEvent, EscrowStatus,
+};
 
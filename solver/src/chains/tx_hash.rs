@@ -2,6 +2,7 @@
 //!
 //! Shared utility for parsing transaction hashes from aptos CLI and Hardhat
 //! script output. Both MVM and EVM clients use this to avoid duplicated parsing logic.
+//! Solana CLI output carries a base58 signature instead, handled by `extract_signature`.
 
 use anyhow::Result;
 
@@ -53,3 +54,92 @@ pub fn extract_tx_hash(output: &str, context: &str) -> Result<String> {
 
     anyhow::bail!("Could not extract transaction hash from {} output: {}", context, output)
 }
+
+/// Extracts a 32-byte transaction hash from the line starting with `label`.
+///
+/// The label is matched case-insensitively at the start of a trimmed line, and
+/// the value after it must be a 66-character `0x`-prefixed hex string. Other
+/// `0x` tokens in the output (addresses, block hashes, approval transactions)
+/// are ignored, unlike the first-match scraping of `extract_tx_hash`.
+///
+/// # Arguments
+///
+/// * `output` - Raw stdout from the script
+/// * `label` - Line prefix that precedes the hash, e.g. `"Transaction hash:"`
+pub fn extract_labeled_tx_hash(output: &str, label: &str) -> Option<String> {
+    output
+        .lines()
+        .map(str::trim)
+        .filter(|l| {
+            l.get(..label.len())
+                .is_some_and(|prefix| prefix.eq_ignore_ascii_case(label))
+        })
+        .filter_map(|l| l[label.len()..].split_whitespace().next())
+        .find(|value| is_tx_hash(value))
+        .map(str::to_string)
+}
+
+/// Returns true if `value` is a `0x`-prefixed 32-byte hex hash.
+fn is_tx_hash(value: &str) -> bool {
+    value.len() == 66
+        && value.starts_with("0x")
+        && value[2..].chars().all(|c| c.is_ascii_hexdigit())
+}
+
+/// A transaction identifier found in CLI output.
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub enum TxIdentifier {
+    /// `0x`-prefixed hex hash (aptos CLI, Hardhat scripts)
+    Hash(String),
+    /// Base58 transaction signature (solana CLI)
+    Signature(String),
+}
+
+/// Extracts a base58 transaction signature from solana CLI output.
+///
+/// The solana CLI prints `Signature: <base58>`. The value must decode to a
+/// 64-byte Ed25519 signature, which keeps a hex hash or a truncated value
+/// from being mistaken for a signature.
+///
+/// # Arguments
+///
+/// * `output` - Raw stdout from the CLI command
+/// * `context` - Description of the command (used in error messages)
+pub fn extract_signature(output: &str, context: &str) -> Result<String> {
+    let signature = output
+        .lines()
+        .filter_map(|l| l.trim().strip_prefix("Signature:"))
+        .map(str::trim)
+        .find(|s| {
+            bs58::decode(s)
+                .into_vec()
+                .is_ok_and(|bytes| bytes.len() == 64)
+        });
+
+    match signature {
+        Some(signature) => Ok(signature.to_string()),
+        None => anyhow::bail!(
+            "Could not extract transaction signature from {} output: {}",
+            context,
+            output
+        ),
+    }
+}
+
+/// Extracts a transaction identifier from aptos, Hardhat, or solana CLI output.
+///
+/// Tries the hex hash formats of `extract_tx_hash` first, then the solana CLI
+/// signature line, and reports which kind was found.
+pub fn extract_tx_identifier(output: &str, context: &str) -> Result<TxIdentifier> {
+    if let Ok(hash) = extract_tx_hash(output, context) {
+        return Ok(TxIdentifier::Hash(hash));
+    }
+    if let Ok(signature) = extract_signature(output, context) {
+        return Ok(TxIdentifier::Signature(signature));
+    }
+    anyhow::bail!(
+        "Could not extract transaction hash or signature from {} output: {}",
+        context,
+        output
+    )
+}
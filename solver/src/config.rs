@@ -5,10 +5,10 @@
 
 use serde::{Deserialize, Serialize};
 use solana_sdk::pubkey::Pubkey;
-use std::collections::HashMap;
+use std::collections::{HashMap, HashSet};
 use std::str::FromStr;
 
-use crate::acceptance::{TokenPair, TokenPairInfo};
+use crate::acceptance::{normalize_requester_addr, TokenPair, TokenPairInfo};
 
 // ============================================================================
 // CONFIGURATION STRUCTURES
@@ -98,6 +98,26 @@ pub struct ServiceConfig {
     /// Solver acceptance API port
     #[serde(default = "default_acceptance_api_port")]
     pub acceptance_api_port: u16,
+    /// Minimum time (seconds) that must remain before an intent's expiry for the solver
+    /// to start fulfilling it. Intents closer to expiry are skipped because the
+    /// fulfillment proof would likely reach the escrow after it expired. 0 disables the gate.
+    #[serde(default)]
+    pub min_expiry_remaining_secs: u64,
+    /// Maximum number of intents the solver has committed to (signed or created, not yet
+    /// settled) at once. New drafts are deferred while the cap is reached.
+    #[serde(default = "default_max_inflight_intents")]
+    pub max_inflight_intents: usize,
+    /// Consecutive failed poll cycles after which a loop's circuit breaker opens and
+    /// pauses polling. 0 disables the breaker.
+    #[serde(default = "default_circuit_breaker_failure_threshold")]
+    pub circuit_breaker_failure_threshold: u32,
+    /// Seconds an open circuit breaker pauses polling before probing recovery
+    #[serde(default = "default_circuit_breaker_cooldown_secs")]
+    pub circuit_breaker_cooldown_secs: u64,
+    /// Re-read the hub right before fulfilling to confirm the intent is still open
+    /// (not cancelled or fulfilled by another solver). Costs one extra RPC per fulfillment.
+    #[serde(default)]
+    pub verify_intent_open_before_fulfill: bool,
 }
 
 /// Configuration for a blockchain connection.
@@ -116,6 +136,12 @@ pub struct ChainConfig {
     /// E2E testing mode: if true, use aptos CLI with profiles; if false, use movement CLI with private keys
     #[serde(default)]
     pub e2e_mode: bool,
+    /// Maximum number of requester accounts queried concurrently when polling intent events
+    #[serde(default = "default_event_query_concurrency")]
+    pub event_query_concurrency: usize,
+    /// Extra HTTP headers sent with every RPC request (e.g. `x-api-key` for hosted providers)
+    #[serde(default)]
+    pub rpc_headers: HashMap<String, String>,
 }
 
 /// Configuration for a connected Move VM chain.
@@ -134,6 +160,15 @@ pub struct MvmChainConfig {
     /// E2E testing mode: if true, use aptos CLI with profiles; if false, use movement CLI with private keys
     #[serde(default)]
     pub e2e_mode: bool,
+    /// Extra HTTP headers sent with every RPC request (e.g. `x-api-key` for hosted providers)
+    #[serde(default)]
+    pub rpc_headers: HashMap<String, String>,
+    /// Maximum fee in octas for a single fulfillment; fulfillment aborts if the estimate exceeds it (unset = no cap)
+    #[serde(default)]
+    pub max_fee_per_fulfillment: Option<u64>,
+    /// Gas units assumed per fulfillment when estimating its fee (multiplied by the node's gas price estimate)
+    #[serde(default = "default_mvm_fulfillment_gas_units")]
+    pub fulfillment_gas_units: u64,
 }
 
 /// Configuration for an EVM-compatible chain.
@@ -158,6 +193,19 @@ pub struct EvmChainConfig {
     /// Address of the IntentGmp contract (GMP endpoint for message delivery)
     #[serde(default)]
     pub gmp_endpoint_addr: Option<String>,
+    /// Extra HTTP headers sent with every RPC request (e.g. `x-api-key` for hosted providers)
+    #[serde(default)]
+    pub rpc_headers: HashMap<String, String>,
+    /// Maximum fee in wei for a single fulfillment; fulfillment aborts if the estimate exceeds it (unset = no cap)
+    #[serde(default)]
+    pub max_fee_per_fulfillment: Option<u64>,
+    /// Gas units assumed per fulfillment when estimating its fee (multiplied by eth_gasPrice)
+    #[serde(default = "default_evm_fulfillment_gas_units")]
+    pub fulfillment_gas_units: u64,
+    /// Blocks that must be mined on top of an escrow event before the solver acts on it
+    /// (default 2; 0 = act on events at the chain tip)
+    #[serde(default = "default_evm_confirmations")]
+    pub confirmations: u64,
 }
 
 /// Configuration for a Solana chain (SVM).
@@ -179,12 +227,27 @@ pub struct SvmChainConfig {
     /// Program ID of the outflow validator (optional, for GMP flow)
     #[serde(default)]
     pub outflow_validator_program_id: Option<String>,
+    /// Extra HTTP headers sent with every RPC request (e.g. `x-api-key` for hosted providers)
+    #[serde(default)]
+    pub rpc_headers: HashMap<String, String>,
 }
 
 fn default_network_name() -> String {
     "localhost".to_string()
 }
 
+fn default_mvm_fulfillment_gas_units() -> u64 {
+    10_000
+}
+
+fn default_evm_fulfillment_gas_units() -> u64 {
+    300_000
+}
+
+fn default_evm_confirmations() -> u64 {
+    2
+}
+
 fn default_acceptance_api_host() -> String {
     "127.0.0.1".to_string()
 }
@@ -193,6 +256,22 @@ fn default_acceptance_api_port() -> u16 {
     4444
 }
 
+fn default_event_query_concurrency() -> usize {
+    8
+}
+
+fn default_max_inflight_intents() -> usize {
+    32
+}
+
+fn default_circuit_breaker_failure_threshold() -> u32 {
+    10
+}
+
+fn default_circuit_breaker_cooldown_secs() -> u64 {
+    300
+}
+
 /// Acceptance criteria configuration.
 ///
 /// Defines which token pairs are supported and their exchange rates.
@@ -207,6 +286,64 @@ pub struct AcceptanceConfig {
     /// Required — at least one token pair must be configured.
     #[serde(rename = "tokenpair")]
     pub token_pairs: Vec<TokenPairConfig>,
+    /// If true, every configured pair is also registered in the reverse direction
+    /// with the reciprocal rate. Explicitly configured reverse pairs take precedence.
+    #[serde(default)]
+    pub bidirectional: bool,
+    /// If true, each draft's offered and desired tokens must resolve on their chains
+    /// (fungible asset metadata on MVM, an ERC20 contract on EVM) before it is signed.
+    #[serde(default)]
+    pub verify_token_metadata: bool,
+    /// If non-empty, only drafts from these hub requester addresses are signed.
+    #[serde(default)]
+    pub requester_allowlist: HashSet<String>,
+    /// Drafts from these hub requester addresses are never signed.
+    #[serde(default)]
+    pub requester_denylist: HashSet<String>,
+}
+
+impl AcceptanceConfig {
+    /// Returns the configured token pairs, plus the reverse of each pair when
+    /// `bidirectional` is enabled.
+    ///
+    /// A reverse entry swaps source and target, uses `1 / ratio` as its rate and
+    /// `move_rate / ratio` as its MOVE rate (the offered token of the reverse
+    /// direction is the original target token). Reverse entries are skipped when
+    /// the same direction is already configured explicitly.
+    ///
+    /// # Returns
+    ///
+    /// * `Vec<TokenPairConfig>` - Configured pairs followed by generated reverse pairs
+    pub fn expanded_token_pairs(&self) -> Vec<TokenPairConfig> {
+        let mut pairs = self.token_pairs.clone();
+        if !self.bidirectional {
+            return pairs;
+        }
+
+        for pair in &self.token_pairs {
+            let already_configured = self.token_pairs.iter().any(|p| {
+                p.source_chain_id == pair.target_chain_id
+                    && p.source_token == pair.target_token
+                    && p.target_chain_id == pair.source_chain_id
+                    && p.target_token == pair.source_token
+            });
+            if !already_configured {
+                pairs.push(pair.reversed());
+            }
+        }
+
+        pairs
+    }
+
+    /// Returns the requester allowlist with every address normalized.
+    pub fn normalized_requester_allowlist(&self) -> HashSet<String> {
+        self.requester_allowlist.iter().map(|a| normalize_requester_addr(a)).collect()
+    }
+
+    /// Returns the requester denylist with every address normalized.
+    pub fn normalized_requester_denylist(&self) -> HashSet<String> {
+        self.requester_denylist.iter().map(|a| normalize_requester_addr(a)).collect()
+    }
 }
 
 /// Acceptance token pair configuration (single entry).
@@ -229,6 +366,30 @@ pub struct TokenPairConfig {
     /// How many offered-token smallest units per 1 MOVE smallest unit (Octa).
     /// e.g., for USD tokens (6 decimals) with MOVE (8 decimals) at 1:1 price: 0.01
     pub move_rate: f64,
+    /// Minimum margin in basis points the offered amount must clear over the
+    /// rate-implied amount (e.g., 30 = 0.3% above `desired_amount * ratio`).
+    /// Unset = any intent at or above the pair's rate is profitable.
+    #[serde(default)]
+    pub min_margin_bps: Option<u64>,
+}
+
+impl TokenPairConfig {
+    /// Returns the reverse-direction pair with the reciprocal exchange rate.
+    ///
+    /// The fee is kept as-is. The MOVE rate is re-expressed in the reverse
+    /// direction's offered token (the original target token): `move_rate / ratio`.
+    pub fn reversed(&self) -> TokenPairConfig {
+        TokenPairConfig {
+            source_chain_id: self.target_chain_id,
+            source_token: self.target_token.clone(),
+            target_chain_id: self.source_chain_id,
+            target_token: self.source_token.clone(),
+            ratio: 1.0 / self.ratio,
+            fee_bps: self.fee_bps,
+            move_rate: self.move_rate / self.ratio,
+            min_margin_bps: self.min_margin_bps,
+        }
+    }
 }
 
 /// Solver signing configuration.
@@ -309,8 +470,9 @@ impl SolverConfig {
     /// - At least one connected chain is configured
     /// - Hub and connected chains have different chain IDs
     /// - All connected chains have unique chain IDs
+    /// - The in-flight intent cap is positive
     /// - Token pairs reference known chains and valid token formats
-    /// - Exchange rates are positive
+    /// - Exchange rates are positive (including reciprocal rates of generated reverse pairs)
     ///
     /// # Returns
     ///
@@ -351,8 +513,45 @@ impl SolverConfig {
             }
         }
 
-        // Validate token pairs and exchange rates
-        for pair in &self.acceptance.token_pairs {
+        if self.hub_chain.event_query_concurrency == 0 {
+            return Err(anyhow::anyhow!(
+                "Configuration error: hub_chain.event_query_concurrency must be > 0"
+            ));
+        }
+
+        if self.service.max_inflight_intents == 0 {
+            return Err(anyhow::anyhow!(
+                "Configuration error: service.max_inflight_intents must be > 0"
+            ));
+        }
+
+        if self.service.circuit_breaker_failure_threshold > 0
+            && self.service.circuit_breaker_cooldown_secs == 0
+        {
+            return Err(anyhow::anyhow!(
+                "Configuration error: service.circuit_breaker_cooldown_secs must be > 0 when the circuit breaker is enabled"
+            ));
+        }
+
+        // A requester on both lists would be silently denied; make the conflict explicit
+        let allowlist = self.acceptance.normalized_requester_allowlist();
+        let mut overlap: Vec<_> = self
+            .acceptance
+            .normalized_requester_denylist()
+            .into_iter()
+            .filter(|addr| allowlist.contains(addr))
+            .collect();
+        if !overlap.is_empty() {
+            overlap.sort();
+            return Err(anyhow::anyhow!(
+                "Configuration error: requester(s) {} appear in both acceptance.requester_allowlist and acceptance.requester_denylist",
+                overlap.join(", ")
+            ));
+        }
+
+        // Validate token pairs and exchange rates (including generated reverse pairs)
+        let token_pairs = self.acceptance.expanded_token_pairs();
+        for pair in &token_pairs {
             // Validate chain IDs exist
             let source_chain_type = self.chain_type_for_id(pair.source_chain_id)
                 .ok_or_else(|| anyhow::anyhow!(
@@ -371,10 +570,11 @@ impl SolverConfig {
             validate_token_format(pair.target_token.as_str(), target_chain_type)
                 .map_err(|e| anyhow::anyhow!("Invalid target_token for chain {}: {}", target_chain_type, e))?;
 
-            // Validate exchange rate is positive
-            if pair.ratio <= 0.0 {
+            // Validate exchange rate is positive and finite (a generated reciprocal
+            // rate overflows to infinity when the configured rate is too small)
+            if !(pair.ratio > 0.0 && pair.ratio.is_finite()) {
                 return Err(anyhow::anyhow!(
-                    "Invalid exchange rate {} for token pair {}:{} -> {}:{}: must be positive",
+                    "Invalid exchange rate {} for token pair {}:{} -> {}:{}: must be positive and finite",
                     pair.ratio,
                     pair.source_chain_id,
                     pair.source_token,
@@ -383,10 +583,10 @@ impl SolverConfig {
                 ));
             }
 
-            // Validate move_rate is positive
-            if pair.move_rate <= 0.0 {
+            // Validate move_rate is positive and finite
+            if !(pair.move_rate > 0.0 && pair.move_rate.is_finite()) {
                 return Err(anyhow::anyhow!(
-                    "Invalid move_rate {} for token pair {}:{} -> {}:{}: must be positive",
+                    "Invalid move_rate {} for token pair {}:{} -> {}:{}: must be positive and finite",
                     pair.move_rate,
                     pair.source_chain_id,
                     pair.source_token,
@@ -406,6 +606,20 @@ impl SolverConfig {
                     pair.target_token
                 ));
             }
+
+            // Validate min_margin_bps is within range (0-10000 basis points = 0-100%)
+            if let Some(min_margin_bps) = pair.min_margin_bps {
+                if min_margin_bps > 10000 {
+                    return Err(anyhow::anyhow!(
+                        "Invalid min_margin_bps {} for token pair {}:{} -> {}:{}: must be <= 10000 (100%)",
+                        min_margin_bps,
+                        pair.source_chain_id,
+                        pair.source_token,
+                        pair.target_chain_id,
+                        pair.target_token
+                    ));
+                }
+            }
         }
 
         // Validate liquidity config
@@ -441,7 +655,7 @@ impl SolverConfig {
             }
 
             // Every acceptance target token must have a liquidity threshold
-            for pair in &self.acceptance.token_pairs {
+            for pair in &token_pairs {
                 let has_threshold = liq.thresholds.iter().any(|t|
                     t.chain_id == pair.target_chain_id && t.token == pair.target_token
                 );
@@ -456,7 +670,7 @@ impl SolverConfig {
 
             // Every chain referenced in acceptance pairs must have a gas token threshold
             let mut referenced_chain_ids = std::collections::HashSet::new();
-            for pair in &self.acceptance.token_pairs {
+            for pair in &token_pairs {
                 referenced_chain_ids.insert(pair.source_chain_id);
                 referenced_chain_ids.insert(pair.target_chain_id);
             }
@@ -486,6 +700,8 @@ impl SolverConfig {
     /// Converts token pair configs to TokenPair structs with rate and fee info.
     ///
     /// This is a helper method for the acceptance module and API to use.
+    /// When `acceptance.bidirectional` is set, the reverse of each configured pair
+    /// is included with the reciprocal rate (see `AcceptanceConfig::expanded_token_pairs`).
     ///
     /// # Returns
     ///
@@ -493,7 +709,7 @@ impl SolverConfig {
     pub fn get_token_pairs(&self) -> anyhow::Result<HashMap<TokenPair, TokenPairInfo>> {
         let mut pairs = HashMap::new();
 
-        for pair in &self.acceptance.token_pairs {
+        for pair in &self.acceptance.expanded_token_pairs() {
             let token_pair = TokenPair {
                 offered_chain_id: pair.source_chain_id,
                 offered_token: pair.source_token.clone(),
@@ -510,6 +726,50 @@ impl SolverConfig {
 
         Ok(pairs)
     }
+
+    /// Returns whether accepting an intent on `pair` clears the pair's configured margin.
+    ///
+    /// The rate-implied amount is `desired_amount * ratio` (offered tokens the solver
+    /// needs to break even on the swap). The intent is profitable when
+    /// `offered_amount >= implied * (10000 + min_margin_bps) / 10000`; an intent that
+    /// lands exactly on that threshold counts as profitable. Unsupported pairs
+    /// (including reverse pairs when `bidirectional` is off) are never profitable.
+    ///
+    /// # Arguments
+    ///
+    /// * `offered_amount` - Amount of the offered token in the intent
+    /// * `desired_amount` - Amount of the desired token the solver must deliver
+    /// * `pair` - Token pair the intent trades
+    ///
+    /// # Returns
+    ///
+    /// * `bool` - True if the offered amount meets the rate plus the minimum margin
+    pub fn is_profitable(
+        &self,
+        offered_amount: u64,
+        desired_amount: u64,
+        pair: &TokenPair,
+    ) -> bool {
+        let Some(config) = self
+            .acceptance
+            .expanded_token_pairs()
+            .into_iter()
+            .find(|p| {
+                p.source_chain_id == pair.offered_chain_id
+                    && p.source_token == pair.offered_token
+                    && p.target_chain_id == pair.desired_chain_id
+                    && p.target_token == pair.desired_token
+            })
+        else {
+            return false;
+        };
+
+        let implied_offered = desired_amount as f64 * config.ratio;
+        let margin_bps = config.min_margin_bps.unwrap_or(0) as f64;
+        let required_offered = implied_offered * (10000.0 + margin_bps) / 10000.0;
+
+        offered_amount as f64 >= required_offered
+    }
 }
 
 /// Liquidity monitoring configuration.
@@ -11,7 +11,7 @@ pub mod service;
 pub mod coordinator_client;
 
 // Re-export public types for convenience
-pub use acceptance::{AcceptanceConfig, AcceptanceResult, DraftintentData, TokenPair, TokenPairInfo, calculate_required_fee, convert_base_fee_in_move_to_offered};
+pub use acceptance::{AcceptanceConfig, AcceptanceResult, DraftintentData, TokenPair, TokenPairInfo, calculate_required_fee, convert_base_fee_in_move_to_offered, evaluate_requester_acceptance, normalize_requester_addr};
 pub use chains::{ConnectedEvmClient, ConnectedMvmClient, HubChainClient};
 pub use config::{SolverConfig, SolverSigningConfig};
 pub use crypto::{get_intent_hash, get_private_key_from_profile, sign_intent_hash};
@@ -22,7 +22,7 @@ pub use service::reconciliation::{
     RECONCILE_INTERVAL_SECS,
 };
 pub use service::signing::SigningService;
-pub use service::tracker::{IntentState, IntentTracker, TrackedIntent, MAX_INFLOW_RETRIES, MAX_OUTFLOW_RETRIES};
+pub use service::tracker::{has_min_expiry_remaining, IntentState, IntentTracker, TrackedIntent, MAX_INFLOW_RETRIES, MAX_OUTFLOW_RETRIES};
 pub use coordinator_client::{
     ApiResponse, CoordinatorClient, PendingDraft, SignatureSubmission,
     SignatureSubmissionResponse,
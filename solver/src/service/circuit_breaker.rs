@@ -0,0 +1,128 @@
+//! Circuit breaker for the solver's poll-and-act loops
+//!
+//! Individual RPC calls and intents already have their own retry/backoff, but a
+//! sustained outage (chain or coordinator down for minutes) makes every loop
+//! cycle fail. Without a breaker the loop keeps hammering the endpoint at the
+//! polling interval and logs an error on every cycle.
+//!
+//! The breaker counts consecutive failed cycles:
+//!
+//! - `Closed` — normal polling. After `failure_threshold` consecutive failures
+//!   it opens and logs a single alert-level error.
+//! - `Open` — cycles are skipped until `cooldown_secs` have passed.
+//! - `HalfOpen` — one probe cycle runs. Success closes the breaker and resumes
+//!   normal polling; failure re-opens it for another cooldown.
+
+use tracing::{error, info, warn};
+
+/// Breaker state.
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum CircuitState {
+    /// Normal polling
+    Closed,
+    /// Polling paused until the cooldown expires
+    Open,
+    /// Cooldown expired; the next cycle is a recovery probe
+    HalfOpen,
+}
+
+/// Consecutive-failure circuit breaker for a polling loop.
+#[derive(Debug, Clone)]
+pub struct CircuitBreaker {
+    /// Loop name used in log messages
+    name: String,
+    /// Consecutive failed cycles that open the breaker (0 disables the breaker)
+    failure_threshold: u32,
+    /// How long the breaker stays open before probing (seconds)
+    cooldown_secs: u64,
+    state: CircuitState,
+    consecutive_failures: u32,
+    /// Unix timestamp after which an open breaker half-opens
+    open_until: u64,
+}
+
+impl CircuitBreaker {
+    /// Creates a closed breaker.
+    ///
+    /// # Arguments
+    ///
+    /// * `name` - Loop name used in log messages
+    /// * `failure_threshold` - Consecutive failed cycles that open the breaker (0 disables it)
+    /// * `cooldown_secs` - Seconds the breaker stays open before probing recovery
+    pub fn new(name: &str, failure_threshold: u32, cooldown_secs: u64) -> Self {
+        Self {
+            name: name.to_string(),
+            failure_threshold,
+            cooldown_secs,
+            state: CircuitState::Closed,
+            consecutive_failures: 0,
+            open_until: 0,
+        }
+    }
+
+    /// Current breaker state.
+    pub fn state(&self) -> CircuitState {
+        self.state
+    }
+
+    /// Returns whether the loop should run a cycle at `now`.
+    ///
+    /// An open breaker whose cooldown has expired moves to `HalfOpen` and
+    /// allows a single probe cycle.
+    pub fn allow(&mut self, now: u64) -> bool {
+        if self.state == CircuitState::Open {
+            if now < self.open_until {
+                return false;
+            }
+            info!(
+                "{} circuit breaker half-open: probing recovery after {}s cooldown",
+                self.name, self.cooldown_secs
+            );
+            self.state = CircuitState::HalfOpen;
+        }
+        true
+    }
+
+    /// Records a successful cycle, closing the breaker.
+    pub fn record_success(&mut self) {
+        if self.state == CircuitState::HalfOpen {
+            info!(
+                "{} circuit breaker closed: resuming normal polling",
+                self.name
+            );
+        }
+        self.state = CircuitState::Closed;
+        self.consecutive_failures = 0;
+    }
+
+    /// Records a failed cycle at `now`, opening the breaker once the threshold is reached.
+    pub fn record_failure(&mut self, now: u64) {
+        self.consecutive_failures = self.consecutive_failures.saturating_add(1);
+
+        match self.state {
+            CircuitState::HalfOpen => {
+                warn!(
+                    "{} circuit breaker re-opened: recovery probe failed, pausing for {}s",
+                    self.name, self.cooldown_secs
+                );
+                self.open(now);
+            }
+            CircuitState::Closed
+                if self.failure_threshold > 0
+                    && self.consecutive_failures >= self.failure_threshold =>
+            {
+                error!(
+                    "ALERT: {} circuit breaker opened after {} consecutive failed cycles; pausing polling for {}s",
+                    self.name, self.consecutive_failures, self.cooldown_secs
+                );
+                self.open(now);
+            }
+            _ => {}
+        }
+    }
+
+    fn open(&mut self, now: u64) {
+        self.state = CircuitState::Open;
+        self.open_until = now.saturating_add(self.cooldown_secs);
+    }
+}
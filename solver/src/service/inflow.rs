@@ -10,7 +10,7 @@
 
 use crate::chains::{ConnectedEvmClient, ConnectedMvmClient, ConnectedSvmClient, HubChainClient};
 use crate::config::{ConnectedChainConfig, SolverConfig};
-use chain_clients_common::normalize_intent_id;
+use chain_clients_common::intent_ids_equal;
 use crate::service::liquidity::LiquidityMonitor;
 use crate::service::tracker::{IntentTracker, TrackedIntent};
 use anyhow::{Context, Result};
@@ -186,9 +186,12 @@ impl InflowService {
         for (chain_id, client) in &self.evm_clients {
             match client.get_block_number().await {
                 Ok(current_block) => {
+                    // get_escrow_events only returns events up to current_block - confirmations,
+                    // so anchor the window there.
                     // Alchemy free tier limits eth_getLogs to 10-block range; cap to 9
-                    let from_block = if current_block > 9 {
-                        current_block - 9
+                    let confirmed_block = current_block.saturating_sub(client.confirmations());
+                    let from_block = if confirmed_block > 9 {
+                        confirmed_block - 9
                     } else {
                         0
                     };
@@ -253,10 +256,8 @@ impl InflowService {
         // Match EVM/SVM escrow events to pending intents by intent_id
         if !evm_svm_escrow_events.is_empty() {
             for intent in &pending_intents {
-                let intent_id_normalized = normalize_intent_id(&intent.intent_id);
                 for escrow in evm_svm_escrow_events.iter() {
-                    let escrow_intent_id_normalized = normalize_intent_id(&escrow.intent_id);
-                    if escrow_intent_id_normalized == intent_id_normalized {
+                    if intent_ids_equal(&escrow.intent_id, &intent.intent_id) {
                         info!(
                             "Match found: intent {} matches escrow {}",
                             intent.intent_id, escrow.escrow_id
@@ -328,6 +329,22 @@ impl InflowService {
                             escrow_id, intent.intent_id
                         );
 
+                        // Optionally re-check that the intent was not cancelled or fulfilled meanwhile
+                        match self.tracker.verify_intent_open(&intent).await {
+                            Ok(true) => {}
+                            Ok(false) => {
+                                self.liquidity_monitor.release(&intent.draft_id).await;
+                                continue;
+                            }
+                            Err(e) => {
+                                warn!(
+                                    "Failed to verify inflow intent {} is still open (will retry): {:#}",
+                                    intent.intent_id, e
+                                );
+                                continue;
+                            }
+                        }
+
                         // Fulfill intent on hub chain
                         match self
                             .fulfill_inflow_intent(&intent, intent.draft_data.desired_amount)
@@ -153,7 +153,7 @@ impl LiquidityMonitor {
         }
 
         // Track all tokens the solver might spend (desired side of each pair)
-        for pair in &solver_config.acceptance.token_pairs {
+        for pair in &solver_config.acceptance.expanded_token_pairs() {
             let chain_token = ChainToken {
                 chain_id: pair.target_chain_id,
                 token: pair.target_token.clone(),
@@ -3,14 +3,17 @@
 //! This module contains service implementations for the solver,
 //! including the signing service loop, intent tracking, and fulfillment services.
 
+pub mod circuit_breaker;
 pub mod inflow;
 pub mod liquidity;
 pub mod outflow;
 pub mod reconciliation;
 pub mod signing;
+pub mod token_resolver;
 pub mod tracker;
 
 // Re-export for convenience
+pub use circuit_breaker::{CircuitBreaker, CircuitState};
 pub use inflow::InflowService;
 pub use liquidity::LiquidityMonitor;
 pub use outflow::OutflowService;
@@ -19,5 +22,6 @@ pub use reconciliation::{
     RECONCILE_INTERVAL_SECS,
 };
 pub use signing::{parse_draft_data, SigningService};
-pub use tracker::{IntentState, IntentTracker, TrackedIntent};
+pub use token_resolver::TokenResolver;
+pub use tracker::{has_min_expiry_remaining, IntentState, IntentTracker, TrackedIntent};
 
@@ -161,6 +161,22 @@ impl OutflowService {
                 }
             };
 
+            // Optionally re-check that the intent was not cancelled or fulfilled meanwhile
+            match self.tracker.verify_intent_open(&intent).await {
+                Ok(true) => {}
+                Ok(false) => {
+                    self.liquidity_monitor.release(&intent.draft_id).await;
+                    continue;
+                }
+                Err(e) => {
+                    warn!(
+                        "Failed to verify outflow intent {} is still open (will retry): {:#}",
+                        intent.intent_id, e
+                    );
+                    continue;
+                }
+            }
+
             // Execute fulfillment on connected chain via GMP
             let tx_hash = match self.execute_connected_transfer(&intent, &requester_addr_connected_chain).await {
                 Ok(hash) => hash,
@@ -250,7 +266,8 @@ impl OutflowService {
             tokio::time::sleep(poll_interval).await;
         }
 
-        // Requirements are available, execute fulfillment
+        // Requirements are available; abort if gas is above budget, then fulfill
+        client.check_fulfillment_fee_budget(&intent.intent_id).await?;
         client.fulfill_outflow_via_gmp(&intent.intent_id, desired_token)
     }
 
@@ -360,7 +377,8 @@ impl OutflowService {
             tokio::time::sleep(poll_interval).await;
         }
 
-        // Requirements are available, execute fulfillment
+        // Requirements are available; abort if gas is above budget, then fulfill
+        client.check_fulfillment_fee_budget(&intent.intent_id).await?;
         client.fulfill_outflow_via_gmp(&intent.intent_id, desired_token)
     }
 
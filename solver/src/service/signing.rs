@@ -3,10 +3,12 @@
 //! Main service loop that polls the coordinator for pending drafts,
 //! evaluates acceptance, and signs/submits accepted drafts.
 
-use crate::acceptance::{evaluate_draft_acceptance, AcceptanceConfig, AcceptanceResult, DraftintentData};
+use crate::acceptance::{evaluate_draft_acceptance, evaluate_requester_acceptance, AcceptanceConfig, AcceptanceResult, DraftintentData};
 use crate::config::SolverConfig;
 use crate::crypto::{get_intent_hash, get_private_key_from_profile, sign_intent_hash};
+use crate::service::circuit_breaker::CircuitBreaker;
 use crate::service::liquidity::{ChainToken, LiquidityMonitor};
+use crate::service::token_resolver::TokenResolver;
 use crate::service::tracker::IntentTracker;
 use crate::coordinator_client::{PendingDraft, CoordinatorClient};
 use anyhow::{Context, Result};
@@ -29,6 +31,8 @@ pub struct SigningService {
     processed_drafts: Arc<RwLock<HashSet<String>>>,
     /// Liquidity monitor for budget checks before signing
     liquidity_monitor: Arc<LiquidityMonitor>,
+    /// Token resolver (set when `acceptance.verify_token_metadata` is enabled)
+    token_resolver: Option<TokenResolver>,
 }
 
 impl SigningService {
@@ -52,6 +56,14 @@ impl SigningService {
         let acceptance_config = AcceptanceConfig {
             base_fee_in_move: config.acceptance.base_fee_in_move,
             token_pairs,
+            requester_allowlist: config.acceptance.normalized_requester_allowlist(),
+            requester_denylist: config.acceptance.normalized_requester_denylist(),
+        };
+
+        let token_resolver = if config.acceptance.verify_token_metadata {
+            Some(TokenResolver::new(&config)?)
+        } else {
+            None
         };
 
         Ok(Self {
@@ -60,6 +72,7 @@ impl SigningService {
             tracker,
             processed_drafts: Arc::new(RwLock::new(HashSet::new())),
             liquidity_monitor,
+            token_resolver,
         })
     }
 
@@ -67,23 +80,39 @@ impl SigningService {
     ///
     /// This function polls the coordinator for pending drafts at the configured interval,
     /// evaluates each draft for acceptance, and signs/submits accepted drafts.
+    /// Consecutive failed cycles trip a [`CircuitBreaker`] that pauses polling
+    /// during sustained outages.
     ///
     /// Runs indefinitely until the service is stopped.
     pub async fn run(&self) -> Result<()> {
         let polling_interval = Duration::from_millis(self.config.service.polling_interval_ms);
+        let mut breaker = CircuitBreaker::new(
+            "Signing loop",
+            self.config.service.circuit_breaker_failure_threshold,
+            self.config.service.circuit_breaker_cooldown_secs,
+        );
 
         info!("Starting signing service loop (polling interval: {:?})", polling_interval);
 
         loop {
-            // Poll for pending drafts
-            match self.poll_and_process_drafts().await {
-                Ok(processed) => {
-                    if processed > 0 {
-                        info!("Processed {} draft(s)", processed);
+            let current_time = std::time::SystemTime::now()
+                .duration_since(std::time::UNIX_EPOCH)
+                .unwrap()
+                .as_secs();
+
+            // Poll for pending drafts unless the breaker is open
+            if breaker.allow(current_time) {
+                match self.poll_and_process_drafts().await {
+                    Ok(processed) => {
+                        breaker.record_success();
+                        if processed > 0 {
+                            info!("Processed {} draft(s)", processed);
+                        }
+                    }
+                    Err(e) => {
+                        error!("Error in signing loop: {}", e);
+                        breaker.record_failure(current_time);
                     }
-                }
-                Err(e) => {
-                    error!("Error in signing loop: {}", e);
                 }
             }
 
@@ -128,6 +157,16 @@ impl SigningService {
                 continue;
             }
 
+            // Defer remaining drafts while at the in-flight cap; they are left
+            // unprocessed so the next poll picks them up once a slot frees
+            if !self.tracker.has_inflight_capacity().await {
+                info!(
+                    "In-flight intent cap ({}) reached, deferring draft {}",
+                    self.config.service.max_inflight_intents, draft.draft_id
+                );
+                break;
+            }
+
             match self.process_draft(&draft).await {
                 Ok(true) => {
                     processed += 1;
@@ -180,9 +219,34 @@ impl SigningService {
         // Parse draft data
         let draft_data = self.parse_draft_data(&draft.draft_data)?;
 
+        // Requester must be served by this solver (allowlist/denylist)
+        if let AcceptanceResult::Reject(reason) =
+            evaluate_requester_acceptance(&draft.requester_addr, &self.acceptance_config)
+        {
+            warn!("Draft {} rejected: {}", draft.draft_id, reason);
+            return Ok(false);
+        }
+
         // Evaluate acceptance
         match evaluate_draft_acceptance(&draft_data, &self.acceptance_config) {
             AcceptanceResult::Accept => {
+                // Tokens must exist on the chains they are claimed for (when enabled)
+                if let Some(resolver) = &self.token_resolver {
+                    let sides = [
+                        (draft_data.offered_chain_id, &draft_data.offered_token),
+                        (draft_data.desired_chain_id, &draft_data.desired_token),
+                    ];
+                    for (chain_id, token) in sides {
+                        if !resolver.token_resolves(chain_id, token).await? {
+                            warn!(
+                                "Draft {} rejected: token {} does not resolve on chain {}",
+                                draft.draft_id, token, chain_id
+                            );
+                            return Ok(false);
+                        }
+                    }
+                }
+
                 // A) Target chain: desired_amount must leave at least threshold in account
                 let target = ChainToken {
                     chain_id: draft_data.desired_chain_id,
@@ -0,0 +1,114 @@
+//! Token Metadata Resolver
+//!
+//! Optional pre-signing check that a draft's tokens exist on the chains they are
+//! claimed for: fungible asset metadata on MVM chains (hub and connected) and an
+//! ERC20 contract on EVM chains. Enabled by `acceptance.verify_token_metadata`.
+//!
+//! Without it the solver trusts token addresses as opaque strings, so a draft could
+//! pair a known token address with the wrong chain.
+
+use anyhow::{Context, Result};
+use chain_clients_evm::{normalize_evm_address, EvmClient};
+use chain_clients_mvm::MvmClient;
+use std::collections::{HashMap, HashSet};
+
+use crate::config::{gas_token_for_chain_type, ConnectedChainConfig, SolverConfig};
+
+/// Resolves token addresses against their chain before a draft is signed.
+pub struct TokenResolver {
+    /// Hub chain ID (hub is always MVM)
+    hub_chain_id: u64,
+    /// Hub chain client
+    hub_client: MvmClient,
+    /// Connected MVM chain clients by chain ID
+    mvm_clients: HashMap<u64, MvmClient>,
+    /// Connected EVM chain clients by chain ID
+    evm_clients: HashMap<u64, EvmClient>,
+    /// Connected SVM chain IDs (mints are not resolved)
+    svm_chain_ids: HashSet<u64>,
+}
+
+impl TokenResolver {
+    /// Creates a resolver with a client for the hub and every connected MVM/EVM chain.
+    ///
+    /// # Arguments
+    ///
+    /// * `config` - Solver configuration
+    ///
+    /// # Returns
+    ///
+    /// * `Ok(TokenResolver)` - Resolver ready to query all configured chains
+    /// * `Err(anyhow::Error)` - Failed to create a chain client
+    pub fn new(config: &SolverConfig) -> Result<Self> {
+        let hub_client =
+            MvmClient::new_with_headers(&config.hub_chain.rpc_url, &config.hub_chain.rpc_headers)
+                .context("Failed to create hub MVM client for token resolver")?;
+
+        let mut mvm_clients = HashMap::new();
+        let mut evm_clients = HashMap::new();
+        let mut svm_chain_ids = HashSet::new();
+        for chain in &config.connected_chain {
+            match chain {
+                ConnectedChainConfig::Mvm(cfg) => {
+                    let client = MvmClient::new_with_headers(&cfg.rpc_url, &cfg.rpc_headers)
+                        .context("Failed to create MVM client for token resolver")?;
+                    mvm_clients.insert(cfg.chain_id, client);
+                }
+                ConnectedChainConfig::Evm(cfg) => {
+                    let client = EvmClient::new_with_headers(
+                        &cfg.rpc_url,
+                        &cfg.escrow_contract_addr,
+                        &cfg.rpc_headers,
+                    )
+                    .context("Failed to create EVM client for token resolver")?;
+                    evm_clients.insert(cfg.chain_id, client);
+                }
+                ConnectedChainConfig::Svm(cfg) => {
+                    svm_chain_ids.insert(cfg.chain_id);
+                }
+            }
+        }
+
+        Ok(Self {
+            hub_chain_id: config.hub_chain.chain_id,
+            hub_client,
+            mvm_clients,
+            evm_clients,
+            svm_chain_ids,
+        })
+    }
+
+    /// Checks that a token exists on the given chain.
+    ///
+    /// MVM tokens must hold `0x1::fungible_asset::Metadata`; EVM tokens must answer
+    /// `decimals()`. The native EVM gas token and SVM mints are accepted unchecked.
+    ///
+    /// # Arguments
+    ///
+    /// * `chain_id` - Chain the token is claimed to live on
+    /// * `token` - Token metadata address (MVM) or contract address (EVM)
+    ///
+    /// # Returns
+    ///
+    /// * `Ok(true)` - Token resolves on the chain
+    /// * `Ok(false)` - Token does not exist on the chain
+    /// * `Err(anyhow::Error)` - Unknown chain or RPC failure
+    pub async fn token_resolves(&self, chain_id: u64, token: &str) -> Result<bool> {
+        if chain_id == self.hub_chain_id {
+            return self.hub_client.is_fungible_asset_metadata(token).await;
+        }
+        if let Some(client) = self.mvm_clients.get(&chain_id) {
+            return client.is_fungible_asset_metadata(token).await;
+        }
+        if let Some(client) = self.evm_clients.get(&chain_id) {
+            if normalize_evm_address(token)? == gas_token_for_chain_type("evm")? {
+                return Ok(true);
+            }
+            return client.is_erc20_token(token).await;
+        }
+        if self.svm_chain_ids.contains(&chain_id) {
+            return Ok(true);
+        }
+        anyhow::bail!("No chain configured for chain_id {}", chain_id)
+    }
+}
@@ -12,12 +12,13 @@
 //! The tracker distinguishes between inflow and outflow intents for fulfillment routing.
 
 use anyhow::{Context, Result};
-use chain_clients_common::normalize_intent_id_to_64_chars;
+use chain_clients_common::intent_ids_equal;
 use std::collections::HashMap;
 use std::sync::Arc;
 use tokio::sync::RwLock;
 
 use crate::acceptance::DraftintentData;
+use crate::chains::hub::DEFAULT_EVENT_QUERY_MAX_PAGES;
 use crate::chains::HubChainClient;
 use crate::config::{ChainConfig, SolverConfig};
 
@@ -30,6 +31,28 @@ pub const MAX_INFLOW_RETRIES: u32 = 3;
 /// Initial backoff duration in seconds after first failure (doubles each retry)
 const INITIAL_BACKOFF_SECS: u64 = 5;
 
+/// Returns whether an intent has enough time left before expiry to be fulfilled.
+///
+/// An intent qualifies when it has not expired and `expiry_time - current_time`
+/// is at least `min_expiry_remaining_secs`. With a minimum of 0 this reduces to
+/// a plain "not yet expired" check.
+///
+/// # Arguments
+///
+/// * `expiry_time` - Intent expiry timestamp (Unix seconds)
+/// * `current_time` - Current timestamp (Unix seconds)
+/// * `min_expiry_remaining_secs` - Minimum seconds that must remain before expiry
+pub fn has_min_expiry_remaining(
+    expiry_time: u64,
+    current_time: u64,
+    min_expiry_remaining_secs: u64,
+) -> bool {
+    match expiry_time.checked_sub(current_time) {
+        Some(remaining) => remaining >= min_expiry_remaining_secs,
+        None => false,
+    }
+}
+
 /// State of a tracked intent
 #[derive(Debug, Clone, PartialEq, Eq)]
 pub enum IntentState {
@@ -86,10 +109,18 @@ pub struct IntentTracker {
     completed_intent_ids: Arc<RwLock<std::collections::HashSet<String>>>,
     /// Set of transaction hashes that have been processed (to avoid re-parsing)
     processed_transactions: Arc<RwLock<std::collections::HashSet<String>>>,
+    /// Per requester address, the transaction sequence number to resume event polling from
+    resume_versions: Arc<RwLock<HashMap<String, u64>>>,
     /// Hub chain client for querying intent events
     hub_client: HubChainClient,
     /// Hub chain configuration
     hub_config: ChainConfig,
+    /// Minimum seconds before expiry required to start fulfilling an intent
+    min_expiry_remaining_secs: u64,
+    /// Maximum number of in-flight (Signed or Created) intents
+    max_inflight_intents: usize,
+    /// Re-check on the hub that an intent is still open before fulfilling it
+    verify_intent_open_before_fulfill: bool,
 }
 
 impl IntentTracker {
@@ -111,8 +142,12 @@ impl IntentTracker {
             requester_addresses: Arc::new(RwLock::new(std::collections::HashSet::new())),
             completed_intent_ids: Arc::new(RwLock::new(std::collections::HashSet::new())),
             processed_transactions: Arc::new(RwLock::new(std::collections::HashSet::new())),
+            resume_versions: Arc::new(RwLock::new(HashMap::new())),
             hub_client,
             hub_config: config.hub_chain.clone(),
+            min_expiry_remaining_secs: config.service.min_expiry_remaining_secs,
+            max_inflight_intents: config.service.max_inflight_intents,
+            verify_intent_open_before_fulfill: config.service.verify_intent_open_before_fulfill,
         })
     }
 
@@ -216,12 +251,25 @@ impl IntentTracker {
             processed.clone()
         };
 
-        // Query hub chain for intent creation events
-        let (events, transaction_hashes) = self
+        let resume_versions = self.resume_versions.read().await.clone();
+
+        // Query hub chain for intent creation events, continuing where the last poll stopped
+        let (events, transaction_hashes, next_resume_versions) = self
             .hub_client
-            .get_intent_events(&requester_addresses, None, Some(&processed_tx_set))
+            .get_intent_events(
+                &requester_addresses,
+                &resume_versions,
+                Some(&processed_tx_set),
+                DEFAULT_EVENT_QUERY_MAX_PAGES,
+            )
             .await
             .context("Failed to query hub chain for intent events")?;
+
+        // Accounts with no new transactions are omitted, so keep their previous resume point
+        self.resume_versions
+            .write()
+            .await
+            .extend(next_resume_versions);
         
         // Mark these transactions as processed
         if !transaction_hashes.is_empty() {
@@ -260,12 +308,8 @@ impl IntentTracker {
                     continue; // Already processed
                 }
 
-                // Normalize intent_id for comparison (strip 0x prefix, pad to 64 chars)
-                let event_intent_id = normalize_intent_id_to_64_chars(&event.intent_id);
-                let tracked_intent_id = normalize_intent_id_to_64_chars(&tracked.intent_id);
-
-                // Match by intent_id (if available) or by draft data
-                let matches = if event_intent_id == tracked_intent_id {
+                // Match by intent_id (canonical form, if available) or by draft data
+                let matches = if intent_ids_equal(&event.intent_id, &tracked.intent_id) {
                     true
                 } else {
                     // Fallback: match by comparing amounts and chain IDs
@@ -277,10 +321,8 @@ impl IntentTracker {
                 };
 
                 if matches {
-                    // Extract requester_addr_connected_chain from MoveOption wrapper
-                    let connected_chain_addr = event.requester_addr_connected_chain
-                        .clone()
-                        .and_then(|opt| opt.into_option());
+                    // Move Option already decoded by deserialize_move_option_string
+                    let connected_chain_addr = event.requester_addr_connected_chain.clone();
                     
                     tracing::info!("Intent {} matched on-chain event {}. Transitioning to Created state.", 
                         _draft_id, event.intent_id);
@@ -311,6 +353,9 @@ impl IntentTracker {
     /// 2. Have been created on-chain by the requester (Created state)
     /// 3. Are now ready for the solver to fulfill
     ///
+    /// Intents with less than `service.min_expiry_remaining_secs` left before expiry
+    /// are skipped, since a fulfillment started that late is unlikely to settle.
+    ///
     /// Optionally filtered by inflow/outflow type.
     ///
     /// # Arguments
@@ -336,8 +381,16 @@ impl IntentTracker {
                     return false;
                 }
 
-                // Skip expired intents
-                if intent.expiry_time < current_time {
+                // Skip expired intents and intents too close to expiry to settle
+                if !has_min_expiry_remaining(intent.expiry_time, current_time, self.min_expiry_remaining_secs) {
+                    if intent.expiry_time >= current_time {
+                        tracing::debug!(
+                            "Skipping intent {}: only {}s left before expiry (min_expiry_remaining_secs={})",
+                            intent.intent_id,
+                            intent.expiry_time - current_time,
+                            self.min_expiry_remaining_secs
+                        );
+                    }
                     return false;
                 }
 
@@ -407,7 +460,7 @@ impl IntentTracker {
     pub async fn mark_outflow_attempted(&self, intent_id: &str) -> Result<()> {
         let mut intents = self.intents.write().await;
         for (_draft_id, intent) in intents.iter_mut() {
-            if intent.intent_id == intent_id {
+            if intent_ids_equal(&intent.intent_id, intent_id) {
                 intent.outflow_attempted = true;
                 return Ok(());
             }
@@ -431,7 +484,7 @@ impl IntentTracker {
     pub async fn record_outflow_failure(&self, intent_id: &str, error: &str) -> Result<IntentState> {
         let mut intents = self.intents.write().await;
         for (_draft_id, intent) in intents.iter_mut() {
-            if intent.intent_id == intent_id {
+            if intent_ids_equal(&intent.intent_id, intent_id) {
                 intent.outflow_attempt_count += 1;
 
                 if intent.outflow_attempt_count >= MAX_OUTFLOW_RETRIES {
@@ -479,7 +532,7 @@ impl IntentTracker {
     pub async fn record_inflow_failure(&self, intent_id: &str, error: &str) -> Result<IntentState> {
         let mut intents = self.intents.write().await;
         for (_draft_id, intent) in intents.iter_mut() {
-            if intent.intent_id == intent_id {
+            if intent_ids_equal(&intent.intent_id, intent_id) {
                 intent.inflow_attempt_count += 1;
 
                 if intent.inflow_attempt_count >= MAX_INFLOW_RETRIES {
@@ -511,6 +564,45 @@ impl IntentTracker {
         anyhow::bail!("Intent not found: {}", intent_id)
     }
 
+    /// Confirms on the hub that an intent is still open right before fulfilling it.
+    ///
+    /// Between observing an intent and fulfilling it, the requester may have cancelled
+    /// it or another solver may have fulfilled it. Both remove the intent from the hub's
+    /// intent registry. An intent that is no longer registered is moved to `Failed` so
+    /// it is not retried. Does nothing (no RPC) unless
+    /// `service.verify_intent_open_before_fulfill` is enabled.
+    ///
+    /// # Arguments
+    ///
+    /// * `intent` - Tracked intent about to be fulfilled
+    ///
+    /// # Returns
+    ///
+    /// * `Ok(true)` - Check disabled, or the intent is still open
+    /// * `Ok(false)` - The intent is no longer open; skip fulfillment
+    /// * `Err(anyhow::Error)` - Intent address not set or the hub query failed
+    pub async fn verify_intent_open(&self, intent: &TrackedIntent) -> Result<bool> {
+        if !self.verify_intent_open_before_fulfill {
+            return Ok(true);
+        }
+
+        let intent_addr = intent
+            .intent_addr
+            .as_ref()
+            .context("Intent address not set (intent not yet created on-chain)")?;
+        if self.hub_client.is_intent_registered(intent_addr).await? {
+            return Ok(true);
+        }
+
+        tracing::info!(
+            "Skipping fulfillment of intent {}: no longer open on hub (cancelled or fulfilled by another solver)",
+            intent.intent_id
+        );
+        self.heal_state_by_intent_id(&intent.intent_id, IntentState::Failed)
+            .await?;
+        Ok(false)
+    }
+
     /// Gets a tracked intent by draft ID
     ///
     /// # Note
@@ -529,6 +621,24 @@ impl IntentTracker {
         intents.values().cloned().collect()
     }
 
+    /// Returns the number of in-flight intents (Signed or Created).
+    ///
+    /// An intent stops counting once it reaches a terminal state (Fulfilled,
+    /// Failed, Expired) or is dropped as an expired, never-created draft.
+    pub async fn inflight_count(&self) -> usize {
+        let intents = self.intents.read().await;
+        intents
+            .values()
+            .filter(|i| matches!(i.state, IntentState::Signed | IntentState::Created))
+            .count()
+    }
+
+    /// Returns whether the solver may commit to another intent without
+    /// exceeding `max_inflight_intents`.
+    pub async fn has_inflight_capacity(&self) -> bool {
+        self.inflight_count().await < self.max_inflight_intents
+    }
+
     /// Overwrites the state of the tracked intent with the given on-chain intent_id.
     ///
     /// Used by the reconciliation sweep to correct drift between the in-memory
@@ -543,7 +653,7 @@ impl IntentTracker {
     ) -> Result<()> {
         let mut intents = self.intents.write().await;
         for (_draft_id, intent) in intents.iter_mut() {
-            if intent.intent_id == intent_id {
+            if intent_ids_equal(&intent.intent_id, intent_id) {
                 intent.state = new_state;
                 return Ok(());
             }
@@ -3,8 +3,8 @@
 //! These tests verify that the solver correctly evaluates draft intents
 //! based on token types and amounts.
 
-use solver::acceptance::{AcceptanceConfig, AcceptanceResult, DraftintentData, TokenPairInfo, calculate_required_fee, convert_base_fee_in_move_to_offered, evaluate_draft_acceptance};
-use std::collections::HashMap;
+use solver::acceptance::{AcceptanceConfig, AcceptanceResult, DraftintentData, TokenPairInfo, calculate_required_fee, convert_base_fee_in_move_to_offered, evaluate_draft_acceptance, evaluate_requester_acceptance};
+use std::collections::{HashMap, HashSet};
 
 #[path = "helpers.rs"]
 mod test_helpers;
@@ -41,6 +41,8 @@ fn test_config() -> AcceptanceConfig {
     AcceptanceConfig {
         base_fee_in_move: 100,
         token_pairs,
+        requester_allowlist: HashSet::new(),
+        requester_denylist: HashSet::new(),
     }
 }
 
@@ -130,7 +132,12 @@ fn test_config_with_fees() -> AcceptanceConfig {
         create_default_token_pair(),
         TokenPairInfo { rate: 1.0, fee_bps: 50, move_rate: 1.0 },
     );
-    AcceptanceConfig { base_fee_in_move: 1000, token_pairs }
+    AcceptanceConfig {
+        base_fee_in_move: 1000,
+        token_pairs,
+        requester_allowlist: HashSet::new(),
+        requester_denylist: HashSet::new(),
+    }
 }
 
 // 5. Test: convert_base_fee_in_move_to_offered correctly converts MOVE base_fee_in_move to offered token
@@ -234,6 +241,8 @@ fn test_fee_zero_accepted_when_no_fees() {
     let config = AcceptanceConfig {
         base_fee_in_move: 0,
         token_pairs,
+        requester_allowlist: HashSet::new(),
+        requester_denylist: HashSet::new(),
     };
     let draft = DraftintentData {
         fee_in_offered_token: 0,
@@ -242,4 +251,44 @@ fn test_fee_zero_accepted_when_no_fees() {
     assert!(matches!(evaluate_draft_acceptance(&draft, &config), AcceptanceResult::Accept));
 }
 
+// ============================================================================
+// REQUESTER ALLOWLIST / DENYLIST TESTS
+// ============================================================================
+
+// 12. Test: requester allowlist admits only listed requesters
+// Verifies that with a non-empty requester_allowlist, evaluate_requester_acceptance accepts a listed requester (including a zero-padded spelling of it) and rejects any other.
+// Why: Solvers restricted to KYC'd counterparties must never sign for an unlisted requester, whatever address form the draft carries.
+#[test]
+fn test_requester_allowlist_only() {
+    let config = AcceptanceConfig {
+        requester_allowlist: HashSet::from(["0xabc".to_string()]),
+        ..test_config()
+    };
+
+    assert!(matches!(evaluate_requester_acceptance("0xabc", &config), AcceptanceResult::Accept));
+    assert!(matches!(
+        evaluate_requester_acceptance("0x0000000000000000000000000000000000000000000000000000000000000ABC", &config),
+        AcceptanceResult::Accept
+    ));
+    match evaluate_requester_acceptance("0xdef", &config) {
+        AcceptanceResult::Reject(reason) => assert!(reason.contains("not on the allowlist")),
+        AcceptanceResult::Accept => panic!("Unlisted requester must be rejected"),
+    }
+}
+
+// 13. Test: requester denylist rejects listed requesters
+// Verifies that with only a requester_denylist configured, evaluate_requester_acceptance rejects a listed requester and accepts everyone else.
+// Why: Blocking known-bad requesters must not turn the solver into an allowlist-only solver.
+#[test]
+fn test_requester_denylist_only() {
+    let config = AcceptanceConfig {
+        requester_denylist: HashSet::from(["0xbad".to_string()]),
+        ..test_config()
+    };
 
+    match evaluate_requester_acceptance("0x0BAD", &config) {
+        AcceptanceResult::Reject(reason) => assert!(reason.contains("denylist")),
+        AcceptanceResult::Accept => panic!("Denylisted requester must be rejected"),
+    }
+    assert!(matches!(evaluate_requester_acceptance("0xabc", &config), AcceptanceResult::Accept));
+}
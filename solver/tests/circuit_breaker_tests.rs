@@ -0,0 +1,84 @@
+//! Unit tests for the poll-loop circuit breaker.
+//!
+//! The breaker takes the current time as an argument, so these tests drive it
+//! with fixed timestamps instead of sleeping.
+
+use solver::service::{CircuitBreaker, CircuitState};
+
+const THRESHOLD: u32 = 3;
+const COOLDOWN_SECS: u64 = 60;
+const T0: u64 = 1_000;
+
+// 1. Test: Repeated failures open the breaker
+// Verifies that the breaker stays closed below the failure threshold, opens on the threshold-th consecutive failure, and then blocks cycles until the cooldown expires.
+// Why: A sustained outage must stop the loop from hammering the endpoint every polling interval.
+#[test]
+fn test_repeated_failures_open_breaker() {
+    let mut breaker = CircuitBreaker::new("test", THRESHOLD, COOLDOWN_SECS);
+
+    for _ in 0..THRESHOLD - 1 {
+        assert!(breaker.allow(T0));
+        breaker.record_failure(T0);
+        assert_eq!(breaker.state(), CircuitState::Closed);
+    }
+    breaker.record_failure(T0);
+
+    assert_eq!(breaker.state(), CircuitState::Open);
+    assert!(!breaker.allow(T0 + COOLDOWN_SECS - 1));
+    assert_eq!(breaker.state(), CircuitState::Open);
+}
+
+// 2. Test: Success after cooldown half-opens then closes the breaker
+// Verifies that an open breaker allows a probe once the cooldown has passed (HalfOpen), and that a successful probe closes it and resets the failure count.
+// Why: Polling must resume automatically once the outage ends, without needing a restart.
+#[test]
+fn test_success_after_cooldown_closes_breaker() {
+    let mut breaker = CircuitBreaker::new("test", THRESHOLD, COOLDOWN_SECS);
+    for _ in 0..THRESHOLD {
+        breaker.record_failure(T0);
+    }
+    assert_eq!(breaker.state(), CircuitState::Open);
+
+    assert!(breaker.allow(T0 + COOLDOWN_SECS));
+    assert_eq!(breaker.state(), CircuitState::HalfOpen);
+
+    breaker.record_success();
+    assert_eq!(breaker.state(), CircuitState::Closed);
+
+    // Failure count was reset: one more failure does not re-open
+    breaker.record_failure(T0 + COOLDOWN_SECS);
+    assert_eq!(breaker.state(), CircuitState::Closed);
+}
+
+// 3. Test: Failed probe re-opens the breaker
+// Verifies that a failure while half-open re-opens the breaker immediately for a fresh cooldown.
+// Why: One failing probe per cooldown is the whole point; the loop must not fall back to polling every interval.
+#[test]
+fn test_failed_probe_reopens_breaker() {
+    let mut breaker = CircuitBreaker::new("test", THRESHOLD, COOLDOWN_SECS);
+    for _ in 0..THRESHOLD {
+        breaker.record_failure(T0);
+    }
+    let probe_time = T0 + COOLDOWN_SECS;
+    assert!(breaker.allow(probe_time));
+
+    breaker.record_failure(probe_time);
+
+    assert_eq!(breaker.state(), CircuitState::Open);
+    assert!(!breaker.allow(probe_time + COOLDOWN_SECS - 1));
+    assert!(breaker.allow(probe_time + COOLDOWN_SECS));
+}
+
+// 4. Test: Threshold 0 disables the breaker
+// Verifies that with failure_threshold = 0 the breaker never opens, however many cycles fail.
+// Why: Operators can opt out and keep the previous poll-every-interval behavior.
+#[test]
+fn test_zero_threshold_disables_breaker() {
+    let mut breaker = CircuitBreaker::new("test", 0, COOLDOWN_SECS);
+    for _ in 0..100 {
+        breaker.record_failure(T0);
+    }
+
+    assert_eq!(breaker.state(), CircuitState::Closed);
+    assert!(breaker.allow(T0));
+}
@@ -10,6 +10,7 @@ use test_helpers::{
 };
 
 use solver::config::{AcceptanceConfig, ConnectedChainConfig, EvmChainConfig, MvmChainConfig, SvmChainConfig, SolverConfig, TokenPairConfig};
+use std::collections::HashMap;
 
 // ============================================================================
 // HELPER FUNCTIONS
@@ -38,7 +39,12 @@ fn create_test_config() -> SolverConfig {
                 ratio: 1.0,
                 fee_bps: 50,
                 move_rate: 1.0,
+                min_margin_bps: None,
             }],
+            bidirectional: false,
+            verify_token_metadata: false,
+            requester_allowlist: Default::default(),
+            requester_denylist: Default::default(),
         },
         liquidity: liq,
         ..create_default_solver_config()
@@ -75,6 +81,10 @@ fn test_config_validation_multiple_connected_chains() {
         network_name: "localhost".to_string(),
         outflow_validator_addr: None,
         gmp_endpoint_addr: None,
+        rpc_headers: HashMap::new(),
+        max_fee_per_fulfillment: None,
+        fulfillment_gas_units: 300_000,
+        confirmations: 2,
     }));
     config.connected_chain.push(ConnectedChainConfig::Svm(SvmChainConfig {
         name: "connected-svm".to_string(),
@@ -84,6 +94,7 @@ fn test_config_validation_multiple_connected_chains() {
         private_key_env: "SOLANA_SOLVER_PRIVATE_KEY".to_string(),
         gmp_endpoint_program_id: None,
         outflow_validator_program_id: None,
+        rpc_headers: HashMap::new(),
     }));
 
     assert!(config.validate().is_ok());
@@ -130,6 +141,7 @@ fn test_config_validation_unknown_chain_id_in_token_pair() {
         ratio: 1.0,
         fee_bps: 50,
         move_rate: 1.0,
+        min_margin_bps: None,
     }];
 
     let result = config.validate();
@@ -151,6 +163,7 @@ fn test_config_validation_rejects_svm_invalid_hex_length() {
         private_key_env: "SOLANA_SOLVER_PRIVATE_KEY".to_string(),
         gmp_endpoint_program_id: None,
         outflow_validator_program_id: None,
+        rpc_headers: HashMap::new(),
     }));
     config.acceptance.token_pairs = vec![TokenPairConfig {
         source_chain_id: 1,
@@ -160,6 +173,7 @@ fn test_config_validation_rejects_svm_invalid_hex_length() {
         ratio: 1.0,
         fee_bps: 50,
         move_rate: 1.0,
+        min_margin_bps: None,
     }];
 
     let result = config.validate();
@@ -181,6 +195,7 @@ fn test_config_validation_rejects_invalid_svm_base58_token() {
         private_key_env: "SOLANA_SOLVER_PRIVATE_KEY".to_string(),
         gmp_endpoint_program_id: None,
         outflow_validator_program_id: None,
+        rpc_headers: HashMap::new(),
     }));
     config.acceptance.token_pairs = vec![TokenPairConfig {
         source_chain_id: 1,
@@ -190,6 +205,7 @@ fn test_config_validation_rejects_invalid_svm_base58_token() {
         ratio: 1.0,
         fee_bps: 50,
         move_rate: 1.0,
+        min_margin_bps: None,
     }];
 
     let result = config.validate();
@@ -211,6 +227,7 @@ fn test_config_validation_fee_bps_too_high() {
         ratio: 1.0,
         fee_bps: 10001,
         move_rate: 1.0,
+        min_margin_bps: None,
     }];
 
     let result = config.validate();
@@ -232,6 +249,7 @@ fn test_config_validation_fee_bps_max_accepted() {
         ratio: 1.0,
         fee_bps: 10000,
         move_rate: 1.0,
+        min_margin_bps: None,
     }];
 
     assert!(config.validate().is_ok());
@@ -252,6 +270,7 @@ fn test_get_token_pairs_with_fees() {
         ratio: 1.0,
         fee_bps: 100,
         move_rate: 1.0,
+        min_margin_bps: None,
     }];
 
     assert_eq!(config.acceptance.base_fee_in_move, 500);
@@ -274,6 +293,7 @@ fn test_config_validation_negative_exchange_rate() {
         ratio: -1.0,
         fee_bps: 50,
         move_rate: 1.0,
+        min_margin_bps: None,
     }];
 
     let result = config.validate();
@@ -295,6 +315,7 @@ fn test_config_validation_zero_exchange_rate() {
         ratio: 0.0,
         fee_bps: 50,
         move_rate: 1.0,
+        min_margin_bps: None,
     }];
 
     let result = config.validate();
@@ -336,6 +357,7 @@ fn test_get_token_pairs_multiple() {
         ratio: 0.5,
         fee_bps: 50,
         move_rate: 0.5,
+        min_margin_bps: None,
     });
 
     let pairs = config.get_token_pairs().unwrap();
@@ -357,6 +379,10 @@ fn test_get_token_pairs_token_address() {
         network_name: "localhost".to_string(),
         outflow_validator_addr: None,
         gmp_endpoint_addr: None,
+        rpc_headers: HashMap::new(),
+        max_fee_per_fulfillment: None,
+        fulfillment_gas_units: 300_000,
+        confirmations: 2,
     }));
     config.acceptance.token_pairs = vec![TokenPairConfig {
         source_chain_id: 1,
@@ -366,6 +392,7 @@ fn test_get_token_pairs_token_address() {
         ratio: 0.5,
         fee_bps: 50,
         move_rate: 0.5,
+        min_margin_bps: None,
     }];
 
     let pairs = config.get_token_pairs().unwrap();
@@ -581,3 +608,194 @@ fn test_config_load_file_not_found() {
     assert!(result.unwrap_err().to_string().contains("not found"));
 }
 
+
+// ============================================================================
+// BIDIRECTIONAL TOKEN PAIR TESTS
+// ============================================================================
+
+// 21. Test: SolverConfig::get_token_pairs() generates the reverse direction when bidirectional
+// Verifies that with acceptance.bidirectional enabled a single configured pair yields both directions, with the reverse rate equal to 1/rate and the reverse move_rate re-expressed in the reverse offered token.
+// Why: Operators should not have to hand-configure the reverse direction; forgetting it silently breaks the reverse flow.
+#[test]
+fn test_get_token_pairs_bidirectional() {
+    let mut config = create_test_config();
+    config.acceptance.bidirectional = true;
+    config.acceptance.token_pairs[0].ratio = 0.5;
+
+    let pairs = config.get_token_pairs().unwrap();
+    assert_eq!(pairs.len(), 2);
+
+    let forward = create_default_token_pair();
+    let reverse = solver::TokenPair {
+        offered_chain_id: forward.desired_chain_id,
+        offered_token: forward.desired_token.clone(),
+        desired_chain_id: forward.offered_chain_id,
+        desired_token: forward.offered_token.clone(),
+    };
+    assert_eq!(pairs[&forward].rate, 0.5);
+    assert_eq!(pairs[&reverse].rate, 1.0 / 0.5);
+    assert_eq!(pairs[&reverse].fee_bps, pairs[&forward].fee_bps);
+    assert_eq!(pairs[&reverse].move_rate, 1.0 / 0.5);
+}
+
+// 22. Test: Explicitly configured reverse pairs take precedence over generated ones
+// Verifies that AcceptanceConfig::expanded_token_pairs() does not generate a reverse entry when the reverse direction is already configured, so the configured ratio is kept.
+// Why: An operator-provided rate must never be silently replaced by a computed reciprocal.
+#[test]
+fn test_get_token_pairs_bidirectional_explicit_reverse_wins() {
+    let mut config = create_test_config();
+    config.acceptance.bidirectional = true;
+    config.acceptance.token_pairs.push(TokenPairConfig {
+        source_chain_id: 2,
+        source_token: DUMMY_TOKEN_ADDR_MVMCON.to_string(),
+        target_chain_id: 1,
+        target_token: DUMMY_TOKEN_ADDR_HUB.to_string(),
+        ratio: 0.9,
+        fee_bps: 50,
+        move_rate: 1.0,
+        min_margin_bps: None,
+    });
+
+    assert_eq!(config.acceptance.expanded_token_pairs().len(), 2);
+    let pairs = config.get_token_pairs().unwrap();
+    let reverse = solver::TokenPair {
+        offered_chain_id: 2,
+        offered_token: DUMMY_TOKEN_ADDR_MVMCON.to_string(),
+        desired_chain_id: 1,
+        desired_token: DUMMY_TOKEN_ADDR_HUB.to_string(),
+    };
+    assert_eq!(pairs[&reverse].rate, 0.9);
+}
+
+// 23. Test: SolverConfig::validate() rejects a rate whose reciprocal is not representable
+// Verifies that with acceptance.bidirectional enabled a ratio so small that 1/ratio overflows to infinity is rejected with a "must be positive and finite" error.
+// Why: The generated reverse pair would otherwise carry an infinite rate and accept any offered amount.
+#[test]
+fn test_config_validation_bidirectional_unrepresentable_reciprocal() {
+    let mut config = create_test_config();
+    config.liquidity = create_mvm_pair_liquidity_config();
+    config.acceptance.bidirectional = true;
+    config.acceptance.token_pairs[0].ratio = 1e-320;
+
+    let result = config.validate();
+    assert!(result.is_err());
+    assert!(result.unwrap_err().to_string().contains("must be positive and finite"));
+}
+
+// 24. Test: SolverConfig::validate() requires liquidity thresholds for generated reverse pairs
+// Verifies that with acceptance.bidirectional enabled validate() fails when the reverse direction's target token has no [[liquidity.threshold]], and succeeds once thresholds for both directions are configured.
+// Why: The solver spends the reverse target token too, so it must be covered by liquidity monitoring.
+#[test]
+fn test_config_validation_bidirectional_requires_reverse_threshold() {
+    let mut config = create_test_config();
+    config.acceptance.bidirectional = true;
+
+    let result = config.validate();
+    assert!(result.is_err());
+    assert!(result.unwrap_err().to_string().contains("no [[liquidity.threshold]]"));
+
+    config.liquidity = create_mvm_pair_liquidity_config();
+    assert!(config.validate().is_ok());
+}
+
+// 25. Test: SolverConfig::validate() rejects a zero in-flight intent cap
+// Verifies that validate() fails with a service.max_inflight_intents error when the cap is 0, and succeeds with a positive cap.
+// Why: A cap of 0 would defer every draft forever, so the solver would silently never accept an intent.
+#[test]
+fn test_config_validation_rejects_zero_max_inflight_intents() {
+    let mut config = create_test_config();
+    config.service.max_inflight_intents = 0;
+
+    let result = config.validate();
+    assert!(result.is_err());
+    assert!(result.unwrap_err().to_string().contains("service.max_inflight_intents"));
+
+    config.service.max_inflight_intents = 2;
+    assert!(config.validate().is_ok());
+}
+
+// 26. Test: SolverConfig::validate() rejects a requester on both allowlist and denylist
+// Verifies that validate() fails naming the requester when the same address (in any spelling) is in both acceptance.requester_allowlist and acceptance.requester_denylist, and succeeds once the lists are disjoint.
+// Why: An overlapping entry is silently denied at runtime; surfacing it at startup catches the misconfiguration.
+#[test]
+fn test_config_validation_rejects_requester_list_overlap() {
+    let mut config = create_test_config();
+    config.acceptance.requester_allowlist = ["0xabc".to_string(), "0x123".to_string()].into();
+    config.acceptance.requester_denylist = ["0x0ABC".to_string()].into();
+
+    let result = config.validate();
+    assert!(result.is_err());
+    let err = result.unwrap_err().to_string();
+    assert!(err.contains("0xabc"), "error should name the requester: {}", err);
+    assert!(err.contains("requester_allowlist"));
+
+    config.acceptance.requester_denylist = ["0xdef".to_string()].into();
+    assert!(config.validate().is_ok());
+}
+
+// 27. Test: SolverConfig::validate() rejects an enabled circuit breaker with no cooldown
+// Verifies that validate() fails with a service.circuit_breaker_cooldown_secs error when the breaker is enabled with a 0s cooldown, and succeeds when the breaker is disabled.
+// Why: A zero cooldown would half-open immediately, so the breaker would never actually pause polling during an outage.
+#[test]
+fn test_config_validation_rejects_zero_circuit_breaker_cooldown() {
+    let mut config = create_test_config();
+    config.service.circuit_breaker_failure_threshold = 5;
+    config.service.circuit_breaker_cooldown_secs = 0;
+
+    let result = config.validate();
+    assert!(result.is_err());
+    assert!(result
+        .unwrap_err()
+        .to_string()
+        .contains("service.circuit_breaker_cooldown_secs"));
+
+    config.service.circuit_breaker_failure_threshold = 0;
+    assert!(config.validate().is_ok());
+}
+
+// ============================================================================
+// PROFITABILITY TESTS
+// ============================================================================
+
+// 28. Test: SolverConfig::is_profitable() accepts an intent above the configured margin
+// Verifies that is_profitable() returns true when the offered amount exceeds desired_amount * ratio by more than min_margin_bps.
+// Why: Intents that clear the margin are the ones the solver should take.
+#[test]
+fn test_is_profitable_above_margin() {
+    let mut config = create_test_config();
+    config.acceptance.token_pairs[0].ratio = 2.0;
+    config.acceptance.token_pairs[0].min_margin_bps = Some(100);
+
+    // Implied offered = 1000 * 2.0 = 2000, required with 1% margin = 2020
+    assert!(config.is_profitable(2100, 1000, &create_default_token_pair()));
+}
+
+// 29. Test: SolverConfig::is_profitable() treats an intent exactly at the margin as profitable
+// Verifies that is_profitable() returns true when the offered amount equals the rate-implied amount plus min_margin_bps, and that with no margin set an intent exactly at the rate is profitable.
+// Why: The margin is a floor; landing on it still meets the solver's requirement.
+#[test]
+fn test_is_profitable_break_even() {
+    let mut config = create_test_config();
+    let pair = create_default_token_pair();
+    assert!(config.is_profitable(1000, 1000, &pair));
+
+    config.acceptance.token_pairs[0].min_margin_bps = Some(100);
+    assert!(config.is_profitable(1010, 1000, &pair));
+}
+
+// 30. Test: SolverConfig::is_profitable() rejects an intent below the configured margin
+// Verifies that is_profitable() returns false when the offered amount covers the rate but not min_margin_bps, and returns false for a pair that is not configured.
+// Why: The solver must skip intents that would fill below its margin.
+#[test]
+fn test_is_profitable_below_margin() {
+    let mut config = create_test_config();
+    config.acceptance.token_pairs[0].min_margin_bps = Some(100);
+    let pair = create_default_token_pair();
+
+    // Covers the 1:1 rate but not the 1% margin (required 1010)
+    assert!(!config.is_profitable(1009, 1000, &pair));
+
+    let mut unknown_pair = pair.clone();
+    unknown_pair.desired_chain_id = 99;
+    assert!(!config.is_profitable(2000, 1000, &unknown_pair));
+}
@@ -4,12 +4,20 @@
 //! Query tests (balance, escrow events, address normalization) moved to
 //! chain-clients/evm/tests/evm_client_tests.rs. See chain-clients/extension-checklist.md.
 
+use serde_json::json;
+use sha3::{Digest, Keccak256};
 use solver::chains::ConnectedEvmClient;
 use solver::config::EvmChainConfig;
+use std::collections::HashMap;
+use wiremock::matchers::{body_partial_json, method};
+use wiremock::{Mock, MockServer, ResponseTemplate};
 
 #[path = "../helpers.rs"]
 mod test_helpers;
-use test_helpers::DUMMY_ESCROW_CONTRACT_ADDR_EVM;
+use test_helpers::{
+    DUMMY_ESCROW_CONTRACT_ADDR_EVM, DUMMY_INTENT_ID, DUMMY_REQUESTER_ADDR_EVM,
+    DUMMY_TOKEN_ADDR_EVM, DUMMY_TX_HASH,
+};
 
 // ============================================================================
 // HELPER FUNCTIONS
@@ -25,6 +33,10 @@ fn create_test_evm_config() -> EvmChainConfig {
         network_name: "localhost".to_string(),
         outflow_validator_addr: None,
         gmp_endpoint_addr: None,
+        rpc_headers: HashMap::new(),
+        max_fee_per_fulfillment: None,
+        fulfillment_gas_units: 300_000,
+        confirmations: 2,
     }
 }
 
@@ -53,7 +65,7 @@ fn test_client_new() {
 // ============================================================================
 
 // #7: test_fulfillment_id_formatting — ⚠️ TODO: implement for EVM
-// #8: test_fulfillment_signature_encoding — ⚠️ TODO: implement for EVM
+// #8: test_fulfillment_signature_encoding — N/A for EVM (inflow escrows are released by GMP; no solver Hardhat script takes a signature)
 // #9: test_fulfillment_command_building — ⚠️ TODO: implement for EVM
 // #10: test_fulfillment_error_handling — ⚠️ TODO: implement for EVM
 
@@ -180,3 +192,179 @@ fn test_is_escrow_released_error_handling() {
 // #35: test_normalize_evm_address_padded — moved to chain-clients/evm/tests/evm_client_tests.rs (#22)
 // #36: test_normalize_evm_address_passthrough — moved to chain-clients/evm/tests/evm_client_tests.rs (#23)
 // #37: test_normalize_evm_address_rejects_non_zero_high_bytes — moved to chain-clients/evm/tests/evm_client_tests.rs (#24)
+
+// ============================================================================
+// FULFILLMENT FEE BUDGET
+// ============================================================================
+
+/// Starts a mock RPC whose eth_gasPrice returns the given price in wei.
+async fn setup_mock_server_with_gas_price(gas_price: u64) -> MockServer {
+    let mock_server = MockServer::start().await;
+    Mock::given(method("POST"))
+        .and(body_partial_json(json!({"method": "eth_gasPrice"})))
+        .respond_with(ResponseTemplate::new(200).set_body_json(json!({
+            "jsonrpc": "2.0",
+            "result": format!("0x{:x}", gas_price),
+            "id": 1
+        })))
+        .mount(&mock_server)
+        .await;
+    mock_server
+}
+
+/// 38. Test: Fulfillment Fee Under Budget Proceeds
+/// Verifies that check_fulfillment_fee_budget() succeeds when gas units × eth_gasPrice is within max_fee_per_fulfillment.
+/// Why: The cap must not block fulfillments at normal gas prices.
+#[tokio::test]
+async fn test_fulfillment_fee_under_budget_proceeds() {
+    let mock_server = setup_mock_server_with_gas_price(1_000_000_000).await;
+    let mut config = create_test_evm_config();
+    config.rpc_url = mock_server.uri();
+    config.fulfillment_gas_units = 300_000;
+    config.max_fee_per_fulfillment = Some(300_000_000_000_000); // exactly 300_000 × 1 gwei
+
+    let client = ConnectedEvmClient::new(&config).unwrap();
+    client.check_fulfillment_fee_budget(DUMMY_INTENT_ID).await.unwrap();
+}
+
+/// 39. Test: Fulfillment Fee Over Budget Aborts
+/// Verifies that check_fulfillment_fee_budget() fails when gas units × eth_gasPrice exceeds max_fee_per_fulfillment.
+/// Why: During congestion the solver must refuse to fulfill at a loss instead of submitting the transaction.
+#[tokio::test]
+async fn test_fulfillment_fee_over_budget_aborts() {
+    let mock_server = setup_mock_server_with_gas_price(2_000_000_000).await;
+    let mut config = create_test_evm_config();
+    config.rpc_url = mock_server.uri();
+    config.fulfillment_gas_units = 300_000;
+    config.max_fee_per_fulfillment = Some(300_000_000_000_000);
+
+    let client = ConnectedEvmClient::new(&config).unwrap();
+    let err = client
+        .check_fulfillment_fee_budget(DUMMY_INTENT_ID)
+        .await
+        .unwrap_err();
+    assert!(err.to_string().contains("exceeds max_fee_per_fulfillment"));
+}
+
+// ============================================================================
+// TOKEN DECIMALS
+// ============================================================================
+
+/// 40. Test: Token Decimals Are Cached
+/// Verifies that get_token_decimals() returns the decimals from decimals() and answers a second lookup from the cache.
+/// Why: Decimals never change; querying them on every amount conversion would spend an RPC round trip each time.
+#[tokio::test]
+async fn test_get_token_decimals_cached() {
+    let mock_server = MockServer::start().await;
+    Mock::given(method("POST"))
+        .and(body_partial_json(json!({"method": "eth_call"})))
+        .respond_with(ResponseTemplate::new(200).set_body_json(json!({
+            "jsonrpc": "2.0",
+            "result": format!("0x{:064x}", 6),
+            "id": 1
+        })))
+        .expect(1)
+        .mount(&mock_server)
+        .await;
+
+    let mut config = create_test_evm_config();
+    config.rpc_url = mock_server.uri();
+    let client = ConnectedEvmClient::new(&config).unwrap();
+
+    for _ in 0..2 {
+        let decimals = client
+            .get_token_decimals(DUMMY_TOKEN_ADDR_EVM)
+            .await
+            .unwrap();
+        assert_eq!(decimals, 6);
+    }
+}
+
+// ============================================================================
+// ESCROW EVENT CONFIRMATIONS
+// ============================================================================
+
+/// Mounts an eth_blockNumber response that is served `times` times.
+async fn mount_block_number(mock_server: &MockServer, block: u64, times: u64) {
+    Mock::given(method("POST"))
+        .and(body_partial_json(json!({"method": "eth_blockNumber"})))
+        .respond_with(ResponseTemplate::new(200).set_body_json(json!({
+            "jsonrpc": "2.0",
+            "result": format!("0x{:x}", block),
+            "id": 1
+        })))
+        .up_to_n_times(times)
+        .mount(mock_server)
+        .await;
+}
+
+/// Mounts an eth_getLogs response for queries ending at `to_block`.
+async fn mount_logs_to_block(mock_server: &MockServer, to_block: u64, logs: serde_json::Value) {
+    Mock::given(method("POST"))
+        .and(body_partial_json(json!({
+            "method": "eth_getLogs",
+            "params": [{ "toBlock": format!("0x{:x}", to_block) }]
+        })))
+        .respond_with(ResponseTemplate::new(200).set_body_json(json!({
+            "jsonrpc": "2.0",
+            "result": logs,
+            "id": 1
+        })))
+        .mount(mock_server)
+        .await;
+}
+
+/// 41. Test: Escrow Events Withheld Until Confirmed
+/// Verifies that get_escrow_events() clamps the query to current_block - confirmations, so a log at the chain tip is not returned until enough blocks are mined on top of it.
+/// Why: A reorg can drop an escrow at the tip; acting on it would make the solver fulfill an intent nobody paid for.
+#[tokio::test]
+async fn test_get_escrow_events_withholds_unconfirmed() {
+    let mut hasher = Keccak256::new();
+    hasher.update(b"EscrowCreated(bytes32,bytes32,address,uint64,address,bytes32,uint64)");
+    let event_topic = format!("0x{}", hex::encode(hasher.finalize()));
+    let word = |n: u64| format!("{:064x}", n);
+    let pad = |addr: &str| {
+        format!(
+            "0x000000000000000000000000{}",
+            addr.strip_prefix("0x").unwrap()
+        )
+    };
+    let tip_log = json!({
+        "address": DUMMY_ESCROW_CONTRACT_ADDR_EVM,
+        "topics": [event_topic, DUMMY_INTENT_ID, pad(DUMMY_REQUESTER_ADDR_EVM), pad(DUMMY_TOKEN_ADDR_EVM)],
+        "data": format!("0x{}{}{}{}", word(2), word(1000), word(9), word(9999)),
+        "blockNumber": "0x10",
+        "transactionHash": DUMMY_TX_HASH,
+        "logIndex": "0x0"
+    });
+
+    let mock_server = MockServer::start().await;
+    // First poll sees the log's block as the tip, second poll two blocks later
+    mount_block_number(&mock_server, 0x10, 1).await;
+    mount_block_number(&mock_server, 0x12, 1).await;
+    mount_logs_to_block(&mock_server, 0x0e, json!([])).await;
+    mount_logs_to_block(&mock_server, 0x10, json!([tip_log])).await;
+
+    let mut config = create_test_evm_config();
+    config.rpc_url = mock_server.uri();
+    config.confirmations = 2;
+    let client = ConnectedEvmClient::new(&config).unwrap();
+    assert_eq!(client.confirmations(), 2);
+
+    let events = client
+        .get_escrow_events(Some(0x08), Some(0x10))
+        .await
+        .unwrap();
+    assert!(
+        events.is_empty(),
+        "tip log must be withheld until it has 2 confirmations"
+    );
+
+    let events = client
+        .get_escrow_events(Some(0x08), Some(0x12))
+        .await
+        .unwrap();
+    assert_eq!(events.len(), 1);
+    assert_eq!(events[0].intent_id, DUMMY_INTENT_ID);
+    assert_eq!(events[0].block_number, "0x10");
+}
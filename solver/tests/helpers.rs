@@ -141,6 +141,11 @@ pub fn create_default_service_config() -> solver::config::ServiceConfig {
         e2e_mode: false,
         acceptance_api_host: "127.0.0.1".to_string(),
         acceptance_api_port: 4444,
+        min_expiry_remaining_secs: 0,
+        max_inflight_intents: 32,
+        circuit_breaker_failure_threshold: 10,
+        circuit_breaker_cooldown_secs: 300,
+        verify_intent_open_before_fulfill: false,
     }
 }
 
@@ -160,6 +165,8 @@ pub fn create_default_hub_chain_config() -> solver::config::ChainConfig {
         module_addr: DUMMY_MODULE_ADDR_HUB.to_string(),
         profile: "hub-profile".to_string(),
         e2e_mode: false,
+        event_query_concurrency: 8,
+        rpc_headers: std::collections::HashMap::new(),
     }
 }
 
@@ -179,6 +186,9 @@ pub fn create_default_connected_mvm_chain_config() -> solver::config::MvmChainCo
         module_addr: DUMMY_MODULE_ADDR_CON.to_string(),
         profile: "connected-profile".to_string(),
         e2e_mode: false,
+        rpc_headers: std::collections::HashMap::new(),
+        max_fee_per_fulfillment: None,
+        fulfillment_gas_units: 10_000,
     }
 }
 
@@ -261,6 +271,10 @@ pub fn create_default_connected_evm_chain_config() -> solver::config::EvmChainCo
         network_name: "localhost".to_string(),
         outflow_validator_addr: None,
         gmp_endpoint_addr: None,
+        rpc_headers: std::collections::HashMap::new(),
+        max_fee_per_fulfillment: None,
+        fulfillment_gas_units: 300_000,
+        confirmations: 2,
     }
 }
 
@@ -274,6 +288,7 @@ pub fn create_default_connected_svm_chain_config() -> solver::config::SvmChainCo
         private_key_env: "SOLANA_SOLVER_PRIVATE_KEY".to_string(),
         gmp_endpoint_program_id: None,
         outflow_validator_program_id: None,
+        rpc_headers: std::collections::HashMap::new(),
     }
 }
 
@@ -297,6 +312,10 @@ pub fn create_default_solver_config() -> solver::config::SolverConfig {
         acceptance: solver::config::AcceptanceConfig {
             base_fee_in_move: 1_000_000,
             token_pairs: Vec::new(),
+            bidirectional: false,
+            verify_token_metadata: false,
+            requester_allowlist: Default::default(),
+            requester_denylist: Default::default(),
         },
         solver: create_default_solver_signing_config(),
         liquidity: create_default_liquidity_config(),
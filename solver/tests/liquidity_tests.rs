@@ -52,6 +52,7 @@ fn test_solver_config_with_pairs() -> solver::config::SolverConfig {
                     ratio: 1.0,
                     fee_bps: 50,
                     move_rate: 1.0,
+                    min_margin_bps: None,
                 },
                 // Outflow: requester offers on hub, solver spends on connected
                 TokenPairConfig {
@@ -62,8 +63,13 @@ fn test_solver_config_with_pairs() -> solver::config::SolverConfig {
                     ratio: 1.0,
                     fee_bps: 50,
                     move_rate: 1.0,
+                    min_margin_bps: None,
                 },
             ],
+            bidirectional: false,
+            verify_token_metadata: false,
+            requester_allowlist: Default::default(),
+            requester_denylist: Default::default(),
         },
         liquidity: create_mvm_pair_liquidity_config(),
         ..create_default_solver_config()
@@ -755,6 +761,7 @@ fn test_config_validation_catches_unknown_chain_before_runtime() {
         ratio: 1.0,
         fee_bps: 50,
         move_rate: 1.0,
+        min_margin_bps: None,
     });
     let result = config.validate();
     assert!(result.is_err(), "validate() must reject acceptance pairs targeting unconfigured chains");
@@ -223,3 +223,92 @@ async fn test_has_outflow_requirements_error() {
 // #35: test_normalize_evm_address_padded - N/A for MVM (EVM-specific address normalization)
 // #36: test_normalize_evm_address_passthrough - N/A for MVM (EVM-specific address normalization)
 // #37: test_normalize_evm_address_rejects_non_zero_high_bytes - N/A for MVM (EVM-specific address normalization)
+
+// ============================================================================
+// FULFILLMENT FEE BUDGET
+// ============================================================================
+
+/// Starts a mock node whose /v1/estimate_gas_price returns the given gas estimate.
+async fn setup_mock_server_with_gas_price(gas_estimate: u64) -> MockServer {
+    let mock_server = MockServer::start().await;
+    Mock::given(method("GET"))
+        .and(path("/v1/estimate_gas_price"))
+        .respond_with(ResponseTemplate::new(200).set_body_json(json!({
+            "gas_estimate": gas_estimate
+        })))
+        .mount(&mock_server)
+        .await;
+    mock_server
+}
+
+/// 38. Test: Fulfillment Fee Under Budget Proceeds
+/// Verifies that check_fulfillment_fee_budget() succeeds when gas units × gas price is within max_fee_per_fulfillment.
+/// Why: The cap must not block fulfillments at normal gas prices.
+#[tokio::test]
+async fn test_fulfillment_fee_under_budget_proceeds() {
+    let mock_server = setup_mock_server_with_gas_price(100).await;
+    let mut config = create_default_connected_mvm_chain_config();
+    config.rpc_url = mock_server.uri();
+    config.fulfillment_gas_units = 10_000;
+    config.max_fee_per_fulfillment = Some(1_000_000); // exactly 10_000 × 100
+
+    let client = ConnectedMvmClient::new(&config).unwrap();
+    client.check_fulfillment_fee_budget(DUMMY_INTENT_ID).await.unwrap();
+}
+
+/// 39. Test: Fulfillment Fee Over Budget Aborts
+/// Verifies that check_fulfillment_fee_budget() fails when gas units × gas price exceeds max_fee_per_fulfillment.
+/// Why: During congestion the solver must refuse to fulfill at a loss instead of submitting the transaction.
+#[tokio::test]
+async fn test_fulfillment_fee_over_budget_aborts() {
+    let mock_server = setup_mock_server_with_gas_price(101).await;
+    let mut config = create_default_connected_mvm_chain_config();
+    config.rpc_url = mock_server.uri();
+    config.fulfillment_gas_units = 10_000;
+    config.max_fee_per_fulfillment = Some(1_000_000);
+
+    let client = ConnectedMvmClient::new(&config).unwrap();
+    let err = client
+        .check_fulfillment_fee_budget(DUMMY_INTENT_ID)
+        .await
+        .unwrap_err();
+    assert!(err.to_string().contains("exceeds max_fee_per_fulfillment"));
+}
+
+// ============================================================================
+// TOKEN DECIMALS
+// ============================================================================
+
+/// 40. Test: Token Decimals Are Cached
+/// Verifies that get_token_decimals() returns the decimals from the fungible asset metadata and answers a second lookup from the cache.
+/// Why: Decimals never change; querying them on every amount conversion would spend an RPC round trip each time.
+#[tokio::test]
+async fn test_get_token_decimals_cached() {
+    let mock_server = MockServer::start().await;
+    Mock::given(method("GET"))
+        .and(path(format!(
+            "/v1/accounts/{}/resource/0x1::fungible_asset::Metadata",
+            DUMMY_TOKEN_ADDR_MVMCON
+        )))
+        .respond_with(ResponseTemplate::new(200).set_body_json(json!({
+            "type": "0x1::fungible_asset::Metadata",
+            "data": {"decimals": 8, "name": "Test", "symbol": "TST"}
+        })))
+        .expect(1)
+        .mount(&mock_server)
+        .await;
+
+    let mut config = create_default_connected_mvm_chain_config();
+    config.rpc_url = mock_server.uri();
+    let client = ConnectedMvmClient::new(&config).unwrap();
+
+    for _ in 0..2 {
+        let decimals = client
+            .get_token_decimals(DUMMY_TOKEN_ADDR_MVMCON)
+            .await
+            .unwrap();
+        assert_eq!(decimals, 8);
+    }
+}
+
+// #41: test_get_escrow_events_withholds_unconfirmed - N/A for MVM (escrow events are read from Aptos account transactions, not block ranges)
@@ -5,8 +5,10 @@
 //! Connected chain tests are in chain_client_tests.rs (synchronized across VMs).
 
 use serde_json::json;
+use solver::chains::hub::{DEFAULT_EVENT_QUERY_MAX_PAGES, EVENT_QUERY_PAGE_SIZE};
 use solver::chains::HubChainClient;
-use wiremock::matchers::{method, path};
+use std::collections::HashMap;
+use wiremock::matchers::{method, path, query_param};
 use wiremock::{Mock, MockServer, ResponseTemplate};
 
 #[path = "../helpers.rs"]
@@ -111,7 +113,15 @@ async fn test_get_intent_events_success() {
     let client = HubChainClient::new(&config).unwrap();
 
     let accounts = vec![DUMMY_REQUESTER_ADDR_HUB.to_string()];
-    let (events, _tx_hashes) = client.get_intent_events(&accounts, None, None).await.unwrap();
+    let (events, _tx_hashes, _) = client
+        .get_intent_events(
+            &accounts,
+            &HashMap::new(),
+            None,
+            DEFAULT_EVENT_QUERY_MAX_PAGES,
+        )
+        .await
+        .unwrap();
 
     assert_eq!(events.len(), 1);
     assert_eq!(events[0].intent_id, DUMMY_INTENT_ID);
@@ -141,7 +151,15 @@ async fn test_get_intent_events_empty() {
     let client = HubChainClient::new(&config).unwrap();
 
     let accounts = vec![DUMMY_REQUESTER_ADDR_HUB.to_string()];
-    let (events, _tx_hashes) = client.get_intent_events(&accounts, None, None).await.unwrap();
+    let (events, _tx_hashes, _) = client
+        .get_intent_events(
+            &accounts,
+            &HashMap::new(),
+            None,
+            DEFAULT_EVENT_QUERY_MAX_PAGES,
+        )
+        .await
+        .unwrap();
 
     assert_eq!(events.len(), 0);
 }
@@ -256,9 +274,7 @@ async fn test_is_solver_registered_http_error() {
     config.rpc_url = base_url;
     let client = HubChainClient::new(&config).unwrap();
 
-    let result = client
-        .is_solver_registered(DUMMY_SOLVER_ADDR_HUB)
-        .await;
+    let result = client.is_solver_registered(DUMMY_SOLVER_ADDR_HUB).await;
 
     assert!(result.is_err());
     assert!(result
@@ -285,9 +301,7 @@ async fn test_is_solver_registered_invalid_json() {
     config.rpc_url = base_url;
     let client = HubChainClient::new(&config).unwrap();
 
-    let result = client
-        .is_solver_registered(DUMMY_SOLVER_ADDR_HUB)
-        .await;
+    let result = client.is_solver_registered(DUMMY_SOLVER_ADDR_HUB).await;
 
     assert!(result.is_err());
 }
@@ -310,9 +324,7 @@ async fn test_is_solver_registered_unexpected_format() {
     config.rpc_url = base_url;
     let client = HubChainClient::new(&config).unwrap();
 
-    let result = client
-        .is_solver_registered(DUMMY_SOLVER_ADDR_HUB)
-        .await;
+    let result = client.is_solver_registered(DUMMY_SOLVER_ADDR_HUB).await;
 
     assert!(result.is_err());
     assert!(result
@@ -320,3 +332,376 @@ async fn test_is_solver_registered_unexpected_format() {
         .to_string()
         .contains("Unexpected response format"));
 }
+
+// ============================================================================
+// MULTI-ACCOUNT INTENT EVENT QUERYING
+// ============================================================================
+
+/// Builds a hub transaction JSON carrying a single LimitOrderEvent for `intent_id`.
+fn limit_order_tx(tx_hash: &str, intent_id: &str, requester_addr: &str) -> serde_json::Value {
+    json!({
+        "hash": tx_hash,
+        "events": [
+            {
+                "type": format!("{}::fa_intent::LimitOrderEvent", DUMMY_MODULE_ADDR_HUB),
+                "data": {
+                    "intent_addr": DUMMY_INTENT_ADDR_HUB,
+                    "intent_id": intent_id,
+                    "offered_metadata": {"inner": DUMMY_TOKEN_ADDR_HUB},
+                    "offered_amount": "1000",
+                    "offered_chain_id": "1",
+                    "desired_metadata": {"inner": DUMMY_TOKEN_ADDR_MVMCON},
+                    "desired_amount": "2000",
+                    "desired_chain_id": "2",
+                    "requester_addr": requester_addr,
+                    "expiry_time": DUMMY_EXPIRY.to_string(),
+                    "revocable": true
+                }
+            }
+        ]
+    })
+}
+
+/// 11. Test: Get Intent Events Multiple Accounts With One Failing
+/// Verifies that get_intent_events() merges events from all accounts and skips an
+/// account whose query fails.
+/// Why: Accounts are queried concurrently. One broken account response must not
+/// hide intents from the other requesters or fail the whole poll.
+#[tokio::test]
+async fn test_get_intent_events_multiple_accounts_one_failing() {
+    let mock_server = MockServer::start().await;
+    let base_url = mock_server.uri().to_string();
+
+    let account_a = "0x00000000000000000000000000000000000000000000000000000000000000a1";
+    let account_b = "0x00000000000000000000000000000000000000000000000000000000000000b2";
+    let account_c = "0x00000000000000000000000000000000000000000000000000000000000000c3";
+    let intent_id_a = "0x00000000000000000000000000000000000000000000000000000000000000aa";
+    let intent_id_b = "0x00000000000000000000000000000000000000000000000000000000000000bb";
+
+    Mock::given(method("GET"))
+        .and(path(format!(
+            "/v1/accounts/{}/transactions",
+            &account_a[2..]
+        )))
+        .respond_with(
+            ResponseTemplate::new(200).set_body_json(json!([limit_order_tx(
+                "0xa1a1",
+                intent_id_a,
+                account_a
+            )])),
+        )
+        .mount(&mock_server)
+        .await;
+
+    Mock::given(method("GET"))
+        .and(path(format!(
+            "/v1/accounts/{}/transactions",
+            &account_b[2..]
+        )))
+        .respond_with(
+            ResponseTemplate::new(200).set_body_json(json!([limit_order_tx(
+                "0xb2b2",
+                intent_id_b,
+                account_b
+            )])),
+        )
+        .mount(&mock_server)
+        .await;
+
+    // Third account returns a malformed body so its query errors
+    Mock::given(method("GET"))
+        .and(path(format!(
+            "/v1/accounts/{}/transactions",
+            &account_c[2..]
+        )))
+        .respond_with(ResponseTemplate::new(200).set_body_string("not json"))
+        .mount(&mock_server)
+        .await;
+
+    let mut config = create_default_hub_chain_config();
+    config.rpc_url = base_url;
+    config.event_query_concurrency = 2;
+    let client = HubChainClient::new(&config).unwrap();
+
+    let accounts = vec![
+        account_a.to_string(),
+        account_b.to_string(),
+        account_c.to_string(),
+    ];
+    let (events, tx_hashes, _) = client
+        .get_intent_events(
+            &accounts,
+            &HashMap::new(),
+            None,
+            DEFAULT_EVENT_QUERY_MAX_PAGES,
+        )
+        .await
+        .unwrap();
+
+    let mut intent_ids: Vec<&str> = events.iter().map(|e| e.intent_id.as_str()).collect();
+    intent_ids.sort();
+    assert_eq!(intent_ids, vec![intent_id_a, intent_id_b]);
+    assert_eq!(tx_hashes.len(), 2);
+}
+
+/// 12. Test: Get Intent Events Deduplicates Across Accounts
+/// Verifies that get_intent_events() returns an intent once when it appears under
+/// more than one queried account.
+/// Why: The same transaction can be listed for several accounts. Duplicates would
+/// make the tracker process the same intent twice.
+#[tokio::test]
+async fn test_get_intent_events_deduplicates_across_accounts() {
+    let mock_server = MockServer::start().await;
+    let base_url = mock_server.uri().to_string();
+
+    let account_a = "0x00000000000000000000000000000000000000000000000000000000000000a1";
+    let account_b = "0x00000000000000000000000000000000000000000000000000000000000000b2";
+
+    for account in [account_a, account_b] {
+        Mock::given(method("GET"))
+            .and(path(format!("/v1/accounts/{}/transactions", &account[2..])))
+            .respond_with(
+                ResponseTemplate::new(200).set_body_json(json!([limit_order_tx(
+                    "0xabab",
+                    DUMMY_INTENT_ID,
+                    DUMMY_REQUESTER_ADDR_HUB
+                )])),
+            )
+            .mount(&mock_server)
+            .await;
+    }
+
+    let mut config = create_default_hub_chain_config();
+    config.rpc_url = base_url;
+    let client = HubChainClient::new(&config).unwrap();
+
+    let accounts = vec![account_a.to_string(), account_b.to_string()];
+    let (events, tx_hashes, _) = client
+        .get_intent_events(
+            &accounts,
+            &HashMap::new(),
+            None,
+            DEFAULT_EVENT_QUERY_MAX_PAGES,
+        )
+        .await
+        .unwrap();
+
+    assert_eq!(events.len(), 1);
+    assert_eq!(events[0].intent_id, DUMMY_INTENT_ID);
+    assert_eq!(tx_hashes, vec!["0xabab".to_string()]);
+}
+
+// ============================================================================
+// MOVE OPTION EVENT FIELDS
+// ============================================================================
+
+/// 13. Test: IntentCreatedEvent With Reserved Solver
+/// Verifies that {"vec": [addr]} Move Option fields decode to Some(addr).
+/// Why: Outflow intents need the reserved solver and connected-chain requester address;
+/// losing either would stop the solver from fulfilling the intent.
+#[test]
+fn test_intent_created_event_with_reserved_solver() {
+    let json = json!({
+        "intent_addr": DUMMY_INTENT_ADDR_HUB,
+        "intent_id": DUMMY_INTENT_ID,
+        "offered_metadata": {"inner": DUMMY_TOKEN_ADDR_HUB},
+        "offered_amount": "1000",
+        "offered_chain_id": "1",
+        "desired_metadata": {"inner": DUMMY_TOKEN_ADDR_MVMCON},
+        "desired_metadata_addr": {"vec": [DUMMY_TOKEN_ADDR_MVMCON]},
+        "desired_amount": "2000",
+        "desired_chain_id": "2",
+        "requester_addr": DUMMY_REQUESTER_ADDR_HUB,
+        "expiry_time": DUMMY_EXPIRY.to_string(),
+        "reserved_solver": {"vec": [DUMMY_SOLVER_ADDR_HUB]},
+        "requester_addr_connected_chain": {"vec": [DUMMY_REQUESTER_ADDR_HUB]}
+    });
+
+    let event: solver::chains::hub::IntentCreatedEvent = serde_json::from_value(json).unwrap();
+    assert_eq!(
+        event.reserved_solver.as_deref(),
+        Some(DUMMY_SOLVER_ADDR_HUB)
+    );
+    assert_eq!(
+        event.requester_addr_connected_chain.as_deref(),
+        Some(DUMMY_REQUESTER_ADDR_HUB)
+    );
+    assert_eq!(
+        event.desired_metadata_addr.as_deref(),
+        Some(DUMMY_TOKEN_ADDR_MVMCON)
+    );
+}
+
+/// 14. Test: IntentCreatedEvent Without Reserved Solver
+/// Verifies that an empty {"vec": []} or null Move Option field decodes to None.
+/// Why: An intent without a reserved solver is valid; the event must parse rather than
+/// fail and hide the intent from the solver.
+#[test]
+fn test_intent_created_event_without_reserved_solver() {
+    let json = json!({
+        "intent_addr": DUMMY_INTENT_ADDR_HUB,
+        "intent_id": DUMMY_INTENT_ID,
+        "offered_metadata": {"inner": DUMMY_TOKEN_ADDR_HUB},
+        "offered_amount": "1000",
+        "offered_chain_id": "1",
+        "desired_metadata": {"inner": DUMMY_TOKEN_ADDR_MVMCON},
+        "desired_metadata_addr": null,
+        "desired_amount": "2000",
+        "desired_chain_id": "2",
+        "requester_addr": DUMMY_REQUESTER_ADDR_HUB,
+        "expiry_time": DUMMY_EXPIRY.to_string(),
+        "reserved_solver": {"vec": []},
+        "requester_addr_connected_chain": {"vec": []}
+    });
+
+    let event: solver::chains::hub::IntentCreatedEvent = serde_json::from_value(json).unwrap();
+    assert_eq!(event.reserved_solver, None);
+    assert_eq!(event.requester_addr_connected_chain, None);
+    assert_eq!(event.desired_metadata_addr, None);
+}
+
+// ============================================================================
+// INTENT EVENT PAGINATION
+// ============================================================================
+
+/// 15. Test: Get Intent Events Reads Every Page
+/// Verifies that get_intent_events() keeps requesting pages until a short page comes
+/// back, collects events from both pages, and returns the version to resume from.
+/// Why: A single request only sees one page of an account's transactions; intents
+/// past it would never be picked up.
+#[tokio::test]
+async fn test_get_intent_events_paginates() {
+    let mock_server = MockServer::start().await;
+    let base_url = mock_server.uri().to_string();
+    let account_path = format!(
+        "/v1/accounts/{}/transactions",
+        DUMMY_REQUESTER_ADDR_HUB.strip_prefix("0x").unwrap()
+    );
+    let intent_id_a = "0x00000000000000000000000000000000000000000000000000000000000000aa";
+    let intent_id_b = "0x00000000000000000000000000000000000000000000000000000000000000bb";
+
+    // First page is full: one intent followed by filler transactions
+    let mut first_page = vec![limit_order_tx("0x0", intent_id_a, DUMMY_REQUESTER_ADDR_HUB)];
+    for i in 1..EVENT_QUERY_PAGE_SIZE {
+        first_page.push(json!({"hash": format!("0x{:x}", i), "events": []}));
+    }
+    for (i, tx) in first_page.iter_mut().enumerate() {
+        tx["sequence_number"] = json!(i.to_string());
+    }
+    let mut second_tx = limit_order_tx("0xb2b2", intent_id_b, DUMMY_REQUESTER_ADDR_HUB);
+    second_tx["sequence_number"] = json!(EVENT_QUERY_PAGE_SIZE.to_string());
+
+    Mock::given(method("GET"))
+        .and(path(account_path.clone()))
+        .and(query_param("start", "0"))
+        .respond_with(ResponseTemplate::new(200).set_body_json(json!(first_page)))
+        .mount(&mock_server)
+        .await;
+    Mock::given(method("GET"))
+        .and(path(account_path))
+        .and(query_param("start", EVENT_QUERY_PAGE_SIZE.to_string()))
+        .respond_with(ResponseTemplate::new(200).set_body_json(json!([second_tx])))
+        .mount(&mock_server)
+        .await;
+
+    let mut config = create_default_hub_chain_config();
+    config.rpc_url = base_url;
+    let client = HubChainClient::new(&config).unwrap();
+
+    let accounts = vec![DUMMY_REQUESTER_ADDR_HUB.to_string()];
+    let (events, tx_hashes, resume_versions) = client
+        .get_intent_events(
+            &accounts,
+            &HashMap::new(),
+            None,
+            DEFAULT_EVENT_QUERY_MAX_PAGES,
+        )
+        .await
+        .unwrap();
+
+    let intent_ids: Vec<&str> = events.iter().map(|e| e.intent_id.as_str()).collect();
+    assert_eq!(intent_ids, vec![intent_id_a, intent_id_b]);
+    assert_eq!(tx_hashes.len() as u64, EVENT_QUERY_PAGE_SIZE + 1);
+    assert_eq!(
+        resume_versions.get(DUMMY_REQUESTER_ADDR_HUB),
+        Some(&(EVENT_QUERY_PAGE_SIZE + 1))
+    );
+
+    // A one-page cap stops after the first page
+    let (events, _, capped_versions) = client
+        .get_intent_events(&accounts, &HashMap::new(), None, 1)
+        .await
+        .unwrap();
+    assert_eq!(events.len(), 1);
+    assert_eq!(
+        capped_versions.get(DUMMY_REQUESTER_ADDR_HUB),
+        Some(&EVENT_QUERY_PAGE_SIZE)
+    );
+}
+
+/// 16. Test: Get Intent Events Resumes From Per-Account Versions
+/// Verifies that get_intent_events() starts each account at the sequence number passed
+/// in `resume_versions` and omits accounts that returned no new transactions.
+/// Why: Restarting at 0 every poll rereads the same capped pages, so a requester with
+/// more transactions than the cap would never have new intents seen.
+#[tokio::test]
+async fn test_get_intent_events_resumes_from_versions() {
+    let mock_server = MockServer::start().await;
+    let base_url = mock_server.uri().to_string();
+    let account_path = format!(
+        "/v1/accounts/{}/transactions",
+        DUMMY_REQUESTER_ADDR_HUB.strip_prefix("0x").unwrap()
+    );
+    let resume_at: u64 = 5_000;
+    let intent_id = "0x00000000000000000000000000000000000000000000000000000000000000cc";
+    let mut tx = limit_order_tx("0xc3c3", intent_id, DUMMY_REQUESTER_ADDR_HUB);
+    tx["sequence_number"] = json!(resume_at.to_string());
+
+    Mock::given(method("GET"))
+        .and(path(account_path.clone()))
+        .and(query_param("start", resume_at.to_string()))
+        .respond_with(ResponseTemplate::new(200).set_body_json(json!([tx])))
+        .mount(&mock_server)
+        .await;
+    Mock::given(method("GET"))
+        .and(path(account_path))
+        .and(query_param("start", (resume_at + 1).to_string()))
+        .respond_with(ResponseTemplate::new(200).set_body_json(json!([])))
+        .mount(&mock_server)
+        .await;
+
+    let mut config = create_default_hub_chain_config();
+    config.rpc_url = base_url;
+    let client = HubChainClient::new(&config).unwrap();
+
+    let accounts = vec![DUMMY_REQUESTER_ADDR_HUB.to_string()];
+    let resume_versions = HashMap::from([(DUMMY_REQUESTER_ADDR_HUB.to_string(), resume_at)]);
+    let (events, _, next_versions) = client
+        .get_intent_events(
+            &accounts,
+            &resume_versions,
+            None,
+            DEFAULT_EVENT_QUERY_MAX_PAGES,
+        )
+        .await
+        .unwrap();
+    assert_eq!(events.len(), 1);
+    assert_eq!(events[0].intent_id, intent_id);
+    assert_eq!(
+        next_versions.get(DUMMY_REQUESTER_ADDR_HUB),
+        Some(&(resume_at + 1))
+    );
+
+    // Nothing new since the last poll: no events and no resume entry for the account
+    let (events, _, next_versions) = client
+        .get_intent_events(
+            &accounts,
+            &next_versions,
+            None,
+            DEFAULT_EVENT_QUERY_MAX_PAGES,
+        )
+        .await
+        .unwrap();
+    assert!(events.is_empty());
+    assert!(next_versions.is_empty());
+}
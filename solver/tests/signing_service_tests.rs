@@ -15,6 +15,8 @@ use serde_json::json;
 use solver::service::parse_draft_data;
 use solver::service::liquidity::LiquidityMonitor;
 use std::sync::Arc;
+use wiremock::matchers::{method, path};
+use wiremock::{Mock, MockServer, ResponseTemplate};
 
 // ============================================================================
 // HELPER FUNCTIONS
@@ -59,7 +61,12 @@ fn create_test_solver_config() -> solver::config::SolverConfig {
                 ratio: 0.5,
                 fee_bps: 50,
                 move_rate: 0.5,
+                min_margin_bps: None,
             }],
+            bidirectional: false,
+            verify_token_metadata: false,
+            requester_allowlist: Default::default(),
+            requester_denylist: Default::default(),
         },
         liquidity: liq,
         ..create_default_solver_config()
@@ -73,6 +80,35 @@ fn create_test_liquidity_monitor(config: &solver::config::SolverConfig) -> Arc<L
     Arc::new(LiquidityMonitor::new(config.clone(), config.liquidity.clone()).unwrap())
 }
 
+/// Seed the monitor with enough balance for the default draft to pass liquidity checks.
+/// Seeds both the intent token AND gas tokens on all referenced chains.
+async fn seed_sufficient_liquidity(monitor: &LiquidityMonitor) {
+    use solver::service::liquidity::ChainToken;
+
+    let mut state = monitor.state().write().await;
+    // Target intent token on chain 2
+    let target = ChainToken {
+        chain_id: 2,
+        token: DUMMY_TOKEN_ADDR_MVMCON.to_string(),
+    };
+    let liq = state.get_mut(&target).expect("test setup: target token must be in state");
+    liq.confirmed_balance = 1_000_000;
+    // Gas token (MOVE) on target chain 2
+    let gas_target = ChainToken {
+        chain_id: 2,
+        token: GAS_TOKEN_MVM.to_string(),
+    };
+    let liq = state.get_mut(&gas_target).expect("test setup: gas target token must be in state");
+    liq.confirmed_balance = 1_000_000;
+    // Gas token (MOVE) on source chain 1
+    let gas_source = ChainToken {
+        chain_id: 1,
+        token: GAS_TOKEN_MVM.to_string(),
+    };
+    let liq = state.get_mut(&gas_source).expect("test setup: gas source token must be in state");
+    liq.confirmed_balance = 1_000_000;
+}
+
 /// Create a PendingDraft with specified expiry time
 fn create_test_pending_draft(expiry_time: u64) -> solver::coordinator_client::PendingDraft {
     solver::coordinator_client::PendingDraft {
@@ -362,39 +398,13 @@ async fn test_process_draft_rejects_expired_draft() {
 // Why: Ensure valid drafts are not rejected due to expiry check and proceed to signing.
 #[tokio::test]
 async fn test_process_draft_accepts_non_expired_draft() {
-    use solver::service::liquidity::ChainToken;
-
     let config = create_test_solver_config();
     let tracker = Arc::new(solver::service::IntentTracker::new(&config).unwrap());
     let monitor = create_test_liquidity_monitor(&config);
 
     // Seed the monitor with sufficient balance so the draft passes liquidity checks
     // and proceeds to signing (which will fail due to missing profile/private key).
-    // Must seed both the intent token AND gas tokens on all referenced chains.
-    {
-        let mut state = monitor.state().write().await;
-        // Target intent token on chain 2
-        let target = ChainToken {
-            chain_id: 2,
-            token: DUMMY_TOKEN_ADDR_MVMCON.to_string(),
-        };
-        let liq = state.get_mut(&target).expect("test setup: target token must be in state");
-        liq.confirmed_balance = 1_000_000;
-        // Gas token (MOVE) on target chain 2
-        let gas_target = ChainToken {
-            chain_id: 2,
-            token: GAS_TOKEN_MVM.to_string(),
-        };
-        let liq = state.get_mut(&gas_target).expect("test setup: gas target token must be in state");
-        liq.confirmed_balance = 1_000_000;
-        // Gas token (MOVE) on source chain 1
-        let gas_source = ChainToken {
-            chain_id: 1,
-            token: GAS_TOKEN_MVM.to_string(),
-        };
-        let liq = state.get_mut(&gas_source).expect("test setup: gas source token must be in state");
-        liq.confirmed_balance = 1_000_000;
-    }
+    seed_sufficient_liquidity(&monitor).await;
 
     let service = solver::service::SigningService::new(config, tracker, monitor).unwrap();
 
@@ -443,3 +453,85 @@ async fn test_process_draft_rejects_draft_at_expiry_boundary() {
     let result = service.process_draft(&draft_at_boundary).await;
     assert!(result.is_ok());
 }
+
+// ============================================================================
+// TOKEN METADATA RESOLUTION TESTS
+// ============================================================================
+
+/// Create a test config with verify_token_metadata enabled and all MVM RPCs pointed at `rpc_url`.
+fn create_token_verifying_solver_config(rpc_url: &str) -> solver::config::SolverConfig {
+    use solver::config::ConnectedChainConfig;
+
+    let mut config = create_test_solver_config();
+    config.acceptance.verify_token_metadata = true;
+    config.hub_chain.rpc_url = rpc_url.to_string();
+    for chain in &mut config.connected_chain {
+        if let ConnectedChainConfig::Mvm(cfg) = chain {
+            cfg.rpc_url = rpc_url.to_string();
+        }
+    }
+    config
+}
+
+/// Mount a fungible asset metadata resource response for `token`.
+async fn mount_metadata_resource(mock_server: &MockServer, token: &str, status: u16) {
+    Mock::given(method("GET"))
+        .and(path(format!(
+            "/v1/accounts/{}/resource/0x1::fungible_asset::Metadata",
+            token
+        )))
+        .respond_with(ResponseTemplate::new(status).set_body_json(json!({})))
+        .mount(mock_server)
+        .await;
+}
+
+// 17. Test: process_draft() accepts drafts whose tokens resolve on their chains
+// Verifies that with verify_token_metadata enabled, a draft whose offered and desired tokens both hold fungible asset metadata passes the resolver and reaches the signing step.
+// Why: Token verification must not block legitimate drafts.
+#[tokio::test]
+async fn test_process_draft_accepts_resolvable_tokens() {
+    let mock_server = MockServer::start().await;
+    mount_metadata_resource(&mock_server, DUMMY_TOKEN_ADDR_HUB, 200).await;
+    mount_metadata_resource(&mock_server, DUMMY_TOKEN_ADDR_MVMCON, 200).await;
+
+    let config = create_token_verifying_solver_config(&mock_server.uri());
+    let tracker = Arc::new(solver::service::IntentTracker::new(&config).unwrap());
+    let monitor = create_test_liquidity_monitor(&config);
+    seed_sufficient_liquidity(&monitor).await;
+    let service = solver::service::SigningService::new(config, tracker, monitor).unwrap();
+
+    let current_time = std::time::SystemTime::now()
+        .duration_since(std::time::UNIX_EPOCH)
+        .unwrap()
+        .as_secs();
+    let draft = create_test_pending_draft(current_time + 1000);
+
+    // Fails only at signing (no profile/private key), i.e. after the resolver accepted
+    let result = service.process_draft(&draft).await;
+    assert!(result.is_err(), "Draft should reach signing, got {:?}", result);
+}
+
+// 18. Test: process_draft() rejects drafts whose token does not resolve
+// Verifies that with verify_token_metadata enabled, a draft whose desired token has no fungible asset metadata on the desired chain returns Ok(false) without signing.
+// Why: Matching token address text on the wrong chain must not be enough to get a draft signed.
+#[tokio::test]
+async fn test_process_draft_rejects_unresolvable_token() {
+    let mock_server = MockServer::start().await;
+    mount_metadata_resource(&mock_server, DUMMY_TOKEN_ADDR_HUB, 200).await;
+    mount_metadata_resource(&mock_server, DUMMY_TOKEN_ADDR_MVMCON, 404).await;
+
+    let config = create_token_verifying_solver_config(&mock_server.uri());
+    let tracker = Arc::new(solver::service::IntentTracker::new(&config).unwrap());
+    let monitor = create_test_liquidity_monitor(&config);
+    seed_sufficient_liquidity(&monitor).await;
+    let service = solver::service::SigningService::new(config, tracker, monitor).unwrap();
+
+    let current_time = std::time::SystemTime::now()
+        .duration_since(std::time::UNIX_EPOCH)
+        .unwrap()
+        .as_secs();
+    let draft = create_test_pending_draft(current_time + 1000);
+
+    let result = service.process_draft(&draft).await.unwrap();
+    assert!(!result);
+}
@@ -1,15 +1,19 @@
 //! Unit tests for intent tracker
 
+use serde_json::json;
 use solver::{
-    acceptance::DraftintentData, service::tracker::IntentTracker,
+    acceptance::DraftintentData, has_min_expiry_remaining, service::tracker::IntentTracker,
     IntentState, MAX_INFLOW_RETRIES, MAX_OUTFLOW_RETRIES,
 };
+use wiremock::matchers::{body_partial_json, method, path};
+use wiremock::{Mock, MockServer, ResponseTemplate};
 
 #[path = "helpers.rs"]
 mod test_helpers;
 use test_helpers::{
-    create_default_solver_config, DUMMY_DRAFT_ID, DUMMY_EXPIRY, DUMMY_INTENT_ID,
-    DUMMY_REQUESTER_ADDR_EVM, DUMMY_TOKEN_ADDR_MVMCON, DUMMY_TOKEN_ADDR_HUB,
+    create_default_solver_config, DUMMY_DRAFT_ID, DUMMY_EXPIRY, DUMMY_INTENT_ADDR_HUB,
+    DUMMY_INTENT_ID, DUMMY_MODULE_ADDR_HUB, DUMMY_REQUESTER_ADDR_EVM, DUMMY_TOKEN_ADDR_HUB,
+    DUMMY_TOKEN_ADDR_MVMCON,
 };
 
 /// Past expiry time (already expired)
@@ -747,3 +751,290 @@ async fn test_outflow_failure_backoff_increases_exponentially() {
     assert!(second_retry_after > first_retry_after);
 }
 
+
+// ============================================================================
+// MIN EXPIRY REMAINING TESTS
+// ============================================================================
+
+/// Minimum expiry window used by the min_expiry_remaining tests (seconds)
+const MIN_EXPIRY_REMAINING_SECS: u64 = 600;
+
+/// Returns the current Unix timestamp in seconds
+fn now_secs() -> u64 {
+    std::time::SystemTime::now()
+        .duration_since(std::time::UNIX_EPOCH)
+        .unwrap()
+        .as_secs()
+}
+
+/// Create a tracker whose service config enforces MIN_EXPIRY_REMAINING_SECS
+fn create_tracker_with_min_expiry_remaining() -> IntentTracker {
+    let mut config = create_default_solver_config();
+    config.service.min_expiry_remaining_secs = MIN_EXPIRY_REMAINING_SECS;
+    IntentTracker::new(&config).unwrap()
+}
+
+// 24. Test: has_min_expiry_remaining() accepts, rejects, and handles expired intents
+// Verifies that has_min_expiry_remaining() returns true when the remaining time meets the minimum, false when it is one second short, and false when the expiry has already passed.
+// Why: The gate must skip intents whose fulfillment could not settle before the escrow expires.
+#[test]
+fn test_has_min_expiry_remaining() {
+    let now = 1_000_000;
+    assert!(has_min_expiry_remaining(now + 600, now, 600));
+    assert!(has_min_expiry_remaining(now + 3600, now, 600));
+    assert!(!has_min_expiry_remaining(now + 599, now, 600));
+    assert!(!has_min_expiry_remaining(now - 1, now, 600));
+    // A minimum of 0 only excludes already expired intents
+    assert!(has_min_expiry_remaining(now, now, 0));
+    assert!(!has_min_expiry_remaining(now - 1, now, 0));
+}
+
+// 25. Test: Intents with ample time before expiry are ready for fulfillment
+// Verifies that get_intents_ready_for_fulfillment() returns a Created intent whose expiry is well beyond min_expiry_remaining_secs.
+// Why: The gate must not block intents that have enough time to settle.
+#[tokio::test]
+async fn test_min_expiry_remaining_ample_time_accepted() {
+    let tracker = create_tracker_with_min_expiry_remaining();
+
+    tracker
+        .add_signed_intent(
+            DUMMY_DRAFT_ID.to_string(),
+            create_default_draft_data_inflow(),
+            DUMMY_REQUESTER_ADDR_EVM.to_string(),
+            now_secs() + MIN_EXPIRY_REMAINING_SECS * 10,
+        )
+        .await
+        .unwrap();
+    tracker.set_intent_state(DUMMY_DRAFT_ID, IntentState::Created).await.unwrap();
+
+    let intents = tracker.get_intents_ready_for_fulfillment(None).await;
+    assert_eq!(intents.len(), 1);
+    assert_eq!(intents[0].draft_id, DUMMY_DRAFT_ID);
+}
+
+// 26. Test: Intents just under the min expiry threshold are skipped
+// Verifies that get_intents_ready_for_fulfillment() excludes a Created intent whose remaining time is below min_expiry_remaining_secs even though it has not yet expired.
+// Why: Fulfilling an intent that close to expiry wastes gas because the claim would fail after the escrow expires.
+#[tokio::test]
+async fn test_min_expiry_remaining_under_threshold_skipped() {
+    let tracker = create_tracker_with_min_expiry_remaining();
+
+    tracker
+        .add_signed_intent(
+            DUMMY_DRAFT_ID.to_string(),
+            create_default_draft_data_inflow(),
+            DUMMY_REQUESTER_ADDR_EVM.to_string(),
+            now_secs() + MIN_EXPIRY_REMAINING_SECS - 10,
+        )
+        .await
+        .unwrap();
+    tracker.set_intent_state(DUMMY_DRAFT_ID, IntentState::Created).await.unwrap();
+
+    let intents = tracker.get_intents_ready_for_fulfillment(None).await;
+    assert!(intents.is_empty());
+
+    // The intent stays tracked in Created state; it is only skipped for fulfillment
+    let tracked = tracker.get_intent(DUMMY_DRAFT_ID).await.unwrap();
+    assert_eq!(tracked.state, IntentState::Created);
+}
+
+// 27. Test: Already expired intents are skipped when min_expiry_remaining_secs is set
+// Verifies that get_intents_ready_for_fulfillment() excludes a Created intent whose expiry has passed when a min_expiry_remaining_secs gate is configured.
+// Why: Expired intents must never be fulfilled regardless of the gate configuration.
+#[tokio::test]
+async fn test_min_expiry_remaining_expired_skipped() {
+    let tracker = create_tracker_with_min_expiry_remaining();
+
+    tracker
+        .add_signed_intent(
+            DUMMY_DRAFT_ID.to_string(),
+            create_default_draft_data_inflow(),
+            DUMMY_REQUESTER_ADDR_EVM.to_string(),
+            PAST_EXPIRY,
+        )
+        .await
+        .unwrap();
+    tracker.set_intent_state(DUMMY_DRAFT_ID, IntentState::Created).await.unwrap();
+
+    let intents = tracker.get_intents_ready_for_fulfillment(None).await;
+    assert!(intents.is_empty());
+}
+
+// ============================================================================
+// INTENT ID NORMALIZATION TESTS
+// ============================================================================
+
+// 28. Test: Tracker lookups by intent_id accept any canonical-equivalent form
+// Verifies that record_outflow_failure() and mark_outflow_attempted() find a tracked intent when called with the unpadded or unprefixed rendering of its 64-char intent_id.
+// Why: Chains render intent IDs with or without leading zeros; a lookup mismatch would strand the intent.
+#[tokio::test]
+async fn test_tracker_intent_id_lookup_normalized() {
+    let config = create_default_solver_config();
+    let tracker = IntentTracker::new(&config).unwrap();
+
+    tracker
+        .add_signed_intent(
+            DUMMY_DRAFT_ID.to_string(),
+            create_default_draft_data_outflow(),
+            DUMMY_REQUESTER_ADDR_EVM.to_string(),
+            DUMMY_EXPIRY,
+        )
+        .await
+        .unwrap();
+    tracker.set_intent_state(DUMMY_DRAFT_ID, IntentState::Created).await.unwrap();
+
+    // DUMMY_INTENT_ID is 0x00..01; look it up by its minimal and unprefixed forms
+    tracker.record_outflow_failure("0x1", "fail").await.unwrap();
+    tracker
+        .mark_outflow_attempted(DUMMY_INTENT_ID.strip_prefix("0x").unwrap())
+        .await
+        .unwrap();
+
+    let tracked = tracker.get_intent(DUMMY_DRAFT_ID).await.unwrap();
+    assert_eq!(tracked.outflow_attempt_count, 1);
+    assert!(tracked.outflow_attempted);
+}
+
+// ============================================================================
+// IN-FLIGHT CAP TESTS
+// ============================================================================
+
+// 29. Test: A third concurrent intent is deferred until one of the first two settles
+// Verifies that with max_inflight_intents = 2, has_inflight_capacity() is false while two intents are Signed/Created, and becomes true once one of them is fulfilled or fails.
+// Why: Committing to more intents than the solver can fund causes a wave of failed fulfillments; settled intents must free their slot.
+#[tokio::test]
+async fn test_inflight_cap_defers_until_slot_frees() {
+    let mut config = create_default_solver_config();
+    config.service.max_inflight_intents = 2;
+    let tracker = IntentTracker::new(&config).unwrap();
+
+    for (draft_id, intent_id) in [("draft-1", "0x01"), ("draft-2", "0x02")] {
+        let mut draft_data = create_default_draft_data_inflow();
+        draft_data.intent_id = intent_id.to_string();
+        tracker
+            .add_signed_intent(
+                draft_id.to_string(),
+                draft_data,
+                DUMMY_REQUESTER_ADDR_EVM.to_string(),
+                DUMMY_EXPIRY,
+            )
+            .await
+            .unwrap();
+    }
+    tracker.set_intent_state("draft-2", IntentState::Created).await.unwrap();
+
+    // Two in flight: a third draft must be deferred
+    assert_eq!(tracker.inflight_count().await, 2);
+    assert!(!tracker.has_inflight_capacity().await);
+
+    // First intent settles: a slot frees up for the third
+    tracker.mark_fulfilled("draft-1").await.unwrap();
+    assert_eq!(tracker.inflight_count().await, 1);
+    assert!(tracker.has_inflight_capacity().await);
+
+    // A failed intent also releases its slot
+    tracker.set_intent_state("draft-2", IntentState::Failed).await.unwrap();
+    assert_eq!(tracker.inflight_count().await, 0);
+}
+
+// ============================================================================
+// PRE-FULFILLMENT OPEN CHECK TESTS
+// ============================================================================
+
+/// Tracks a Created outflow intent whose hub view calls are answered by a mock
+/// returning `registered` for `intent_registry::is_intent_registered`.
+async fn setup_created_intent_with_hub_view(
+    registered: bool,
+) -> (MockServer, IntentTracker, solver::TrackedIntent) {
+    let mock_server = MockServer::start().await;
+    Mock::given(method("POST"))
+        .and(path("/v1/view"))
+        .and(body_partial_json(json!({
+            "function": format!("{}::intent_registry::is_intent_registered", DUMMY_MODULE_ADDR_HUB)
+        })))
+        .respond_with(ResponseTemplate::new(200).set_body_json(json!([registered])))
+        .expect(1)
+        .mount(&mock_server)
+        .await;
+
+    let mut config = create_default_solver_config();
+    config.hub_chain.rpc_url = mock_server.uri();
+    config.service.verify_intent_open_before_fulfill = true;
+    let tracker = IntentTracker::new(&config).unwrap();
+
+    tracker
+        .add_signed_intent(
+            DUMMY_DRAFT_ID.to_string(),
+            create_default_draft_data_outflow(),
+            DUMMY_REQUESTER_ADDR_EVM.to_string(),
+            DUMMY_EXPIRY,
+        )
+        .await
+        .unwrap();
+    tracker
+        .set_intent_state(DUMMY_DRAFT_ID, IntentState::Created)
+        .await
+        .unwrap();
+    let mut intent = tracker.get_intent(DUMMY_DRAFT_ID).await.unwrap();
+    intent.intent_addr = Some(DUMMY_INTENT_ADDR_HUB.to_string());
+
+    (mock_server, tracker, intent)
+}
+
+// 30. Test: An intent still open on the hub proceeds to fulfillment
+// Verifies that verify_intent_open() returns true when the hub reports the intent as registered, and leaves the intent in Created state.
+// Why: The re-check must not block fulfillment of intents that are still open.
+#[tokio::test]
+async fn test_verify_intent_open_proceeds_when_open() {
+    let (_mock_server, tracker, intent) = setup_created_intent_with_hub_view(true).await;
+
+    assert!(tracker.verify_intent_open(&intent).await.unwrap());
+    let tracked = tracker.get_intent(DUMMY_DRAFT_ID).await.unwrap();
+    assert_eq!(tracked.state, IntentState::Created);
+}
+
+// 31. Test: An intent already fulfilled or cancelled on the hub is skipped
+// Verifies that verify_intent_open() returns false when the hub no longer has the intent registered, and moves the intent to Failed so it is not retried.
+// Why: Fulfilling an intent that is already closed wastes the solver's gas on a transaction that cannot succeed.
+#[tokio::test]
+async fn test_verify_intent_open_skips_closed_intent() {
+    let (_mock_server, tracker, intent) = setup_created_intent_with_hub_view(false).await;
+
+    assert!(!tracker.verify_intent_open(&intent).await.unwrap());
+    let tracked = tracker.get_intent(DUMMY_DRAFT_ID).await.unwrap();
+    assert_eq!(tracked.state, IntentState::Failed);
+    assert!(tracker
+        .get_intents_ready_for_fulfillment(None)
+        .await
+        .is_empty());
+}
+
+// 32. Test: The open check makes no RPC when disabled
+// Verifies that verify_intent_open() returns true without querying the hub when verify_intent_open_before_fulfill is false.
+// Why: The check costs an RPC per fulfillment; operators who leave it off must not pay for it.
+#[tokio::test]
+async fn test_verify_intent_open_disabled_skips_rpc() {
+    let mock_server = MockServer::start().await;
+    Mock::given(method("POST"))
+        .respond_with(ResponseTemplate::new(200).set_body_json(json!([false])))
+        .expect(0)
+        .mount(&mock_server)
+        .await;
+
+    let mut config = create_default_solver_config();
+    config.hub_chain.rpc_url = mock_server.uri();
+    let tracker = IntentTracker::new(&config).unwrap();
+
+    tracker
+        .add_signed_intent(
+            DUMMY_DRAFT_ID.to_string(),
+            create_default_draft_data_outflow(),
+            DUMMY_REQUESTER_ADDR_EVM.to_string(),
+            DUMMY_EXPIRY,
+        )
+        .await
+        .unwrap();
+    let intent = tracker.get_intent(DUMMY_DRAFT_ID).await.unwrap();
+
+    assert!(tracker.verify_intent_open(&intent).await.unwrap());
+}
@@ -1,9 +1,13 @@
 //! Unit tests for transaction hash extraction from CLI output
 //!
 //! Tests the shared tx_hash module used by both MVM and EVM connected clients
-//! to parse transaction hashes from aptos CLI and Hardhat script output.
+//! to parse transaction hashes from aptos CLI and Hardhat script output, and
+//! base58 signatures from solana CLI output.
 
-use solver::chains::tx_hash::extract_tx_hash;
+use solver::chains::tx_hash::{
+    extract_labeled_tx_hash, extract_signature, extract_tx_hash, extract_tx_identifier,
+    TxIdentifier,
+};
 
 // ============================================================================
 // JSON Parsing Tests
@@ -61,3 +65,114 @@ fn test_extract_hardhat_output() {
     let output = "Solver address: 0xf39...\nTransaction hash: 0xfeed1234\nBlock number: 42";
     assert_eq!(extract_tx_hash(output, "hardhat").unwrap(), "0xfeed1234");
 }
+
+// ============================================================================
+// Solana Signature Tests
+// ============================================================================
+
+/// A valid base58-encoded 64-byte signature
+fn dummy_solana_signature() -> String {
+    bs58::encode([7u8; 64]).into_string()
+}
+
+// 6. Test: extract_signature parses solana CLI Signature line
+// Verifies that extract_signature returns the base58 value from a `Signature: <base58>` line among other solana CLI output.
+// Why: The solana CLI reports transactions by signature, not by a 0x hash.
+#[test]
+fn test_extract_signature_from_solana_output() {
+    let signature = dummy_solana_signature();
+    let output = format!("Transfer 1 SOL\nSignature: {}\n", signature);
+    assert_eq!(extract_signature(&output, "solana").unwrap(), signature);
+}
+
+// 7. Test: extract_signature rejects values that are not 64-byte base58 signatures
+// Verifies that extract_signature fails for a hex hash, a truncated base58 value, and output without a Signature line.
+// Why: A malformed value recorded as a signature could never be looked up on-chain.
+#[test]
+fn test_extract_signature_rejects_invalid_values() {
+    assert!(extract_signature("Signature: 0xfeed1234", "solana").is_err());
+    assert!(extract_signature("Signature: 3yZe7d", "solana").is_err());
+    assert!(extract_signature("Transaction hash: 0xfeed1234", "solana").is_err());
+}
+
+// 8. Test: extract_tx_identifier distinguishes hex hashes from solana signatures
+// Verifies that aptos JSON and traditional `Transaction hash:` output yield TxIdentifier::Hash, and a solana `Signature:` line yields TxIdentifier::Signature.
+// Why: Callers must know whether to query an EVM/Aptos node by hash or a Solana node by signature.
+#[test]
+fn test_extract_tx_identifier_distinguishes_formats() {
+    let aptos = r#"{"Result": {"transaction_hash": "0xabc123", "gas_used": 100}}"#;
+    assert_eq!(
+        extract_tx_identifier(aptos, "aptos").unwrap(),
+        TxIdentifier::Hash("0xabc123".to_string())
+    );
+
+    let traditional = "Transaction hash: 0xdef456";
+    assert_eq!(
+        extract_tx_identifier(traditional, "hardhat").unwrap(),
+        TxIdentifier::Hash("0xdef456".to_string())
+    );
+
+    let signature = dummy_solana_signature();
+    let solana = format!("Signature: {}", signature);
+    assert_eq!(
+        extract_tx_identifier(&solana, "solana").unwrap(),
+        TxIdentifier::Signature(signature)
+    );
+
+    assert!(extract_tx_identifier("nothing here", "test").is_err());
+}
+
+// ============================================================================
+// Labeled Hash Tests
+// ============================================================================
+
+// 9. Test: extract_labeled_tx_hash ignores other hashes in Hardhat output
+// Verifies that only the 32-byte value on the `Transaction hash:` line is returned when the output also contains a block hash, an approval tx hash and addresses.
+// Why: First-match scraping of any "hash" line or 0x token would record the wrong transaction.
+#[test]
+fn test_extract_labeled_tx_hash_ignores_other_hashes() {
+    let tx_hash = format!("0x{}", "ab".repeat(32));
+    let block_hash = format!("0x{}", "cd".repeat(32));
+    let approval_hash = format!("0x{}", "ef".repeat(32));
+    let output = format!(
+        "Solver address: 0xf39Fd6e51aad88F6F4ce6aB8827279cffFb92266\n\
+         Block hash: {}\n\
+         Approval tx: {}\n\
+         Transaction hash: {}\n\
+         Block number: 42",
+        block_hash, approval_hash, tx_hash
+    );
+    assert_eq!(
+        extract_labeled_tx_hash(&output, "Transaction hash:"),
+        Some(tx_hash.clone())
+    );
+
+    let lowercase = format!("  transaction HASH: {}", tx_hash);
+    assert_eq!(
+        extract_labeled_tx_hash(&lowercase, "Transaction hash:"),
+        Some(tx_hash)
+    );
+}
+
+// 10. Test: extract_labeled_tx_hash rejects 0x tokens that are not 32-byte hashes
+// Verifies that a labeled line carrying an address, a truncated hash or non-hex characters yields None.
+// Why: A value that is not a transaction hash can never be looked up on-chain.
+#[test]
+fn test_extract_labeled_tx_hash_rejects_non_hash_tokens() {
+    let label = "Transaction hash:";
+    assert_eq!(
+        extract_labeled_tx_hash(
+            "Transaction hash: 0xf39Fd6e51aad88F6F4ce6aB8827279cffFb92266",
+            label
+        ),
+        None
+    );
+    assert_eq!(
+        extract_labeled_tx_hash("Transaction hash: 0xfeed1234", label),
+        None
+    );
+    let non_hex = format!("Transaction hash: 0x{}", "zz".repeat(32));
+    assert_eq!(extract_labeled_tx_hash(&non_hex, label), None);
+    let unlabeled = format!("Block hash: 0x{}", "cd".repeat(32));
+    assert_eq!(extract_labeled_tx_hash(&unlabeled, label), None);
+}
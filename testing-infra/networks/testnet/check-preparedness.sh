@@ -1087,8 +1087,8 @@ if [ -z "$SOLANA_GMP_PROGRAM_ID" ] || [ "$SOLANA_GMP_PROGRAM_ID" = "" ]; then
     echo "      ❌ Locally Configured (not set in integrated-gmp_testnet.toml)"
 else
     deployed_status=$(check_solana_program "$SOLANA_GMP_PROGRAM_ID" "$SOLANA_RPC_URL"); mark "$deployed_status"
-    # ConfigAccount: disc=1 base64=AQ==, size=38
-    config_pda=$(check_solana_has_account "$SOLANA_GMP_PROGRAM_ID" "$SOLANA_RPC_URL" "AQ==" 38); mark "$config_pda"
+    # ConfigAccount: disc=1 base64=AQ==, size=39
+    config_pda=$(check_solana_has_account "$SOLANA_GMP_PROGRAM_ID" "$SOLANA_RPC_URL" "AQ==" 39); mark "$config_pda"
     # RemoteGmpEndpoint: disc=3 base64=Aw==, size=38
     remote_gmp_endpoint=$(check_solana_has_account "$SOLANA_GMP_PROGRAM_ID" "$SOLANA_RPC_URL" "Aw==" 38); mark "$remote_gmp_endpoint"
     # RoutingConfig: disc=6 base64=Bg==, size=66